@@ -1,7 +1,7 @@
 use crate::{
     balances,
     common_types::AssetId,
-    staking::MaxNameLength,
+    dao_manager::MaxNameLength,
     xcm_config::{
         AccountIdToMultiLocation, BaseXcmWeight, CurrencyIdConvert, MaxInstructions,
         ParachainMinFee, SelfLocation, UniversalLocation, XcmConfig,
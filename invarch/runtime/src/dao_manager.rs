@@ -1,7 +1,8 @@
 use crate::{
-    balances::DealWithFees, common_types::CommonId, AccountId, Balance, Balances, CoreAssets,
-    ParachainInfo, Runtime, RuntimeCall, RuntimeEvent, RuntimeHoldReason, RuntimeOrigin,
-    TransactionByteFee, UNIT,
+    balances::DealWithFees,
+    common_types::{AssetId, CommonId},
+    AccountId, Balance, Balances, BlockNumber, CoreAssets, ParachainInfo, Runtime, RuntimeCall,
+    RuntimeEvent, RuntimeHoldReason, RuntimeOrigin, TransactionByteFee, DAYS, UNIT,
 };
 use codec::{Decode, Encode};
 use frame_support::{
@@ -13,14 +14,23 @@ use frame_support::{
     },
     weights::ConstantMultiplier,
 };
-use pallet_dao_manager::fee_handling::{FeeAsset, FeeAssetNegativeImbalance, MultisigFeeHandler};
+use pallet_dao_manager::{
+    fee_handling::{FeeAsset, FeeAssetNegativeImbalance, MultisigFeeHandler},
+    voting::NoNfts,
+};
 use pallet_transaction_payment::ChargeTransactionPayment;
 use scale_info::TypeInfo;
 use sp_core::ConstU32;
-use sp_runtime::traits::{One, SignedExtension, Zero};
+use sp_runtime::{
+    traits::{One, SignedExtension, Zero},
+    Perbill,
+};
 
 parameter_types! {
     pub const MaxMetadata: u32 = 10000;
+    pub const MaxNameLength: u32 = 20;
+    pub const MaxDescriptionLength: u32 = 300;
+    pub const MaxUrlLength: u32 = 100;
     pub const MaxCallers: u32 = 10000;
     pub const DaoSeedBalance: Balance = 1000000u128;
     pub const DaoCreationFee: Balance = UNIT * 1000;
@@ -30,11 +40,35 @@ parameter_types! {
 
     pub ParaId: u32 = ParachainInfo::parachain_id().into();
 
-    pub const NoId: () = ();
+    pub const NoId: AssetId = 0;
+
+    pub const EmergencySupport: Perbill = Perbill::from_percent(80);
+    /// Pallets whose calls can all be executed through emergency proposals, to move the DAO's funds.
+    pub const EmergencyFundsPallets: &'static [&'static str] = &["Balances"];
+
+    pub const MaxJointDaos: u32 = 10;
+    pub const MaxNftVotingItems: u32 = 1000;
+
+    pub const MaxHandleLength: u32 = 32;
+    pub const HandleDeposit: Balance = UNIT * 10;
+
+    pub const MaxSymbolLength: u32 = 10;
+    pub const MaxVestingSchedules: u32 = 10;
+    pub const MaxCouncilMembers: u32 = 20;
+
+    pub const PublicProposalDeposit: Balance = UNIT * 100;
+    pub const PublicProposalDuration: BlockNumber = DAYS * 14;
 }
 
+/// Length a signed extrinsic adds to its call when estimating fees: 4 bytes for the length prefix, 1 for the version,
+/// 33 for the signer, 66 for the longest signature and 9 for the mortal era, nonce, tip and metadata hash mode.
+pub const SIGNED_EXTRINSIC_OVERHEAD: u32 = 113;
+
 impl pallet_dao_manager::Config for Runtime {
     type MaxMetadata = MaxMetadata;
+    type MaxNameLength = MaxNameLength;
+    type MaxDescriptionLength = MaxDescriptionLength;
+    type MaxUrlLength = MaxUrlLength;
     type DaoId = CommonId;
     type RuntimeEvent = RuntimeEvent;
     type Currency = Balances;
@@ -53,12 +87,29 @@ impl pallet_dao_manager::Config for Runtime {
     type MaxCallSize = MaxCallSize;
     type ParaId = ParaId;
     type LengthToFee = ConstantMultiplier<Balance, TransactionByteFee>;
+    type EmergencySupport = EmergencySupport;
+    type EmergencyCalls =
+        pallet_dao_manager::multisig::EmergencyCalls<Runtime, EmergencyFundsPallets>;
+    type NftCollectionId = CommonId;
+    type NftItemId = u32;
+    type Nfts = NoNfts<CommonId>;
+    type MaxNftVotingItems = MaxNftVotingItems;
+    type MaxJointDaos = MaxJointDaos;
+
+    type MaxHandleLength = MaxHandleLength;
+    type HandleDeposit = HandleDeposit;
+
+    type MaxSymbolLength = MaxSymbolLength;
+    type MaxVestingSchedules = MaxVestingSchedules;
+    type MaxCouncilMembers = MaxCouncilMembers;
+    type PublicProposalDeposit = PublicProposalDeposit;
+    type PublicProposalDuration = PublicProposalDuration;
 }
 
 pub struct NoTokens;
 
 impl Inspect<AccountId> for NoTokens {
-    type AssetId = ();
+    type AssetId = AssetId;
     type Balance = u128;
 
     fn total_issuance(_asset: Self::AssetId) -> Self::Balance {
@@ -129,8 +180,8 @@ impl Unbalanced<AccountId> for NoTokens {
 }
 
 pub struct NoHandle;
-impl frame_support::traits::tokens::fungibles::HandleImbalanceDrop<(), u128> for NoHandle {
-    fn handle(_asset: (), _amount: u128) {}
+impl frame_support::traits::tokens::fungibles::HandleImbalanceDrop<AssetId, u128> for NoHandle {
+    fn handle(_asset: AssetId, _amount: u128) {}
 }
 
 impl Balanced<AccountId> for NoTokens {
@@ -156,9 +207,11 @@ impl MultisigFeeHandler<Runtime> for FeeCharger {
             FeeAsset::Native => ChargeTransactionPayment::<Runtime>::from(Zero::zero())
                 .pre_dispatch(who, call, info, len),
 
-            FeeAsset::Relay => Err(frame_support::unsigned::TransactionValidityError::Invalid(
-                sp_runtime::transaction_validity::InvalidTransaction::Payment,
-            )),
+            FeeAsset::Relay | FeeAsset::Asset(_) => {
+                Err(frame_support::unsigned::TransactionValidityError::Invalid(
+                    sp_runtime::transaction_validity::InvalidTransaction::Payment,
+                ))
+            }
         }
     }
 
@@ -175,12 +228,18 @@ impl MultisigFeeHandler<Runtime> for FeeCharger {
                 pre, info, post_info, len, result,
             ),
 
-            FeeAsset::Relay => Err(frame_support::unsigned::TransactionValidityError::Invalid(
-                sp_runtime::transaction_validity::InvalidTransaction::Payment,
-            )),
+            FeeAsset::Relay | FeeAsset::Asset(_) => {
+                Err(frame_support::unsigned::TransactionValidityError::Invalid(
+                    sp_runtime::transaction_validity::InvalidTransaction::Payment,
+                ))
+            }
         }
     }
 
+    fn native_to_asset_fee(_asset_id: u32, _fee: Balance) -> Option<Balance> {
+        None
+    }
+
     fn handle_creation_fee(
         imbalance: FeeAssetNegativeImbalance<
             Credit<AccountId, Balances>,
@@ -190,7 +249,7 @@ impl MultisigFeeHandler<Runtime> for FeeCharger {
         match imbalance {
             FeeAssetNegativeImbalance::Native(imb) => DealWithFees::on_unbalanced(imb),
 
-            FeeAssetNegativeImbalance::Relay(_) => {}
+            FeeAssetNegativeImbalance::Relay(_) | FeeAssetNegativeImbalance::Asset(_) => {}
         }
     }
 }
@@ -218,20 +277,39 @@ impl
 {
     fn on_transfer(
         currency_id: <Runtime as pallet_dao_manager::Config>::DaoId,
-        _from: &AccountId,
+        from: &AccountId,
         _to: &AccountId,
-        _amount: Balance,
+        amount: Balance,
     ) -> sp_runtime::DispatchResult {
         if let Some(true) = crate::INV4::is_asset_frozen(currency_id) {
             Err(sp_runtime::DispatchError::Token(
                 sp_runtime::TokenError::Frozen,
             ))
         } else {
-            Ok(())
+            crate::INV4::ensure_unlocked(currency_id, from, amount)
         }
     }
 }
 
+pub struct UpdateQuadraticVotingPower;
+impl
+    orml_traits::currency::OnTransfer<
+        AccountId,
+        <Runtime as pallet_dao_manager::Config>::DaoId,
+        Balance,
+    > for UpdateQuadraticVotingPower
+{
+    fn on_transfer(
+        currency_id: <Runtime as pallet_dao_manager::Config>::DaoId,
+        from: &AccountId,
+        to: &AccountId,
+        amount: Balance,
+    ) -> sp_runtime::DispatchResult {
+        crate::INV4::on_voting_token_transfer(currency_id, from, to, amount);
+        Ok(())
+    }
+}
+
 pub struct HandleNewMembers;
 impl orml_traits::Happened<(AccountId, <Runtime as pallet_dao_manager::Config>::DaoId)>
     for HandleNewMembers
@@ -263,7 +341,7 @@ impl
     type OnSlash = ();
     type PreDeposit = ();
     type PostDeposit = ();
-    type PostTransfer = ();
+    type PostTransfer = UpdateQuadraticVotingPower;
     type OnNewTokenAccount = HandleNewMembers;
     type OnKilledTokenAccount = HandleRemovedMembers;
 }
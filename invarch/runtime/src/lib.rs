@@ -115,7 +115,17 @@ pub type Executive = frame_executive::Executive<
     frame_system::ChainContext<Runtime>,
     Runtime,
     AllPalletsWithSystem,
-    pallet_dao_staking::migrations::v1::MigrateToV1<Runtime>,
+    (
+        pallet_dao_staking::migrations::v1::MigrateToV1<Runtime>,
+        pallet_dao_staking::migrations::v2::MigrateToV2<Runtime>,
+        pallet_dao_staking::migrations::v3::MigrateToV3<Runtime>,
+        pallet_dao_staking::migrations::v4::MigrateToV4<Runtime>,
+        pallet_dao_manager::migrations::v3::MigrateToV3<Runtime>,
+        pallet_dao_manager::migrations::v4::MigrateToV4<Runtime>,
+        pallet_dao_manager::migrations::v5::MigrateToV5<Runtime>,
+        pallet_dao_manager::migrations::v6::MigrateToV6<Runtime>,
+        pallet_dao_manager::migrations::v7::MigrateToV7<Runtime>,
+    ),
 >;
 
 /// Maximum number of blocks simultaneously accepted by the Runtime, not yet included into the
@@ -822,6 +832,52 @@ impl_runtime_apis! {
     }
 }
 
+    impl pallet_dao_manager::dry_run::DaoManagerApi<Block, common_types::CommonId, AccountId, RuntimeCall, RuntimeEvent> for Runtime {
+        fn dry_run_proposal(
+            dao_id: common_types::CommonId,
+            call: RuntimeCall,
+            fee_asset: pallet_dao_manager::fee_handling::FeeAsset,
+            fee_payer: Option<AccountId>,
+        ) -> pallet_dao_manager::dry_run::ProposalDryRunEffects<RuntimeEvent> {
+            INV4::dry_run_proposal(
+                dao_id,
+                call,
+                fee_asset,
+                fee_payer,
+                <xcm_config::XcmRouter as xcm_builder::InspectMessageQueues>::get_messages,
+            )
+        }
+    }
+
+    impl pallet_dao_manager::fee_handling::DaoManagerFeeApi<Block, common_types::CommonId, Balance, RuntimeCall> for Runtime {
+        fn estimate_operate_multisig_fees(
+            dao_id: common_types::CommonId,
+            metadata_len: u32,
+            call: RuntimeCall,
+            fee_asset: pallet_dao_manager::fee_handling::FeeAsset,
+        ) -> Option<pallet_dao_manager::fee_handling::OperateMultisigFees<Balance>> {
+            // Only the native token can be used to pay for fees in this chain.
+            if fee_asset != pallet_dao_manager::fee_handling::FeeAsset::Native {
+                return None;
+            }
+
+            Some(INV4::estimate_operate_multisig_fees(
+                dao_id,
+                metadata_len,
+                call,
+                fee_asset,
+                dao_manager::SIGNED_EXTRINSIC_OVERHEAD,
+                |len, info| TransactionPayment::compute_fee(len, info, 0),
+            ))
+        }
+    }
+
+    impl pallet_dao_manager::token::DaoTokenApi<Block, common_types::CommonId, Balance> for Runtime {
+        fn token_info(dao_id: common_types::CommonId) -> Option<pallet_dao_manager::token::DaoTokenInfo<Balance>> {
+            INV4::token_info(dao_id)
+        }
+    }
+
     impl cumulus_primitives_core::CollectCollationInfo<Block> for Runtime {
         fn collect_collation_info(header: &<Block as BlockT>::Header) -> cumulus_primitives_core::CollationInfo {
             ParachainSystem::collect_collation_info(header)
@@ -5,21 +5,22 @@ use crate::{
 use cumulus_primitives_core::AggregateMessageOrigin;
 use frame_support::{parameter_types, PalletId};
 use pallet_dao_staking::primitives::CustomAggregateMessageOrigin;
+use sp_runtime::Perbill;
 parameter_types! {
     pub const BlocksPerEra: BlockNumber = DAYS;
     pub const RegisterDeposit: Balance = 5000 * UNIT;
     pub const MaxStakersPerDao: u32 = 10000;
     // Temporarily dropping down from 50 to 5.
     pub const MinimumStakingAmount: Balance = 5 * UNIT;
-    pub const MaxEraStakeValues: u32 = 5;
+    pub const MaxDaosPerClaim: u32 = 16;
+    pub const HistoryDepth: u32 = 84;
+    pub const InstantUnstakePenalty: Perbill = Perbill::from_percent(10);
+    pub const PoolDeposit: Balance = 500 * UNIT;
     pub const MaxUnlockingChunks: u32 = 5;
     pub const UnbondingPeriod: u32 = 28;
     pub const OcifStakingPot: PalletId = PalletId(*b"inv/stak");
     pub const RewardRatio: (u32, u32) = (60, 40);
     pub const StakeThresholdForActiveDao: Balance = 250_000 * UNIT;
-    pub const MaxNameLength: u32 = 20;
-    pub const MaxDescriptionLength: u32 = 300;
-    pub const MaxImageUrlLength: u32 = 100;
     pub const UnregisterOrigin: CustomAggregateMessageOrigin<AggregateMessageOrigin> = CustomAggregateMessageOrigin::UnregisterMessageOrigin;
 }
 
@@ -35,12 +36,14 @@ impl pallet_dao_staking::Config for Runtime {
     type MaxUnlocking = MaxUnlockingChunks;
     type UnbondingPeriod = UnbondingPeriod;
     type MinimumStakingAmount = MinimumStakingAmount;
-    type MaxEraStakeValues = MaxEraStakeValues;
     type RewardRatio = RewardRatio;
     type StakeThresholdForActiveDao = StakeThresholdForActiveDao;
-    type MaxNameLength = MaxNameLength;
-    type MaxDescriptionLength = MaxDescriptionLength;
-    type MaxImageUrlLength = MaxImageUrlLength;
+    type MaxDaosPerClaim = MaxDaosPerClaim;
+    type HistoryDepth = HistoryDepth;
+    type InstantUnstakePenalty = InstantUnstakePenalty;
+    type PoolDeposit = PoolDeposit;
+    // Receipt tokens are general assets registered in the asset registry and enabled per dao by Root.
+    type ReceiptAssets = crate::Tokens;
     type WeightInfo = pallet_dao_staking::weights::SubstrateWeight<Runtime>;
     type StakingMessage = frame_support::traits::EnqueueWithOrigin<MessageQueue, UnregisterOrigin>;
     type WeightToFee = crate::WeightToFee;
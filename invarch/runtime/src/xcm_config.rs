@@ -19,6 +19,7 @@ use orml_traits2::{
 use orml_xcm_support::{
     DepositToAlternative, IsNativeConcrete, MultiCurrencyAdapter, MultiNativeAsset,
 };
+use pallet_dao_manager::xcm_origin::{BridgedDaoManagerChains, DaoPluralityAsMirroredOrigin};
 use pallet_dao_staking::primitives::{
     CustomAggregateMessageOrigin, CustomMessageProcessor, CustomNarrowOriginToSibling,
     CustomParaIdToSibling,
@@ -96,6 +97,9 @@ pub type XcmOriginToTransactDispatchOrigin = (
     // Native converter for sibling Parachains; will convert to a `SiblingPara` origin when
     // recognized.
     SiblingParachainAsNative<cumulus_pallet_xcm::Origin, RuntimeOrigin>,
+    // Native converter for DAOs in the DAO manager chain across the Polkadot <> Kusama bridge; will
+    // convert to the `DaoOrigin` of the local DAO mirroring them. Mirrors are opt-in and set by each local DAO.
+    DaoPluralityAsMirroredOrigin<BridgedDaoManagerChains<RelayNetwork>, Runtime, RuntimeOrigin>,
     // Native signed account converter; this just converts an `AccountId32` origin into a normal
     // `Origin::Signed` origin of the same 32-byte value.
     SignedAccountId32AsNative<RelayNetwork, RuntimeOrigin>,
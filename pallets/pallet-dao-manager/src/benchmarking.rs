@@ -2,12 +2,17 @@
 
 use super::*;
 use crate::{
+    bicameral::ChamberOf,
+    dao_manager_core::DaoMetadataOf,
     fee_handling::FeeAsset,
     multisig::MAX_SIZE,
     origin::{DaoOrigin, MultisigInternalOrigin},
+    token::DaoTokenMetadata,
+    vesting::{VestingSchedule, VestingScheduleOf},
     voting::{Tally, Vote},
     BalanceOf,
 };
+use codec::Decode;
 use core::convert::TryFrom;
 use frame_benchmarking::{account, benchmarks, whitelisted_caller};
 use frame_support::{
@@ -17,15 +22,21 @@ use frame_support::{
         fungible::{Inspect, Mutate},
         Get,
     },
-    BoundedBTreeMap, BoundedVec,
+    BoundedBTreeMap, BoundedBTreeSet, BoundedVec,
 };
 use frame_system::RawOrigin as SystemOrigin;
+use primitives::{Chamber, ChamberMembers, DaoMetadata, VotingStrategy};
 use sp_runtime::{
-    traits::{Bounded, Hash, Zero},
+    traits::{Bounded, Hash, TrailingZeroInput, Zero},
     DispatchError, DispatchErrorWithPostInfo, Perbill,
 };
 use sp_std::{
-    collections::btree_map::BTreeMap, convert::TryInto, iter::Sum, ops::Div, prelude::*, vec,
+    collections::{btree_map::BTreeMap, btree_set::BTreeSet},
+    convert::TryInto,
+    iter::Sum,
+    ops::Div,
+    prelude::*,
+    vec,
 };
 
 use crate::Pallet as dao_manager;
@@ -62,13 +73,20 @@ where
 
     dao_manager::<T>::create_dao(
         SystemOrigin::Signed(whitelisted_caller()).into(),
-        vec![].try_into().unwrap(),
+        Default::default(),
         perbill_one(),
         perbill_one(),
         FeeAsset::Native,
     )
 }
 
+fn mock_metadata<T: Config>(description_length: u32) -> DaoMetadataOf<T> {
+    DaoMetadata {
+        description: vec![b'a'; description_length as usize].try_into().unwrap(),
+        ..Default::default()
+    }
+}
+
 fn mock_mint<T: Config>() -> Result<(), DispatchError>
 where
     Result<DaoOrigin<T>, <T as frame_system::Config>::RuntimeOrigin>:
@@ -136,6 +154,43 @@ where
     )
 }
 
+fn mock_joint_calls<T: Config>(
+    d: u32,
+    z: u32,
+) -> BoundedVec<(T::DaoId, <T as Config>::RuntimeCall), T::MaxJointDaos> {
+    (0..d)
+        .map(|dao_id| {
+            (
+                dao_id.into(),
+                frame_system::Call::<T>::remark {
+                    remark: vec![0; if dao_id == 0 { z as usize } else { 0 }],
+                }
+                .into(),
+            )
+        })
+        .collect::<Vec<_>>()
+        .try_into()
+        .unwrap()
+}
+
+fn mock_joint_call<T: Config>(
+    d: u32,
+) -> Result<PostDispatchInfo, DispatchErrorWithPostInfo<PostDispatchInfo>>
+where
+    Result<DaoOrigin<T>, <T as frame_system::Config>::RuntimeOrigin>:
+        From<<T as frame_system::Config>::RuntimeOrigin>,
+    <<T as Config>::Currency as Inspect<<T as frame_system::Config>::AccountId>>::Balance: Sum,
+    <T as frame_system::Config>::RuntimeOrigin: From<DaoOrigin<T>>,
+    T::AccountId: From<[u8; 32]>,
+{
+    dao_manager::<T>::operate_joint_multisig(
+        SystemOrigin::Signed(whitelisted_caller()).into(),
+        mock_joint_calls::<T>(d, 1),
+        None,
+        FeeAsset::Native,
+    )
+}
+
 benchmarks! {
 
     where_clause {
@@ -150,9 +205,9 @@ benchmarks! {
 }
 
     create_dao {
-        let m in 0 .. T::MaxMetadata::get();
+        let m in 0 .. T::MaxDescriptionLength::get();
 
-        let metadata: BoundedVec<u8, T::MaxMetadata> = vec![u8::MAX; m as usize].try_into().unwrap();
+        let metadata = mock_metadata::<T>(m);
         let caller = whitelisted_caller();
         let minimum_support = perbill_one();
         let required_approval = perbill_one();
@@ -164,18 +219,18 @@ benchmarks! {
             assert_last_event::<T>(Event::DaoCreated {
                 dao_account: derive_account::<T>(0u32.into()),
                 dao_id: 0u32.into(),
-                metadata: metadata.to_vec(),
+                metadata,
                 minimum_support,
                 required_approval
             }.into());
         }
 
     set_parameters {
-        let m in 0 .. T::MaxMetadata::get();
+        let m in 0 .. T::MaxDescriptionLength::get();
 
         mock_dao().unwrap();
 
-        let metadata: Option<BoundedVec<u8, T::MaxMetadata>> = Some(vec![u8::MAX; m as usize].try_into().unwrap());
+        let metadata = Some(mock_metadata::<T>(m));
         let minimum_support = Some(perbill_one());
         let required_approval = Some(perbill_one());
         let frozen_tokens = Some(true);
@@ -184,7 +239,7 @@ benchmarks! {
         verify {
             assert_last_event::<T>(Event::ParametersSet {
                 dao_id: 0u32.into(),
-                metadata: metadata.map(|m| m.to_vec()),
+                metadata,
                 minimum_support,
                 required_approval,
                 frozen_tokens
@@ -250,6 +305,34 @@ benchmarks! {
             }.into());
         }
 
+    operate_emergency_multisig {
+        let m in 0 .. T::MaxMetadata::get();
+        let z in 0 .. (MAX_SIZE - 10);
+
+        mock_dao().unwrap();
+        mock_mint().unwrap();
+
+        let call: <T as Config>::RuntimeCall = frame_system::Call::<T>::remark {
+            remark: vec![0; z as usize]
+        }.into();
+
+        let metadata: BoundedVec<u8, T::MaxMetadata> = vec![u8::MAX; m as usize].try_into().unwrap();
+        let caller: T::AccountId = whitelisted_caller();
+        let dao_id: T::DaoId = 0u32.into();
+        let call_hash = <<T as frame_system::Config>::Hashing as Hash>::hash_of(&call.clone());
+        let fee_asset = FeeAsset::Native;
+
+    }: _(SystemOrigin::Signed(caller.clone()), dao_id, Some(metadata), fee_asset, Box::new(call.clone()))
+        verify {
+            assert_last_event::<T>(Event::EmergencyVoteStarted {
+                dao_id,
+                executor_account: derive_account::<T>(dao_id),
+                voter: caller,
+                votes_added: Vote::Aye(T::DaoSeedBalance::get()),
+                call_hash,
+            }.into());
+        }
+
     vote_multisig {
         mock_dao().unwrap();
         mock_mint().unwrap();
@@ -329,4 +412,374 @@ benchmarks! {
                 call_hash,
             }.into());
         }
+
+    set_mirror {
+        mock_dao().unwrap();
+
+        let source = Some((2000u32, 0u32));
+
+    }: _(DaoOrigin::Multisig(MultisigInternalOrigin::new(0u32.into())), source)
+        verify {
+            assert_last_event::<T>(Event::MirrorSet {
+                dao_id: 0u32.into(),
+                source,
+            }.into());
+        }
+
+    operate_joint_multisig {
+        let d in 2 .. T::MaxJointDaos::get();
+        let m in 0 .. T::MaxMetadata::get();
+        let z in 0 .. (MAX_SIZE - 10);
+
+        for _ in 0 .. d {
+            mock_dao().unwrap();
+        }
+        mock_mint().unwrap();
+
+        let calls = mock_joint_calls::<T>(d, z);
+
+        let metadata: BoundedVec<u8, T::MaxMetadata> = vec![u8::MAX; m as usize].try_into().unwrap();
+        let caller: T::AccountId = whitelisted_caller();
+        let proposal_hash = <<T as frame_system::Config>::Hashing as Hash>::hash_of(&calls);
+        let fee_asset = FeeAsset::Native;
+
+    }: _(SystemOrigin::Signed(caller.clone()), calls, Some(metadata), fee_asset)
+        verify {
+            assert_last_event::<T>(Event::JointMultisigVoteStarted {
+                daos: (0..d).map(Into::into).collect(),
+                voter: caller,
+                proposal_hash,
+            }.into());
+        }
+
+    vote_joint_multisig {
+        let d in 2 .. T::MaxJointDaos::get();
+
+        for _ in 0 .. d {
+            mock_dao().unwrap();
+        }
+        mock_mint().unwrap();
+        mock_mint_2().unwrap();
+        mock_joint_call::<T>(d).unwrap();
+
+        let caller: T::AccountId = account("target", 0, SEED);
+        let dao_id: T::DaoId = 0u32.into();
+        let proposal_hash = <<T as frame_system::Config>::Hashing as Hash>::hash_of(&mock_joint_calls::<T>(d, 1));
+
+    }: _(SystemOrigin::Signed(caller.clone()), dao_id, proposal_hash, true)
+        verify {
+            assert_last_event::<T>(Event::JointMultisigVoteAdded {
+                dao_id,
+                voter: caller.clone(),
+                votes_added: Vote::Aye(BalanceOf::<T>::max_value().div(4u32.into())),
+                current_votes: Tally::<T>::from_parts(
+                    (BalanceOf::<T>::max_value().div(4u32.into()) + T::DaoSeedBalance::get()).into(),
+                    Zero::zero(),
+                    BoundedBTreeMap::try_from(BTreeMap::from([
+                        (whitelisted_caller(), Vote::Aye(T::DaoSeedBalance::get())),
+                        (caller, Vote::Aye(BalanceOf::<T>::max_value().div(4u32.into()))),
+                    ])).unwrap()
+                ),
+                proposal_hash,
+            }.into());
+        }
+
+    withdraw_vote_joint_multisig {
+        let d = T::MaxJointDaos::get();
+
+        for _ in 0 .. d {
+            mock_dao().unwrap();
+        }
+        mock_mint().unwrap();
+        mock_mint_2().unwrap();
+        mock_joint_call::<T>(d).unwrap();
+
+        let caller: T::AccountId = account("target", 0, SEED);
+        let dao_id: T::DaoId = 0u32.into();
+        let proposal_hash = <<T as frame_system::Config>::Hashing as Hash>::hash_of(&mock_joint_calls::<T>(d, 1));
+
+        dao_manager::<T>::vote_joint_multisig(SystemOrigin::Signed(caller.clone()).into(), dao_id, proposal_hash, true).unwrap();
+
+    }: _(SystemOrigin::Signed(caller.clone()), dao_id, proposal_hash)
+        verify {
+            assert_last_event::<T>(Event::JointMultisigVoteWithdrawn {
+                dao_id,
+                voter: caller,
+                votes_removed: Vote::Aye(BalanceOf::<T>::max_value().div(4u32.into())),
+                proposal_hash,
+            }.into());
+        }
+
+    cancel_joint_multisig_proposal {
+        let d = T::MaxJointDaos::get();
+
+        for _ in 0 .. d {
+            mock_dao().unwrap();
+        }
+        mock_mint().unwrap();
+        mock_joint_call::<T>(d).unwrap();
+
+        let dao_id: T::DaoId = 0u32.into();
+        let proposal_hash = <<T as frame_system::Config>::Hashing as Hash>::hash_of(&mock_joint_calls::<T>(d, 1));
+
+    }: _(DaoOrigin::Multisig(MultisigInternalOrigin::new(0u32.into())), proposal_hash)
+        verify {
+            assert_last_event::<T>(Event::JointMultisigCanceled {
+                dao_id,
+                proposal_hash,
+            }.into());
+        }
+
+    set_voting_strategy {
+        mock_dao().unwrap();
+
+        let strategy = VotingStrategy::Quadratic;
+
+    }: _(DaoOrigin::Multisig(MultisigInternalOrigin::new(0u32.into())), strategy)
+        verify {
+            assert_last_event::<T>(Event::VotingStrategySet {
+                dao_id: 0u32.into(),
+                strategy,
+            }.into());
+        }
+
+    set_handle {
+        mock_dao().unwrap();
+
+        let dao_id: T::DaoId = 0u32.into();
+        <<T as pallet::Config>::Currency as Mutate<<T as frame_system::Config>::AccountId>>::set_balance(
+            &derive_account::<T>(dao_id),
+            T::HandleDeposit::get() + <<T as pallet::Config>::Currency as Inspect<<T as frame_system::Config>::AccountId>>::minimum_balance(),
+        );
+
+        let handle: Vec<u8> = vec![b'a'; T::MaxHandleLength::get() as usize];
+
+    }: _(DaoOrigin::Multisig(MultisigInternalOrigin::new(dao_id)), Some(handle.clone().try_into().unwrap()))
+        verify {
+            assert_last_event::<T>(Event::HandleSet {
+                dao_id,
+                handle: Some(handle),
+            }.into());
+        }
+
+    set_token_metadata {
+        mock_dao().unwrap();
+
+        let dao_id: T::DaoId = 0u32.into();
+        let metadata = DaoTokenMetadata {
+            name: vec![b'a'; T::MaxNameLength::get() as usize].try_into().unwrap(),
+            symbol: vec![b'A'; T::MaxSymbolLength::get() as usize].try_into().unwrap(),
+            decimals: 12,
+        };
+
+    }: _(DaoOrigin::Multisig(MultisigInternalOrigin::new(dao_id)), metadata.clone())
+        verify {
+            assert_last_event::<T>(Event::TokenMetadataSet {
+                dao_id,
+                metadata,
+            }.into());
+        }
+
+    set_token_supply_cap {
+        mock_dao().unwrap();
+
+        let dao_id: T::DaoId = 0u32.into();
+        let cap: BalanceOf<T> = T::DaoSeedBalance::get();
+
+        // Worst case is lowering an already existing cap.
+        TokenSupplyCap::<T>::insert(dao_id, cap + cap);
+
+    }: _(DaoOrigin::Multisig(MultisigInternalOrigin::new(dao_id)), cap)
+        verify {
+            assert_last_event::<T>(Event::TokenSupplyCapSet {
+                dao_id,
+                cap,
+            }.into());
+        }
+
+    token_mint_vested {
+        mock_dao().unwrap();
+
+        let dao_id: T::DaoId = 0u32.into();
+        let target: T::AccountId = account("target", 0, SEED);
+        let schedule = VestingSchedule::Linear {
+            amount: BalanceOf::<T>::max_value().div(2u32.into()),
+            start: frame_system::Pallet::<T>::block_number(),
+            duration: 1000u32.into(),
+        };
+
+        // Worst case is going through every other schedule of the target.
+        let schedules: Vec<VestingScheduleOf<T>> =
+            vec![schedule; T::MaxVestingSchedules::get() as usize - 1];
+        VestingSchedules::<T>::insert(dao_id, &target, BoundedVec::truncate_from(schedules));
+
+    }: _(DaoOrigin::Multisig(MultisigInternalOrigin::new(dao_id)), target.clone(), schedule)
+        verify {
+            assert_last_event::<T>(Event::MintedVested {
+                dao_id,
+                target,
+                schedule,
+            }.into());
+        }
+
+    set_locked_tokens_vote {
+        mock_dao().unwrap();
+
+        let dao_id: T::DaoId = 0u32.into();
+
+    }: _(DaoOrigin::Multisig(MultisigInternalOrigin::new(dao_id)), false)
+        verify {
+            assert_last_event::<T>(Event::LockedTokensVoteSet {
+                dao_id,
+                can_vote: false,
+            }.into());
+        }
+
+    set_second_chamber {
+        mock_dao().unwrap();
+
+        let dao_id: T::DaoId = 0u32.into();
+
+        let members = (0..T::MaxCouncilMembers::get())
+            .map(|i| account("council", i, SEED))
+            .collect::<BTreeSet<T::AccountId>>();
+
+        let chamber: ChamberOf<T> = Chamber {
+            members: ChamberMembers::Council(BoundedBTreeSet::try_from(members).unwrap()),
+            minimum_support: perbill_one(),
+            required_approval: perbill_one(),
+        };
+
+    }: _(DaoOrigin::Multisig(MultisigInternalOrigin::new(dao_id)), Some(chamber.clone()))
+        verify {
+            assert_last_event::<T>(Event::SecondChamberSet {
+                dao_id,
+                chamber: Some(chamber),
+            }.into());
+        }
+
+    operate_public_multisig {
+        let m in 0 .. T::MaxMetadata::get();
+        let z in 0 .. (MAX_SIZE - 10);
+
+        mock_dao().unwrap();
+
+        let call: <T as Config>::RuntimeCall = frame_system::Call::<T>::remark {
+            remark: vec![0; z as usize]
+        }.into();
+
+        let metadata: BoundedVec<u8, T::MaxMetadata> = vec![u8::MAX; m as usize].try_into().unwrap();
+        let caller: T::AccountId = account("public", 0, SEED);
+        let dao_id: T::DaoId = 0u32.into();
+        let call_hash = <<T as frame_system::Config>::Hashing as Hash>::hash_of(&call.clone());
+        let fee_asset = FeeAsset::Native;
+        let deposit = T::PublicProposalDeposit::get();
+        let expires_at = frame_system::Pallet::<T>::block_number() + T::PublicProposalDuration::get();
+
+        <<T as pallet::Config>::Currency as Mutate<<T as frame_system::Config>::AccountId>>::set_balance(
+            &caller,
+            BalanceOf::<T>::max_value().div(4u32.into()),
+        );
+
+    }: _(SystemOrigin::Signed(caller.clone()), dao_id, Some(metadata), fee_asset, Box::new(call.clone()))
+        verify {
+            assert_last_event::<T>(Event::PublicProposalSubmitted {
+                dao_id,
+                executor_account: derive_account::<T>(dao_id),
+                proposer: caller,
+                call_hash,
+                deposit,
+                expires_at,
+            }.into());
+        }
+
+    expire_public_proposal {
+        mock_dao().unwrap();
+
+        let call: <T as Config>::RuntimeCall = frame_system::Call::<T>::remark {
+            remark: vec![0]
+        }.into();
+
+        let proposer: T::AccountId = account("public", 0, SEED);
+        let dao_id: T::DaoId = 0u32.into();
+        let call_hash = <<T as frame_system::Config>::Hashing as Hash>::hash_of(&call.clone());
+        let deposit = T::PublicProposalDeposit::get();
+
+        <<T as pallet::Config>::Currency as Mutate<<T as frame_system::Config>::AccountId>>::set_balance(
+            &proposer,
+            BalanceOf::<T>::max_value().div(4u32.into()),
+        );
+
+        dao_manager::<T>::operate_public_multisig(
+            SystemOrigin::Signed(proposer.clone()).into(),
+            dao_id,
+            None,
+            FeeAsset::Native,
+            Box::new(call),
+        ).unwrap();
+
+        frame_system::Pallet::<T>::set_block_number(
+            frame_system::Pallet::<T>::block_number() + T::PublicProposalDuration::get() + 1u32.into()
+        );
+
+        let caller: T::AccountId = whitelisted_caller();
+
+    }: _(SystemOrigin::Signed(caller), dao_id, call_hash)
+        verify {
+            assert_last_event::<T>(Event::PublicProposalDepositSlashed {
+                dao_id,
+                proposer,
+                call_hash,
+                deposit,
+            }.into());
+        }
+
+    note_nft_voting_power {
+        // Items can't be minted through the generic NFT provider, the per item cost of the
+        // weight accounts for reading the owner and the voting weight attribute of each item.
+        let n in 0 .. T::MaxNftVotingItems::get();
+
+        mock_dao().unwrap();
+
+        let dao_id: T::DaoId = 0u32.into();
+        let collection = T::NftCollectionId::decode(&mut TrailingZeroInput::zeroes()).unwrap();
+        let strategy = VotingStrategy::NftAttribute(collection);
+
+        CoreStorage::<T>::mutate(dao_id, |dao| {
+            if let Some(dao) = dao {
+                dao.voting_strategy = strategy;
+            }
+        });
+
+    }: {
+        Pallet::<T>::note_nft_voting_power(dao_id);
+    }
+        verify {
+            assert!(NftVotingPower::<T>::contains_key(collection));
+        }
+
+    note_proposal_voting_power {
+        // Items can't be minted through the generic NFT provider, the per item cost of the
+        // weight accounts for reading the caller's items, their voting weight attribute and
+        // replacing the records of the items the caller voted with before.
+        let n in 0 .. T::MaxNftVotingItems::get();
+
+        mock_dao().unwrap();
+
+        let dao_id: T::DaoId = 0u32.into();
+        let collection = T::NftCollectionId::decode(&mut TrailingZeroInput::zeroes()).unwrap();
+        let strategy = VotingStrategy::NftAttribute(collection);
+
+        CoreStorage::<T>::mutate(dao_id, |dao| {
+            if let Some(dao) = dao {
+                dao.voting_strategy = strategy;
+            }
+        });
+
+        let call_hash = <<T as frame_system::Config>::Hashing as Hash>::hash_of(&n);
+        let caller: T::AccountId = whitelisted_caller();
+
+    }: {
+        Pallet::<T>::note_proposal_voting_power(dao_id, call_hash, &caller);
+    }
 }
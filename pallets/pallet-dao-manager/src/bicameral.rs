@@ -0,0 +1,140 @@
+//! Bicameral DAOs.
+//!
+//! ## Overview
+//!
+//! Allows DAOs to define a second chamber whose approval proposals require on top of the voting token holders'.
+//! The second chamber is either an explicit council, where every member has a single vote,
+//! or the holders of a second voting token, voting with their balance.
+//!
+//! Proposals of a bicameral DAO carry a second [`Tally`] for the second chamber, and are only executed
+//! once both tallies meet the thresholds of their chamber. Members of both chambers vote in both tallies at once,
+//! and the second chamber votes are weighed with the chamber's current membership whenever the thresholds are checked.
+//!
+//! - `inner_set_second_chamber`: Sets or removes the second chamber of a DAO.
+
+use crate::{
+    origin::{ensure_multisig, DaoOrigin},
+    pallet::*,
+    voting::{Tally, Vote, Votes},
+};
+use frame_support::{pallet_prelude::*, traits::fungibles::Inspect, BoundedBTreeSet};
+use frame_system::pallet_prelude::*;
+use primitives::{Chamber, ChamberMembers};
+use sp_runtime::{
+    traits::{One, Saturating, Zero},
+    Perbill,
+};
+
+/// Members of a council chamber.
+pub type CouncilMembersOf<T> =
+    BoundedBTreeSet<<T as frame_system::Config>::AccountId, <T as Config>::MaxCouncilMembers>;
+
+/// Second chamber of a dao, either a council or the holders of another dao's voting token.
+pub type ChamberOf<T> = Chamber<CouncilMembersOf<T>, <T as Config>::DaoId>;
+
+impl<T: Config> Pallet<T>
+where
+    Result<DaoOrigin<T>, <T as frame_system::Config>::RuntimeOrigin>:
+        From<<T as frame_system::Config>::RuntimeOrigin>,
+    <T as frame_system::Config>::AccountId: From<[u8; 32]>,
+{
+    /// Inner function for the set_second_chamber call.
+    pub(crate) fn inner_set_second_chamber(
+        origin: OriginFor<T>,
+        chamber: Option<ChamberOf<T>>,
+    ) -> DispatchResult {
+        let dao_origin = ensure_multisig::<T, OriginFor<T>>(origin)?;
+        let dao_id = dao_origin.id;
+
+        // A council needs members and a token chamber must use the voting token of another existing dao.
+        if let Some(chamber) = &chamber {
+            ensure!(
+                match &chamber.members {
+                    ChamberMembers::Council(members) => !members.is_empty(),
+                    ChamberMembers::Token(asset) =>
+                        *asset != dao_id && CoreStorage::<T>::contains_key(asset),
+                },
+                Error::<T>::InvalidSecondChamber
+            );
+        }
+
+        CoreStorage::<T>::try_mutate(dao_id, |dao| {
+            let dao = dao.as_mut().ok_or(Error::<T>::DaoNotFound)?;
+
+            dao.second_chamber = chamber.clone();
+
+            Self::deposit_event(Event::SecondChamberSet { dao_id, chamber });
+
+            Ok(())
+        })
+    }
+}
+
+impl<T: Config> Pallet<T> {
+    /// Returns the second chamber of a dao, `None` if the dao is unicameral.
+    pub fn second_chamber(dao_id: T::DaoId) -> Option<ChamberOf<T>> {
+        CoreStorage::<T>::get(dao_id).and_then(|dao| dao.second_chamber)
+    }
+
+    /// Returns the voting power of an account in a chamber.
+    pub fn chamber_voting_power(chamber: &ChamberOf<T>, who: &T::AccountId) -> Votes<T> {
+        match &chamber.members {
+            ChamberMembers::Council(members) => {
+                if members.contains(who) {
+                    One::one()
+                } else {
+                    Zero::zero()
+                }
+            }
+            ChamberMembers::Token(asset) => T::AssetsProvider::balance(*asset, who),
+        }
+    }
+
+    /// Returns the total voting power of a chamber.
+    pub fn chamber_total_voting_power(chamber: &ChamberOf<T>) -> Votes<T> {
+        match &chamber.members {
+            ChamberMembers::Council(members) => (members.len() as u32).into(),
+            ChamberMembers::Token(asset) => T::AssetsProvider::total_issuance(*asset),
+        }
+    }
+
+    /// Checks whether the second chamber tally of a proposal meets the thresholds of the chamber,
+    /// always passing for unicameral daos.
+    pub fn second_chamber_passes(
+        dao_id: T::DaoId,
+        tally: Option<&Tally<T>>,
+        emergency: bool,
+    ) -> bool {
+        let chamber = match Self::second_chamber(dao_id) {
+            Some(chamber) => chamber,
+            None => return true,
+        };
+
+        // Votes are weighed with the current membership of the chamber, as it may have changed since they were cast.
+        let (ayes, nays) = tally.map_or((Zero::zero(), Zero::zero()), |tally| {
+            tally.records.iter().fold(
+                (Zero::zero(), Zero::zero()),
+                |(ayes, nays): (Votes<T>, Votes<T>), (who, vote)| {
+                    let power = Self::chamber_voting_power(&chamber, who);
+
+                    match vote {
+                        Vote::Aye(_) => (ayes.saturating_add(power), nays),
+                        Vote::Nay(_) => (ayes, nays.saturating_add(power)),
+                    }
+                },
+            )
+        });
+
+        // A chamber without any voting power can't approve anything.
+        let total = Self::chamber_total_voting_power(&chamber);
+        if total.is_zero() {
+            return false;
+        }
+
+        let support = Perbill::from_rational(ayes, total);
+        let approval = Perbill::from_rational(ayes, <Votes<T> as One>::one().max(ayes + nays));
+
+        support >= Self::proposal_minimum_support(chamber.minimum_support, emergency)
+            && approval >= chamber.required_approval
+    }
+}
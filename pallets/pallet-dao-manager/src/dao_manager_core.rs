@@ -6,6 +6,7 @@
 //!
 //! - `inner_create_dao`: Sets up a new dao, deriving its AccountId, distributing voting tokens, and handling creation fees.
 //! - `inner_set_parameters`: Updates the DAO's operational rules.
+//! - `inner_set_voting_strategy`: Updates how the voting power of the DAO members is computed.
 //! - `is_asset_frozen`: Utility function for checking if a DAO's voting asset is frozen (can't be transferred by the owner).
 
 use super::pallet::*;
@@ -19,17 +20,23 @@ use frame_support::{
     traits::{
         fungible::Balanced,
         fungibles::{Balanced as Balanceds, Mutate as Mutates},
-        tokens::{Fortitude, Precision, Preservation},
+        tokens::{nonfungibles::Inspect as NftInspect, Fortitude, Precision, Preservation},
     },
 };
 use frame_system::{ensure_signed, pallet_prelude::*};
-use primitives::DaoInfo;
-use sp_arithmetic::traits::{CheckedAdd, One};
+pub use primitives::DaoMetadata;
+use primitives::{DaoInfo, VotingStrategy};
+use sp_arithmetic::traits::{CheckedAdd, One, Zero};
 use sp_runtime::Perbill;
 
 pub type DaoIndexOf<T> = <T as Config>::DaoId;
 
-pub type DaoMetadataOf<T> = BoundedVec<u8, <T as Config>::MaxMetadata>;
+pub type DaoMetadataOf<T> = DaoMetadata<
+    BoundedVec<u8, <T as Config>::MaxNameLength>,
+    BoundedVec<u8, <T as Config>::MaxDescriptionLength>,
+    BoundedVec<u8, <T as Config>::MaxUrlLength>,
+    BoundedVec<u8, <T as Config>::MaxUrlLength>,
+>;
 
 impl<T: Config> Pallet<T>
 where
@@ -40,7 +47,7 @@ where
     /// Inner function for the create_dao call.
     pub(crate) fn inner_create_dao(
         origin: OriginFor<T>,
-        metadata: BoundedVec<u8, T::MaxMetadata>,
+        metadata: DaoMetadataOf<T>,
         minimum_support: Perbill,
         required_approval: Perbill,
         creation_fee_asset: FeeAsset,
@@ -48,6 +55,8 @@ where
         NextCoreId::<T>::try_mutate(|next_id| -> DispatchResult {
             let creator = ensure_signed(origin)?;
 
+            ensure!(metadata.is_valid(), Error::<T>::InvalidMetadata);
+
             // Increment dao id counter
             let current_id = *next_id;
             *next_id = next_id
@@ -60,6 +69,7 @@ where
             // Mint base amount of voting token to the caller
             let seed_balance = <T as Config>::DaoSeedBalance::get();
             T::AssetsProvider::mint_into(current_id, &creator, seed_balance)?;
+            Self::note_voting_balance_change(current_id, &creator, Zero::zero());
 
             // Build the structure of the new DAK
             // Tokens are set to frozen by default
@@ -69,6 +79,8 @@ where
                 minimum_support,
                 required_approval,
                 frozen_tokens: true,
+                voting_strategy: VotingStrategy::TokenBalance,
+                second_chamber: None,
             };
 
             // Charge creation fee from the caller
@@ -97,6 +109,23 @@ where
                         Fortitude::Force,
                     )?)
                 }
+
+                FeeAsset::Asset(asset_id) => {
+                    let fee =
+                        T::FeeCharger::native_to_asset_fee(asset_id, T::DaoCreationFee::get())
+                            .ok_or(Error::<T>::UnsupportedFeeAsset)?;
+
+                    FeeAssetNegativeImbalance::Asset(<<T as Config>::Tokens as Balanceds<
+                        T::AccountId,
+                    >>::withdraw(
+                        asset_id,
+                        &creator,
+                        fee,
+                        Precision::Exact,
+                        Preservation::Protect,
+                        Fortitude::Force,
+                    )?)
+                }
             });
 
             // Update dao storages
@@ -105,7 +134,7 @@ where
 
             Self::deposit_event(Event::DaoCreated {
                 dao_account,
-                metadata: metadata.to_vec(),
+                metadata,
                 dao_id: current_id,
                 minimum_support,
                 required_approval,
@@ -118,7 +147,7 @@ where
     /// Inner function for the set_parameters call.
     pub(crate) fn inner_set_parameters(
         origin: OriginFor<T>,
-        metadata: Option<BoundedVec<u8, T::MaxMetadata>>,
+        metadata: Option<DaoMetadataOf<T>>,
         minimum_support: Option<Perbill>,
         required_approval: Option<Perbill>,
         frozen_tokens: Option<bool>,
@@ -126,6 +155,11 @@ where
         let dao_origin = ensure_multisig::<T, OriginFor<T>>(origin)?;
         let dao_id = dao_origin.id;
 
+        ensure!(
+            metadata.as_ref().map_or(true, |m| m.is_valid()),
+            Error::<T>::InvalidMetadata
+        );
+
         CoreStorage::<T>::try_mutate(dao_id, |dao| {
             let mut c = dao.take().ok_or(Error::<T>::DaoNotFound)?;
 
@@ -149,7 +183,7 @@ where
 
             Self::deposit_event(Event::ParametersSet {
                 dao_id,
-                metadata: metadata.map(|m| m.to_vec()),
+                metadata,
                 minimum_support,
                 required_approval,
                 frozen_tokens,
@@ -159,8 +193,62 @@ where
         })
     }
 
+    /// Inner function for the set_voting_strategy call.
+    pub(crate) fn inner_set_voting_strategy(
+        origin: OriginFor<T>,
+        strategy: VotingStrategyOf<T>,
+    ) -> DispatchResult {
+        let dao_origin = ensure_multisig::<T, OriginFor<T>>(origin)?;
+        let dao_id = dao_origin.id;
+
+        CoreStorage::<T>::try_mutate(dao_id, |dao| {
+            let dao = dao.as_mut().ok_or(Error::<T>::DaoNotFound)?;
+
+            // NFT strategies can only use collections owned by the dao itself
+            let nft_voting_power = match Self::nft_collection_of(&strategy) {
+                Some(collection) => {
+                    let owner = T::Nfts::collection_owner(&collection)
+                        .ok_or(Error::<T>::NftCollectionNotFound)?;
+
+                    ensure!(owner == dao.account, Error::<T>::NotNftCollectionOwner);
+
+                    Some((collection, Self::compute_nft_voting_power(&strategy)?))
+                }
+                None => None,
+            };
+
+            // Unbind the collection used by the previous strategy
+            if let Some(collection) = Self::nft_collection_of(&dao.voting_strategy) {
+                NftCollectionDao::<T>::mutate_exists(collection, |bound| {
+                    if *bound == Some(dao_id) {
+                        *bound = None;
+                        NftVotingPower::<T>::remove(collection);
+                    }
+                });
+            }
+
+            if let Some((collection, voting_power)) = nft_voting_power {
+                NftCollectionDao::<T>::insert(collection, dao_id);
+                NftVotingPower::<T>::insert(collection, voting_power);
+            }
+
+            dao.voting_strategy = strategy;
+
+            Self::deposit_event(Event::VotingStrategySet { dao_id, strategy });
+
+            Ok(())
+        })
+    }
+
     /// Checks if the voting asset is frozen.
     pub fn is_asset_frozen(dao_id: T::DaoId) -> Option<bool> {
         CoreStorage::<T>::get(dao_id).map(|c| c.frozen_tokens)
     }
+
+    /// Checks if the items of an NFT collection are frozen by the dao using it for voting.
+    pub fn is_nft_collection_frozen(collection: T::NftCollectionId) -> bool {
+        NftCollectionDao::<T>::get(collection)
+            .and_then(Self::is_asset_frozen)
+            .unwrap_or(false)
+    }
 }
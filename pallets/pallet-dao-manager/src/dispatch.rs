@@ -5,6 +5,7 @@
 //! This module employs a custom `MultisigInternalOrigin` to ensure calls originate
 //! from the multisig account itself, automating fee payments. The `dispatch_call` function
 //! includes pre and post dispatch handling for streamlined fee management within the multisig context.
+//! Fees are charged to the multisig account unless the proposal is sponsored by its proposer.
 
 use crate::{
     fee_handling::{FeeAsset, MultisigFeeHandler},
@@ -16,9 +17,12 @@ use frame_support::{dispatch::GetDispatchInfo, pallet_prelude::*};
 use sp_runtime::traits::Dispatchable;
 
 /// Dispatch a call executing pre/post dispatch for proper fee handling.
+///
+/// The fee is charged to `fee_payer`, or to the multisig account if `None`.
 pub fn dispatch_call<T: Config>(
     dao_id: <T as Config>::DaoId,
     fee_asset: &FeeAsset,
+    fee_payer: Option<&T::AccountId>,
     call: <T as Config>::RuntimeCall,
 ) -> DispatchResultWithPostInfo
 where
@@ -32,10 +36,10 @@ where
     let info = call.get_dispatch_info();
     let len = call.encode().len();
 
-    // Execute pre dispatch using the multisig account instead of the extrinsic caller.
+    // Execute pre dispatch using the fee payer instead of the extrinsic caller.
     let pre = <T::FeeCharger as MultisigFeeHandler<T>>::pre_dispatch(
         fee_asset,
-        &multisig_account,
+        fee_payer.unwrap_or(&multisig_account),
         &call,
         &info,
         len,
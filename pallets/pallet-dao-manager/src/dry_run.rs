@@ -0,0 +1,215 @@
+//! Proposal Dry Run.
+//!
+//! ## Overview
+//!
+//! Allows members to know what a proposal will do before voting on it.
+//!
+//! The call is dispatched as the dao origin, exactly like [`crate::dispatch::dispatch_call`] does once a proposal passes,
+//! but inside a storage layer that is always rolled back. The events emitted, the dispatch result, the actual weight,
+//! the fee charged to the dao, or to the proposer of a sponsored proposal, and the XCM messages sent by the call
+//! are returned through the [`DaoManagerApi`] runtime API.
+
+use crate::{
+    fee_handling::{FeeAsset, MultisigFeeHandler},
+    origin::{DaoOrigin, MultisigInternalOrigin},
+    Config, Error, Pallet,
+};
+use codec::{Codec, Decode, Encode};
+use frame_support::{
+    dispatch::GetDispatchInfo,
+    pallet_prelude::*,
+    storage::{with_transaction, TransactionOutcome},
+    traits::{fungible::Inspect, fungibles::Inspect as Inspects},
+};
+use sp_runtime::traits::{Dispatchable, UniqueSaturatedInto};
+use sp_std::vec::Vec;
+use xcm::{VersionedLocation, VersionedXcm};
+
+/// XCM messages grouped by destination.
+pub type ForwardedXcms = Vec<(VersionedLocation, Vec<VersionedXcm<()>>)>;
+
+/// Effects of dispatching a proposal's call as the dao origin.
+#[derive(Encode, Decode, TypeInfo, RuntimeDebug, PartialEq, Eq)]
+pub struct ProposalDryRunEffects<Event> {
+    /// Result of dispatching the call, fee payment errors included.
+    pub execution_result: DispatchResult,
+    /// Weight actually used by the call.
+    pub actual_weight: Weight,
+    /// Fee charged to the fee payer in the selected fee asset.
+    pub fee: u128,
+    /// Events emitted while dispatching the call, fee payment included.
+    pub emitted_events: Vec<Event>,
+    /// XCM messages sent by the call.
+    pub forwarded_xcms: ForwardedXcms,
+}
+
+sp_api::decl_runtime_apis! {
+    /// API for simulating the execution of dao proposals.
+    pub trait DaoManagerApi<DaoId, AccountId, RuntimeCall, RuntimeEvent>
+    where
+        DaoId: Codec,
+        AccountId: Codec,
+        RuntimeCall: Codec,
+        RuntimeEvent: Codec,
+    {
+        /// Dispatches `call` as the multisig origin of `dao_id` without persisting any of its changes.
+        /// The fee is charged to `fee_payer`, the proposer of a sponsored proposal, or to the dao if `None`.
+        fn dry_run_proposal(
+            dao_id: DaoId,
+            call: RuntimeCall,
+            fee_asset: FeeAsset,
+            fee_payer: Option<AccountId>,
+        ) -> ProposalDryRunEffects<RuntimeEvent>;
+    }
+}
+
+impl<T: Config> Pallet<T>
+where
+    T::AccountId: From<[u8; 32]>,
+{
+    /// Dispatches a call as the dao origin and rolls back every change, returning the effects of the call.
+    ///
+    /// The fee is charged to `fee_payer`, or to the multisig account if `None`, like [`crate::dispatch::dispatch_call`].
+    /// `forwarded_xcms` should return the messages queued by the runtime's XCM router,
+    /// only the ones queued by the call are returned.
+    pub fn dry_run_proposal(
+        dao_id: T::DaoId,
+        call: <T as Config>::RuntimeCall,
+        fee_asset: FeeAsset,
+        fee_payer: Option<T::AccountId>,
+        forwarded_xcms: impl Fn() -> ForwardedXcms,
+    ) -> ProposalDryRunEffects<<T as frame_system::Config>::RuntimeEvent> {
+        with_transaction(|| {
+            TransactionOutcome::Rollback(Ok::<_, DispatchError>(Self::simulate_proposal(
+                dao_id,
+                call,
+                fee_asset,
+                fee_payer,
+                forwarded_xcms,
+            )))
+        })
+        .unwrap_or_else(|error| ProposalDryRunEffects {
+            execution_result: Err(error),
+            actual_weight: Weight::zero(),
+            fee: 0,
+            emitted_events: Vec::new(),
+            forwarded_xcms: Vec::new(),
+        })
+    }
+
+    /// Dispatches a call as the dao origin, must be called within a storage layer that is rolled back.
+    fn simulate_proposal(
+        dao_id: T::DaoId,
+        call: <T as Config>::RuntimeCall,
+        fee_asset: FeeAsset,
+        fee_payer: Option<T::AccountId>,
+        forwarded_xcms: impl Fn() -> ForwardedXcms,
+    ) -> ProposalDryRunEffects<<T as frame_system::Config>::RuntimeEvent> {
+        // Only keep track of the events and messages coming from this call
+        frame_system::Pallet::<T>::reset_events();
+        let queued_xcms = forwarded_xcms();
+
+        let internal_origin = MultisigInternalOrigin::new(dao_id);
+        let fee_payer = fee_payer.unwrap_or_else(|| internal_origin.to_account_id());
+
+        let info = call.get_dispatch_info();
+        let len = call.encode().len();
+
+        // The fee is measured around the fee handling only, as the call itself can move the payer's funds
+        let balance_before_pre = Self::fee_asset_balance(&fee_asset, &fee_payer);
+
+        let pre = <T::FeeCharger as MultisigFeeHandler<T>>::pre_dispatch(
+            &fee_asset, &fee_payer, &call, &info, len,
+        );
+
+        let (execution_result, actual_weight, fee) = match pre {
+            Ok(pre) => {
+                let withdrawn = balance_before_pre
+                    .saturating_sub(Self::fee_asset_balance(&fee_asset, &fee_payer));
+
+                let dispatch_result = call.dispatch(DaoOrigin::Multisig(internal_origin).into());
+
+                let post = match dispatch_result {
+                    Ok(p) => p,
+                    Err(e) => e.post_info,
+                };
+
+                let result = dispatch_result.map(|_| ()).map_err(|e| e.error);
+
+                let balance_before_post = Self::fee_asset_balance(&fee_asset, &fee_payer);
+
+                let result = <T::FeeCharger as MultisigFeeHandler<T>>::post_dispatch(
+                    &fee_asset,
+                    Some(pre),
+                    &info,
+                    &post,
+                    len,
+                    &result,
+                )
+                .map_err(|_| Error::<T>::CallFeePaymentFailed.into())
+                .and(result);
+
+                let balance_after_post = Self::fee_asset_balance(&fee_asset, &fee_payer);
+
+                let fee = withdrawn
+                    .saturating_add(balance_before_post.saturating_sub(balance_after_post))
+                    .saturating_sub(balance_after_post.saturating_sub(balance_before_post));
+
+                (result, post.calc_actual_weight(&info), fee)
+            }
+            Err(_) => (
+                Err(Error::<T>::CallFeePaymentFailed.into()),
+                Weight::zero(),
+                0,
+            ),
+        };
+
+        let emitted_events = frame_system::Pallet::<T>::read_events_no_consensus()
+            .map(|record| record.event)
+            .collect();
+
+        // Message queues only grow, so the messages sent by the call are the ones after those already queued
+        let forwarded_xcms = forwarded_xcms()
+            .into_iter()
+            .filter_map(|(destination, messages)| {
+                let already_queued = queued_xcms
+                    .iter()
+                    .find(|(queued_destination, _)| *queued_destination == destination)
+                    .map(|(_, queued)| queued.len())
+                    .unwrap_or(0);
+
+                let messages = messages
+                    .into_iter()
+                    .skip(already_queued)
+                    .collect::<Vec<_>>();
+
+                (!messages.is_empty()).then_some((destination, messages))
+            })
+            .collect();
+
+        ProposalDryRunEffects {
+            execution_result,
+            actual_weight,
+            fee,
+            emitted_events,
+            forwarded_xcms,
+        }
+    }
+
+    /// Balance of an account in the given fee asset.
+    fn fee_asset_balance(fee_asset: &FeeAsset, who: &T::AccountId) -> u128 {
+        match fee_asset {
+            FeeAsset::Native => {
+                <T::Currency as Inspect<T::AccountId>>::balance(who).unique_saturated_into()
+            }
+            FeeAsset::Relay => {
+                <T::Tokens as Inspects<T::AccountId>>::balance(T::RelayAssetId::get(), who)
+                    .unique_saturated_into()
+            }
+            FeeAsset::Asset(asset_id) => {
+                <T::Tokens as Inspects<T::AccountId>>::balance(*asset_id, who)
+                    .unique_saturated_into()
+            }
+        }
+    }
+}
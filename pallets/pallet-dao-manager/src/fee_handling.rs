@@ -4,12 +4,18 @@
 //!
 //! Defines how transaction fees are charged to the multisig account.
 //! This trait requires proper runtime implementation to allow the usage of native or non-native assets.
+//!
+//! Also declares the [`DaoManagerFeeApi`] runtime API, used by wallets to estimate the costs of a proposal.
 
-use crate::Config;
-use codec::{Decode, Encode, MaxEncodedLen};
+use crate::{BalanceOf, Config};
+use codec::{Codec, Decode, Encode, MaxEncodedLen};
 use frame_support::{
-    traits::{fungible::Credit, fungibles::Credit as Credits},
+    traits::{
+        fungible::Credit,
+        fungibles::{Credit as Credits, Inspect as Inspects},
+    },
     unsigned::TransactionValidityError,
+    RuntimeDebug,
 };
 use scale_info::TypeInfo;
 use sp_runtime::{
@@ -24,21 +30,64 @@ use sp_runtime::{
 pub enum FeeAsset {
     Native,
     Relay,
+    /// Any other asset registered in the runtime, identified by its asset id.
+    Asset(u32),
+}
+
+/// Account paying the execution fee of a multisig proposal.
+#[derive(Clone, Copy, TypeInfo, Encode, Decode, MaxEncodedLen, Debug, PartialEq, Eq)]
+pub enum FeePayer {
+    /// The multisig account of the dao.
+    Dao,
+    /// The account that created the proposal.
+    Proposer,
 }
 
 /// Represents a potential negative asset balance incurred during fee payment operations
 /// within a multisig context.
 ///
-/// This enum handles imbalances in either the native token or
-/// a relay chain asset used for fees.
+/// This enum handles imbalances in either the native token,
+/// a relay chain asset or any other registered asset used for fees.
 ///
 /// - `Native(NativeNegativeImbalance)`: Indicates a deficit balance in the chain's native asset.
-/// - `Relay(RelayNegativeImbalance)`: Indicates a deficit balance in an asset originating on the relay chain.
+/// - `Relay(TokensNegativeImbalance)`: Indicates a deficit balance in an asset originating on the relay chain.
+/// - `Asset(TokensNegativeImbalance)`: Indicates a deficit balance in another registered asset.
 ///
 /// This enum plays a role in resolving deficit balances in the `MultisigFeeHandler` trait.
-pub enum FeeAssetNegativeImbalance<NativeNegativeImbalance, RelayNegativeImbalance> {
+pub enum FeeAssetNegativeImbalance<NativeNegativeImbalance, TokensNegativeImbalance> {
     Native(NativeNegativeImbalance),
-    Relay(RelayNegativeImbalance),
+    Relay(TokensNegativeImbalance),
+    Asset(TokensNegativeImbalance),
+}
+
+/// Itemised costs of proposing a call through `operate_multisig`.
+#[derive(Clone, TypeInfo, Encode, Decode, RuntimeDebug, PartialEq, Eq)]
+pub struct OperateMultisigFees<Balance> {
+    /// Fee of the `operate_multisig` extrinsic itself, paid by the proposer in the native token.
+    pub extrinsic_fee: Balance,
+    /// Fee for storing the proposal, paid by the proposer in the native token unless the call is executed right away.
+    pub storage_fee: Balance,
+    /// Fee charged to the multisig account in the selected fee asset when the call is dispatched.
+    pub call_fee: Balance,
+}
+
+sp_api::decl_runtime_apis! {
+    /// API for estimating the costs of dao proposals.
+    pub trait DaoManagerFeeApi<DaoId, Balance, RuntimeCall>
+    where
+        DaoId: Codec,
+        Balance: Codec,
+        RuntimeCall: Codec,
+    {
+        /// Returns the itemised costs of proposing `call` through `operate_multisig` with `metadata_len` bytes of metadata.
+        /// Returns `None` if the runtime doesn't support paying call fees with `fee_asset`.
+        fn estimate_operate_multisig_fees(
+            dao_id: DaoId,
+            metadata_len: u32,
+            call: RuntimeCall,
+            fee_asset: FeeAsset,
+        ) -> Option<OperateMultisigFees<Balance>>;
+    }
 }
 
 /// Fee handler trait.
@@ -67,6 +116,14 @@ pub trait MultisigFeeHandler<T: Config> {
         result: &DispatchResult,
     ) -> Result<(), TransactionValidityError>;
 
+    /// Converts a fee in the native token into the registered asset `asset_id`.
+    ///
+    /// Returns `None` if the asset can't be used for paying fees.
+    fn native_to_asset_fee(
+        asset_id: u32,
+        fee: BalanceOf<T>,
+    ) -> Option<<T::Tokens as Inspects<T::AccountId>>::Balance>;
+
     /// Charges the fee for creating the dao (multisig).
     fn handle_creation_fee(
         imbalance: FeeAssetNegativeImbalance<
@@ -0,0 +1,119 @@
+//! DAO Handles.
+//!
+//! ## Overview
+//!
+//! Allows DAOs to claim a unique human-readable handle, so users can refer to them by name instead of by their numeric id.
+//! Claiming a handle holds `T::HandleDeposit` from the DAO account under [`HoldReason::DaoManager`], released once the handle is cleared.
+//! Handles are resolved by [`crate::DaoLookup`] from a `MultiAddress::Raw` address.
+//!
+//! - `inner_set_handle`: Claims, changes or clears the handle of a DAO.
+
+use crate::{
+    origin::{ensure_multisig, DaoOrigin},
+    pallet::*,
+};
+use frame_support::{
+    pallet_prelude::*,
+    traits::{fungible::MutateHold, tokens::Precision},
+};
+use frame_system::pallet_prelude::*;
+
+/// Handle of a DAO, limited to lowercase ascii letters, digits and dashes.
+pub type DaoHandleOf<T> = BoundedVec<u8, <T as Config>::MaxHandleLength>;
+
+impl<T: Config> Pallet<T>
+where
+    Result<DaoOrigin<T>, <T as frame_system::Config>::RuntimeOrigin>:
+        From<<T as frame_system::Config>::RuntimeOrigin>,
+    <T as frame_system::Config>::AccountId: From<[u8; 32]>,
+{
+    /// Inner function for the set_handle call.
+    pub(crate) fn inner_set_handle(
+        origin: OriginFor<T>,
+        handle: Option<DaoHandleOf<T>>,
+    ) -> DispatchResult {
+        let dao_origin = ensure_multisig::<T, OriginFor<T>>(origin)?;
+        let dao_id = dao_origin.id;
+        let dao_account = dao_origin.to_account_id();
+
+        ensure!(
+            CoreStorage::<T>::contains_key(dao_id),
+            Error::<T>::DaoNotFound
+        );
+
+        // A handle can only be used by a single dao.
+        if let Some(handle) = &handle {
+            ensure!(Self::is_valid_handle(handle), Error::<T>::InvalidHandle);
+
+            ensure!(
+                DaoByHandle::<T>::get(handle)
+                    .filter(|owner| *owner != dao_id)
+                    .is_none(),
+                Error::<T>::HandleAlreadyInUse
+            );
+        }
+
+        let previous = DaoHandles::<T>::take(dao_id);
+
+        // Clear the previous handle before setting the new one, keeping the deposit if the dao is only changing it.
+        let deposit = match previous {
+            Some((previous_handle, deposit)) => {
+                DaoByHandle::<T>::remove(previous_handle);
+
+                if handle.is_none() {
+                    <T as Config>::Currency::release(
+                        &HoldReason::DaoManager.into(),
+                        &dao_account,
+                        deposit,
+                        Precision::BestEffort,
+                    )?;
+                }
+
+                deposit
+            }
+
+            None => {
+                let deposit = T::HandleDeposit::get();
+
+                if handle.is_some() {
+                    <T as Config>::Currency::hold(
+                        &HoldReason::DaoManager.into(),
+                        &dao_account,
+                        deposit,
+                    )?;
+                }
+
+                deposit
+            }
+        };
+
+        if let Some(handle) = &handle {
+            DaoByHandle::<T>::insert(handle, dao_id);
+            DaoHandles::<T>::insert(dao_id, (handle.clone(), deposit));
+        }
+
+        Self::deposit_event(Event::HandleSet {
+            dao_id,
+            handle: handle.map(|h| h.into_inner()),
+        });
+
+        Ok(())
+    }
+}
+
+impl<T: Config> Pallet<T> {
+    /// Handles are restricted to lowercase ascii letters, digits and dashes, so they can't be mistaken for one another.
+    pub fn is_valid_handle(handle: &[u8]) -> bool {
+        !handle.is_empty()
+            && handle
+                .iter()
+                .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || *c == b'-')
+    }
+
+    /// Queries `DaoByHandle` to retrieve the id of the DAO using a handle.
+    pub fn lookup_handle(handle: &[u8]) -> Option<T::DaoId> {
+        DaoHandleOf::<T>::try_from(handle.to_vec())
+            .ok()
+            .and_then(DaoByHandle::<T>::get)
+    }
+}
@@ -0,0 +1,387 @@
+//! Joint Multisig Operations.
+//!
+//! ## Overview
+//!
+//! Handles proposals that need the approval of several daos at once.
+//!
+//! A joint proposal holds one call for each of the daos taking part in it, along with one [`Tally`] per dao,
+//! all of them inside a single storage entry. Members vote in the tally of the dao they are voting as,
+//! using their voting power in that dao.
+//!
+//! ### Core functionalities:
+//! - Creating joint proposals, adding the caller's vote to every dao they have voting power in.
+//! - Handling votes per dao.
+//! - Dispatching the calls of every dao, each one with its own dao origin, once all of the tallies meet the
+//!   thresholds of their dao. Either all of the calls succeed or none of them has any effect.
+//! - Canceling joint proposals from any of the daos taking part in them.
+
+use super::pallet::{self, *};
+use crate::{
+    fee_handling::{FeeAsset, FeeAssetNegativeImbalance, MultisigFeeHandler},
+    multisig::BoundedCallBytes,
+    origin::{ensure_multisig, DaoOrigin},
+    voting::{Tally, Vote},
+};
+use codec::DecodeLimit;
+use core::{
+    convert::{TryFrom, TryInto},
+    iter::Sum,
+};
+use frame_support::{
+    pallet_prelude::*,
+    storage::{with_transaction, TransactionOutcome},
+    traits::{
+        fungible::{Balanced, Inspect},
+        tokens::{Fortitude, Precision, Preservation},
+        VoteTally,
+    },
+    BoundedBTreeMap,
+};
+use frame_system::{ensure_signed, pallet_prelude::*};
+use sp_runtime::traits::{Hash, Zero};
+use sp_std::{
+    collections::{btree_map::BTreeMap, btree_set::BTreeSet},
+    vec::Vec,
+};
+
+/// Call proposed by one of the daos taking part in a joint proposal, along with that dao's tally.
+#[derive(Clone, Encode, Decode, RuntimeDebug, MaxEncodedLen, TypeInfo, PartialEq, Eq)]
+pub struct JointMultisigPart<DaoId, TallyOf, Call> {
+    pub dao_id: DaoId,
+    pub tally: TallyOf,
+    pub actual_call: Call,
+}
+
+pub type JointMultisigPartOf<T> =
+    JointMultisigPart<<T as pallet::Config>::DaoId, Tally<T>, BoundedCallBytes<T>>;
+
+/// Details of a joint multisig operation.
+#[derive(Clone, Encode, Decode, RuntimeDebug, MaxEncodedLen, TypeInfo, PartialEq, Eq)]
+pub struct JointMultisigOperation<AccountId, Parts, Metadata> {
+    pub parts: Parts,
+    pub original_caller: AccountId,
+    pub metadata: Option<Metadata>,
+    pub fee_asset: FeeAsset,
+}
+
+pub type JointMultisigOperationOf<T> = JointMultisigOperation<
+    <T as frame_system::Config>::AccountId,
+    BoundedVec<JointMultisigPartOf<T>, <T as pallet::Config>::MaxJointDaos>,
+    BoundedVec<u8, <T as pallet::Config>::MaxMetadata>,
+>;
+
+impl<T: Config> Pallet<T>
+where
+    Result<DaoOrigin<T>, <T as frame_system::Config>::RuntimeOrigin>:
+        From<<T as frame_system::Config>::RuntimeOrigin>,
+    <<T as Config>::Currency as Inspect<<T as frame_system::Config>::AccountId>>::Balance: Sum,
+    <T as frame_system::Config>::AccountId: From<[u8; 32]>,
+{
+    /// Inner function for the operate_joint_multisig call.
+    pub(crate) fn inner_operate_joint_multisig(
+        caller: OriginFor<T>,
+        calls: BoundedVec<(T::DaoId, <T as Config>::RuntimeCall), T::MaxJointDaos>,
+        metadata: Option<BoundedVec<u8, T::MaxMetadata>>,
+        fee_asset: FeeAsset,
+    ) -> DispatchResultWithPostInfo {
+        let owner = ensure_signed(caller)?;
+
+        // A joint proposal needs at least two different daos
+        ensure!(calls.len() >= 2, Error::<T>::TooFewJointDaos);
+
+        let daos = calls
+            .iter()
+            .map(|(dao_id, _)| *dao_id)
+            .collect::<Vec<T::DaoId>>();
+
+        ensure!(
+            daos.iter().collect::<BTreeSet<_>>().len() == daos.len(),
+            Error::<T>::DuplicateJointDao
+        );
+
+        // Compute the proposal hash
+        let proposal_hash = <<T as frame_system::Config>::Hashing as Hash>::hash_of(&calls);
+
+        // Make sure this exact joint proposal doesn't already exist
+        ensure!(
+            JointMultisig::<T>::get(proposal_hash).is_none(),
+            Error::<T>::JointMultisigAlreadyExists
+        );
+
+        // Build the part of every dao, voting aye in the daos where the caller has voting power
+        let mut parts = Vec::with_capacity(calls.len());
+        let mut total_length = metadata.clone().unwrap_or_default().len() as u64;
+
+        for (dao_id, call) in calls.iter() {
+            ensure!(
+                CoreStorage::<T>::contains_key(dao_id),
+                Error::<T>::DaoNotFound
+            );
+
+            // Bring the total voting power of NFT collections up to date for the new proposal
+            Self::note_nft_voting_power(*dao_id);
+
+            let owner_power: BalanceOf<T> =
+                Self::note_proposal_voting_power(*dao_id, proposal_hash, &owner);
+
+            let records = if owner_power.is_zero() {
+                BoundedBTreeMap::new()
+            } else {
+                BoundedBTreeMap::try_from(BTreeMap::from([(owner.clone(), Vote::Aye(owner_power))]))
+                    .map_err(|_| Error::<T>::MaxCallersExceeded)?
+            };
+
+            // Wrap the call making sure it fits the size boundary
+            let bounded_call: BoundedCallBytes<T> = call
+                .encode()
+                .try_into()
+                .map_err(|_| Error::<T>::MaxCallLengthExceeded)?;
+
+            total_length = total_length.saturating_add(bounded_call.len() as u64);
+
+            parts.push(JointMultisigPart {
+                dao_id: *dao_id,
+                tally: Tally::from_parts(owner_power, Zero::zero(), records),
+                actual_call: bounded_call,
+            });
+        }
+
+        // The caller has to be a member of at least one of the daos
+        ensure!(
+            parts.iter().any(|part| !part.tally.ayes.is_zero()),
+            Error::<T>::NoPermission
+        );
+
+        // If the caller alone meets the thresholds of every dao, then go ahead and execute the calls now
+        if Self::joint_multisig_passes(&parts)? {
+            Self::execute_joint_multisig(proposal_hash, owner, calls.into_inner(), &fee_asset);
+        } else {
+            let storage_cost: BalanceOf<T> = Self::proposal_storage_fee(total_length);
+
+            T::FeeCharger::handle_creation_fee(FeeAssetNegativeImbalance::Native(
+                <<T as Config>::Currency as Balanced<T::AccountId>>::withdraw(
+                    &owner,
+                    storage_cost,
+                    Precision::Exact,
+                    Preservation::Preserve,
+                    Fortitude::Polite,
+                )?,
+            ));
+
+            // Insert proposal in storage, it's now in the voting stage
+            JointMultisig::<T>::insert(
+                proposal_hash,
+                JointMultisigOperation {
+                    parts: BoundedVec::truncate_from(parts),
+                    original_caller: owner.clone(),
+                    metadata,
+                    fee_asset,
+                },
+            );
+
+            Self::deposit_event(Event::JointMultisigVoteStarted {
+                daos,
+                voter: owner,
+                proposal_hash,
+            });
+        }
+
+        Ok(().into())
+    }
+
+    /// Inner function for the vote_joint_multisig call.
+    pub(crate) fn inner_vote_joint_multisig(
+        caller: OriginFor<T>,
+        dao_id: T::DaoId,
+        proposal_hash: T::Hash,
+        aye: bool,
+    ) -> DispatchResultWithPostInfo {
+        JointMultisig::<T>::try_mutate_exists(proposal_hash, |data| {
+            let owner = ensure_signed(caller.clone())?;
+
+            // Get the voting power of the caller in the dao they are voting as
+            let voter_power: BalanceOf<T> =
+                Self::note_proposal_voting_power(dao_id, proposal_hash, &owner);
+
+            // If caller doesn't own the token, they have no voting power.
+            ensure!(!voter_power.is_zero(), Error::<T>::NoPermission);
+
+            // Get the joint proposal data from the storage
+            let mut old_data = data.take().ok_or(Error::<T>::JointMultisigNotFound)?;
+
+            let part = old_data
+                .parts
+                .iter_mut()
+                .find(|part| part.dao_id == dao_id)
+                .ok_or(Error::<T>::NotJointMultisigDao)?;
+
+            let new_vote_record = if aye {
+                Vote::Aye(voter_power)
+            } else {
+                Vote::Nay(voter_power)
+            };
+
+            // Mutate the dao's tally with the new vote
+            part.tally
+                .process_vote(owner.clone(), Some(new_vote_record))?;
+
+            let current_votes = part.tally.clone();
+
+            // Check if every dao's tally passes its thresholds with the added vote
+            if Self::joint_multisig_passes(&old_data.parts)? {
+                // Decode the calls
+                let calls = old_data
+                    .parts
+                    .iter()
+                    .map(|part| {
+                        <T as Config>::RuntimeCall::decode_all_with_depth_limit(
+                            sp_api::MAX_EXTRINSIC_DEPTH / 4,
+                            &mut &part.actual_call[..],
+                        )
+                        .map(|call| (part.dao_id, call))
+                    })
+                    .collect::<Result<Vec<_>, _>>()
+                    .map_err(|_| Error::<T>::FailedDecodingCall)?;
+
+                // If the proposal thresholds are met, remove proposal from storage
+                *data = None;
+
+                Self::execute_joint_multisig(proposal_hash, owner, calls, &old_data.fee_asset);
+            } else {
+                // If the thresholds aren't met, update storage with the new tally
+                *data = Some(old_data);
+
+                Self::deposit_event(Event::JointMultisigVoteAdded {
+                    dao_id,
+                    voter: owner,
+                    votes_added: new_vote_record,
+                    current_votes,
+                    proposal_hash,
+                });
+            }
+
+            Ok(().into())
+        })
+    }
+
+    /// Inner function for the withdraw_vote_joint_multisig call.
+    pub(crate) fn inner_withdraw_vote_joint_multisig(
+        caller: OriginFor<T>,
+        dao_id: T::DaoId,
+        proposal_hash: T::Hash,
+    ) -> DispatchResultWithPostInfo {
+        JointMultisig::<T>::try_mutate_exists(proposal_hash, |data| {
+            let owner = ensure_signed(caller.clone())?;
+
+            let mut old_data = data.take().ok_or(Error::<T>::JointMultisigNotFound)?;
+
+            let part = old_data
+                .parts
+                .iter_mut()
+                .find(|part| part.dao_id == dao_id)
+                .ok_or(Error::<T>::NotJointMultisigDao)?;
+
+            // Try to mutate the dao's tally to remove the vote
+            let old_vote = part.tally.process_vote(owner.clone(), None)?;
+
+            // The NFT items the caller voted with can be used by their new owners
+            Self::clear_proposal_nft_votes(dao_id, proposal_hash, Some(&owner));
+
+            // Update storage with the new tally
+            *data = Some(old_data);
+
+            Self::deposit_event(Event::JointMultisigVoteWithdrawn {
+                dao_id,
+                voter: owner,
+                votes_removed: old_vote,
+                proposal_hash,
+            });
+
+            Ok(().into())
+        })
+    }
+
+    /// Inner function for the cancel_joint_multisig_proposal call.
+    pub(crate) fn inner_cancel_joint_multisig_proposal(
+        origin: OriginFor<T>,
+        proposal_hash: T::Hash,
+    ) -> DispatchResultWithPostInfo {
+        // Ensure that this is being called by the multisig origin rather than by a normal caller
+        let dao_origin = ensure_multisig::<T, OriginFor<T>>(origin)?;
+        let dao_id = dao_origin.id;
+
+        let data =
+            JointMultisig::<T>::get(proposal_hash).ok_or(Error::<T>::JointMultisigNotFound)?;
+
+        // Any of the daos taking part in the proposal can cancel it
+        ensure!(
+            data.parts.iter().any(|part| part.dao_id == dao_id),
+            Error::<T>::NotJointMultisigDao
+        );
+
+        // Remove the proposal from storage
+        JointMultisig::<T>::remove(proposal_hash);
+
+        for part in data.parts.iter() {
+            Self::clear_proposal_nft_votes(part.dao_id, proposal_hash, None);
+        }
+
+        Self::deposit_event(Event::<T>::JointMultisigCanceled {
+            dao_id,
+            proposal_hash,
+        });
+
+        Ok(().into())
+    }
+
+    /// Checks whether the tally of every dao in a joint proposal meets the thresholds of that dao.
+    ///
+    /// Joint proposals only hold one tally per dao, so bicameral daos can't take part in them.
+    fn joint_multisig_passes(parts: &[JointMultisigPartOf<T>]) -> Result<bool, DispatchError> {
+        for part in parts {
+            let dao = CoreStorage::<T>::get(part.dao_id).ok_or(Error::<T>::DaoNotFound)?;
+
+            ensure!(dao.second_chamber.is_none(), Error::<T>::BicameralJointDao);
+
+            if part.tally.support(part.dao_id) < dao.minimum_support
+                || part.tally.approval(part.dao_id) < dao.required_approval
+            {
+                return Ok(false);
+            }
+        }
+
+        Ok(true)
+    }
+
+    /// Dispatches the call of every dao in a joint proposal, reverting all of them if any fails.
+    fn execute_joint_multisig(
+        proposal_hash: T::Hash,
+        voter: T::AccountId,
+        calls: Vec<(T::DaoId, <T as Config>::RuntimeCall)>,
+        fee_asset: &FeeAsset,
+    ) {
+        let daos: Vec<T::DaoId> = calls.iter().map(|(dao_id, _)| *dao_id).collect();
+
+        // The proposal is gone, so are the NFT items voting on it
+        for dao_id in daos.iter() {
+            Self::clear_proposal_nft_votes(*dao_id, proposal_hash, None);
+        }
+
+        let result = with_transaction(|| {
+            for (dao_id, call) in calls {
+                if let Err(e) = crate::dispatch::dispatch_call::<T>(dao_id, fee_asset, None, call) {
+                    return TransactionOutcome::Rollback(Err(e.error));
+                }
+            }
+
+            TransactionOutcome::Commit(Ok(()))
+        });
+
+        Self::deposit_event(Event::JointMultisigExecuted {
+            daos,
+            voter,
+            proposal_hash,
+            result,
+        });
+    }
+}
@@ -17,10 +17,30 @@
 //! - `token_mint` - Mint the DAO's voting token to a target (called by a DAO origin)
 //! - `token_burn` - Burn the DAO's voting token from a target (called by a DAO origin)
 //! - `operate_multisig` - Create a new multisig proposal, auto-executing if caller passes execution threshold requirements
+//! - `operate_sponsored_multisig` - Create a new multisig proposal whose execution fee is paid by the caller instead of the DAO
+//! - `operate_emergency_multisig` - Create a new emergency multisig proposal, requiring a supermajority of the total token supply to execute
 //! - `vote_multisig` - Vote on an existing multisig proposal, auto-executing if caller puts vote tally past execution threshold requirements
 //! - `withdraw_vote_multisig` - Remove caller's vote from an existing multisig proposal
 //! - `cancel_multisig_proposal` - Cancel an existing multisig proposal (called by a DAO origin)
 //! - `set_parameters` - Change DAO parameters incl. voting thresholds and token freeze state (called by a DAO origin)
+//! - `set_voting_strategy` - Change how the voting power of the DAO members is computed (called by a DAO origin)
+//! - `set_mirror` - Allow a DAO in a sibling chain to operate this DAO natively through XCM (called by a DAO origin)
+//! - `set_handle` - Claim a unique handle the DAO can be looked up by, holding a deposit from the DAO account (called by a DAO origin)
+//! - `set_token_metadata` - Set the name, symbol and decimals of the DAO's voting token (called by a DAO origin)
+//! - `set_token_supply_cap` - Set or lower the maximum supply of the DAO's voting token (called by a DAO origin)
+//! - `token_mint_vested` - Mint the DAO's voting token to a target, locked under a vesting schedule (called by a DAO origin)
+//! - `set_locked_tokens_vote` - Set whether voting tokens still locked by vesting count towards voting power (called by a DAO origin)
+//! - `set_second_chamber` - Set or remove the second chamber whose approval proposals also require, making the DAO bicameral (called by a DAO origin)
+//! - `operate_public_multisig` - Submit a proposal to a DAO without being a member of it, backed by a deposit
+//! - `expire_public_proposal` - Remove a public proposal that wasn't approved in time, slashing its deposit to the DAO
+//! - `operate_joint_multisig` - Create a new joint proposal with one call per DAO, executed once every DAO approves it
+//! - `vote_joint_multisig` - Vote as a member of one of the DAOs on an existing joint proposal, auto-executing if every DAO's tally passes its thresholds
+//! - `withdraw_vote_joint_multisig` - Remove caller's vote from an existing joint proposal
+//! - `cancel_joint_multisig_proposal` - Cancel an existing joint proposal (called by the origin of any of its DAOs)
+//!
+//! ### Runtime API
+//!
+//! - `DaoManagerApi::dry_run_proposal` - Dispatch a call as a DAO without persisting it, returning the events, result, weight, fee and XCM messages it would produce, charging the fee to the dao or to a sponsoring proposer
 
 #![cfg_attr(not(feature = "std"), no_std)]
 #![allow(clippy::unused_unit)]
@@ -35,20 +55,28 @@ mod benchmarking;
 #[cfg(test)]
 mod tests;
 
-//pub mod migrations;
+pub mod migrations;
 
 pub mod account_derivation;
+pub mod bicameral;
 pub mod dao_manager_core;
 mod dispatch;
+pub mod dry_run;
 pub mod fee_handling;
+pub mod handles;
+pub mod joint_multisig;
 mod lookup;
 pub mod multisig;
 pub mod origin;
+pub mod public_proposals;
+pub mod token;
+pub mod vesting;
 pub mod voting;
 pub mod weights;
+pub mod xcm_origin;
 
 pub use account_derivation::DaoAccountDerivation;
-use fee_handling::FeeAsset;
+use fee_handling::{FeeAsset, FeePayer};
 pub use lookup::DaoLookup;
 pub use weights::WeightInfo;
 
@@ -57,7 +85,12 @@ pub mod pallet {
     use core::iter::Sum;
 
     use crate::{
+        bicameral::ChamberOf,
         fee_handling::MultisigFeeHandler,
+        handles::DaoHandleOf,
+        public_proposals::PublicProposalOf,
+        token::DaoTokenMetadataOf,
+        vesting::VestingScheduleOf,
         voting::{Tally, VoteRecord},
     };
 
@@ -69,14 +102,15 @@ pub mod pallet {
         traits::{
             fungible::{Balanced, Inspect, InspectHold, Mutate, MutateHold},
             fungibles::{self, Balanced as Balanceds, Inspect as Inspects},
-            Get, GetCallMetadata,
+            tokens::nonfungibles,
+            CallMetadata, Contains, Get, GetCallMetadata,
         },
         transactional,
         weights::WeightToFee,
         Parameter,
     };
     use frame_system::{pallet_prelude::*, RawOrigin};
-    use primitives::DaoInfo;
+    use primitives::{DaoInfo, VotingStrategy};
     use scale_info::prelude::fmt::Display;
     use sp_runtime::{
         traits::{AtLeast32BitUnsigned, Dispatchable, Member},
@@ -84,15 +118,21 @@ pub mod pallet {
     };
     use sp_std::{boxed::Box, convert::TryInto, vec::Vec};
 
-    pub use super::{dao_manager_core, multisig};
+    pub use super::{dao_manager_core, joint_multisig, multisig};
 
-    use crate::origin::DaoOrigin;
+    use crate::{dao_manager_core::DaoMetadataOf, origin::DaoOrigin};
 
     pub type BalanceOf<T> =
         <<T as Config>::Currency as Inspect<<T as frame_system::Config>::AccountId>>::Balance;
 
-    pub type DaoInfoOf<T> =
-        DaoInfo<<T as frame_system::Config>::AccountId, dao_manager_core::DaoMetadataOf<T>>;
+    pub type DaoInfoOf<T> = DaoInfo<
+        <T as frame_system::Config>::AccountId,
+        DaoMetadataOf<T>,
+        <T as Config>::NftCollectionId,
+        ChamberOf<T>,
+    >;
+
+    pub type VotingStrategyOf<T> = VotingStrategy<<T as Config>::NftCollectionId>;
 
     pub type CallOf<T> = <T as Config>::RuntimeCall;
 
@@ -135,10 +175,22 @@ pub mod pallet {
         #[pallet::constant]
         type MaxCallers: Get<u32>;
 
-        /// The maximum length of the dao metadata and the metadata of multisig proposals
+        /// The maximum length of the metadata of multisig proposals
         #[pallet::constant]
         type MaxMetadata: Get<u32>;
 
+        /// The maximum length of a dao name
+        #[pallet::constant]
+        type MaxNameLength: Get<u32>;
+
+        /// The maximum length of a dao description
+        #[pallet::constant]
+        type MaxDescriptionLength: Get<u32>;
+
+        /// The maximum length of the website and IPFS CIDs in the dao metadata
+        #[pallet::constant]
+        type MaxUrlLength: Get<u32>;
+
         /// The outer `Origin` type.
         type RuntimeOrigin: From<Origin<Self>>
             + From<<Self as frame_system::Config>::RuntimeOrigin>
@@ -166,8 +218,8 @@ pub mod pallet {
         type AssetsProvider: fungibles::Inspect<Self::AccountId, Balance = BalanceOf<Self>, AssetId = Self::DaoId>
             + fungibles::Mutate<Self::AccountId, AssetId = Self::DaoId>;
 
-        /// Provider of balance tokens in the runtime
-        type Tokens: Balanceds<Self::AccountId> + Inspects<Self::AccountId>;
+        /// Provider of balance tokens in the runtime, assets are identified by their registered id
+        type Tokens: Balanceds<Self::AccountId> + Inspects<Self::AccountId, AssetId = u32>;
 
         /// Implementation of the fee handler for both dao creation fee and multisig call fees
         type FeeCharger: MultisigFeeHandler<Self>;
@@ -184,10 +236,66 @@ pub mod pallet {
 
         /// Byte to fee conversion provider, from pallet_transaction_payment.
         type LengthToFee: WeightToFee<Balance = BalanceOf<Self>>;
+
+        /// Minimum amount of positive votes out of total token supply required to execute an emergency proposal
+        #[pallet::constant]
+        type EmergencySupport: Get<Perbill>;
+
+        /// Calls that are allowed to be proposed as emergency proposals
+        type EmergencyCalls: Contains<CallMetadata>;
+
+        /// Collection id type of the NFTs that can be used as voting power
+        type NftCollectionId: Parameter + Member + Copy + MaxEncodedLen;
+
+        /// Item id type of the NFTs that can be used as voting power
+        type NftItemId: Parameter + Member + Copy + MaxEncodedLen;
+
+        /// Provider of NFT functionality for daos using NFT voting strategies
+        type Nfts: nonfungibles::InspectEnumerable<
+            Self::AccountId,
+            CollectionId = Self::NftCollectionId,
+            ItemId = Self::NftItemId,
+        >;
+
+        /// The maximum number of items of an NFT collection a dao can derive its voting power from
+        #[pallet::constant]
+        type MaxNftVotingItems: Get<u32>;
+
+        /// The maximum number of daos taking part in a single joint proposal
+        #[pallet::constant]
+        type MaxJointDaos: Get<u32>;
+
+        /// The maximum length of a dao handle
+        #[pallet::constant]
+        type MaxHandleLength: Get<u32>;
+
+        /// Deposit held from the dao account for claiming a handle
+        #[pallet::constant]
+        type HandleDeposit: Get<BalanceOf<Self>>;
+
+        /// The maximum length of the symbol of a dao voting token
+        #[pallet::constant]
+        type MaxSymbolLength: Get<u32>;
+
+        /// The maximum amount of vesting schedules locking the voting tokens of an account in a dao
+        #[pallet::constant]
+        type MaxVestingSchedules: Get<u32>;
+
+        /// The maximum number of members in the council chamber of a bicameral dao
+        #[pallet::constant]
+        type MaxCouncilMembers: Get<u32>;
+
+        /// Deposit held from the proposer of a public proposal, slashed to the dao if the proposal isn't approved
+        #[pallet::constant]
+        type PublicProposalDeposit: Get<BalanceOf<Self>>;
+
+        /// Number of blocks a public proposal can be voted on before expiring
+        #[pallet::constant]
+        type PublicProposalDuration: Get<BlockNumberFor<Self>>;
     }
 
     /// The current storage version.
-    const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(7);
 
     /// The custom dao origin.
     #[pallet::origin]
@@ -207,6 +315,13 @@ pub mod pallet {
     #[pallet::getter(fn dao_storage)]
     pub type CoreStorage<T: Config> = StorageMap<_, Blake2_128Concat, T::DaoId, DaoInfoOf<T>>;
 
+    /// Free-form metadata of the daos created before the metadata was structured,
+    /// kept for the daos whose old metadata didn't fully fit in their description.
+    #[pallet::storage]
+    #[pallet::getter(fn legacy_dao_metadata)]
+    pub type LegacyDaoMetadata<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::DaoId, BoundedVec<u8, T::MaxMetadata>>;
+
     /// Mapping of account id -> dao id.
     #[pallet::storage]
     #[pallet::getter(fn dao_by_account)]
@@ -226,6 +341,18 @@ pub mod pallet {
         crate::multisig::MultisigOperationOf<T>,
     >;
 
+    /// Details of a joint multisig proposal, holding the call and the tally of every dao taking part in it.
+    ///
+    /// Key: proposal hash
+    #[pallet::storage]
+    #[pallet::getter(fn joint_multisig)]
+    pub type JointMultisig<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::Hash,
+        crate::joint_multisig::JointMultisigOperationOf<T>,
+    >;
+
     /// Stores a list of members for each DAO.
     /// This storage should be always handled by the runtime and mutated by CoreAssets hooks.
     // We make this a StorageDoubleMap so we don't have to bound the list.
@@ -234,6 +361,117 @@ pub mod pallet {
     pub type CoreMembers<T: Config> =
         StorageDoubleMap<_, Blake2_128Concat, T::DaoId, Blake2_128Concat, T::AccountId, ()>;
 
+    /// Number of members of each DAO, kept in sync with `CoreMembers`.
+    #[pallet::storage]
+    #[pallet::getter(fn dao_member_count)]
+    pub type CoreMemberCount<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::DaoId, u32, ValueQuery>;
+
+    /// Sum of the square roots of the voting token balances of each DAO's members,
+    /// the total voting power of DAOs voting quadratically.
+    /// This storage should be always handled by the runtime and mutated by CoreAssets hooks.
+    #[pallet::storage]
+    #[pallet::getter(fn dao_quadratic_voting_power)]
+    pub type CoreQuadraticVotingPower<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::DaoId, BalanceOf<T>, ValueQuery>;
+
+    /// Mapping of NFT collection -> dao deriving its voting power from it.
+    #[pallet::storage]
+    #[pallet::getter(fn nft_collection_dao)]
+    pub type NftCollectionDao<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::NftCollectionId, T::DaoId>;
+
+    /// Total voting power of the NFT collections used by daos for voting.
+    /// Computed when the voting strategy is set and every time a proposal is created in the dao.
+    #[pallet::storage]
+    #[pallet::getter(fn nft_voting_power)]
+    pub type NftVotingPower<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::NftCollectionId, BalanceOf<T>, ValueQuery>;
+
+    /// Mapping of NFT items used to vote on an ongoing proposal -> account that voted with them.
+    /// Keeps items from voting again on the same proposal after being transferred.
+    ///
+    /// Key: (Dao id, proposal hash, item id)
+    #[pallet::storage]
+    #[pallet::getter(fn nft_proposal_vote)]
+    pub type NftProposalVotes<T: Config> = StorageNMap<
+        _,
+        (
+            NMapKey<Blake2_128Concat, T::DaoId>,
+            NMapKey<Blake2_128Concat, T::Hash>,
+            NMapKey<Blake2_128Concat, T::NftItemId>,
+        ),
+        T::AccountId,
+    >;
+
+    /// Mapping of a dao in a trusted remote chain -> local dao it is allowed to operate through XCM.
+    ///
+    /// Key: (Remote chain para id, remote dao id)
+    #[pallet::storage]
+    #[pallet::getter(fn mirrored_dao)]
+    pub type MirroredDaos<T: Config> =
+        StorageDoubleMap<_, Twox64Concat, u32, Twox64Concat, u32, T::DaoId>;
+
+    /// Mapping of local dao id -> (remote chain para id, remote dao id) of the dao it mirrors.
+    #[pallet::storage]
+    #[pallet::getter(fn mirror_source)]
+    pub type MirrorSource<T: Config> = StorageMap<_, Blake2_128Concat, T::DaoId, (u32, u32)>;
+
+    /// Mapping of dao handle -> dao id.
+    #[pallet::storage]
+    #[pallet::getter(fn dao_by_handle)]
+    pub type DaoByHandle<T: Config> = StorageMap<_, Blake2_128Concat, DaoHandleOf<T>, T::DaoId>;
+
+    /// Mapping of dao id -> (handle, deposit held for it).
+    #[pallet::storage]
+    #[pallet::getter(fn dao_handle)]
+    pub type DaoHandles<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::DaoId, (DaoHandleOf<T>, BalanceOf<T>)>;
+
+    /// Mapping of dao id -> metadata of its voting token.
+    #[pallet::storage]
+    #[pallet::getter(fn token_metadata)]
+    pub type TokenMetadata<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::DaoId, DaoTokenMetadataOf<T>>;
+
+    /// Mapping of dao id -> maximum supply of its voting token.
+    #[pallet::storage]
+    #[pallet::getter(fn token_supply_cap)]
+    pub type TokenSupplyCap<T: Config> = StorageMap<_, Blake2_128Concat, T::DaoId, BalanceOf<T>>;
+
+    /// Vesting schedules locking the voting tokens of an account.
+    ///
+    /// Key: (Dao ID, account)
+    #[pallet::storage]
+    #[pallet::getter(fn vesting_schedules)]
+    pub type VestingSchedules<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::DaoId,
+        Blake2_128Concat,
+        T::AccountId,
+        BoundedVec<VestingScheduleOf<T>, T::MaxVestingSchedules>,
+        ValueQuery,
+    >;
+
+    /// Mapping of dao id -> whether voting tokens locked by vesting schedules count towards voting power.
+    #[pallet::storage]
+    pub type LockedTokensVote<T: Config> = StorageMap<_, Blake2_128Concat, T::DaoId, bool>;
+
+    /// Proposer, deposit and expiry of the multisig proposals submitted publicly.
+    ///
+    /// Key: (Dao ID, call hash)
+    #[pallet::storage]
+    #[pallet::getter(fn public_proposal)]
+    pub type PublicProposals<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::DaoId,
+        Blake2_128Concat,
+        T::Hash,
+        PublicProposalOf<T>,
+    >;
+
     #[pallet::event]
     #[pallet::generate_deposit(pub(crate) fn deposit_event)]
     pub enum Event<T: Config> {
@@ -241,7 +479,7 @@ pub mod pallet {
         DaoCreated {
             dao_account: T::AccountId,
             dao_id: T::DaoId,
-            metadata: Vec<u8>,
+            metadata: DaoMetadataOf<T>,
             minimum_support: Perbill,
             required_approval: Perbill,
         },
@@ -249,12 +487,18 @@ pub mod pallet {
         /// A dao had parameters changed
         ParametersSet {
             dao_id: T::DaoId,
-            metadata: Option<Vec<u8>>,
+            metadata: Option<DaoMetadataOf<T>>,
             minimum_support: Option<Perbill>,
             required_approval: Option<Perbill>,
             frozen_tokens: Option<bool>,
         },
 
+        /// A dao changed how the voting power of its members is computed
+        VotingStrategySet {
+            dao_id: T::DaoId,
+            strategy: VotingStrategyOf<T>,
+        },
+
         /// A dao's voting token was minted
         Minted {
             dao_id: T::DaoId,
@@ -278,6 +522,15 @@ pub mod pallet {
             call_hash: T::Hash,
         },
 
+        /// An emergency multisig proposal has started, it needs a supermajority of the total token supply to pass
+        EmergencyVoteStarted {
+            dao_id: T::DaoId,
+            executor_account: T::AccountId,
+            voter: T::AccountId,
+            votes_added: VoteRecord<T>,
+            call_hash: T::Hash,
+        },
+
         /// A vote was added to an existing multisig proposal
         MultisigVoteAdded {
             dao_id: T::DaoId,
@@ -312,6 +565,124 @@ pub mod pallet {
             dao_id: T::DaoId,
             call_hash: T::Hash,
         },
+
+        /// A dao changed the sibling dao allowed to operate it through XCM
+        MirrorSet {
+            dao_id: T::DaoId,
+            source: Option<(u32, u32)>,
+        },
+
+        /// A dao claimed, changed or cleared its handle
+        HandleSet {
+            dao_id: T::DaoId,
+            handle: Option<Vec<u8>>,
+        },
+
+        /// A dao set the metadata of its voting token
+        TokenMetadataSet {
+            dao_id: T::DaoId,
+            metadata: DaoTokenMetadataOf<T>,
+        },
+
+        /// A dao set or lowered the supply cap of its voting token
+        TokenSupplyCapSet { dao_id: T::DaoId, cap: BalanceOf<T> },
+
+        /// A dao's voting token was minted under a vesting schedule
+        MintedVested {
+            dao_id: T::DaoId,
+            target: T::AccountId,
+            schedule: VestingScheduleOf<T>,
+        },
+
+        /// A dao set whether voting tokens locked by vesting count towards voting power
+        LockedTokensVoteSet { dao_id: T::DaoId, can_vote: bool },
+
+        /// A dao set or removed its second chamber
+        SecondChamberSet {
+            dao_id: T::DaoId,
+            chamber: Option<ChamberOf<T>>,
+        },
+
+        /// A vote was added to the second chamber tally of an existing multisig proposal
+        SecondChamberVoteAdded {
+            dao_id: T::DaoId,
+            voter: T::AccountId,
+            votes_added: VoteRecord<T>,
+            current_votes: Tally<T>,
+            call_hash: T::Hash,
+        },
+
+        /// A vote was removed from the second chamber tally of an existing multisig proposal
+        SecondChamberVoteWithdrawn {
+            dao_id: T::DaoId,
+            voter: T::AccountId,
+            votes_removed: VoteRecord<T>,
+            call_hash: T::Hash,
+        },
+
+        /// A public proposal was submitted, it needs the votes of the dao members to pass
+        PublicProposalSubmitted {
+            dao_id: T::DaoId,
+            executor_account: T::AccountId,
+            proposer: T::AccountId,
+            call_hash: T::Hash,
+            deposit: BalanceOf<T>,
+            expires_at: BlockNumberFor<T>,
+        },
+
+        /// A public proposal was approved and its deposit returned to the proposer
+        PublicProposalDepositReturned {
+            dao_id: T::DaoId,
+            proposer: T::AccountId,
+            call_hash: T::Hash,
+            deposit: BalanceOf<T>,
+        },
+
+        /// A public proposal was rejected or expired and its deposit slashed to the dao
+        PublicProposalDepositSlashed {
+            dao_id: T::DaoId,
+            proposer: T::AccountId,
+            call_hash: T::Hash,
+            deposit: BalanceOf<T>,
+        },
+
+        /// A joint multisig proposal has started, it needs the approval of every dao taking part in it
+        JointMultisigVoteStarted {
+            daos: Vec<T::DaoId>,
+            voter: T::AccountId,
+            proposal_hash: T::Hash,
+        },
+
+        /// A vote was added to the tally of one of the daos in an existing joint multisig proposal
+        JointMultisigVoteAdded {
+            dao_id: T::DaoId,
+            voter: T::AccountId,
+            votes_added: VoteRecord<T>,
+            current_votes: Tally<T>,
+            proposal_hash: T::Hash,
+        },
+
+        /// A vote was removed from the tally of one of the daos in an existing joint multisig proposal
+        JointMultisigVoteWithdrawn {
+            dao_id: T::DaoId,
+            voter: T::AccountId,
+            votes_removed: VoteRecord<T>,
+            proposal_hash: T::Hash,
+        },
+
+        /// A joint multisig proposal passed in every dao and it's calls were executed
+        JointMultisigExecuted {
+            daos: Vec<T::DaoId>,
+            voter: T::AccountId,
+            proposal_hash: T::Hash,
+            result: DispatchResult,
+        },
+
+        /// A joint multisig proposal was cancelled by one of it's daos
+        JointMultisigCanceled {
+            dao_id: T::DaoId,
+            proposal_hash: T::Hash,
+        },
     }
 
     /// Errors for dao_manager pallet
@@ -343,6 +714,58 @@ pub mod pallet {
         CallFeePaymentFailed,
         /// Call is too long
         MaxCallLengthExceeded,
+        /// The sibling dao is already mirrored by another dao
+        MirrorSourceAlreadyInUse,
+        /// The call is not allowed to be proposed as an emergency proposal
+        CallNotEmergencyEligible,
+        /// NFT collection not found
+        NftCollectionNotFound,
+        /// The NFT collection is not owned by the dao
+        NotNftCollectionOwner,
+        /// The NFT collection has more items than daos can derive their voting power from
+        TooManyNftItems,
+        /// The dao doesn't derive its voting power from an NFT collection
+        NoNftVotingStrategy,
+        /// Joint multisig proposal not found
+        JointMultisigNotFound,
+        /// Joint multisig proposal already exists and is being voted on
+        JointMultisigAlreadyExists,
+        /// A joint multisig proposal needs at least two daos
+        TooFewJointDaos,
+        /// A dao can only appear once in a joint multisig proposal
+        DuplicateJointDao,
+        /// The dao is not taking part in the joint multisig proposal
+        NotJointMultisigDao,
+        /// The asset can't be used for paying fees
+        UnsupportedFeeAsset,
+        /// The dao metadata is malformed
+        InvalidMetadata,
+        /// Handles can only contain lowercase ascii letters, digits and dashes
+        InvalidHandle,
+        /// The handle is already used by another dao
+        HandleAlreadyInUse,
+        /// The token name must be UTF-8 and the symbol made of ascii letters and digits
+        InvalidTokenMetadata,
+        /// Minting would push the voting token supply over the dao's supply cap
+        SupplyCapExceeded,
+        /// The supply cap of a dao can only be lowered
+        SupplyCapCannotBeRaised,
+        /// The supply cap can't be lower than the current voting token supply
+        SupplyCapBelowIssuance,
+        /// Vesting schedules must vest a non-zero amount and linear ones need a non-zero duration
+        InvalidVestingSchedule,
+        /// The account already has the maximum amount of vesting schedules in this dao
+        TooManyVestingSchedules,
+        /// A council chamber needs members and a token chamber must use the voting token of another existing dao
+        InvalidSecondChamber,
+        /// Bicameral daos can't take part in joint multisig proposals
+        BicameralJointDao,
+        /// Public proposal not found
+        PublicProposalNotFound,
+        /// The public proposal can still be voted on
+        PublicProposalNotExpired,
+        /// The public proposal can't be voted on anymore
+        PublicProposalExpired,
     }
 
     /// A reason for the pallet contracts placing a hold on funds.
@@ -361,16 +784,16 @@ pub mod pallet {
         <T as frame_system::Config>::AccountId: From<[u8; 32]>,
     {
         /// Create a new DAO
-        /// - `metadata`: Arbitrary byte vec to be attached to the dao info
+        /// - `metadata`: Name, description, logo, website and IPFS CID of the dao
         /// - `minimum_support`: Minimum amount of positive votes out of total token supply required to approve a proposal
         /// - `required_approval`: Minimum amount of positive votes out of current positive + negative votes required to approve a proposal
         /// - `creation_fee_asset`: Token to be used to pay the dao creation fee
         #[pallet::call_index(0)]
         #[transactional]
-        #[pallet::weight(<T as Config>::WeightInfo::create_dao(metadata.len() as u32))]
+        #[pallet::weight(<T as Config>::WeightInfo::create_dao(metadata.encoded_size() as u32))]
         pub fn create_dao(
             owner: OriginFor<T>,
-            metadata: BoundedVec<u8, T::MaxMetadata>,
+            metadata: DaoMetadataOf<T>,
             minimum_support: Perbill,
             required_approval: Perbill,
             creation_fee_asset: FeeAsset,
@@ -428,6 +851,7 @@ pub mod pallet {
                 metadata.clone().map(|m| m.len()).unwrap_or(0) as u32,
                 call.using_encoded(|c| c.len() as u32)
             )
+            .saturating_add(Pallet::<T>::nft_proposal_creation_weight())
         )]
         pub fn operate_multisig(
             caller: OriginFor<T>,
@@ -436,7 +860,15 @@ pub mod pallet {
             fee_asset: FeeAsset,
             call: Box<<T as pallet::Config>::RuntimeCall>,
         ) -> DispatchResultWithPostInfo {
-            Pallet::<T>::inner_operate_multisig(caller, dao_id, metadata, fee_asset, call)
+            Pallet::<T>::inner_operate_multisig(
+                caller,
+                dao_id,
+                metadata,
+                fee_asset,
+                call,
+                false,
+                FeePayer::Dao,
+            )
         }
 
         /// Vote on an existing multisig proposal, auto-executing if caller puts vote tally past execution threshold requirements
@@ -444,7 +876,11 @@ pub mod pallet {
         /// - `call_hash`: Hash of the call identifying the proposal
         /// - `aye`: Wheter or not to vote positively
         #[pallet::call_index(4)]
-        #[pallet::weight(<T as Config>::WeightInfo::vote_multisig())]
+        #[pallet::weight(
+            <T as Config>::WeightInfo::vote_multisig().saturating_add(
+                <T as Config>::WeightInfo::note_proposal_voting_power(T::MaxNftVotingItems::get())
+            )
+        )]
         pub fn vote_multisig(
             caller: OriginFor<T>,
             dao_id: T::DaoId,
@@ -458,7 +894,11 @@ pub mod pallet {
         /// - `dao_id`: Id of the dao where the proposal is
         /// - `call_hash`: Hash of the call identifying the proposal
         #[pallet::call_index(5)]
-        #[pallet::weight(<T as Config>::WeightInfo::withdraw_vote_multisig())]
+        #[pallet::weight(
+            <T as Config>::WeightInfo::withdraw_vote_multisig().saturating_add(
+                <T as Config>::WeightInfo::note_proposal_voting_power(T::MaxNftVotingItems::get())
+            )
+        )]
         pub fn withdraw_vote_multisig(
             caller: OriginFor<T>,
             dao_id: T::DaoId,
@@ -470,7 +910,11 @@ pub mod pallet {
         /// Cancel an existing multisig proposal (called by a dao origin)
         /// - `call_hash`: Hash of the call identifying the proposal
         #[pallet::call_index(6)]
-        #[pallet::weight(<T as Config>::WeightInfo::cancel_multisig_proposal())]
+        #[pallet::weight(
+            <T as Config>::WeightInfo::cancel_multisig_proposal().saturating_add(
+                <T as Config>::WeightInfo::note_proposal_voting_power(T::MaxNftVotingItems::get())
+            )
+        )]
         pub fn cancel_multisig_proposal(
             caller: OriginFor<T>,
             call_hash: T::Hash,
@@ -479,17 +923,17 @@ pub mod pallet {
         }
 
         /// Change dao parameters incl. voting thresholds and token freeze state (called by a dao origin)
-        /// - `metadata`: Arbitrary byte vec to be attached to the dao info
+        /// - `metadata`: Name, description, logo, website and IPFS CID of the dao
         /// - `minimum_support`: Minimum amount of positive votes out of total token supply required to approve a proposal
         /// - `required_approval`: Minimum amount of positive votes out of current positive + negative votes required to approve a proposal
         /// - `frozen_tokens`: Wheter or not the dao's voting token should be transferable by the holders
         #[pallet::call_index(9)]
         #[pallet::weight(<T as Config>::WeightInfo::set_parameters(
-            metadata.clone().map(|m| m.len()).unwrap_or(0) as u32
+            metadata.as_ref().map(|m| m.encoded_size()).unwrap_or(0) as u32
         ))]
         pub fn set_parameters(
             origin: OriginFor<T>,
-            metadata: Option<BoundedVec<u8, T::MaxMetadata>>,
+            metadata: Option<DaoMetadataOf<T>>,
             minimum_support: Option<Perbill>,
             required_approval: Option<Perbill>,
             frozen_tokens: Option<bool>,
@@ -502,5 +946,283 @@ pub mod pallet {
                 frozen_tokens,
             )
         }
+
+        /// Change how the voting power of the dao members is computed (called by a dao origin)
+        /// Ongoing proposals keep the votes already cast, but their support is computed with the new strategy
+        /// NFT strategies require the dao to own the collection, whose items become non transferable while the dao's tokens are frozen
+        /// - `strategy`: The new voting strategy
+        #[pallet::call_index(12)]
+        #[pallet::weight(
+            <T as Config>::WeightInfo::set_voting_strategy().saturating_add(
+                <T as Config>::WeightInfo::note_nft_voting_power(T::MaxNftVotingItems::get())
+            )
+        )]
+        pub fn set_voting_strategy(
+            origin: OriginFor<T>,
+            strategy: VotingStrategyOf<T>,
+        ) -> DispatchResult {
+            Pallet::<T>::inner_set_voting_strategy(origin, strategy)
+        }
+
+        /// Allow a dao in a trusted remote chain to operate this dao natively through XCM (called by a dao origin)
+        /// - `source`: Para id of the remote chain and id of the dao in it, `None` removes the current source
+        #[pallet::call_index(10)]
+        #[pallet::weight(<T as Config>::WeightInfo::set_mirror())]
+        pub fn set_mirror(origin: OriginFor<T>, source: Option<(u32, u32)>) -> DispatchResult {
+            Pallet::<T>::inner_set_mirror(origin, source)
+        }
+
+        /// Create a new emergency multisig proposal, restricted to the calls allowed by `T::EmergencyCalls`
+        /// Emergency proposals require at least `T::EmergencySupport` of the total token supply and execute as soon as it is reached
+        /// - `dao_id`: Id of the dao to propose the call in
+        /// - `metadata`: Arbitrary byte vec to be attached to the proposal
+        /// - `fee_asset`: Token to be used by the multisig to pay for call fees
+        /// - `call`: The actual call to be proposed
+        #[pallet::call_index(11)]
+        #[pallet::weight(
+            <T as Config>::WeightInfo::operate_emergency_multisig(
+                metadata.clone().map(|m| m.len()).unwrap_or(0) as u32,
+                call.using_encoded(|c| c.len() as u32)
+            )
+            .saturating_add(Pallet::<T>::nft_proposal_creation_weight())
+        )]
+        pub fn operate_emergency_multisig(
+            caller: OriginFor<T>,
+            dao_id: T::DaoId,
+            metadata: Option<BoundedVec<u8, T::MaxMetadata>>,
+            fee_asset: FeeAsset,
+            call: Box<<T as pallet::Config>::RuntimeCall>,
+        ) -> DispatchResultWithPostInfo {
+            Pallet::<T>::inner_operate_multisig(
+                caller,
+                dao_id,
+                metadata,
+                fee_asset,
+                call,
+                true,
+                FeePayer::Dao,
+            )
+        }
+
+        /// Create a new joint multisig proposal, requiring the approval of every dao taking part in it
+        /// Each dao has its own tally, and its call is only executed once every tally meets the thresholds of its dao
+        /// The calls are executed in order with the origin of their dao, if any of them fails none of them has any effect
+        /// The caller's vote is added to the tally of every dao they have voting power in
+        /// - `calls`: Id of every dao taking part in the proposal along with the call it executes
+        /// - `metadata`: Arbitrary byte vec to be attached to the proposal
+        /// - `fee_asset`: Token to be used by the multisigs to pay for call fees
+        #[pallet::call_index(13)]
+        #[pallet::weight(
+            <T as Config>::WeightInfo::operate_joint_multisig(
+                calls.len() as u32,
+                metadata.clone().map(|m| m.len()).unwrap_or(0) as u32,
+                calls.using_encoded(|c| c.len() as u32)
+            )
+            .saturating_add(
+                Pallet::<T>::nft_proposal_creation_weight().saturating_mul(calls.len() as u64)
+            )
+        )]
+        pub fn operate_joint_multisig(
+            caller: OriginFor<T>,
+            calls: BoundedVec<(T::DaoId, <T as pallet::Config>::RuntimeCall), T::MaxJointDaos>,
+            metadata: Option<BoundedVec<u8, T::MaxMetadata>>,
+            fee_asset: FeeAsset,
+        ) -> DispatchResultWithPostInfo {
+            Pallet::<T>::inner_operate_joint_multisig(caller, calls, metadata, fee_asset)
+        }
+
+        /// Vote on an existing joint multisig proposal as a member of one of its daos, auto-executing if every dao's tally passes its thresholds
+        /// - `dao_id`: Id of the dao to vote as
+        /// - `proposal_hash`: Hash of the calls identifying the proposal
+        /// - `aye`: Wheter or not to vote positively
+        #[pallet::call_index(14)]
+        #[pallet::weight(
+            <T as Config>::WeightInfo::vote_joint_multisig(T::MaxJointDaos::get()).saturating_add(
+                <T as Config>::WeightInfo::note_proposal_voting_power(T::MaxNftVotingItems::get())
+                    .saturating_mul(T::MaxJointDaos::get() as u64)
+            )
+        )]
+        pub fn vote_joint_multisig(
+            caller: OriginFor<T>,
+            dao_id: T::DaoId,
+            proposal_hash: T::Hash,
+            aye: bool,
+        ) -> DispatchResultWithPostInfo {
+            Pallet::<T>::inner_vote_joint_multisig(caller, dao_id, proposal_hash, aye)
+        }
+
+        /// Remove caller's vote from the tally of one of the daos in an existing joint multisig proposal
+        /// - `dao_id`: Id of the dao the vote was cast as
+        /// - `proposal_hash`: Hash of the calls identifying the proposal
+        #[pallet::call_index(15)]
+        #[pallet::weight(
+            <T as Config>::WeightInfo::withdraw_vote_joint_multisig().saturating_add(
+                <T as Config>::WeightInfo::note_proposal_voting_power(T::MaxNftVotingItems::get())
+            )
+        )]
+        pub fn withdraw_vote_joint_multisig(
+            caller: OriginFor<T>,
+            dao_id: T::DaoId,
+            proposal_hash: T::Hash,
+        ) -> DispatchResultWithPostInfo {
+            Pallet::<T>::inner_withdraw_vote_joint_multisig(caller, dao_id, proposal_hash)
+        }
+
+        /// Cancel an existing joint multisig proposal (called by the origin of any of its daos)
+        /// - `proposal_hash`: Hash of the calls identifying the proposal
+        #[pallet::call_index(16)]
+        #[pallet::weight(
+            <T as Config>::WeightInfo::cancel_joint_multisig_proposal().saturating_add(
+                <T as Config>::WeightInfo::note_proposal_voting_power(T::MaxNftVotingItems::get())
+                    .saturating_mul(T::MaxJointDaos::get() as u64)
+            )
+        )]
+        pub fn cancel_joint_multisig_proposal(
+            caller: OriginFor<T>,
+            proposal_hash: T::Hash,
+        ) -> DispatchResultWithPostInfo {
+            Pallet::<T>::inner_cancel_joint_multisig_proposal(caller, proposal_hash)
+        }
+
+        /// Create a new multisig proposal sponsored by the caller, auto-executing if caller passes execution threshold requirements
+        /// Works like `operate_multisig`, but the proposed call's fees are charged to the caller instead of the multisig,
+        /// allowing daos without funds to execute proposals
+        /// - `dao_id`: Id of the dao to propose the call in
+        /// - `metadata`: Arbitrary byte vec to be attached to the proposal
+        /// - `fee_asset`: Token to be used by the caller to pay for call fees
+        /// - `call`: The actual call to be proposed
+        #[pallet::call_index(17)]
+        #[pallet::weight(
+            <T as Config>::WeightInfo::operate_multisig(
+                metadata.clone().map(|m| m.len()).unwrap_or(0) as u32,
+                call.using_encoded(|c| c.len() as u32)
+            )
+            .saturating_add(Pallet::<T>::nft_proposal_creation_weight())
+        )]
+        pub fn operate_sponsored_multisig(
+            caller: OriginFor<T>,
+            dao_id: T::DaoId,
+            metadata: Option<BoundedVec<u8, T::MaxMetadata>>,
+            fee_asset: FeeAsset,
+            call: Box<<T as pallet::Config>::RuntimeCall>,
+        ) -> DispatchResultWithPostInfo {
+            Pallet::<T>::inner_operate_multisig(
+                caller,
+                dao_id,
+                metadata,
+                fee_asset,
+                call,
+                false,
+                FeePayer::Proposer,
+            )
+        }
+
+        /// Claim, change or clear the handle of the dao, used to look it up by name (called by a dao origin)
+        /// Claiming a handle holds `T::HandleDeposit` from the dao account until the handle is cleared
+        /// - `handle`: Unique handle made of lowercase ascii letters, digits and dashes, `None` clears the current handle
+        #[pallet::call_index(18)]
+        #[pallet::weight(<T as Config>::WeightInfo::set_handle())]
+        pub fn set_handle(origin: OriginFor<T>, handle: Option<DaoHandleOf<T>>) -> DispatchResult {
+            Pallet::<T>::inner_set_handle(origin, handle)
+        }
+
+        /// Set the name, symbol and decimals of the dao's voting token (called by a dao origin)
+        /// - `metadata`: Metadata of the voting token, displayed by wallets
+        #[pallet::call_index(19)]
+        #[pallet::weight(<T as Config>::WeightInfo::set_token_metadata())]
+        pub fn set_token_metadata(
+            origin: OriginFor<T>,
+            metadata: DaoTokenMetadataOf<T>,
+        ) -> DispatchResult {
+            Pallet::<T>::inner_set_token_metadata(origin, metadata)
+        }
+
+        /// Set or lower the maximum supply of the dao's voting token (called by a dao origin)
+        /// Once set, the cap can never be raised
+        /// - `cap`: Maximum supply of the voting token, can't be lower than the current supply
+        #[pallet::call_index(20)]
+        #[pallet::weight(<T as Config>::WeightInfo::set_token_supply_cap())]
+        pub fn set_token_supply_cap(origin: OriginFor<T>, cap: BalanceOf<T>) -> DispatchResult {
+            Pallet::<T>::inner_set_token_supply_cap(origin, cap)
+        }
+
+        /// Mint the dao's voting token to a target, locked under a vesting schedule (called by a dao origin)
+        /// The tokens can't be transferred until they unlock
+        /// - `target`: Account receiving the minted tokens
+        /// - `schedule`: Amount minted and how it unlocks, either linearly or all at once
+        #[pallet::call_index(21)]
+        #[pallet::weight(<T as Config>::WeightInfo::token_mint_vested())]
+        pub fn token_mint_vested(
+            origin: OriginFor<T>,
+            target: T::AccountId,
+            schedule: VestingScheduleOf<T>,
+        ) -> DispatchResult {
+            Pallet::<T>::inner_token_mint_vested(origin, target, schedule)
+        }
+
+        /// Set whether voting tokens still locked by vesting count towards voting power (called by a dao origin)
+        /// Locked tokens always count towards the total voting power of the dao
+        /// - `can_vote`: Whether locked tokens count towards voting power, they do by default
+        #[pallet::call_index(22)]
+        #[pallet::weight(<T as Config>::WeightInfo::set_locked_tokens_vote())]
+        pub fn set_locked_tokens_vote(origin: OriginFor<T>, can_vote: bool) -> DispatchResult {
+            Pallet::<T>::inner_set_locked_tokens_vote(origin, can_vote)
+        }
+
+        /// Set or remove the second chamber of the dao, whose approval proposals also require (called by a dao origin)
+        /// Ongoing proposals need the approval of the new second chamber, the votes already cast are weighed with its membership
+        /// - `chamber`: Council or voting token of the chamber along with its thresholds, `None` makes the dao unicameral
+        #[pallet::call_index(23)]
+        #[pallet::weight(<T as Config>::WeightInfo::set_second_chamber())]
+        pub fn set_second_chamber(
+            origin: OriginFor<T>,
+            chamber: Option<ChamberOf<T>>,
+        ) -> DispatchResult {
+            Pallet::<T>::inner_set_second_chamber(origin, chamber)
+        }
+
+        /// Submit a multisig proposal to a dao the caller doesn't need to be a member of, backed by `T::PublicProposalDeposit`
+        /// The proposal starts with no votes, the deposit is returned once it's approved and slashed to the dao
+        /// if the dao cancels it or if it isn't approved within `T::PublicProposalDuration` blocks
+        /// - `dao_id`: Id of the dao to propose the call in
+        /// - `metadata`: Arbitrary byte vec to be attached to the proposal
+        /// - `fee_asset`: Token to be used by the multisig to pay for call fees
+        /// - `call`: The actual call to be proposed
+        #[pallet::call_index(24)]
+        #[pallet::weight(
+            <T as Config>::WeightInfo::operate_public_multisig(
+                metadata.clone().map(|m| m.len()).unwrap_or(0) as u32,
+                call.using_encoded(|c| c.len() as u32)
+            )
+            .saturating_add(<T as Config>::WeightInfo::note_nft_voting_power(
+                T::MaxNftVotingItems::get()
+            ))
+        )]
+        pub fn operate_public_multisig(
+            caller: OriginFor<T>,
+            dao_id: T::DaoId,
+            metadata: Option<BoundedVec<u8, T::MaxMetadata>>,
+            fee_asset: FeeAsset,
+            call: Box<<T as pallet::Config>::RuntimeCall>,
+        ) -> DispatchResult {
+            Pallet::<T>::inner_operate_public_multisig(caller, dao_id, metadata, fee_asset, call)
+        }
+
+        /// Remove a public proposal that wasn't approved in time, slashing its deposit to the dao (callable by anyone)
+        /// - `dao_id`: Id of the dao where the proposal is
+        /// - `call_hash`: Hash of the call identifying the proposal
+        #[pallet::call_index(25)]
+        #[pallet::weight(
+            <T as Config>::WeightInfo::expire_public_proposal().saturating_add(
+                <T as Config>::WeightInfo::note_proposal_voting_power(T::MaxNftVotingItems::get())
+            )
+        )]
+        pub fn expire_public_proposal(
+            caller: OriginFor<T>,
+            dao_id: T::DaoId,
+            call_hash: T::Hash,
+        ) -> DispatchResult {
+            Pallet::<T>::inner_expire_public_proposal(caller, dao_id, call_hash)
+        }
     }
 }
@@ -4,12 +4,13 @@
 //!
 //!
 //! This module implements the [`StaticLookup`] trait allowing for convenient lookup of a DAO's
-//! AccountId from its DaoId.
+//! AccountId from its DaoId or its handle.
 //! This implementation abstracts on top of two lower level functions:
 //! - `lookup_dao`: Used for accessing the storage and retrieving a DAO's AccountId.
-//! - `lookup_address`: Used for converting from a `MultiAddress::Index` that contains a DaoId to this DAO's AccountId.
+//! - `lookup_address`: Used for converting from a `MultiAddress::Index` that contains a DaoId, or a `MultiAddress::Raw`
+//!   that contains a DAO handle, to this DAO's AccountId.
 
-use crate::{Config, CoreByAccount, CoreStorage, Pallet};
+use crate::{Config, CoreByAccount, CoreStorage, DaoHandles, Pallet};
 use core::marker::PhantomData;
 use frame_support::error::LookupError;
 use sp_runtime::{traits::StaticLookup, MultiAddress};
@@ -20,18 +21,19 @@ impl<T: Config> Pallet<T> {
         CoreStorage::<T>::get(dao_id).map(|dao| dao.account)
     }
 
-    /// Matches `MultiAddress` to allow for a `MultiAddress::Index` containing a DaoId to be converted
-    /// to it's derived AccountId.
+    /// Matches `MultiAddress` to allow for a `MultiAddress::Index` containing a DaoId, or a `MultiAddress::Raw`
+    /// containing a DAO handle, to be converted to it's derived AccountId.
     pub fn lookup_address(a: MultiAddress<T::AccountId, T::DaoId>) -> Option<T::AccountId> {
         match a {
             MultiAddress::Id(i) => Some(i),
             MultiAddress::Index(i) => Self::lookup_dao(i),
+            MultiAddress::Raw(handle) => Self::lookup_handle(&handle).and_then(Self::lookup_dao),
             _ => None,
         }
     }
 }
 
-/// StaticLookup implementor using MultiAddress::Index for looking up DAOs by id.
+/// StaticLookup implementor using MultiAddress::Index for looking up DAOs by id and MultiAddress::Raw for looking them up by handle.
 pub struct DaoLookup<T: Config>(PhantomData<T>);
 
 impl<T: Config> StaticLookup for DaoLookup<T> {
@@ -44,7 +46,10 @@ impl<T: Config> StaticLookup for DaoLookup<T> {
 
     fn unlookup(a: Self::Target) -> Self::Source {
         match CoreByAccount::<T>::get(&a) {
-            Some(dao_id) => MultiAddress::Index(dao_id),
+            Some(dao_id) => match DaoHandles::<T>::get(dao_id) {
+                Some((handle, _)) => MultiAddress::Raw(handle.into_inner()),
+                None => MultiAddress::Index(dao_id),
+            },
             None => MultiAddress::Id(a),
         }
     }
@@ -1,6 +1,6 @@
 use super::*;
 use frame_support::{
-    dispatch::GetStorageVersion,
+    pallet_prelude::{GetStorageVersion, StorageVersion},
     traits::{Get, OnRuntimeUpgrade},
     weights::Weight,
 };
@@ -124,3 +124,619 @@ pub mod v2 {
         }
     }
 }
+
+pub mod v3 {
+    use super::*;
+    use crate::{multisig::BoundedCallBytes, voting::Tally};
+    use codec::{Decode, Encode};
+    use frame_support::{storage_alias, Blake2_128Concat, BoundedVec};
+
+    /// Multisig storage as it was up to v4, before sponsored proposals were introduced.
+    #[storage_alias]
+    pub type Multisig<T: Config> = StorageDoubleMap<
+        Pallet<T>,
+        Blake2_128Concat,
+        <T as Config>::DaoId,
+        Blake2_128Concat,
+        <T as frame_system::Config>::Hash,
+        super::v5::OldMultisigOperationOf<T>,
+    >;
+
+    /// Multisig operation as stored before emergency proposals were introduced.
+    #[derive(Encode, Decode)]
+    pub struct OldMultisigOperation<AccountId, TallyOf, Call, Metadata> {
+        pub tally: TallyOf,
+        pub original_caller: AccountId,
+        pub actual_call: Call,
+        pub metadata: Option<Metadata>,
+        pub fee_asset: FeeAsset,
+    }
+
+    pub type OldMultisigOperationOf<T> = OldMultisigOperation<
+        <T as frame_system::Config>::AccountId,
+        Tally<T>,
+        BoundedCallBytes<T>,
+        BoundedVec<u8, <T as Config>::MaxMetadata>,
+    >;
+
+    /// Marks every ongoing multisig proposal as a regular, non emergency, proposal.
+    pub fn migrate_multisig_operations<T: Config>() -> Weight {
+        let mut count: u64 = 0;
+
+        Multisig::<T>::translate::<OldMultisigOperationOf<T>, _>(|_, _, old| {
+            count += 1;
+
+            Some(super::v5::OldMultisigOperationOf::<T> {
+                tally: old.tally,
+                original_caller: old.original_caller,
+                actual_call: old.actual_call,
+                metadata: old.metadata,
+                fee_asset: old.fee_asset,
+                emergency: false,
+            })
+        });
+
+        info!("Migrated {} multisig operations", count);
+        T::DbWeight::get().reads_writes(count, count)
+    }
+
+    pub struct MigrateToV3<T>(sp_std::marker::PhantomData<T>);
+    impl<T: Config> OnRuntimeUpgrade for MigrateToV3<T> {
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<sp_std::vec::Vec<u8>, sp_runtime::DispatchError> {
+            frame_support::ensure!(
+                Pallet::<T>::on_chain_storage_version() == 2,
+                "Required v2 before upgrading to v3"
+            );
+
+            Ok((Multisig::<T>::iter_keys().count() as u32).encode())
+        }
+
+        fn on_runtime_upgrade() -> Weight {
+            let mut weight = T::DbWeight::get().reads(1);
+
+            if Pallet::<T>::on_chain_storage_version() == 2 {
+                weight.saturating_accrue(migrate_multisig_operations::<T>());
+
+                StorageVersion::new(3).put::<Pallet<T>>();
+
+                info!("v3 applied successfully");
+                weight.saturating_add(T::DbWeight::get().writes(1))
+            } else {
+                warn!("Skipping v3, should be removed");
+                weight
+            }
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: sp_std::vec::Vec<u8>) -> Result<(), sp_runtime::DispatchError> {
+            frame_support::ensure!(
+                Pallet::<T>::on_chain_storage_version() == 3,
+                "v3 not applied"
+            );
+
+            let old_count = u32::decode(&mut &state[..])
+                .map_err(|_| sp_runtime::DispatchError::Other("Failed decoding state"))?;
+
+            frame_support::ensure!(
+                Multisig::<T>::iter_values()
+                    .filter(|op| !op.emergency)
+                    .count() as u32
+                    == old_count,
+                "Multisig operations lost during migration"
+            );
+
+            Ok(())
+        }
+    }
+}
+
+pub mod v4 {
+    use super::*;
+    use codec::{Decode, Encode};
+    use frame_support::{storage_alias, traits::fungibles::Inspect, Blake2_128Concat, BoundedVec};
+    use primitives::VotingStrategy;
+    use sp_runtime::traits::{IntegerSquareRoot, Saturating};
+
+    /// Dao storage as it was up to v5, before the dao metadata was structured.
+    #[storage_alias]
+    pub type CoreStorage<T: Config> =
+        StorageMap<Pallet<T>, Blake2_128Concat, <T as Config>::DaoId, super::v6::OldDaoInfoOf<T>>;
+
+    /// Dao info as stored before voting strategies were introduced.
+    #[derive(Encode, Decode)]
+    pub struct OldDaoInfo<AccountId, DaoMetadataOf> {
+        pub account: AccountId,
+        pub metadata: DaoMetadataOf,
+        pub minimum_support: sp_runtime::Perbill,
+        pub required_approval: sp_runtime::Perbill,
+        pub frozen_tokens: bool,
+    }
+
+    pub type OldDaoInfoOf<T> = OldDaoInfo<
+        <T as frame_system::Config>::AccountId,
+        BoundedVec<u8, <T as Config>::MaxMetadata>,
+    >;
+
+    /// Sets the voting strategy of every dao to token balance, which was the only one available before.
+    pub fn migrate_dao_info<T: Config>() -> Weight {
+        let mut count: u64 = 0;
+
+        CoreStorage::<T>::translate::<OldDaoInfoOf<T>, _>(|_, old| {
+            count += 1;
+
+            Some(super::v7::OldDaoInfo {
+                account: old.account,
+                metadata: old.metadata,
+                minimum_support: old.minimum_support,
+                required_approval: old.required_approval,
+                frozen_tokens: old.frozen_tokens,
+                voting_strategy: VotingStrategy::TokenBalance,
+            })
+        });
+
+        info!("Migrated {} daos", count);
+        T::DbWeight::get().reads_writes(count, count)
+    }
+
+    /// Fills the member count of every dao from the current members.
+    pub fn fill_member_count<T: Config>() -> Weight {
+        let mut reads: u64 = 0;
+
+        CoreMembers::<T>::iter_keys().for_each(|(dao_id, _)| {
+            reads += 1;
+            CoreMemberCount::<T>::mutate(dao_id, |count| *count = count.saturating_add(1));
+        });
+
+        T::DbWeight::get().reads_writes(reads.saturating_mul(2), reads)
+    }
+
+    /// Fills the quadratic voting power of every dao from the balances of the current members.
+    pub fn fill_quadratic_voting_power<T: Config>() -> Weight {
+        let mut reads: u64 = 0;
+
+        CoreMembers::<T>::iter_keys().for_each(|(dao_id, member)| {
+            reads += 1;
+            let balance = <T::AssetsProvider as Inspect<T::AccountId>>::balance(dao_id, &member);
+            CoreQuadraticVotingPower::<T>::mutate(dao_id, |total| {
+                *total = total.saturating_add(balance.integer_sqrt())
+            });
+        });
+
+        T::DbWeight::get().reads_writes(reads.saturating_mul(3), reads)
+    }
+
+    pub struct MigrateToV4<T>(sp_std::marker::PhantomData<T>);
+    impl<T: Config> OnRuntimeUpgrade for MigrateToV4<T> {
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<sp_std::vec::Vec<u8>, sp_runtime::DispatchError> {
+            frame_support::ensure!(
+                Pallet::<T>::on_chain_storage_version() == 3,
+                "Required v3 before upgrading to v4"
+            );
+
+            Ok((CoreMembers::<T>::iter_keys().count() as u32).encode())
+        }
+
+        fn on_runtime_upgrade() -> Weight {
+            let mut weight = T::DbWeight::get().reads(1);
+
+            if Pallet::<T>::on_chain_storage_version() == 3 {
+                weight.saturating_accrue(migrate_dao_info::<T>());
+                weight.saturating_accrue(fill_member_count::<T>());
+                weight.saturating_accrue(fill_quadratic_voting_power::<T>());
+
+                StorageVersion::new(4).put::<Pallet<T>>();
+
+                info!("v4 applied successfully");
+                weight.saturating_add(T::DbWeight::get().writes(1))
+            } else {
+                warn!("Skipping v4, should be removed");
+                weight
+            }
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: sp_std::vec::Vec<u8>) -> Result<(), sp_runtime::DispatchError> {
+            frame_support::ensure!(
+                Pallet::<T>::on_chain_storage_version() == 4,
+                "v4 not applied"
+            );
+
+            let members = u32::decode(&mut &state[..])
+                .map_err(|_| sp_runtime::DispatchError::Other("Failed decoding state"))?;
+
+            frame_support::ensure!(
+                CoreMemberCount::<T>::iter_values().sum::<u32>() == members,
+                "Member count doesn't match the members"
+            );
+
+            Ok(())
+        }
+    }
+}
+
+pub mod v5 {
+    use super::*;
+    use crate::{fee_handling::FeePayer, multisig::BoundedCallBytes, voting::Tally};
+    use codec::{Decode, Encode};
+    use frame_support::{storage_alias, Blake2_128Concat, BoundedVec};
+
+    /// Multisig storage as it was up to v6, before bicameral daos were introduced.
+    #[storage_alias]
+    pub type Multisig<T: Config> = StorageDoubleMap<
+        Pallet<T>,
+        Blake2_128Concat,
+        <T as Config>::DaoId,
+        Blake2_128Concat,
+        <T as frame_system::Config>::Hash,
+        super::v7::OldMultisigOperationOf<T>,
+    >;
+
+    /// Multisig operation as stored before sponsored proposals were introduced.
+    #[derive(Encode, Decode)]
+    pub struct OldMultisigOperation<AccountId, TallyOf, Call, Metadata> {
+        pub tally: TallyOf,
+        pub original_caller: AccountId,
+        pub actual_call: Call,
+        pub metadata: Option<Metadata>,
+        pub fee_asset: FeeAsset,
+        pub emergency: bool,
+    }
+
+    pub type OldMultisigOperationOf<T> = OldMultisigOperation<
+        <T as frame_system::Config>::AccountId,
+        Tally<T>,
+        BoundedCallBytes<T>,
+        BoundedVec<u8, <T as Config>::MaxMetadata>,
+    >;
+
+    /// Makes the dao pay for every ongoing multisig proposal, as it did before.
+    pub fn migrate_multisig_operations<T: Config>() -> Weight {
+        let mut count: u64 = 0;
+
+        Multisig::<T>::translate::<OldMultisigOperationOf<T>, _>(|_, _, old| {
+            count += 1;
+
+            Some(super::v7::OldMultisigOperationOf::<T> {
+                tally: old.tally,
+                original_caller: old.original_caller,
+                actual_call: old.actual_call,
+                metadata: old.metadata,
+                fee_asset: old.fee_asset,
+                emergency: old.emergency,
+                fee_payer: FeePayer::Dao,
+            })
+        });
+
+        info!("Migrated {} multisig operations", count);
+        T::DbWeight::get().reads_writes(count, count)
+    }
+
+    pub struct MigrateToV5<T>(sp_std::marker::PhantomData<T>);
+    impl<T: Config> OnRuntimeUpgrade for MigrateToV5<T> {
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<sp_std::vec::Vec<u8>, sp_runtime::DispatchError> {
+            frame_support::ensure!(
+                Pallet::<T>::on_chain_storage_version() == 4,
+                "Required v4 before upgrading to v5"
+            );
+
+            Ok((Multisig::<T>::iter_keys().count() as u32).encode())
+        }
+
+        fn on_runtime_upgrade() -> Weight {
+            let mut weight = T::DbWeight::get().reads(1);
+
+            if Pallet::<T>::on_chain_storage_version() == 4 {
+                weight.saturating_accrue(migrate_multisig_operations::<T>());
+
+                StorageVersion::new(5).put::<Pallet<T>>();
+
+                info!("v5 applied successfully");
+                weight.saturating_add(T::DbWeight::get().writes(1))
+            } else {
+                warn!("Skipping v5, should be removed");
+                weight
+            }
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: sp_std::vec::Vec<u8>) -> Result<(), sp_runtime::DispatchError> {
+            frame_support::ensure!(
+                Pallet::<T>::on_chain_storage_version() == 5,
+                "v5 not applied"
+            );
+
+            let old_count = u32::decode(&mut &state[..])
+                .map_err(|_| sp_runtime::DispatchError::Other("Failed decoding state"))?;
+
+            frame_support::ensure!(
+                Multisig::<T>::iter_values()
+                    .filter(|op| op.fee_payer == FeePayer::Dao)
+                    .count() as u32
+                    == old_count,
+                "Multisig operations lost during migration"
+            );
+
+            Ok(())
+        }
+    }
+}
+
+pub mod v6 {
+    use super::*;
+    use crate::dao_manager_core::DaoMetadataOf;
+    use codec::{Decode, Encode};
+    use frame_support::{storage_alias, Blake2_128Concat, BoundedVec};
+    use primitives::DaoMetadata;
+
+    /// Dao storage as it was up to v6, before bicameral daos were introduced.
+    #[storage_alias]
+    pub type CoreStorage<T: Config> =
+        StorageMap<Pallet<T>, Blake2_128Concat, <T as Config>::DaoId, super::v7::OldDaoInfoOf<T>>;
+
+    /// Dao info as stored before the dao metadata was structured.
+    pub type OldDaoInfoOf<T> = super::v7::OldDaoInfo<
+        <T as frame_system::Config>::AccountId,
+        BoundedVec<u8, <T as Config>::MaxMetadata>,
+        <T as Config>::NftCollectionId,
+    >;
+
+    /// Longest prefix of `bytes` that is valid UTF-8 and at most `max_len` bytes long.
+    pub fn utf8_prefix(bytes: &[u8], max_len: usize) -> &[u8] {
+        let bytes = &bytes[..bytes.len().min(max_len)];
+
+        match sp_std::str::from_utf8(bytes) {
+            Ok(_) => bytes,
+            Err(error) => &bytes[..error.valid_up_to()],
+        }
+    }
+
+    /// Keeps the free-form metadata of every dao as its description, cut on a character boundary to fit
+    /// `T::MaxDescriptionLength`. The full metadata of the daos whose description had to be cut is kept
+    /// in `LegacyDaoMetadata`.
+    pub fn migrate_dao_metadata<T: Config>() -> Weight {
+        let mut count: u64 = 0;
+        let mut truncated: u64 = 0;
+
+        CoreStorage::<T>::translate::<OldDaoInfoOf<T>, _>(|dao_id, old| {
+            count += 1;
+
+            let description = utf8_prefix(&old.metadata, T::MaxDescriptionLength::get() as usize);
+
+            let metadata: DaoMetadataOf<T> = DaoMetadata {
+                description: BoundedVec::truncate_from(description.to_vec()),
+                ..Default::default()
+            };
+
+            if metadata.description.len() < old.metadata.len() {
+                truncated += 1;
+                warn!(
+                    "Metadata of dao {:?} didn't fit its description, kept as legacy metadata",
+                    dao_id
+                );
+                LegacyDaoMetadata::<T>::insert(dao_id, old.metadata);
+            }
+
+            Some(super::v7::OldDaoInfo {
+                account: old.account,
+                metadata,
+                minimum_support: old.minimum_support,
+                required_approval: old.required_approval,
+                frozen_tokens: old.frozen_tokens,
+                voting_strategy: old.voting_strategy,
+            })
+        });
+
+        info!(
+            "Migrated {} daos, {} kept their full metadata as legacy metadata",
+            count, truncated
+        );
+        T::DbWeight::get().reads_writes(count, count.saturating_add(truncated))
+    }
+
+    pub struct MigrateToV6<T>(sp_std::marker::PhantomData<T>);
+    impl<T: Config> OnRuntimeUpgrade for MigrateToV6<T> {
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<sp_std::vec::Vec<u8>, sp_runtime::DispatchError> {
+            frame_support::ensure!(
+                Pallet::<T>::on_chain_storage_version() == 5,
+                "Required v5 before upgrading to v6"
+            );
+
+            Ok((CoreStorage::<T>::iter_keys().count() as u32).encode())
+        }
+
+        fn on_runtime_upgrade() -> Weight {
+            let mut weight = T::DbWeight::get().reads(1);
+
+            if Pallet::<T>::on_chain_storage_version() == 5 {
+                weight.saturating_accrue(migrate_dao_metadata::<T>());
+
+                StorageVersion::new(6).put::<Pallet<T>>();
+
+                info!("v6 applied successfully");
+                weight.saturating_add(T::DbWeight::get().writes(1))
+            } else {
+                warn!("Skipping v6, should be removed");
+                weight
+            }
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: sp_std::vec::Vec<u8>) -> Result<(), sp_runtime::DispatchError> {
+            frame_support::ensure!(
+                Pallet::<T>::on_chain_storage_version() == 6,
+                "v6 not applied"
+            );
+
+            let old_count = u32::decode(&mut &state[..])
+                .map_err(|_| sp_runtime::DispatchError::Other("Failed decoding state"))?;
+
+            frame_support::ensure!(
+                CoreStorage::<T>::iter_values().count() as u32 == old_count,
+                "Daos lost during migration"
+            );
+
+            Ok(())
+        }
+    }
+}
+
+pub mod v7 {
+    use super::*;
+    use crate::{
+        dao_manager_core::DaoMetadataOf,
+        fee_handling::FeePayer,
+        multisig::{BoundedCallBytes, MultisigOperationOf},
+        voting::Tally,
+    };
+    use codec::{Decode, Encode};
+    use frame_support::BoundedVec;
+    use primitives::{DaoInfo, VotingStrategy};
+
+    /// Dao info as stored before bicameral daos were introduced.
+    #[derive(Encode, Decode)]
+    pub struct OldDaoInfo<AccountId, DaoMetadataOf, CollectionId> {
+        pub account: AccountId,
+        pub metadata: DaoMetadataOf,
+        pub minimum_support: sp_runtime::Perbill,
+        pub required_approval: sp_runtime::Perbill,
+        pub frozen_tokens: bool,
+        pub voting_strategy: VotingStrategy<CollectionId>,
+    }
+
+    pub type OldDaoInfoOf<T> = OldDaoInfo<
+        <T as frame_system::Config>::AccountId,
+        DaoMetadataOf<T>,
+        <T as Config>::NftCollectionId,
+    >;
+
+    /// Multisig operation as stored before bicameral daos were introduced.
+    #[derive(Encode, Decode)]
+    pub struct OldMultisigOperation<AccountId, TallyOf, Call, Metadata> {
+        pub tally: TallyOf,
+        pub original_caller: AccountId,
+        pub actual_call: Call,
+        pub metadata: Option<Metadata>,
+        pub fee_asset: FeeAsset,
+        pub emergency: bool,
+        pub fee_payer: FeePayer,
+    }
+
+    pub type OldMultisigOperationOf<T> = OldMultisigOperation<
+        <T as frame_system::Config>::AccountId,
+        Tally<T>,
+        BoundedCallBytes<T>,
+        BoundedVec<u8, <T as Config>::MaxMetadata>,
+    >;
+
+    /// Makes every dao unicameral, as they all were before.
+    pub fn migrate_dao_info<T: Config>() -> Weight {
+        let mut count: u64 = 0;
+
+        CoreStorage::<T>::translate::<OldDaoInfoOf<T>, _>(|_, old| {
+            count += 1;
+
+            Some(DaoInfo {
+                account: old.account,
+                metadata: old.metadata,
+                minimum_support: old.minimum_support,
+                required_approval: old.required_approval,
+                frozen_tokens: old.frozen_tokens,
+                voting_strategy: old.voting_strategy,
+                second_chamber: None,
+            })
+        });
+
+        info!("Migrated {} daos", count);
+        T::DbWeight::get().reads_writes(count, count)
+    }
+
+    /// Leaves every ongoing multisig proposal without a second chamber tally, as their daos are unicameral.
+    pub fn migrate_multisig_operations<T: Config>() -> Weight {
+        let mut count: u64 = 0;
+
+        Multisig::<T>::translate::<OldMultisigOperationOf<T>, _>(|_, _, old| {
+            count += 1;
+
+            Some(MultisigOperationOf::<T> {
+                tally: old.tally,
+                original_caller: old.original_caller,
+                actual_call: old.actual_call,
+                metadata: old.metadata,
+                fee_asset: old.fee_asset,
+                emergency: old.emergency,
+                fee_payer: old.fee_payer,
+                second_tally: None,
+            })
+        });
+
+        info!("Migrated {} multisig operations", count);
+        T::DbWeight::get().reads_writes(count, count)
+    }
+
+    pub struct MigrateToV7<T>(sp_std::marker::PhantomData<T>);
+    impl<T: Config> OnRuntimeUpgrade for MigrateToV7<T> {
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<sp_std::vec::Vec<u8>, sp_runtime::DispatchError> {
+            frame_support::ensure!(
+                Pallet::<T>::on_chain_storage_version() == 6,
+                "Required v6 before upgrading to v7"
+            );
+
+            Ok((
+                CoreStorage::<T>::iter_keys().count() as u32,
+                Multisig::<T>::iter_keys().count() as u32,
+            )
+                .encode())
+        }
+
+        fn on_runtime_upgrade() -> Weight {
+            let mut weight = T::DbWeight::get().reads(1);
+
+            if Pallet::<T>::on_chain_storage_version() == 6 {
+                weight.saturating_accrue(migrate_dao_info::<T>());
+                weight.saturating_accrue(migrate_multisig_operations::<T>());
+
+                StorageVersion::new(7).put::<Pallet<T>>();
+
+                info!("v7 applied successfully");
+                weight.saturating_add(T::DbWeight::get().writes(1))
+            } else {
+                warn!("Skipping v7, should be removed");
+                weight
+            }
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: sp_std::vec::Vec<u8>) -> Result<(), sp_runtime::DispatchError> {
+            frame_support::ensure!(
+                Pallet::<T>::on_chain_storage_version() == 7,
+                "v7 not applied"
+            );
+
+            let (old_daos, old_operations) = <(u32, u32)>::decode(&mut &state[..])
+                .map_err(|_| sp_runtime::DispatchError::Other("Failed decoding state"))?;
+
+            frame_support::ensure!(
+                CoreStorage::<T>::iter_values()
+                    .filter(|dao| dao.second_chamber.is_none())
+                    .count() as u32
+                    == old_daos,
+                "Daos lost during migration"
+            );
+
+            frame_support::ensure!(
+                Multisig::<T>::iter_values()
+                    .filter(|op| op.second_tally.is_none())
+                    .count() as u32
+                    == old_operations,
+                "Multisig operations lost during migration"
+            );
+
+            Ok(())
+        }
+    }
+}
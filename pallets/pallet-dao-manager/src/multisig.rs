@@ -8,12 +8,17 @@
 //! - Minting/Burning voting tokens to existing and new members.
 //! - Handling proposal votes.
 //! - Dispatching approved proposals when both support and approval meet/exceed their minimum required thresholds.
-//! - Canceling proposals.
+//! - Handling emergency proposals, which require a supermajority of the total token supply to be dispatched.
+//! - Handling sponsored proposals, whose execution fee is paid by the proposer instead of the dao.
+//! - Handling proposals of bicameral daos, which also require the approval of the dao's second chamber.
+//! - Canceling proposals, slashing the deposit of public proposals.
 
 use super::pallet::{self, *};
 use crate::{
     account_derivation::DaoAccountDerivation,
-    fee_handling::{FeeAsset, FeeAssetNegativeImbalance, MultisigFeeHandler},
+    fee_handling::{
+        FeeAsset, FeeAssetNegativeImbalance, FeePayer, MultisigFeeHandler, OperateMultisigFees,
+    },
     origin::{ensure_multisig, DaoOrigin},
     voting::{Tally, Vote},
 };
@@ -21,14 +26,16 @@ use codec::DecodeLimit;
 use core::{
     convert::{TryFrom, TryInto},
     iter::Sum,
+    marker::PhantomData,
 };
 use frame_support::{
+    dispatch::{DispatchInfo, GetDispatchInfo},
     pallet_prelude::*,
     traits::{
         fungible::{Balanced, Inspect},
         fungibles::{Inspect as Inspects, Mutate as Mutates},
         tokens::{Fortitude, Precision, Preservation},
-        VoteTally,
+        CallMetadata, Contains, GetCallMetadata, PalletInfoAccess, VoteTally,
     },
     weights::WeightToFee,
     BoundedBTreeMap,
@@ -38,7 +45,7 @@ use sp_runtime::{
     traits::{Hash, Zero},
     Perbill,
 };
-use sp_std::{boxed::Box, collections::btree_map::BTreeMap};
+use sp_std::{boxed::Box, collections::btree_map::BTreeMap, vec};
 
 /// Maximum size of call we store is 50kb.
 pub const MAX_SIZE: u32 = 50 * 1024;
@@ -53,6 +60,10 @@ pub struct MultisigOperation<AccountId, TallyOf, Call, Metadata> {
     pub actual_call: Call,
     pub metadata: Option<Metadata>,
     pub fee_asset: FeeAsset,
+    pub emergency: bool,
+    pub fee_payer: FeePayer,
+    /// Tally of the second chamber, for proposals of bicameral daos.
+    pub second_tally: Option<TallyOf>,
 }
 
 pub type MultisigOperationOf<T> = MultisigOperation<
@@ -79,8 +90,12 @@ where
         let dao_origin = ensure_multisig::<T, OriginFor<T>>(origin)?;
         let dao_id = dao_origin.id;
 
+        Self::ensure_within_supply_cap(dao_id, amount)?;
+
         // Mint the dao's voting token to the target.
+        let old_balance = T::AssetsProvider::balance(dao_id, &target);
         T::AssetsProvider::mint_into(dao_id, &target, amount)?;
+        Self::note_voting_balance_change(dao_id, &target, old_balance);
 
         Self::deposit_event(Event::Minted {
             dao_id,
@@ -102,6 +117,7 @@ where
         let dao_id = dao_origin.id;
 
         // Burn the dao's voting token from the target.
+        let old_balance = T::AssetsProvider::balance(dao_id, &target);
         T::AssetsProvider::burn_from(
             dao_id,
             &target,
@@ -110,6 +126,8 @@ where
             Precision::Exact,
             Fortitude::Polite,
         )?;
+        Self::note_voting_balance_change(dao_id, &target, old_balance);
+        Self::trim_vesting_schedules(dao_id, &target);
 
         Self::deposit_event(Event::Burned {
             dao_id,
@@ -127,20 +145,16 @@ where
         metadata: Option<BoundedVec<u8, T::MaxMetadata>>,
         fee_asset: FeeAsset,
         call: Box<<T as Config>::RuntimeCall>,
+        emergency: bool,
+        fee_payer: FeePayer,
     ) -> DispatchResultWithPostInfo {
         let owner = ensure_signed(caller)?;
 
-        // Get the voting token balance of the caller
-        let owner_balance: BalanceOf<T> = T::AssetsProvider::balance(dao_id, &owner);
-
-        ensure!(!owner_balance.is_zero(), Error::<T>::NoPermission);
-
-        // Get the minimum support value of the target DAO
-        let (minimum_support, _) = Pallet::<T>::minimum_support_and_required_approval(dao_id)
-            .ok_or(Error::<T>::DaoNotFound)?;
-
-        // Get the total issuance of the dao's voting token
-        let total_issuance: BalanceOf<T> = T::AssetsProvider::total_issuance(dao_id);
+        // Emergency proposals are restricted to the calls allowed by the runtime
+        ensure!(
+            !emergency || T::EmergencyCalls::contains(&call.get_call_metadata()),
+            Error::<T>::CallNotEmergencyEligible
+        );
 
         // Compute the call hash
         let call_hash = <<T as frame_system::Config>::Hashing as Hash>::hash_of(&call);
@@ -151,11 +165,60 @@ where
             Error::<T>::MultisigCallAlreadyExists
         );
 
-        // If caller has enough balance to meet/exeed the threshold, then go ahead and execute the call now
+        // Bring the total voting power of NFT collections up to date for the new proposal
+        Self::note_nft_voting_power(dao_id);
+
+        // Get the voting power of the caller, in the second chamber too if the dao is bicameral
+        let owner_power: BalanceOf<T> = Self::note_proposal_voting_power(dao_id, call_hash, &owner);
+        let second_chamber = Self::second_chamber(dao_id);
+        let owner_chamber_power: BalanceOf<T> = second_chamber
+            .as_ref()
+            .map(|chamber| Self::chamber_voting_power(chamber, &owner))
+            .unwrap_or_else(Zero::zero);
+
+        ensure!(
+            !owner_power.is_zero() || !owner_chamber_power.is_zero(),
+            Error::<T>::NoPermission
+        );
+
+        // Get the minimum support value of the target DAO
+        let (minimum_support, _) = Pallet::<T>::minimum_support_and_required_approval(dao_id)
+            .ok_or(Error::<T>::DaoNotFound)?;
+        let minimum_support = Self::proposal_minimum_support(minimum_support, emergency);
+
+        // Get the total voting power of the dao
+        let total_power: BalanceOf<T> = Self::total_voting_power(dao_id);
+
+        // Build the tallies with the caller's aye vote in the chambers they have voting power in
+        let tally = Tally::from_parts(
+            owner_power,
+            Zero::zero(),
+            Self::initial_vote_records(&owner, owner_power)?,
+        );
+
+        let second_tally = match second_chamber {
+            Some(_) => Some(Tally::from_parts(
+                owner_chamber_power,
+                Zero::zero(),
+                Self::initial_vote_records(&owner, owner_chamber_power)?,
+            )),
+            None => None,
+        };
+
+        // If caller has enough voting power to meet/exeed the threshold, then go ahead and execute the call now
         // There is no need to check against required_approval as it's assumed the caller is voting aye
-        if Perbill::from_rational(owner_balance, total_issuance) >= minimum_support {
-            let dispatch_result =
-                crate::dispatch::dispatch_call::<T>(dao_id, &fee_asset, *call.clone());
+        if Perbill::from_rational(owner_power, total_power) >= minimum_support
+            && Self::second_chamber_passes(dao_id, second_tally.as_ref(), emergency)
+        {
+            // The proposal is never stored, so neither are the NFT items voting on it
+            Self::clear_proposal_nft_votes(dao_id, call_hash, None);
+
+            let dispatch_result = crate::dispatch::dispatch_call::<T>(
+                dao_id,
+                &fee_asset,
+                Self::fee_payer_account(fee_payer, &owner),
+                *call.clone(),
+            );
 
             Self::deposit_event(Event::MultisigExecuted {
                 dao_id,
@@ -175,8 +238,7 @@ where
             let total_lenght = (bounded_call.len() as u64)
                 .saturating_add(metadata.clone().unwrap_or_default().len() as u64);
 
-            let storage_cost: BalanceOf<T> =
-                T::LengthToFee::weight_to_fee(&Weight::from_parts(total_lenght as u64, 0));
+            let storage_cost: BalanceOf<T> = Self::proposal_storage_fee(total_lenght);
 
             T::FeeCharger::handle_creation_fee(FeeAssetNegativeImbalance::Native(
                 <<T as Config>::Currency as Balanced<T::AccountId>>::withdraw(
@@ -193,29 +255,34 @@ where
                 dao_id,
                 call_hash,
                 MultisigOperation {
-                    tally: Tally::from_parts(
-                        owner_balance,
-                        Zero::zero(),
-                        BoundedBTreeMap::try_from(BTreeMap::from([(
-                            owner.clone(),
-                            Vote::Aye(owner_balance),
-                        )]))
-                        .map_err(|_| Error::<T>::MaxCallersExceeded)?,
-                    ),
+                    tally,
                     original_caller: owner.clone(),
                     actual_call: bounded_call,
                     metadata,
                     fee_asset,
+                    emergency,
+                    fee_payer,
+                    second_tally,
                 },
             );
 
-            Self::deposit_event(Event::MultisigVoteStarted {
-                dao_id,
-                executor_account: Self::derive_dao_account(dao_id),
-                voter: owner,
-                votes_added: Vote::Aye(owner_balance),
-                call_hash,
-            });
+            if emergency {
+                Self::deposit_event(Event::EmergencyVoteStarted {
+                    dao_id,
+                    executor_account: Self::derive_dao_account(dao_id),
+                    voter: owner,
+                    votes_added: Vote::Aye(owner_power),
+                    call_hash,
+                });
+            } else {
+                Self::deposit_event(Event::MultisigVoteStarted {
+                    dao_id,
+                    executor_account: Self::derive_dao_account(dao_id),
+                    voter: owner,
+                    votes_added: Vote::Aye(owner_power),
+                    call_hash,
+                });
+            }
         }
 
         Ok(().into())
@@ -231,36 +298,76 @@ where
         Multisig::<T>::try_mutate_exists(dao_id, call_hash, |data| {
             let owner = ensure_signed(caller.clone())?;
 
-            // Get the voting token balance of the caller
-            let voter_balance: BalanceOf<T> = T::AssetsProvider::balance(dao_id, &owner);
-
-            // If caller doesn't own the token, they have no voting power.
-            ensure!(!voter_balance.is_zero(), Error::<T>::NoPermission);
+            // Get the voting power of the caller, in the second chamber too if the dao is bicameral
+            let voter_power: BalanceOf<T> =
+                Self::note_proposal_voting_power(dao_id, call_hash, &owner);
+            let second_chamber = Self::second_chamber(dao_id);
+            let voter_chamber_power: BalanceOf<T> = second_chamber
+                .as_ref()
+                .map(|chamber| Self::chamber_voting_power(chamber, &owner))
+                .unwrap_or_else(Zero::zero);
+
+            // If caller doesn't own the token nor sits in the second chamber, they have no voting power.
+            ensure!(
+                !voter_power.is_zero() || !voter_chamber_power.is_zero(),
+                Error::<T>::NoPermission
+            );
 
             // Get the multisig call data from the storage
             let mut old_data = data.take().ok_or(Error::<T>::MultisigCallNotFound)?;
 
+            // Public proposals can't be voted on once they expire
+            Self::ensure_public_proposal_not_expired(dao_id, call_hash)?;
+
             // Get the minimum support and required approval values of the target DAO
             let (minimum_support, required_approval) =
                 Pallet::<T>::minimum_support_and_required_approval(dao_id)
                     .ok_or(Error::<T>::DaoNotFound)?;
+            let minimum_support =
+                Self::proposal_minimum_support(minimum_support, old_data.emergency);
 
             let new_vote_record = if aye {
-                Vote::Aye(voter_balance)
+                Vote::Aye(voter_power)
+            } else {
+                Vote::Nay(voter_power)
+            };
+
+            // Mutate the tally of every chamber the caller has voting power in with the new vote,
+            // a vote cast before in a chamber the caller has no voting power left in is removed
+            if !voter_power.is_zero() {
+                old_data
+                    .tally
+                    .process_vote(owner.clone(), Some(new_vote_record))?;
+            } else {
+                let _ = old_data.tally.process_vote(owner.clone(), None);
+            }
+
+            let new_chamber_vote_record = if aye {
+                Vote::Aye(voter_chamber_power)
             } else {
-                Vote::Nay(voter_balance)
+                Vote::Nay(voter_chamber_power)
             };
 
-            // Mutate tally with the new vote
-            old_data
-                .tally
-                .process_vote(owner.clone(), Some(new_vote_record))?;
+            if !voter_chamber_power.is_zero() {
+                // Proposals made before the dao became bicameral start their second tally with this vote
+                old_data
+                    .second_tally
+                    .get_or_insert_with(|| Tally::new(dao_id))
+                    .process_vote(owner.clone(), Some(new_chamber_vote_record))?;
+            }
 
             let support = old_data.tally.support(dao_id);
             let approval = old_data.tally.approval(dao_id);
 
-            // Check if the multisig proposal passes the thresholds with the added vote
-            if (support >= minimum_support) && (approval >= required_approval) {
+            // Check if the multisig proposal passes the thresholds of both chambers with the added vote
+            if (support >= minimum_support)
+                && (approval >= required_approval)
+                && Self::second_chamber_passes(
+                    dao_id,
+                    old_data.second_tally.as_ref(),
+                    old_data.emergency,
+                )
+            {
                 // Decode the call
                 let decoded_call = <T as Config>::RuntimeCall::decode_all_with_depth_limit(
                     sp_api::MAX_EXTRINSIC_DEPTH / 4,
@@ -270,11 +377,16 @@ where
 
                 // If the proposal thresholds are met, remove proposal from storage
                 *data = None;
+                Self::clear_proposal_nft_votes(dao_id, call_hash, None);
+
+                // The proposal was approved, so its deposit goes back to the proposer if it's public
+                Self::return_public_proposal_deposit(dao_id, call_hash)?;
 
                 // Dispatch the call and get the result
                 let dispatch_result = crate::dispatch::dispatch_call::<T>(
                     dao_id,
                     &old_data.fee_asset,
+                    Self::fee_payer_account(old_data.fee_payer, &old_data.original_caller),
                     decoded_call.clone(),
                 );
 
@@ -287,17 +399,32 @@ where
                     result: dispatch_result.map(|_| ()).map_err(|e| e.error),
                 });
             } else {
-                // If the thresholds aren't met, update storage with the new tally
+                // If the thresholds aren't met, update storage with the new tallies
                 *data = Some(old_data.clone());
 
-                Self::deposit_event(Event::MultisigVoteAdded {
-                    dao_id,
-                    executor_account: Self::derive_dao_account(dao_id),
-                    voter: owner,
-                    votes_added: new_vote_record,
-                    current_votes: old_data.tally,
-                    call_hash,
-                });
+                if !voter_power.is_zero() {
+                    Self::deposit_event(Event::MultisigVoteAdded {
+                        dao_id,
+                        executor_account: Self::derive_dao_account(dao_id),
+                        voter: owner.clone(),
+                        votes_added: new_vote_record,
+                        current_votes: old_data.tally,
+                        call_hash,
+                    });
+                }
+
+                if let Some(second_tally) = old_data
+                    .second_tally
+                    .filter(|_| !voter_chamber_power.is_zero())
+                {
+                    Self::deposit_event(Event::SecondChamberVoteAdded {
+                        dao_id,
+                        voter: owner,
+                        votes_added: new_chamber_vote_record,
+                        current_votes: second_tally,
+                        call_hash,
+                    });
+                }
             }
 
             Ok(().into())
@@ -316,19 +443,42 @@ where
             // Get the voting token balance of the caller
             let mut old_data = data.take().ok_or(Error::<T>::MultisigCallNotFound)?;
 
-            // Try to mutate tally to remove the vote
-            let old_vote = old_data.tally.process_vote(owner.clone(), None)?;
+            // Try to mutate the tallies to remove the vote from every chamber the caller voted in
+            let old_vote = old_data.tally.process_vote(owner.clone(), None).ok();
+            let old_chamber_vote = old_data
+                .second_tally
+                .as_mut()
+                .and_then(|tally| tally.process_vote(owner.clone(), None).ok());
 
-            // Update storage with the new tally
+            ensure!(
+                old_vote.is_some() || old_chamber_vote.is_some(),
+                Error::<T>::NotAVoter
+            );
+
+            // The NFT items the caller voted with can be used by their new owners
+            Self::clear_proposal_nft_votes(dao_id, call_hash, Some(&owner));
+
+            // Update storage with the new tallies
             *data = Some(old_data.clone());
 
-            Self::deposit_event(Event::MultisigVoteWithdrawn {
-                dao_id,
-                executor_account: Self::derive_dao_account(dao_id),
-                voter: owner,
-                votes_removed: old_vote,
-                call_hash,
-            });
+            if let Some(old_vote) = old_vote {
+                Self::deposit_event(Event::MultisigVoteWithdrawn {
+                    dao_id,
+                    executor_account: Self::derive_dao_account(dao_id),
+                    voter: owner.clone(),
+                    votes_removed: old_vote,
+                    call_hash,
+                });
+            }
+
+            if let Some(old_chamber_vote) = old_chamber_vote {
+                Self::deposit_event(Event::SecondChamberVoteWithdrawn {
+                    dao_id,
+                    voter: owner,
+                    votes_removed: old_chamber_vote,
+                    call_hash,
+                });
+            }
 
             Ok(().into())
         })
@@ -345,17 +495,136 @@ where
 
         // Remove the proposal from storage
         Multisig::<T>::remove(dao_id, call_hash);
+        Self::clear_proposal_nft_votes(dao_id, call_hash, None);
+
+        // The dao rejected the proposal, so its deposit is slashed if it's public
+        Self::slash_public_proposal_deposit(dao_id, call_hash)?;
 
         Self::deposit_event(Event::<T>::MultisigCanceled { dao_id, call_hash });
 
         Ok(().into())
     }
 
+    /// Fee charged to the proposer for storing a proposal of `length` bytes, counting both the call and the metadata.
+    pub fn proposal_storage_fee(length: u64) -> BalanceOf<T> {
+        T::LengthToFee::weight_to_fee(&Weight::from_parts(length, 0))
+    }
+
+    /// Itemised costs in the native token of proposing `call` through `operate_multisig` with
+    /// `metadata_len` bytes of metadata, shared by the runtimes implementing `DaoManagerFeeApi`.
+    ///
+    /// `compute_fee` returns the transaction fee of an extrinsic from its length and dispatch info,
+    /// `extrinsic_overhead` is the length a signed extrinsic adds to its call, which includes
+    /// the signer, the signature and the signed extensions.
+    pub fn estimate_operate_multisig_fees(
+        dao_id: T::DaoId,
+        metadata_len: u32,
+        call: <T as Config>::RuntimeCall,
+        fee_asset: FeeAsset,
+        extrinsic_overhead: u32,
+        compute_fee: impl Fn(u32, &DispatchInfo) -> BalanceOf<T>,
+    ) -> OperateMultisigFees<BalanceOf<T>>
+    where
+        <T as Config>::RuntimeCall: From<Call<T>>,
+    {
+        let call_len = call.encoded_size() as u32;
+        let call_fee = compute_fee(call_len, &call.get_dispatch_info());
+
+        let metadata: Option<BoundedVec<u8, T::MaxMetadata>> =
+            (metadata_len > 0).then(|| BoundedVec::truncate_from(vec![0u8; metadata_len as usize]));
+        let metadata_len = metadata.as_ref().map(|m| m.len() as u64).unwrap_or(0);
+
+        let operate_call: <T as Config>::RuntimeCall = Call::<T>::operate_multisig {
+            dao_id,
+            metadata,
+            fee_asset,
+            call: Box::new(call),
+        }
+        .into();
+        let operate_call_len =
+            (operate_call.encoded_size() as u32).saturating_add(extrinsic_overhead);
+
+        OperateMultisigFees {
+            extrinsic_fee: compute_fee(operate_call_len, &operate_call.get_dispatch_info()),
+            storage_fee: Self::proposal_storage_fee((call_len as u64).saturating_add(metadata_len)),
+            call_fee,
+        }
+    }
+
+    /// Vote records of a new proposal, holding the proposer's aye vote if they have voting power.
+    fn initial_vote_records(
+        owner: &T::AccountId,
+        power: BalanceOf<T>,
+    ) -> Result<BoundedBTreeMap<T::AccountId, Vote<BalanceOf<T>>, T::MaxCallers>, DispatchError>
+    {
+        if power.is_zero() {
+            Ok(BoundedBTreeMap::new())
+        } else {
+            BoundedBTreeMap::try_from(BTreeMap::from([(owner.clone(), Vote::Aye(power))]))
+                .map_err(|_| Error::<T>::MaxCallersExceeded.into())
+        }
+    }
+
+    /// Account charged with the execution fee of a proposal, `None` meaning the multisig account itself.
+    fn fee_payer_account(fee_payer: FeePayer, proposer: &T::AccountId) -> Option<&T::AccountId> {
+        match fee_payer {
+            FeePayer::Dao => None,
+            FeePayer::Proposer => Some(proposer),
+        }
+    }
+
     pub fn add_member(dao_id: &T::DaoId, member: &T::AccountId) {
-        CoreMembers::<T>::insert(dao_id, member, ())
+        if !CoreMembers::<T>::contains_key(dao_id, member) {
+            CoreMembers::<T>::insert(dao_id, member, ());
+            CoreMemberCount::<T>::mutate(dao_id, |count| *count = count.saturating_add(1));
+        }
     }
 
     pub fn remove_member(dao_id: &T::DaoId, member: &T::AccountId) {
-        CoreMembers::<T>::remove(dao_id, member)
+        if CoreMembers::<T>::take(dao_id, member).is_some() {
+            CoreMemberCount::<T>::mutate(dao_id, |count| *count = count.saturating_sub(1));
+        }
+
+        // Nothing left to lock once the account is gone.
+        VestingSchedules::<T>::remove(dao_id, member);
+    }
+}
+
+impl<T: Config> Pallet<T> {
+    /// Minimum support required by a proposal, emergency proposals require at least `T::EmergencySupport`.
+    pub(crate) fn proposal_minimum_support(minimum_support: Perbill, emergency: bool) -> Perbill {
+        if emergency {
+            minimum_support.max(T::EmergencySupport::get())
+        } else {
+            minimum_support
+        }
+    }
+}
+
+/// Calls that DAOs are allowed to execute through emergency proposals, meant for quickly securing
+/// the DAO's funds or stopping an ongoing attack on it.
+///
+/// Allows burning tokens, changing the DAO parameters or mirror and canceling proposals in this pallet,
+/// along with every call of the pallets named in `FundsPallets`, to be used as `Config::EmergencyCalls`.
+pub struct EmergencyCalls<T, FundsPallets>(PhantomData<(T, FundsPallets)>);
+
+impl<T: Config, FundsPallets: Get<&'static [&'static str]>> Contains<CallMetadata>
+    for EmergencyCalls<T, FundsPallets>
+{
+    fn contains(c: &CallMetadata) -> bool {
+        // Benchmarks propose `System::remark` calls.
+        #[cfg(feature = "runtime-benchmarks")]
+        if c.pallet_name == "System" {
+            return true;
+        }
+
+        if c.pallet_name == <Pallet<T> as PalletInfoAccess>::name() {
+            return matches!(
+                c.function_name,
+                "token_burn" | "set_parameters" | "cancel_multisig_proposal" | "set_mirror"
+            );
+        }
+
+        FundsPallets::get().contains(&c.pallet_name)
     }
 }
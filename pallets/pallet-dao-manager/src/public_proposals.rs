@@ -0,0 +1,239 @@
+//! Public Proposals.
+//!
+//! ## Overview
+//!
+//! Allows accounts without voting power in a DAO, such as grant applicants, to submit proposals to it directly.
+//! Public proposals start with no votes and are voted on by the members like any other proposal,
+//! but the proposer has to back them with `T::PublicProposalDeposit`, held under [`HoldReason::DaoManager`].
+//!
+//! The deposit is returned to the proposer once the proposal is approved, and slashed to the DAO account
+//! if the DAO rejects it by canceling it or if it isn't approved within `T::PublicProposalDuration` blocks.
+//!
+//! - `inner_operate_public_multisig`: Submits a public proposal, holding the deposit from the proposer.
+//! - `inner_expire_public_proposal`: Removes a public proposal that wasn't approved in time, slashing its deposit.
+
+use crate::{
+    account_derivation::DaoAccountDerivation,
+    fee_handling::{FeeAsset, FeeAssetNegativeImbalance, FeePayer, MultisigFeeHandler},
+    multisig::{BoundedCallBytes, MultisigOperation},
+    origin::DaoOrigin,
+    pallet::*,
+    voting::Tally,
+};
+use frame_support::{
+    pallet_prelude::*,
+    traits::{
+        fungible::{Balanced, MutateHold},
+        tokens::{Fortitude, Precision, Preservation, Restriction},
+        VoteTally,
+    },
+};
+use frame_system::{ensure_signed, pallet_prelude::*};
+use sp_runtime::traits::{Hash, Saturating};
+use sp_std::boxed::Box;
+
+/// Details of a public proposal, kept along with its multisig operation.
+#[derive(Clone, Encode, Decode, RuntimeDebug, MaxEncodedLen, TypeInfo, PartialEq, Eq)]
+pub struct PublicProposal<AccountId, Balance, BlockNumber> {
+    /// Account that submitted the proposal and backs it with the deposit.
+    pub proposer: AccountId,
+    /// Amount held from the proposer.
+    pub deposit: Balance,
+    /// Last block at which the proposal can be approved.
+    pub expires_at: BlockNumber,
+}
+
+pub type PublicProposalOf<T> =
+    PublicProposal<<T as frame_system::Config>::AccountId, BalanceOf<T>, BlockNumberFor<T>>;
+
+impl<T: Config> Pallet<T>
+where
+    Result<DaoOrigin<T>, <T as frame_system::Config>::RuntimeOrigin>:
+        From<<T as frame_system::Config>::RuntimeOrigin>,
+    <T as frame_system::Config>::AccountId: From<[u8; 32]>,
+{
+    /// Inner function for the operate_public_multisig call.
+    pub(crate) fn inner_operate_public_multisig(
+        caller: OriginFor<T>,
+        dao_id: T::DaoId,
+        metadata: Option<BoundedVec<u8, T::MaxMetadata>>,
+        fee_asset: FeeAsset,
+        call: Box<<T as Config>::RuntimeCall>,
+    ) -> DispatchResult {
+        let proposer = ensure_signed(caller)?;
+
+        ensure!(
+            CoreStorage::<T>::contains_key(dao_id),
+            Error::<T>::DaoNotFound
+        );
+
+        // Compute the call hash
+        let call_hash = <<T as frame_system::Config>::Hashing as Hash>::hash_of(&call);
+
+        // Make sure this exact multisig call doesn't already exist
+        ensure!(
+            Multisig::<T>::get(dao_id, call_hash).is_none(),
+            Error::<T>::MultisigCallAlreadyExists
+        );
+
+        // Wrap the call making sure it fits the size boundary
+        let bounded_call: BoundedCallBytes<T> = (*call)
+            .encode()
+            .try_into()
+            .map_err(|_| Error::<T>::MaxCallLengthExceeded)?;
+
+        let total_lenght = (bounded_call.len() as u64)
+            .saturating_add(metadata.clone().unwrap_or_default().len() as u64);
+
+        let storage_cost: BalanceOf<T> = Self::proposal_storage_fee(total_lenght);
+
+        // Bring the total voting power of NFT collections up to date for the new proposal
+        Self::note_nft_voting_power(dao_id);
+
+        T::FeeCharger::handle_creation_fee(FeeAssetNegativeImbalance::Native(
+            <<T as Config>::Currency as Balanced<T::AccountId>>::withdraw(
+                &proposer,
+                storage_cost,
+                Precision::Exact,
+                Preservation::Preserve,
+                Fortitude::Polite,
+            )?,
+        ));
+
+        // Back the proposal with the deposit
+        let deposit = T::PublicProposalDeposit::get();
+
+        <T as Config>::Currency::hold(&HoldReason::DaoManager.into(), &proposer, deposit)?;
+
+        let expires_at = frame_system::Pallet::<T>::block_number()
+            .saturating_add(T::PublicProposalDuration::get());
+
+        // Insert proposal in storage without any votes, it's now in the voting stage
+        Multisig::<T>::insert(
+            dao_id,
+            call_hash,
+            MultisigOperation {
+                tally: Tally::new(dao_id),
+                original_caller: proposer.clone(),
+                actual_call: bounded_call,
+                metadata,
+                fee_asset,
+                emergency: false,
+                fee_payer: FeePayer::Dao,
+                second_tally: Self::second_chamber(dao_id).map(|_| Tally::new(dao_id)),
+            },
+        );
+
+        PublicProposals::<T>::insert(
+            dao_id,
+            call_hash,
+            PublicProposal {
+                proposer: proposer.clone(),
+                deposit,
+                expires_at,
+            },
+        );
+
+        Self::deposit_event(Event::PublicProposalSubmitted {
+            dao_id,
+            executor_account: Self::derive_dao_account(dao_id),
+            proposer,
+            call_hash,
+            deposit,
+            expires_at,
+        });
+
+        Ok(())
+    }
+
+    /// Inner function for the expire_public_proposal call.
+    pub(crate) fn inner_expire_public_proposal(
+        caller: OriginFor<T>,
+        dao_id: T::DaoId,
+        call_hash: T::Hash,
+    ) -> DispatchResult {
+        // Anyone can clean up expired proposals
+        ensure_signed(caller)?;
+
+        let proposal = PublicProposals::<T>::get(dao_id, call_hash)
+            .ok_or(Error::<T>::PublicProposalNotFound)?;
+
+        ensure!(
+            frame_system::Pallet::<T>::block_number() > proposal.expires_at,
+            Error::<T>::PublicProposalNotExpired
+        );
+
+        Multisig::<T>::remove(dao_id, call_hash);
+        Self::clear_proposal_nft_votes(dao_id, call_hash, None);
+
+        Self::slash_public_proposal_deposit(dao_id, call_hash)
+    }
+
+    /// Slashes the deposit of a public proposal to the dao account, if the proposal is public.
+    pub(crate) fn slash_public_proposal_deposit(
+        dao_id: T::DaoId,
+        call_hash: T::Hash,
+    ) -> DispatchResult {
+        if let Some(proposal) = PublicProposals::<T>::take(dao_id, call_hash) {
+            <T as Config>::Currency::transfer_on_hold(
+                &HoldReason::DaoManager.into(),
+                &proposal.proposer,
+                &Self::derive_dao_account(dao_id),
+                proposal.deposit,
+                Precision::BestEffort,
+                Restriction::Free,
+                Fortitude::Polite,
+            )?;
+
+            Self::deposit_event(Event::PublicProposalDepositSlashed {
+                dao_id,
+                proposer: proposal.proposer,
+                call_hash,
+                deposit: proposal.deposit,
+            });
+        }
+
+        Ok(())
+    }
+}
+
+impl<T: Config> Pallet<T> {
+    /// Checks that a proposal can still be voted on, public proposals can't once they expire.
+    pub fn ensure_public_proposal_not_expired(
+        dao_id: T::DaoId,
+        call_hash: T::Hash,
+    ) -> DispatchResult {
+        if let Some(proposal) = PublicProposals::<T>::get(dao_id, call_hash) {
+            ensure!(
+                frame_system::Pallet::<T>::block_number() <= proposal.expires_at,
+                Error::<T>::PublicProposalExpired
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Returns the deposit of a public proposal to its proposer, if the proposal is public.
+    pub(crate) fn return_public_proposal_deposit(
+        dao_id: T::DaoId,
+        call_hash: T::Hash,
+    ) -> DispatchResult {
+        if let Some(proposal) = PublicProposals::<T>::take(dao_id, call_hash) {
+            <T as Config>::Currency::release(
+                &HoldReason::DaoManager.into(),
+                &proposal.proposer,
+                proposal.deposit,
+                Precision::BestEffort,
+            )?;
+
+            Self::deposit_event(Event::PublicProposalDepositReturned {
+                dao_id,
+                proposer: proposal.proposer,
+                call_hash,
+                deposit: proposal.deposit,
+            });
+        }
+
+        Ok(())
+    }
+}
@@ -4,17 +4,17 @@ use core::convert::TryFrom;
 use frame_support::{
     derive_impl, parameter_types,
     traits::{
-        fungible::Credit, fungibles::Credit as Credits, ConstU128, ConstU32, ConstU64, Contains,
-        Currency, EnsureOrigin, EnsureOriginWithArg,
+        fungible::Credit, fungibles::Credit as Credits, AsEnsureOriginWithArg, CallMetadata,
+        ConstU128, ConstU32, ConstU64, Contains, Currency, EnsureOrigin, EnsureOriginWithArg,
     },
     weights::ConstantMultiplier,
 };
-use frame_system::EnsureRoot;
+use frame_system::{EnsureRoot, EnsureSigned};
 use orml_asset_registry::AssetMetadata;
 use pallet_balances::AccountData;
 use scale_info::TypeInfo;
 use sp_core::H256;
-use sp_runtime::{AccountId32, BuildStorage};
+use sp_runtime::{AccountId32, BuildStorage, Perbill};
 use sp_std::{convert::TryInto, vec};
 
 type Block = frame_system::mocking::MockBlock<Test>;
@@ -38,6 +38,7 @@ frame_support::construct_runtime!(
         AssetRegistry: orml_asset_registry::module,
         CoreAssets: orml_tokens2,
         INV4: pallet,
+        Uniques: pallet_uniques,
     }
 );
 
@@ -113,20 +114,67 @@ impl
 {
     fn on_transfer(
         currency_id: <Test as pallet::Config>::DaoId,
-        _from: &AccountId,
+        from: &AccountId,
         _to: &AccountId,
-        _amount: Balance,
+        amount: Balance,
     ) -> sp_std::result::Result<(), orml_traits::parameters::sp_runtime::DispatchError> {
         if let Some(true) = INV4::is_asset_frozen(currency_id) {
             Err(sp_runtime::DispatchError::Token(
                 sp_runtime::TokenError::Frozen,
             ))
         } else {
-            Ok(())
+            INV4::ensure_unlocked(currency_id, from, amount)
         }
     }
 }
 
+pub struct DisallowNftIfFrozen;
+impl frame_support::traits::Locker<u32, u32> for DisallowNftIfFrozen {
+    fn is_locked(collection: u32, _item: u32) -> bool {
+        INV4::is_nft_collection_frozen(collection)
+    }
+}
+
+impl pallet_uniques::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type CollectionId = u32;
+    type ItemId = u32;
+    type Currency = Balances;
+    type ForceOrigin = EnsureRoot<AccountId>;
+    type CreateOrigin = AsEnsureOriginWithArg<EnsureSigned<AccountId>>;
+    type Locker = DisallowNftIfFrozen;
+    type CollectionDeposit = ConstU128<0>;
+    type ItemDeposit = ConstU128<0>;
+    type MetadataDepositBase = ConstU128<0>;
+    type AttributeDepositBase = ConstU128<0>;
+    type DepositPerByte = ConstU128<0>;
+    type StringLimit = ConstU32<128>;
+    type KeyLimit = ConstU32<32>;
+    type ValueLimit = ConstU32<64>;
+    type WeightInfo = ();
+    #[cfg(feature = "runtime-benchmarks")]
+    type Helper = ();
+}
+
+pub struct UpdateQuadraticVotingPower;
+impl
+    orml_traits2::currency::OnTransfer<
+        <Test as frame_system::Config>::AccountId,
+        <Test as pallet::Config>::DaoId,
+        Balance,
+    > for UpdateQuadraticVotingPower
+{
+    fn on_transfer(
+        currency_id: <Test as pallet::Config>::DaoId,
+        from: &AccountId,
+        to: &AccountId,
+        amount: Balance,
+    ) -> sp_std::result::Result<(), orml_traits::parameters::sp_runtime::DispatchError> {
+        INV4::on_voting_token_transfer(currency_id, from, to, amount);
+        Ok(())
+    }
+}
+
 pub struct HandleNewMembers;
 impl
     orml_traits2::Happened<(
@@ -164,7 +212,7 @@ impl
     type OnSlash = ();
     type PreDeposit = ();
     type PostDeposit = ();
-    type PostTransfer = ();
+    type PostTransfer = UpdateQuadraticVotingPower;
     type OnNewTokenAccount = HandleNewMembers;
     type OnKilledTokenAccount = HandleRemovedMembers;
 }
@@ -191,6 +239,9 @@ impl orml_tokens2::Config for Test {
 
 parameter_types! {
     pub const MaxMetadata: u32 = 10000;
+    pub const MaxNameLength: u32 = 20;
+    pub const MaxDescriptionLength: u32 = 300;
+    pub const MaxUrlLength: u32 = 100;
     pub const MaxCallers: u32 = 10000;
     pub const DaoSeedBalance: Balance = 1000000u128;
     pub const DaoCreationFee: Balance = UNIT;
@@ -217,6 +268,20 @@ parameter_types! {
     pub const MaxCallSize: u32 = 50 * 1024;
     pub const StringLimit: u32 = 2125;
     pub const TransactionByteFee: Balance = 10 * MICROUNIT;
+    pub const EmergencySupport: Perbill = Perbill::from_percent(80);
+    pub const EmergencyFundsPallets: &'static [&'static str] = &["Balances"];
+    pub const MaxJointDaos: u32 = 5;
+    pub const MaxNftVotingItems: u32 = 5;
+    pub const MaxHandleLength: u32 = 16;
+    pub const HandleDeposit: Balance = 1000000000000;
+    pub const MaxSymbolLength: u32 = 8;
+    pub const MaxVestingSchedules: u32 = 2;
+    pub const MaxCouncilMembers: u32 = 3;
+    pub const PublicProposalDeposit: Balance = 1000000000000;
+    pub const PublicProposalDuration: u64 = 10;
+
+    // Account charged by the last dispatched multisig call.
+    pub static LastFeePayer: Option<AccountId> = None;
 
 }
 
@@ -310,6 +375,8 @@ impl MultisigFeeHandler<Test> for FeeCharger {
         _info: &sp_runtime::traits::DispatchInfoOf<RuntimeCall>,
         _len: usize,
     ) -> Result<Self::Pre, frame_support::unsigned::TransactionValidityError> {
+        LastFeePayer::set(Some(who.clone()));
+
         Ok((
             0u128,
             who.clone(),
@@ -317,6 +384,7 @@ impl MultisigFeeHandler<Test> for FeeCharger {
             match fee_asset {
                 FeeAsset::Native => None,
                 FeeAsset::Relay => Some(1u32),
+                FeeAsset::Asset(asset_id) => Some(*asset_id),
             },
         ))
     }
@@ -332,6 +400,11 @@ impl MultisigFeeHandler<Test> for FeeCharger {
         Ok(())
     }
 
+    fn native_to_asset_fee(asset_id: u32, fee: Balance) -> Option<Balance> {
+        // Every registered asset is worth twenty times the native token.
+        orml_asset_registry::Metadata::<Test>::contains_key(asset_id).then(|| fee / 20)
+    }
+
     fn handle_creation_fee(
         _imbalance: FeeAssetNegativeImbalance<
             Credit<AccountId, Balances>,
@@ -343,6 +416,9 @@ impl MultisigFeeHandler<Test> for FeeCharger {
 
 impl pallet::Config for Test {
     type MaxMetadata = MaxMetadata;
+    type MaxNameLength = MaxNameLength;
+    type MaxDescriptionLength = MaxDescriptionLength;
+    type MaxUrlLength = MaxUrlLength;
     type DaoId = u32;
     type RuntimeEvent = RuntimeEvent;
     type Currency = Balances;
@@ -364,6 +440,27 @@ impl pallet::Config for Test {
     type ParaId = ConstU32<2125>;
     type LengthToFee = ConstantMultiplier<Balance, TransactionByteFee>;
     type RuntimeHoldReason = RuntimeHoldReason;
+    type EmergencySupport = EmergencySupport;
+    type EmergencyCalls = EmergencyCalls;
+    type NftCollectionId = u32;
+    type NftItemId = u32;
+    type Nfts = Uniques;
+    type MaxNftVotingItems = MaxNftVotingItems;
+    type MaxJointDaos = MaxJointDaos;
+    type MaxHandleLength = MaxHandleLength;
+    type HandleDeposit = HandleDeposit;
+    type MaxSymbolLength = MaxSymbolLength;
+    type MaxVestingSchedules = MaxVestingSchedules;
+    type MaxCouncilMembers = MaxCouncilMembers;
+    type PublicProposalDeposit = PublicProposalDeposit;
+    type PublicProposalDuration = PublicProposalDuration;
+}
+
+pub struct EmergencyCalls;
+impl Contains<CallMetadata> for EmergencyCalls {
+    fn contains(c: &CallMetadata) -> bool {
+        matches!(c.pallet_name, "INV4" | "Balances")
+    }
 }
 
 pub struct ExtBuilder;
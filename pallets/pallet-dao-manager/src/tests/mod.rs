@@ -4,26 +4,52 @@ mod mock;
 extern crate alloc;
 
 use crate::{
+    bicameral::CouncilMembersOf,
+    dao_manager_core::DaoMetadataOf,
     multisig::{BoundedCallBytes, MultisigOperation, MAX_SIZE},
     origin::MultisigInternalOrigin,
-    voting::{Tally, Vote},
+    public_proposals::PublicProposal,
+    token::{DaoTokenInfo, DaoTokenMetadata},
+    vesting::VestingSchedule,
+    voting::{Tally, Vote, NFT_VOTING_WEIGHT_KEY},
     *,
 };
-use alloc::collections::BTreeMap;
+use alloc::collections::{BTreeMap, BTreeSet};
 use codec::Encode;
-use frame_support::{assert_err, assert_ok, error::BadOrigin, BoundedBTreeMap};
+use frame_support::{
+    assert_err, assert_ok,
+    dispatch::GetDispatchInfo,
+    error::BadOrigin,
+    traits::{fungible::InspectHold, Contains, GetCallMetadata, VoteTally},
+    BoundedBTreeMap, BoundedVec,
+};
 use frame_system::RawOrigin;
 use mock::*;
-use primitives::DaoInfo;
+use primitives::{Chamber, ChamberMembers, DaoInfo, DaoMetadata, VotingStrategy};
 use sp_runtime::{
-    traits::{Hash, Zero},
-    ArithmeticError, Perbill, TokenError,
+    traits::{Hash, StaticLookup, Zero},
+    ArithmeticError, MultiAddress, Perbill, TokenError,
 };
 use sp_std::{
     convert::{TryFrom, TryInto},
     vec,
 };
 
+fn mock_metadata() -> DaoMetadataOf<Test> {
+    DaoMetadata {
+        name: b"Dao".to_vec().try_into().unwrap(),
+        description: b"A dao".to_vec().try_into().unwrap(),
+        logo: Some(
+            b"QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG"
+                .to_vec()
+                .try_into()
+                .unwrap(),
+        ),
+        website: Some(b"https://invarch.network".to_vec().try_into().unwrap()),
+        ipfs_cid: None,
+    }
+}
+
 #[test]
 fn create_dao_works() {
     ExtBuilder::default().build().execute_with(|| {
@@ -35,7 +61,7 @@ fn create_dao_works() {
 
         assert_ok!(INV4::create_dao(
             RawOrigin::Signed(ALICE).into(),
-            vec![].try_into().unwrap(),
+            Default::default(),
             Perbill::from_percent(1),
             Perbill::from_percent(1),
             FeeAsset::Native
@@ -47,10 +73,12 @@ fn create_dao_works() {
             INV4::dao_storage(0u32),
             Some(DaoInfo {
                 account: INV4::derive_dao_account(0u32),
-                metadata: vec![].try_into().unwrap(),
+                metadata: Default::default(),
                 minimum_support: Perbill::from_percent(1),
                 required_approval: Perbill::from_percent(1),
                 frozen_tokens: true,
+                voting_strategy: VotingStrategy::TokenBalance,
+                second_chamber: None,
             })
         );
 
@@ -67,7 +95,7 @@ fn create_dao_works() {
 
         assert_ok!(INV4::create_dao(
             RawOrigin::Signed(BOB).into(),
-            vec![1, 2, 3].try_into().unwrap(),
+            mock_metadata(),
             Perbill::from_percent(100),
             Perbill::from_percent(100),
             FeeAsset::Relay
@@ -79,10 +107,12 @@ fn create_dao_works() {
             INV4::dao_storage(1u32),
             Some(DaoInfo {
                 account: INV4::derive_dao_account(1u32),
-                metadata: vec![1, 2, 3].try_into().unwrap(),
+                metadata: mock_metadata(),
                 minimum_support: Perbill::from_percent(100),
                 required_approval: Perbill::from_percent(100),
                 frozen_tokens: true,
+                voting_strategy: VotingStrategy::TokenBalance,
+                second_chamber: None,
             })
         );
 
@@ -107,7 +137,7 @@ fn create_dao_fails() {
         assert_err!(
             INV4::create_dao(
                 RawOrigin::Signed(DAVE).into(),
-                vec![].try_into().unwrap(),
+                Default::default(),
                 Perbill::from_percent(1),
                 Perbill::from_percent(1),
                 FeeAsset::Native
@@ -125,7 +155,7 @@ fn create_dao_fails() {
         assert_err!(
             INV4::create_dao(
                 RawOrigin::Signed(DAVE).into(),
-                vec![].try_into().unwrap(),
+                Default::default(),
                 Perbill::from_percent(1),
                 Perbill::from_percent(1),
                 FeeAsset::Relay
@@ -135,6 +165,43 @@ fn create_dao_fails() {
 
         assert_eq!(INV4::next_dao_id(), 0u32);
         assert_eq!(INV4::dao_storage(0u32), None);
+
+        // Invalid metadata.
+
+        assert_err!(
+            INV4::create_dao(
+                RawOrigin::Signed(ALICE).into(),
+                DaoMetadata {
+                    name: vec![0xff, 0xfe].try_into().unwrap(),
+                    ..mock_metadata()
+                },
+                Perbill::from_percent(1),
+                Perbill::from_percent(1),
+                FeeAsset::Native
+            ),
+            Error::<Test>::InvalidMetadata
+        );
+
+        assert_err!(
+            INV4::create_dao(
+                RawOrigin::Signed(ALICE).into(),
+                DaoMetadata {
+                    logo: Some(
+                        b"ipfs://QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG"
+                            .to_vec()
+                            .try_into()
+                            .unwrap()
+                    ),
+                    ..mock_metadata()
+                },
+                Perbill::from_percent(1),
+                Perbill::from_percent(1),
+                FeeAsset::Native
+            ),
+            Error::<Test>::InvalidMetadata
+        );
+
+        assert_eq!(INV4::next_dao_id(), 0u32);
     });
 }
 
@@ -143,7 +210,7 @@ fn set_parameters_works() {
     ExtBuilder::default().build().execute_with(|| {
         INV4::create_dao(
             RawOrigin::Signed(ALICE).into(),
-            vec![].try_into().unwrap(),
+            Default::default(),
             Perbill::from_percent(1),
             Perbill::from_percent(1),
             FeeAsset::Native,
@@ -152,7 +219,7 @@ fn set_parameters_works() {
 
         assert_ok!(INV4::set_parameters(
             Origin::Multisig(MultisigInternalOrigin::new(0u32)).into(),
-            Some(vec![1, 2, 3].try_into().unwrap()),
+            Some(mock_metadata()),
             Some(Perbill::from_percent(100)),
             Some(Perbill::from_percent(100)),
             Some(false)
@@ -162,10 +229,12 @@ fn set_parameters_works() {
             INV4::dao_storage(0u32),
             Some(DaoInfo {
                 account: INV4::derive_dao_account(0u32),
-                metadata: vec![1, 2, 3].try_into().unwrap(),
+                metadata: mock_metadata(),
                 minimum_support: Perbill::from_percent(100),
                 required_approval: Perbill::from_percent(100),
                 frozen_tokens: false,
+                voting_strategy: VotingStrategy::TokenBalance,
+                second_chamber: None,
             })
         );
     });
@@ -176,7 +245,7 @@ fn set_parameters_fails() {
     ExtBuilder::default().build().execute_with(|| {
         INV4::create_dao(
             RawOrigin::Signed(ALICE).into(),
-            vec![].try_into().unwrap(),
+            Default::default(),
             Perbill::from_percent(1),
             Perbill::from_percent(1),
             FeeAsset::Native,
@@ -188,7 +257,7 @@ fn set_parameters_fails() {
         assert_err!(
             INV4::set_parameters(
                 RawOrigin::Signed(ALICE).into(),
-                Some(vec![1, 2, 3].try_into().unwrap()),
+                Some(mock_metadata()),
                 Some(Perbill::from_percent(100)),
                 Some(Perbill::from_percent(100)),
                 Some(false)
@@ -201,13 +270,29 @@ fn set_parameters_fails() {
         assert_err!(
             INV4::set_parameters(
                 Origin::Multisig(MultisigInternalOrigin::new(1u32)).into(),
-                Some(vec![1, 2, 3].try_into().unwrap()),
+                Some(mock_metadata()),
                 Some(Perbill::from_percent(100)),
                 Some(Perbill::from_percent(100)),
                 Some(false)
             ),
             Error::<Test>::DaoNotFound
         );
+
+        // Website is not an http(s) url.
+
+        assert_err!(
+            INV4::set_parameters(
+                Origin::Multisig(MultisigInternalOrigin::new(0u32)).into(),
+                Some(DaoMetadata {
+                    website: Some(b"ftp://invarch.network".to_vec().try_into().unwrap()),
+                    ..mock_metadata()
+                }),
+                None,
+                None,
+                None
+            ),
+            Error::<Test>::InvalidMetadata
+        );
     });
 }
 
@@ -216,7 +301,7 @@ fn token_mint_works() {
     ExtBuilder::default().build().execute_with(|| {
         INV4::create_dao(
             RawOrigin::Signed(ALICE).into(),
-            vec![].try_into().unwrap(),
+            Default::default(),
             Perbill::from_percent(1),
             Perbill::from_percent(1),
             FeeAsset::Native,
@@ -248,7 +333,7 @@ fn token_mint_fails() {
     ExtBuilder::default().build().execute_with(|| {
         INV4::create_dao(
             RawOrigin::Signed(ALICE).into(),
-            vec![].try_into().unwrap(),
+            Default::default(),
             Perbill::from_percent(1),
             Perbill::from_percent(1),
             FeeAsset::Native,
@@ -278,7 +363,7 @@ fn token_burn_works() {
     ExtBuilder::default().build().execute_with(|| {
         INV4::create_dao(
             RawOrigin::Signed(ALICE).into(),
-            vec![].try_into().unwrap(),
+            Default::default(),
             Perbill::from_percent(1),
             Perbill::from_percent(1),
             FeeAsset::Native,
@@ -331,7 +416,7 @@ fn token_burn_fails() {
     ExtBuilder::default().build().execute_with(|| {
         INV4::create_dao(
             RawOrigin::Signed(ALICE).into(),
-            vec![].try_into().unwrap(),
+            Default::default(),
             Perbill::from_percent(1),
             Perbill::from_percent(1),
             FeeAsset::Native,
@@ -393,7 +478,7 @@ fn operate_multisig_works() {
     ExtBuilder::default().build().execute_with(|| {
         INV4::create_dao(
             RawOrigin::Signed(ALICE).into(),
-            vec![].try_into().unwrap(),
+            Default::default(),
             Perbill::from_percent(100),
             Perbill::from_percent(100),
             FeeAsset::Native,
@@ -496,17 +581,102 @@ fn operate_multisig_works() {
                     )]))
                     .unwrap()
                 ),
+                emergency: false,
+                fee_payer: FeePayer::Dao,
+                second_tally: None,
             })
         );
     });
 }
 
+#[test]
+fn operate_sponsored_multisig_works() {
+    ExtBuilder::default().build().execute_with(|| {
+        INV4::create_dao(
+            RawOrigin::Signed(ALICE).into(),
+            Default::default(),
+            Perbill::from_percent(100),
+            Perbill::from_percent(100),
+            FeeAsset::Native,
+        )
+        .unwrap();
+
+        System::set_block_number(1);
+
+        let call: RuntimeCall = pallet::Call::token_mint {
+            amount: DaoSeedBalance::get(),
+            target: BOB,
+        }
+        .into();
+
+        // Executed right away, the proposer pays.
+
+        assert_ok!(INV4::operate_sponsored_multisig(
+            RawOrigin::Signed(ALICE).into(),
+            0u32,
+            None,
+            FeeAsset::Native,
+            Box::new(call.clone())
+        ));
+
+        assert_eq!(LastFeePayer::get(), Some(ALICE));
+
+        // Executed by another voter, the proposer still pays.
+
+        assert_ok!(INV4::operate_sponsored_multisig(
+            RawOrigin::Signed(ALICE).into(),
+            0u32,
+            None,
+            FeeAsset::Native,
+            Box::new(call.clone())
+        ));
+
+        let call_hash = <<Test as frame_system::Config>::Hashing as Hash>::hash_of(&call);
+
+        assert_eq!(
+            INV4::multisig(0u32, call_hash).map(|op| op.fee_payer),
+            Some(FeePayer::Proposer)
+        );
+
+        LastFeePayer::set(None);
+
+        assert_ok!(INV4::vote_multisig(
+            RawOrigin::Signed(BOB).into(),
+            0u32,
+            call_hash,
+            true
+        ));
+
+        assert_eq!(INV4::multisig(0u32, call_hash), None);
+        assert_eq!(LastFeePayer::get(), Some(ALICE));
+
+        // Regular proposals are still paid by the dao.
+
+        assert_ok!(INV4::operate_multisig(
+            RawOrigin::Signed(ALICE).into(),
+            0u32,
+            None,
+            FeeAsset::Native,
+            Box::new(call.clone())
+        ));
+
+        assert_ok!(INV4::vote_multisig(
+            RawOrigin::Signed(BOB).into(),
+            0u32,
+            call_hash,
+            true
+        ));
+
+        assert_eq!(LastFeePayer::get(), Some(INV4::derive_dao_account(0u32)));
+    });
+}
+
 #[test]
 fn operate_multisig_fails() {
     ExtBuilder::default().build().execute_with(|| {
         INV4::create_dao(
             RawOrigin::Signed(ALICE).into(),
-            vec![].try_into().unwrap(),
+            Default::default(),
             Perbill::from_percent(100),
             Perbill::from_percent(100),
             FeeAsset::Native,
@@ -587,7 +757,7 @@ fn cancel_multisig_works() {
     ExtBuilder::default().build().execute_with(|| {
         INV4::create_dao(
             RawOrigin::Signed(ALICE).into(),
-            vec![].try_into().unwrap(),
+            Default::default(),
             Perbill::from_percent(100),
             Perbill::from_percent(100),
             FeeAsset::Native,
@@ -641,6 +811,9 @@ fn cancel_multisig_works() {
                     )]))
                     .unwrap()
                 ),
+                emergency: false,
+                fee_payer: FeePayer::Dao,
+                second_tally: None,
             })
         );
 
@@ -664,7 +837,7 @@ fn cancel_multisig_fails() {
     ExtBuilder::default().build().execute_with(|| {
         INV4::create_dao(
             RawOrigin::Signed(ALICE).into(),
-            vec![].try_into().unwrap(),
+            Default::default(),
             Perbill::from_percent(100),
             Perbill::from_percent(100),
             FeeAsset::Native,
@@ -727,6 +900,9 @@ fn cancel_multisig_fails() {
                     )]))
                     .unwrap()
                 ),
+                emergency: false,
+                fee_payer: FeePayer::Dao,
+                second_tally: None,
             })
         );
     });
@@ -737,7 +913,7 @@ fn vote_multisig_works() {
     ExtBuilder::default().build().execute_with(|| {
         INV4::create_dao(
             RawOrigin::Signed(ALICE).into(),
-            vec![].try_into().unwrap(),
+            Default::default(),
             Perbill::from_percent(100),
             Perbill::from_percent(100),
             FeeAsset::Native,
@@ -801,6 +977,9 @@ fn vote_multisig_works() {
                     )]))
                     .unwrap()
                 ),
+                emergency: false,
+                fee_payer: FeePayer::Dao,
+                second_tally: None,
             })
         );
 
@@ -852,6 +1031,9 @@ fn vote_multisig_works() {
                     ]))
                     .unwrap()
                 ),
+                emergency: false,
+                fee_payer: FeePayer::Dao,
+                second_tally: None,
             })
         );
 
@@ -891,7 +1073,7 @@ fn vote_multisig_fails() {
     ExtBuilder::default().build().execute_with(|| {
         INV4::create_dao(
             RawOrigin::Signed(ALICE).into(),
-            vec![].try_into().unwrap(),
+            Default::default(),
             Perbill::from_percent(100),
             Perbill::from_percent(100),
             FeeAsset::Native,
@@ -955,6 +1137,9 @@ fn vote_multisig_fails() {
                     )]))
                     .unwrap()
                 ),
+                emergency: false,
+                fee_payer: FeePayer::Dao,
+                second_tally: None,
             })
         );
 
@@ -987,7 +1172,7 @@ fn withdraw_vote_multisig_works() {
     ExtBuilder::default().build().execute_with(|| {
         INV4::create_dao(
             RawOrigin::Signed(ALICE).into(),
-            vec![].try_into().unwrap(),
+            Default::default(),
             Perbill::from_percent(100),
             Perbill::from_percent(100),
             FeeAsset::Native,
@@ -1080,6 +1265,9 @@ fn withdraw_vote_multisig_works() {
                     ]))
                     .unwrap()
                 ),
+                emergency: false,
+                fee_payer: FeePayer::Dao,
+                second_tally: None,
             })
         );
 
@@ -1121,6 +1309,9 @@ fn withdraw_vote_multisig_works() {
                     )]))
                     .unwrap()
                 ),
+                emergency: false,
+                fee_payer: FeePayer::Dao,
+                second_tally: None,
             })
         );
 
@@ -1154,6 +1345,9 @@ fn withdraw_vote_multisig_works() {
                 original_caller: ALICE,
                 metadata: None,
                 tally: Tally::from_parts(Zero::zero(), Zero::zero(), BoundedBTreeMap::new()),
+                emergency: false,
+                fee_payer: FeePayer::Dao,
+                second_tally: None,
             })
         );
     });
@@ -1164,7 +1358,7 @@ fn withdraw_vote_multisig_fails() {
     ExtBuilder::default().build().execute_with(|| {
         INV4::create_dao(
             RawOrigin::Signed(ALICE).into(),
-            vec![].try_into().unwrap(),
+            Default::default(),
             Perbill::from_percent(100),
             Perbill::from_percent(100),
             FeeAsset::Native,
@@ -1257,6 +1451,9 @@ fn withdraw_vote_multisig_fails() {
                     ]))
                     .unwrap()
                 ),
+                emergency: false,
+                fee_payer: FeePayer::Dao,
+                second_tally: None,
             })
         );
     });
@@ -1276,96 +1473,3695 @@ fn dao_address_matches() {
     assert_eq!(dao_account_bytes, ACCOUNT_IN_ASSET_HUB);
 }
 
-// SRLabs tests.
 #[test]
-fn vote_multisig_stack_overflow() {
+fn set_handle_works() {
     ExtBuilder::default().build().execute_with(|| {
         INV4::create_dao(
             RawOrigin::Signed(ALICE).into(),
-            vec![].try_into().unwrap(),
-            Perbill::from_percent(100),
-            Perbill::from_percent(100),
+            Default::default(),
+            Perbill::from_percent(1),
+            Perbill::from_percent(1),
             FeeAsset::Native,
         )
         .unwrap();
 
-        System::set_block_number(1);
+        let dao_account = INV4::derive_dao_account(0u32);
 
-        let call1: RuntimeCall = pallet::Call::token_mint {
-            amount: DaoSeedBalance::get(),
-            target: BOB,
-        }
-        .into();
+        assert_ok!(INV4::set_handle(
+            Origin::Multisig(MultisigInternalOrigin::new(0u32)).into(),
+            Some(b"invarch".to_vec().try_into().unwrap())
+        ));
 
-        let mut nested_call: RuntimeCall = pallet::Call::operate_multisig {
-            dao_id: 0u32,
-            metadata: None,
-            fee_asset: FeeAsset::Native,
-            call: Box::new(call1.clone()),
-        }
-        .into();
+        assert_eq!(
+            INV4::dao_by_handle(
+                BoundedVec::<u8, MaxHandleLength>::try_from(b"invarch".to_vec()).unwrap()
+            ),
+            Some(0u32)
+        );
+        assert_eq!(
+            Balances::balance_on_hold(&HoldReason::DaoManager.into(), &dao_account),
+            HandleDeposit::get()
+        );
 
-        for _ in 0..(sp_api::MAX_EXTRINSIC_DEPTH / 4) + 1 {
-            nested_call = pallet::Call::operate_multisig {
-                dao_id: 0u32,
-                metadata: None,
-                fee_asset: FeeAsset::Native,
-                call: Box::new(nested_call.clone()),
-            }
-            .into();
-        }
+        // The dao can be looked up by its handle.
 
-        INV4::operate_multisig(
+        assert_eq!(
+            DaoLookup::<Test>::lookup(MultiAddress::Raw(b"invarch".to_vec())),
+            Ok(dao_account.clone())
+        );
+        assert_eq!(
+            DaoLookup::<Test>::unlookup(dao_account.clone()),
+            MultiAddress::Raw(b"invarch".to_vec())
+        );
+
+        // Changing the handle frees the old one and keeps the deposit.
+
+        assert_ok!(INV4::set_handle(
+            Origin::Multisig(MultisigInternalOrigin::new(0u32)).into(),
+            Some(b"tinkernet-dao".to_vec().try_into().unwrap())
+        ));
+
+        assert!(DaoLookup::<Test>::lookup(MultiAddress::Raw(b"invarch".to_vec())).is_err());
+        assert_eq!(
+            DaoLookup::<Test>::lookup(MultiAddress::Raw(b"tinkernet-dao".to_vec())),
+            Ok(dao_account.clone())
+        );
+        assert_eq!(
+            Balances::balance_on_hold(&HoldReason::DaoManager.into(), &dao_account),
+            HandleDeposit::get()
+        );
+
+        // Clearing the handle releases the deposit.
+
+        assert_ok!(INV4::set_handle(
+            Origin::Multisig(MultisigInternalOrigin::new(0u32)).into(),
+            None
+        ));
+
+        assert!(DaoLookup::<Test>::lookup(MultiAddress::Raw(b"tinkernet-dao".to_vec())).is_err());
+        assert_eq!(INV4::dao_handle(0u32), None);
+        assert_eq!(
+            Balances::balance_on_hold(&HoldReason::DaoManager.into(), &dao_account),
+            0u128
+        );
+        assert_eq!(
+            DaoLookup::<Test>::unlookup(dao_account.clone()),
+            MultiAddress::Index(0u32)
+        );
+    });
+}
+
+#[test]
+fn set_handle_fails() {
+    ExtBuilder::default().build().execute_with(|| {
+        INV4::create_dao(
             RawOrigin::Signed(ALICE).into(),
-            0u32,
-            None,
+            Default::default(),
+            Perbill::from_percent(1),
+            Perbill::from_percent(1),
             FeeAsset::Native,
-            Box::new(call1.clone()),
         )
         .unwrap();
 
-        System::set_block_number(2);
-
-        INV4::operate_multisig(
-            RawOrigin::Signed(ALICE).into(),
-            0u32,
-            None,
+        INV4::create_dao(
+            RawOrigin::Signed(BOB).into(),
+            Default::default(),
+            Perbill::from_percent(1),
+            Perbill::from_percent(1),
             FeeAsset::Native,
-            Box::new(nested_call.clone()),
         )
         .unwrap();
 
-        assert_eq!(
-            INV4::multisig(
-                0u32,
-                <<Test as frame_system::Config>::Hashing as Hash>::hash_of(&nested_call)
+        // Not a dao origin.
+
+        assert_err!(
+            INV4::set_handle(
+                RawOrigin::Signed(ALICE).into(),
+                Some(b"invarch".to_vec().try_into().unwrap())
             ),
-            Some(MultisigOperation {
-                actual_call: BoundedCallBytes::<Test>::try_from(nested_call.clone().encode())
-                    .unwrap(),
-                fee_asset: FeeAsset::Native,
-                original_caller: ALICE,
-                metadata: None,
-                tally: Tally::from_parts(
-                    DaoSeedBalance::get(),
-                    Zero::zero(),
-                    BoundedBTreeMap::try_from(BTreeMap::from([(
-                        ALICE,
-                        Vote::Aye(DaoSeedBalance::get())
-                    )]))
-                    .unwrap()
-                ),
-            })
+            BadOrigin
         );
 
+        // Invalid handles.
+
         assert_err!(
-            INV4::vote_multisig(
-                RawOrigin::Signed(BOB).into(),
-                0u32,
-                <<Test as frame_system::Config>::Hashing as Hash>::hash_of(&nested_call),
-                true
+            INV4::set_handle(
+                Origin::Multisig(MultisigInternalOrigin::new(0u32)).into(),
+                Some(b"InvArch".to_vec().try_into().unwrap())
             ),
-            Error::<Test>::FailedDecodingCall
+            Error::<Test>::InvalidHandle
+        );
+
+        assert_err!(
+            INV4::set_handle(
+                Origin::Multisig(MultisigInternalOrigin::new(0u32)).into(),
+                Some(vec![].try_into().unwrap())
+            ),
+            Error::<Test>::InvalidHandle
+        );
+
+        // Not enough funds for the deposit.
+
+        assert_err!(
+            INV4::set_handle(
+                Origin::Multisig(MultisigInternalOrigin::new(1u32)).into(),
+                Some(b"bob-dao".to_vec().try_into().unwrap())
+            ),
+            TokenError::FundsUnavailable
+        );
+
+        // Handle used by another dao.
+
+        assert_ok!(INV4::set_handle(
+            Origin::Multisig(MultisigInternalOrigin::new(0u32)).into(),
+            Some(b"invarch".to_vec().try_into().unwrap())
+        ));
+
+        Balances::transfer_keep_alive(
+            RawOrigin::Signed(BOB).into(),
+            INV4::derive_dao_account(1u32),
+            HandleDeposit::get() * 2,
+        )
+        .unwrap();
+
+        assert_err!(
+            INV4::set_handle(
+                Origin::Multisig(MultisigInternalOrigin::new(1u32)).into(),
+                Some(b"invarch".to_vec().try_into().unwrap())
+            ),
+            Error::<Test>::HandleAlreadyInUse
+        );
+
+        // Dao doesn't exist.
+
+        assert_err!(
+            INV4::set_handle(
+                Origin::Multisig(MultisigInternalOrigin::new(2u32)).into(),
+                Some(b"missing".to_vec().try_into().unwrap())
+            ),
+            Error::<Test>::DaoNotFound
         );
     });
 }
+
+#[test]
+fn set_token_metadata_works() {
+    ExtBuilder::default().build().execute_with(|| {
+        INV4::create_dao(
+            RawOrigin::Signed(ALICE).into(),
+            Default::default(),
+            Perbill::from_percent(1),
+            Perbill::from_percent(1),
+            FeeAsset::Native,
+        )
+        .unwrap();
+
+        let metadata = DaoTokenMetadata {
+            name: b"Dao Token".to_vec().try_into().unwrap(),
+            symbol: b"DAO".to_vec().try_into().unwrap(),
+            decimals: 12,
+        };
+
+        assert_ok!(INV4::set_token_metadata(
+            Origin::Multisig(MultisigInternalOrigin::new(0u32)).into(),
+            metadata.clone()
+        ));
+
+        assert_eq!(INV4::token_metadata(0u32), Some(metadata.clone()));
+
+        System::assert_last_event(
+            Event::TokenMetadataSet {
+                dao_id: 0u32,
+                metadata,
+            }
+            .into(),
+        );
+
+        assert_eq!(
+            INV4::token_info(0u32),
+            Some(DaoTokenInfo {
+                name: b"Dao Token".to_vec(),
+                symbol: b"DAO".to_vec(),
+                decimals: 12,
+                total_issuance: DaoSeedBalance::get(),
+                supply_cap: None,
+            })
+        );
+
+        assert_eq!(INV4::token_info(1u32), None);
+    });
+}
+
+#[test]
+fn set_token_metadata_fails() {
+    ExtBuilder::default().build().execute_with(|| {
+        INV4::create_dao(
+            RawOrigin::Signed(ALICE).into(),
+            Default::default(),
+            Perbill::from_percent(1),
+            Perbill::from_percent(1),
+            FeeAsset::Native,
+        )
+        .unwrap();
+
+        // Wrong origin.
+        assert_err!(
+            INV4::set_token_metadata(RawOrigin::Signed(ALICE).into(), Default::default()),
+            BadOrigin
+        );
+
+        // Dao doesn't exist.
+        assert_err!(
+            INV4::set_token_metadata(
+                Origin::Multisig(MultisigInternalOrigin::new(1u32)).into(),
+                Default::default()
+            ),
+            Error::<Test>::DaoNotFound
+        );
+
+        // Empty symbol.
+        assert_err!(
+            INV4::set_token_metadata(
+                Origin::Multisig(MultisigInternalOrigin::new(0u32)).into(),
+                Default::default()
+            ),
+            Error::<Test>::InvalidTokenMetadata
+        );
+
+        // Symbol with non alphanumeric characters.
+        assert_err!(
+            INV4::set_token_metadata(
+                Origin::Multisig(MultisigInternalOrigin::new(0u32)).into(),
+                DaoTokenMetadata {
+                    name: b"Dao Token".to_vec().try_into().unwrap(),
+                    symbol: b"$DAO".to_vec().try_into().unwrap(),
+                    decimals: 12,
+                }
+            ),
+            Error::<Test>::InvalidTokenMetadata
+        );
+
+        assert_eq!(INV4::token_metadata(0u32), None);
+    });
+}
+
+#[test]
+fn token_supply_cap_works() {
+    ExtBuilder::default().build().execute_with(|| {
+        INV4::create_dao(
+            RawOrigin::Signed(ALICE).into(),
+            Default::default(),
+            Perbill::from_percent(1),
+            Perbill::from_percent(1),
+            FeeAsset::Native,
+        )
+        .unwrap();
+
+        let cap = DaoSeedBalance::get() * 3;
+
+        assert_ok!(INV4::set_token_supply_cap(
+            Origin::Multisig(MultisigInternalOrigin::new(0u32)).into(),
+            cap
+        ));
+
+        assert_eq!(INV4::token_supply_cap(0u32), Some(cap));
+
+        System::assert_last_event(Event::TokenSupplyCapSet { dao_id: 0u32, cap }.into());
+
+        // Minting up to the cap is allowed.
+        assert_ok!(INV4::token_mint(
+            Origin::Multisig(MultisigInternalOrigin::new(0u32)).into(),
+            DaoSeedBalance::get() * 2,
+            BOB
+        ));
+
+        assert_eq!(CoreAssets::total_issuance(0u32), cap);
+
+        // Burning makes room for minting again.
+        assert_ok!(INV4::token_burn(
+            Origin::Multisig(MultisigInternalOrigin::new(0u32)).into(),
+            DaoSeedBalance::get(),
+            BOB
+        ));
+
+        assert_ok!(INV4::token_mint(
+            Origin::Multisig(MultisigInternalOrigin::new(0u32)).into(),
+            DaoSeedBalance::get(),
+            BOB
+        ));
+
+        // Lowering the cap down to the current supply.
+        assert_ok!(INV4::token_burn(
+            Origin::Multisig(MultisigInternalOrigin::new(0u32)).into(),
+            DaoSeedBalance::get(),
+            BOB
+        ));
+
+        assert_ok!(INV4::set_token_supply_cap(
+            Origin::Multisig(MultisigInternalOrigin::new(0u32)).into(),
+            DaoSeedBalance::get() * 2
+        ));
+
+        assert_eq!(
+            INV4::token_supply_cap(0u32),
+            Some(DaoSeedBalance::get() * 2)
+        );
+
+        assert_eq!(
+            INV4::token_info(0u32).map(|info| (info.total_issuance, info.supply_cap)),
+            Some((DaoSeedBalance::get() * 2, Some(DaoSeedBalance::get() * 2)))
+        );
+    });
+}
+
+#[test]
+fn token_supply_cap_fails() {
+    ExtBuilder::default().build().execute_with(|| {
+        INV4::create_dao(
+            RawOrigin::Signed(ALICE).into(),
+            Default::default(),
+            Perbill::from_percent(1),
+            Perbill::from_percent(1),
+            FeeAsset::Native,
+        )
+        .unwrap();
+
+        // Wrong origin.
+        assert_err!(
+            INV4::set_token_supply_cap(RawOrigin::Signed(ALICE).into(), DaoSeedBalance::get()),
+            BadOrigin
+        );
+
+        // Dao doesn't exist.
+        assert_err!(
+            INV4::set_token_supply_cap(
+                Origin::Multisig(MultisigInternalOrigin::new(1u32)).into(),
+                DaoSeedBalance::get()
+            ),
+            Error::<Test>::DaoNotFound
+        );
+
+        // Cap lower than the current supply.
+        assert_err!(
+            INV4::set_token_supply_cap(
+                Origin::Multisig(MultisigInternalOrigin::new(0u32)).into(),
+                DaoSeedBalance::get() - 1
+            ),
+            Error::<Test>::SupplyCapBelowIssuance
+        );
+
+        assert_ok!(INV4::set_token_supply_cap(
+            Origin::Multisig(MultisigInternalOrigin::new(0u32)).into(),
+            DaoSeedBalance::get() * 2
+        ));
+
+        // The cap can't be raised.
+        assert_err!(
+            INV4::set_token_supply_cap(
+                Origin::Multisig(MultisigInternalOrigin::new(0u32)).into(),
+                DaoSeedBalance::get() * 2 + 1
+            ),
+            Error::<Test>::SupplyCapCannotBeRaised
+        );
+
+        // Minting past the cap.
+        assert_err!(
+            INV4::token_mint(
+                Origin::Multisig(MultisigInternalOrigin::new(0u32)).into(),
+                DaoSeedBalance::get() + 1,
+                BOB
+            ),
+            Error::<Test>::SupplyCapExceeded
+        );
+
+        assert_eq!(CoreAssets::total_issuance(0u32), DaoSeedBalance::get());
+    });
+}
+
+#[test]
+fn token_mint_vested_works() {
+    ExtBuilder::default().build().execute_with(|| {
+        INV4::create_dao(
+            RawOrigin::Signed(ALICE).into(),
+            Default::default(),
+            Perbill::from_percent(1),
+            Perbill::from_percent(1),
+            FeeAsset::Native,
+        )
+        .unwrap();
+
+        System::set_block_number(1);
+
+        // Unfreeze the voting token so only vesting locks prevent transfers.
+        assert_ok!(INV4::set_parameters(
+            Origin::Multisig(MultisigInternalOrigin::new(0u32)).into(),
+            None,
+            None,
+            None,
+            Some(false)
+        ));
+
+        let schedule = VestingSchedule::Linear {
+            amount: 1000,
+            start: 1,
+            duration: 100,
+        };
+
+        assert_ok!(INV4::token_mint_vested(
+            Origin::Multisig(MultisigInternalOrigin::new(0u32)).into(),
+            BOB,
+            schedule
+        ));
+
+        System::assert_last_event(
+            Event::MintedVested {
+                dao_id: 0u32,
+                target: BOB,
+                schedule,
+            }
+            .into(),
+        );
+
+        assert_eq!(CoreAssets::accounts(BOB, 0u32).free, 1000);
+        assert_eq!(INV4::dao_members(0u32, BOB), Some(()));
+        assert_eq!(INV4::vesting_locked(0u32, &BOB), 1000);
+
+        // Nothing unlocked yet.
+        assert_err!(
+            CoreAssets::transfer(RawOrigin::Signed(BOB).into(), ALICE, 0u32, 1),
+            TokenError::Frozen
+        );
+
+        // A quarter of the schedule unlocked.
+        System::set_block_number(26);
+        assert_eq!(INV4::vesting_locked(0u32, &BOB), 750);
+
+        assert_ok!(CoreAssets::transfer(
+            RawOrigin::Signed(BOB).into(),
+            ALICE,
+            0u32,
+            250
+        ));
+
+        assert_err!(
+            CoreAssets::transfer(RawOrigin::Signed(BOB).into(), ALICE, 0u32, 1),
+            TokenError::Frozen
+        );
+
+        // Cliff schedules unlock all at once.
+        assert_ok!(INV4::token_mint_vested(
+            Origin::Multisig(MultisigInternalOrigin::new(0u32)).into(),
+            CHARLIE,
+            VestingSchedule::Cliff {
+                amount: 500,
+                unlock_at: 50,
+            }
+        ));
+
+        System::set_block_number(49);
+        assert_eq!(INV4::vesting_locked(0u32, &CHARLIE), 500);
+        assert_err!(
+            CoreAssets::transfer(RawOrigin::Signed(CHARLIE).into(), ALICE, 0u32, 1),
+            TokenError::Frozen
+        );
+
+        System::set_block_number(50);
+        assert_eq!(INV4::vesting_locked(0u32, &CHARLIE), 0);
+        assert_ok!(CoreAssets::transfer(
+            RawOrigin::Signed(CHARLIE).into(),
+            ALICE,
+            0u32,
+            500
+        ));
+
+        // Schedules are cleared along with the account.
+        assert_eq!(INV4::dao_members(0u32, CHARLIE), None);
+        assert!(INV4::vesting_schedules(0u32, CHARLIE).is_empty());
+
+        // Everything unlocked at the end of the schedule.
+        System::set_block_number(101);
+        assert_eq!(INV4::vesting_locked(0u32, &BOB), 0);
+        assert_ok!(CoreAssets::transfer(
+            RawOrigin::Signed(BOB).into(),
+            ALICE,
+            0u32,
+            750
+        ));
+    });
+}
+
+#[test]
+fn token_burn_trims_vesting_schedules() {
+    ExtBuilder::default().build().execute_with(|| {
+        INV4::create_dao(
+            RawOrigin::Signed(ALICE).into(),
+            Default::default(),
+            Perbill::from_percent(1),
+            Perbill::from_percent(1),
+            FeeAsset::Native,
+        )
+        .unwrap();
+
+        System::set_block_number(1);
+
+        assert_ok!(INV4::set_parameters(
+            Origin::Multisig(MultisigInternalOrigin::new(0u32)).into(),
+            None,
+            None,
+            None,
+            Some(false)
+        ));
+
+        let schedule = VestingSchedule::Linear {
+            amount: 1000,
+            start: 1,
+            duration: 100,
+        };
+
+        assert_ok!(INV4::token_mint_vested(
+            Origin::Multisig(MultisigInternalOrigin::new(0u32)).into(),
+            BOB,
+            schedule
+        ));
+        assert_ok!(INV4::token_mint(
+            Origin::Multisig(MultisigInternalOrigin::new(0u32)).into(),
+            200,
+            BOB
+        ));
+
+        System::set_block_number(51);
+        assert_eq!(INV4::vesting_locked(0u32, &BOB), 500);
+
+        // Burning unlocked tokens leaves the schedules untouched.
+        assert_ok!(INV4::token_burn(
+            Origin::Multisig(MultisigInternalOrigin::new(0u32)).into(),
+            700,
+            BOB
+        ));
+
+        assert_eq!(INV4::vesting_schedules(0u32, BOB).to_vec(), vec![schedule]);
+
+        // Burning locked tokens cuts the schedule, the rest keeps unlocking until its end.
+        assert_ok!(INV4::token_burn(
+            Origin::Multisig(MultisigInternalOrigin::new(0u32)).into(),
+            200,
+            BOB
+        ));
+
+        assert_eq!(INV4::vesting_locked(0u32, &BOB), 300);
+        assert_eq!(
+            INV4::vesting_schedules(0u32, BOB).to_vec(),
+            vec![VestingSchedule::Linear {
+                amount: 300,
+                start: 51,
+                duration: 50,
+            }]
+        );
+
+        System::set_block_number(76);
+        assert_eq!(INV4::vesting_locked(0u32, &BOB), 150);
+
+        assert_ok!(CoreAssets::transfer(
+            RawOrigin::Signed(BOB).into(),
+            ALICE,
+            0u32,
+            150
+        ));
+
+        assert_err!(
+            CoreAssets::transfer(RawOrigin::Signed(BOB).into(), ALICE, 0u32, 1),
+            TokenError::Frozen
+        );
+
+        // The schedules added last are cut first.
+        assert_ok!(INV4::token_mint_vested(
+            Origin::Multisig(MultisigInternalOrigin::new(0u32)).into(),
+            BOB,
+            VestingSchedule::Cliff {
+                amount: 100,
+                unlock_at: 200,
+            }
+        ));
+
+        assert_ok!(INV4::token_burn(
+            Origin::Multisig(MultisigInternalOrigin::new(0u32)).into(),
+            120,
+            BOB
+        ));
+
+        assert_eq!(INV4::vesting_locked(0u32, &BOB), 130);
+        assert_eq!(
+            INV4::vesting_schedules(0u32, BOB).to_vec(),
+            vec![VestingSchedule::Linear {
+                amount: 130,
+                start: 76,
+                duration: 25,
+            }]
+        );
+
+        // Burning everything clears the schedules.
+        assert_ok!(INV4::token_burn(
+            Origin::Multisig(MultisigInternalOrigin::new(0u32)).into(),
+            130,
+            BOB
+        ));
+
+        assert!(INV4::vesting_schedules(0u32, BOB).is_empty());
+        assert_eq!(INV4::vesting_locked(0u32, &BOB), 0);
+    });
+}
+
+#[test]
+fn token_mint_vested_fails() {
+    ExtBuilder::default().build().execute_with(|| {
+        INV4::create_dao(
+            RawOrigin::Signed(ALICE).into(),
+            Default::default(),
+            Perbill::from_percent(1),
+            Perbill::from_percent(1),
+            FeeAsset::Native,
+        )
+        .unwrap();
+
+        System::set_block_number(1);
+
+        let schedule = VestingSchedule::Cliff {
+            amount: 1000,
+            unlock_at: 100,
+        };
+
+        // Wrong origin.
+        assert_err!(
+            INV4::token_mint_vested(RawOrigin::Signed(ALICE).into(), BOB, schedule),
+            BadOrigin
+        );
+
+        // Nothing to vest.
+        assert_err!(
+            INV4::token_mint_vested(
+                Origin::Multisig(MultisigInternalOrigin::new(0u32)).into(),
+                BOB,
+                VestingSchedule::Cliff {
+                    amount: 0,
+                    unlock_at: 100,
+                }
+            ),
+            Error::<Test>::InvalidVestingSchedule
+        );
+
+        // Linear schedule without duration.
+        assert_err!(
+            INV4::token_mint_vested(
+                Origin::Multisig(MultisigInternalOrigin::new(0u32)).into(),
+                BOB,
+                VestingSchedule::Linear {
+                    amount: 1000,
+                    start: 1,
+                    duration: 0,
+                }
+            ),
+            Error::<Test>::InvalidVestingSchedule
+        );
+
+        // Too many schedules.
+        for _ in 0..MaxVestingSchedules::get() {
+            assert_ok!(INV4::token_mint_vested(
+                Origin::Multisig(MultisigInternalOrigin::new(0u32)).into(),
+                BOB,
+                schedule
+            ));
+        }
+
+        assert_err!(
+            INV4::token_mint_vested(
+                Origin::Multisig(MultisigInternalOrigin::new(0u32)).into(),
+                BOB,
+                schedule
+            ),
+            Error::<Test>::TooManyVestingSchedules
+        );
+
+        // Unlocked schedules make room for new ones.
+        System::set_block_number(100);
+        assert_ok!(INV4::token_mint_vested(
+            Origin::Multisig(MultisigInternalOrigin::new(0u32)).into(),
+            BOB,
+            schedule
+        ));
+        assert_eq!(INV4::vesting_schedules(0u32, BOB).len(), 1);
+
+        // Supply cap applies to vested mints too.
+        assert_ok!(INV4::set_token_supply_cap(
+            Origin::Multisig(MultisigInternalOrigin::new(0u32)).into(),
+            CoreAssets::total_issuance(0u32)
+        ));
+
+        assert_err!(
+            INV4::token_mint_vested(
+                Origin::Multisig(MultisigInternalOrigin::new(0u32)).into(),
+                CHARLIE,
+                schedule
+            ),
+            Error::<Test>::SupplyCapExceeded
+        );
+    });
+}
+
+#[test]
+fn set_locked_tokens_vote_works() {
+    ExtBuilder::default().build().execute_with(|| {
+        INV4::create_dao(
+            RawOrigin::Signed(ALICE).into(),
+            Default::default(),
+            Perbill::from_percent(1),
+            Perbill::from_percent(1),
+            FeeAsset::Native,
+        )
+        .unwrap();
+
+        System::set_block_number(1);
+
+        assert_ok!(INV4::token_mint_vested(
+            Origin::Multisig(MultisigInternalOrigin::new(0u32)).into(),
+            BOB,
+            VestingSchedule::Linear {
+                amount: 1000,
+                start: 1,
+                duration: 100,
+            }
+        ));
+
+        // Locked tokens vote by default.
+        assert!(INV4::locked_tokens_vote(0u32));
+        assert_eq!(INV4::voting_power(0u32, &BOB), 1000);
+
+        assert_ok!(INV4::set_locked_tokens_vote(
+            Origin::Multisig(MultisigInternalOrigin::new(0u32)).into(),
+            false
+        ));
+
+        System::assert_last_event(
+            Event::LockedTokensVoteSet {
+                dao_id: 0u32,
+                can_vote: false,
+            }
+            .into(),
+        );
+
+        assert_eq!(INV4::voting_power(0u32, &BOB), 0);
+        assert_eq!(INV4::voting_power(0u32, &ALICE), DaoSeedBalance::get());
+
+        // Only the unlocked part votes.
+        System::set_block_number(51);
+        assert_eq!(INV4::voting_power(0u32, &BOB), 500);
+
+        // The total voting power still counts locked tokens, however long ago BOB's lock was last touched.
+        assert_eq!(INV4::total_voting_power(0u32), DaoSeedBalance::get() + 1000);
+
+        System::set_block_number(101);
+        assert_eq!(INV4::voting_power(0u32, &BOB), 1000);
+        assert_eq!(INV4::total_voting_power(0u32), DaoSeedBalance::get() + 1000);
+
+        // Wrong origin.
+        assert_err!(
+            INV4::set_locked_tokens_vote(RawOrigin::Signed(ALICE).into(), true),
+            BadOrigin
+        );
+
+        // Dao doesn't exist.
+        assert_err!(
+            INV4::set_locked_tokens_vote(
+                Origin::Multisig(MultisigInternalOrigin::new(1u32)).into(),
+                true
+            ),
+            Error::<Test>::DaoNotFound
+        );
+    });
+}
+
+#[test]
+fn set_second_chamber_works() {
+    ExtBuilder::default().build().execute_with(|| {
+        INV4::create_dao(
+            RawOrigin::Signed(ALICE).into(),
+            Default::default(),
+            Perbill::from_percent(1),
+            Perbill::from_percent(1),
+            FeeAsset::Native,
+        )
+        .unwrap();
+
+        INV4::create_dao(
+            RawOrigin::Signed(BOB).into(),
+            Default::default(),
+            Perbill::from_percent(1),
+            Perbill::from_percent(1),
+            FeeAsset::Native,
+        )
+        .unwrap();
+
+        System::set_block_number(1);
+
+        // Council chamber.
+        let council = Chamber {
+            members: ChamberMembers::Council(
+                CouncilMembersOf::<Test>::try_from(BTreeSet::from([BOB, CHARLIE])).unwrap(),
+            ),
+            minimum_support: Perbill::from_percent(51),
+            required_approval: Perbill::from_percent(51),
+        };
+
+        assert_ok!(INV4::set_second_chamber(
+            Origin::Multisig(MultisigInternalOrigin::new(0u32)).into(),
+            Some(council.clone())
+        ));
+
+        System::assert_last_event(
+            Event::SecondChamberSet {
+                dao_id: 0u32,
+                chamber: Some(council.clone()),
+            }
+            .into(),
+        );
+
+        assert_eq!(INV4::second_chamber(0u32), Some(council.clone()));
+        assert_eq!(INV4::chamber_voting_power(&council, &BOB), 1);
+        assert_eq!(INV4::chamber_voting_power(&council, &ALICE), 0);
+        assert_eq!(INV4::chamber_total_voting_power(&council), 2);
+
+        // Token chamber using the voting token of dao 1.
+        let token = Chamber {
+            members: ChamberMembers::Token(1u32),
+            minimum_support: Perbill::from_percent(51),
+            required_approval: Perbill::from_percent(51),
+        };
+
+        assert_ok!(INV4::set_second_chamber(
+            Origin::Multisig(MultisigInternalOrigin::new(0u32)).into(),
+            Some(token.clone())
+        ));
+
+        assert_eq!(INV4::second_chamber(0u32), Some(token.clone()));
+        assert_eq!(
+            INV4::chamber_voting_power(&token, &BOB),
+            DaoSeedBalance::get()
+        );
+        assert_eq!(INV4::chamber_voting_power(&token, &ALICE), 0);
+        assert_eq!(
+            INV4::chamber_total_voting_power(&token),
+            DaoSeedBalance::get()
+        );
+
+        // Back to unicameral.
+        assert_ok!(INV4::set_second_chamber(
+            Origin::Multisig(MultisigInternalOrigin::new(0u32)).into(),
+            None
+        ));
+
+        System::assert_last_event(
+            Event::SecondChamberSet {
+                dao_id: 0u32,
+                chamber: None,
+            }
+            .into(),
+        );
+
+        assert_eq!(INV4::second_chamber(0u32), None);
+    });
+}
+
+#[test]
+fn set_second_chamber_fails() {
+    ExtBuilder::default().build().execute_with(|| {
+        INV4::create_dao(
+            RawOrigin::Signed(ALICE).into(),
+            Default::default(),
+            Perbill::from_percent(1),
+            Perbill::from_percent(1),
+            FeeAsset::Native,
+        )
+        .unwrap();
+
+        System::set_block_number(1);
+
+        let chamber = Chamber {
+            members: ChamberMembers::Council(
+                CouncilMembersOf::<Test>::try_from(BTreeSet::from([BOB])).unwrap(),
+            ),
+            minimum_support: Perbill::from_percent(51),
+            required_approval: Perbill::from_percent(51),
+        };
+
+        // Wrong origin.
+        assert_err!(
+            INV4::set_second_chamber(RawOrigin::Signed(ALICE).into(), Some(chamber.clone())),
+            BadOrigin
+        );
+
+        // Council without members.
+        assert_err!(
+            INV4::set_second_chamber(
+                Origin::Multisig(MultisigInternalOrigin::new(0u32)).into(),
+                Some(Chamber {
+                    members: ChamberMembers::Council(Default::default()),
+                    minimum_support: Perbill::from_percent(51),
+                    required_approval: Perbill::from_percent(51),
+                })
+            ),
+            Error::<Test>::InvalidSecondChamber
+        );
+
+        // Token chamber using the dao's own voting token.
+        assert_err!(
+            INV4::set_second_chamber(
+                Origin::Multisig(MultisigInternalOrigin::new(0u32)).into(),
+                Some(Chamber {
+                    members: ChamberMembers::Token(0u32),
+                    minimum_support: Perbill::from_percent(51),
+                    required_approval: Perbill::from_percent(51),
+                })
+            ),
+            Error::<Test>::InvalidSecondChamber
+        );
+
+        // Token chamber using the voting token of a dao that doesn't exist.
+        assert_err!(
+            INV4::set_second_chamber(
+                Origin::Multisig(MultisigInternalOrigin::new(0u32)).into(),
+                Some(Chamber {
+                    members: ChamberMembers::Token(1u32),
+                    minimum_support: Perbill::from_percent(51),
+                    required_approval: Perbill::from_percent(51),
+                })
+            ),
+            Error::<Test>::InvalidSecondChamber
+        );
+
+        // Dao doesn't exist.
+        assert_err!(
+            INV4::set_second_chamber(
+                Origin::Multisig(MultisigInternalOrigin::new(1u32)).into(),
+                Some(chamber)
+            ),
+            Error::<Test>::DaoNotFound
+        );
+    });
+}
+
+#[test]
+fn bicameral_multisig_works() {
+    ExtBuilder::default().build().execute_with(|| {
+        INV4::create_dao(
+            RawOrigin::Signed(ALICE).into(),
+            Default::default(),
+            Perbill::from_percent(51),
+            Perbill::from_percent(51),
+            FeeAsset::Native,
+        )
+        .unwrap();
+
+        System::set_block_number(1);
+
+        // BOB and CHARLIE sit in the council, ALICE holds every voting token.
+        assert_ok!(INV4::set_second_chamber(
+            Origin::Multisig(MultisigInternalOrigin::new(0u32)).into(),
+            Some(Chamber {
+                members: ChamberMembers::Council(
+                    CouncilMembersOf::<Test>::try_from(BTreeSet::from([BOB, CHARLIE])).unwrap(),
+                ),
+                minimum_support: Perbill::from_percent(51),
+                required_approval: Perbill::from_percent(51),
+            })
+        ));
+
+        let call: RuntimeCall = pallet::Call::token_mint {
+            amount: 1,
+            target: DAVE,
+        }
+        .into();
+
+        let call_hash = <<Test as frame_system::Config>::Hashing as Hash>::hash_of(&call);
+
+        // Token holders alone can't execute the proposal.
+        assert_ok!(INV4::operate_multisig(
+            RawOrigin::Signed(ALICE).into(),
+            0u32,
+            None,
+            FeeAsset::Native,
+            Box::new(call.clone())
+        ));
+
+        assert_eq!(
+            INV4::multisig(0u32, call_hash),
+            Some(MultisigOperation {
+                actual_call: BoundedCallBytes::<Test>::try_from(call.clone().encode()).unwrap(),
+                fee_asset: FeeAsset::Native,
+                original_caller: ALICE,
+                metadata: None,
+                tally: Tally::from_parts(
+                    DaoSeedBalance::get(),
+                    Zero::zero(),
+                    BoundedBTreeMap::try_from(BTreeMap::from([(
+                        ALICE,
+                        Vote::Aye(DaoSeedBalance::get())
+                    )]))
+                    .unwrap()
+                ),
+                emergency: false,
+                fee_payer: FeePayer::Dao,
+                second_tally: Some(Tally::from_parts(
+                    Zero::zero(),
+                    Zero::zero(),
+                    BoundedBTreeMap::new()
+                )),
+            })
+        );
+
+        // DAVE is in neither chamber.
+        assert_err!(
+            INV4::vote_multisig(RawOrigin::Signed(DAVE).into(), 0u32, call_hash, true),
+            Error::<Test>::NoPermission
+        );
+
+        // Half of the council isn't enough.
+        assert_ok!(INV4::vote_multisig(
+            RawOrigin::Signed(BOB).into(),
+            0u32,
+            call_hash,
+            true
+        ));
+
+        System::assert_last_event(
+            Event::SecondChamberVoteAdded {
+                dao_id: 0u32,
+                voter: BOB,
+                votes_added: Vote::Aye(1),
+                current_votes: Tally::from_parts(
+                    1,
+                    Zero::zero(),
+                    BoundedBTreeMap::try_from(BTreeMap::from([(BOB, Vote::Aye(1))])).unwrap(),
+                ),
+                call_hash,
+            }
+            .into(),
+        );
+
+        assert_eq!(CoreAssets::accounts(DAVE, 0u32).free, 0);
+
+        // Withdrawing a council vote.
+        assert_ok!(INV4::withdraw_vote_multisig(
+            RawOrigin::Signed(BOB).into(),
+            0u32,
+            call_hash
+        ));
+
+        System::assert_last_event(
+            Event::SecondChamberVoteWithdrawn {
+                dao_id: 0u32,
+                voter: BOB,
+                votes_removed: Vote::Aye(1),
+                call_hash,
+            }
+            .into(),
+        );
+
+        assert_err!(
+            INV4::withdraw_vote_multisig(RawOrigin::Signed(BOB).into(), 0u32, call_hash),
+            Error::<Test>::NotAVoter
+        );
+
+        // Both chambers approve.
+        assert_ok!(INV4::vote_multisig(
+            RawOrigin::Signed(BOB).into(),
+            0u32,
+            call_hash,
+            true
+        ));
+
+        assert_ok!(INV4::vote_multisig(
+            RawOrigin::Signed(CHARLIE).into(),
+            0u32,
+            call_hash,
+            true
+        ));
+
+        System::assert_has_event(
+            Event::MultisigExecuted {
+                dao_id: 0u32,
+                executor_account: INV4::derive_dao_account(0u32),
+                voter: CHARLIE,
+                call: call.clone(),
+                call_hash,
+                result: Ok(()),
+            }
+            .into(),
+        );
+
+        assert_eq!(INV4::multisig(0u32, call_hash), None);
+        assert_eq!(CoreAssets::accounts(DAVE, 0u32).free, 1);
+
+        // Council members without voting tokens can propose too.
+        let call: RuntimeCall = pallet::Call::token_burn {
+            amount: 1,
+            target: DAVE,
+        }
+        .into();
+
+        let call_hash = <<Test as frame_system::Config>::Hashing as Hash>::hash_of(&call);
+
+        assert_ok!(INV4::operate_multisig(
+            RawOrigin::Signed(CHARLIE).into(),
+            0u32,
+            None,
+            FeeAsset::Native,
+            Box::new(call.clone())
+        ));
+
+        assert_eq!(
+            INV4::multisig(0u32, call_hash).map(|m| (m.tally, m.second_tally)),
+            Some((
+                Tally::from_parts(Zero::zero(), Zero::zero(), BoundedBTreeMap::new()),
+                Some(Tally::from_parts(
+                    1,
+                    Zero::zero(),
+                    BoundedBTreeMap::try_from(BTreeMap::from([(CHARLIE, Vote::Aye(1))])).unwrap()
+                ))
+            ))
+        );
+
+        assert_ok!(INV4::vote_multisig(
+            RawOrigin::Signed(BOB).into(),
+            0u32,
+            call_hash,
+            true
+        ));
+
+        // The council approves, but token holders haven't voted yet.
+        assert_eq!(CoreAssets::accounts(DAVE, 0u32).free, 1);
+
+        assert_ok!(INV4::vote_multisig(
+            RawOrigin::Signed(ALICE).into(),
+            0u32,
+            call_hash,
+            true
+        ));
+
+        System::assert_has_event(
+            Event::MultisigExecuted {
+                dao_id: 0u32,
+                executor_account: INV4::derive_dao_account(0u32),
+                voter: ALICE,
+                call: call.clone(),
+                call_hash,
+                result: Ok(()),
+            }
+            .into(),
+        );
+
+        assert_eq!(CoreAssets::accounts(DAVE, 0u32).free, 0);
+    });
+}
+
+#[test]
+fn second_chamber_without_voting_power_never_passes() {
+    ExtBuilder::default().build().execute_with(|| {
+        for creator in [ALICE, BOB] {
+            INV4::create_dao(
+                RawOrigin::Signed(creator).into(),
+                Default::default(),
+                Perbill::from_percent(1),
+                Perbill::from_percent(1),
+                FeeAsset::Native,
+            )
+            .unwrap();
+        }
+
+        System::set_block_number(1);
+
+        assert_ok!(INV4::set_second_chamber(
+            Origin::Multisig(MultisigInternalOrigin::new(0u32)).into(),
+            Some(Chamber {
+                members: ChamberMembers::Token(1u32),
+                minimum_support: Perbill::zero(),
+                required_approval: Perbill::zero(),
+            })
+        ));
+
+        // Thresholds of zero are met without votes.
+        assert!(INV4::second_chamber_passes(0u32, None, false));
+
+        // The whole voting token supply of the chamber is burned.
+        assert_ok!(INV4::token_burn(
+            Origin::Multisig(MultisigInternalOrigin::new(1u32)).into(),
+            DaoSeedBalance::get(),
+            BOB
+        ));
+
+        assert_eq!(CoreAssets::total_issuance(1u32), 0);
+        assert!(!INV4::second_chamber_passes(0u32, None, false));
+    });
+}
+
+#[test]
+fn bicameral_dao_cant_join_joint_multisig() {
+    ExtBuilder::default().build().execute_with(|| {
+        INV4::create_dao(
+            RawOrigin::Signed(ALICE).into(),
+            Default::default(),
+            Perbill::from_percent(1),
+            Perbill::from_percent(1),
+            FeeAsset::Native,
+        )
+        .unwrap();
+
+        INV4::create_dao(
+            RawOrigin::Signed(ALICE).into(),
+            Default::default(),
+            Perbill::from_percent(1),
+            Perbill::from_percent(1),
+            FeeAsset::Native,
+        )
+        .unwrap();
+
+        System::set_block_number(1);
+
+        assert_ok!(INV4::set_second_chamber(
+            Origin::Multisig(MultisigInternalOrigin::new(0u32)).into(),
+            Some(Chamber {
+                members: ChamberMembers::Council(
+                    CouncilMembersOf::<Test>::try_from(BTreeSet::from([BOB])).unwrap(),
+                ),
+                minimum_support: Perbill::from_percent(51),
+                required_approval: Perbill::from_percent(51),
+            })
+        ));
+
+        let calls: BoundedVec<(u32, RuntimeCall), MaxJointDaos> = vec![
+            (
+                0u32,
+                pallet::Call::token_mint {
+                    amount: DaoSeedBalance::get(),
+                    target: DAVE,
+                }
+                .into(),
+            ),
+            (
+                1u32,
+                pallet::Call::token_mint {
+                    amount: DaoSeedBalance::get(),
+                    target: DAVE,
+                }
+                .into(),
+            ),
+        ]
+        .try_into()
+        .unwrap();
+
+        assert_err!(
+            INV4::operate_joint_multisig(
+                RawOrigin::Signed(ALICE).into(),
+                calls,
+                None,
+                FeeAsset::Native
+            ),
+            Error::<Test>::BicameralJointDao
+        );
+    });
+}
+
+#[test]
+fn operate_public_multisig_works() {
+    ExtBuilder::default().build().execute_with(|| {
+        INV4::create_dao(
+            RawOrigin::Signed(ALICE).into(),
+            Default::default(),
+            Perbill::from_percent(1),
+            Perbill::from_percent(1),
+            FeeAsset::Native,
+        )
+        .unwrap();
+
+        System::set_block_number(1);
+
+        let call: RuntimeCall = pallet::Call::token_mint {
+            amount: 1,
+            target: DAVE,
+        }
+        .into();
+
+        let call_hash = <<Test as frame_system::Config>::Hashing as Hash>::hash_of(&call);
+
+        // BOB has no voting power in the dao.
+        assert_ok!(INV4::operate_public_multisig(
+            RawOrigin::Signed(BOB).into(),
+            0u32,
+            None,
+            FeeAsset::Native,
+            Box::new(call.clone())
+        ));
+
+        System::assert_last_event(
+            Event::PublicProposalSubmitted {
+                dao_id: 0u32,
+                executor_account: INV4::derive_dao_account(0u32),
+                proposer: BOB,
+                call_hash,
+                deposit: PublicProposalDeposit::get(),
+                expires_at: 11,
+            }
+            .into(),
+        );
+
+        assert_eq!(
+            Balances::balance_on_hold(&HoldReason::DaoManager.into(), &BOB),
+            PublicProposalDeposit::get()
+        );
+
+        assert_eq!(
+            INV4::public_proposal(0u32, call_hash),
+            Some(PublicProposal {
+                proposer: BOB,
+                deposit: PublicProposalDeposit::get(),
+                expires_at: 11,
+            })
+        );
+
+        // Public proposals start without any votes.
+        assert_eq!(
+            INV4::multisig(0u32, call_hash).map(|m| (m.tally, m.fee_payer)),
+            Some((
+                Tally::from_parts(Zero::zero(), Zero::zero(), BoundedBTreeMap::new()),
+                FeePayer::Dao
+            ))
+        );
+
+        assert_ok!(INV4::vote_multisig(
+            RawOrigin::Signed(ALICE).into(),
+            0u32,
+            call_hash,
+            true
+        ));
+
+        System::assert_has_event(
+            Event::MultisigExecuted {
+                dao_id: 0u32,
+                executor_account: INV4::derive_dao_account(0u32),
+                voter: ALICE,
+                call: call.clone(),
+                call_hash,
+                result: Ok(()),
+            }
+            .into(),
+        );
+
+        System::assert_has_event(
+            Event::PublicProposalDepositReturned {
+                dao_id: 0u32,
+                proposer: BOB,
+                call_hash,
+                deposit: PublicProposalDeposit::get(),
+            }
+            .into(),
+        );
+
+        assert_eq!(CoreAssets::accounts(DAVE, 0u32).free, 1);
+        assert_eq!(
+            Balances::balance_on_hold(&HoldReason::DaoManager.into(), &BOB),
+            0
+        );
+        assert_eq!(INV4::public_proposal(0u32, call_hash), None);
+    });
+}
+
+#[test]
+fn operate_public_multisig_fails() {
+    ExtBuilder::default().build().execute_with(|| {
+        INV4::create_dao(
+            RawOrigin::Signed(ALICE).into(),
+            Default::default(),
+            Perbill::from_percent(1),
+            Perbill::from_percent(1),
+            FeeAsset::Native,
+        )
+        .unwrap();
+
+        let call: RuntimeCall = pallet::Call::token_mint {
+            amount: 1,
+            target: DAVE,
+        }
+        .into();
+
+        // Dao doesn't exist.
+        assert_err!(
+            INV4::operate_public_multisig(
+                RawOrigin::Signed(BOB).into(),
+                1u32,
+                None,
+                FeeAsset::Native,
+                Box::new(call.clone())
+            ),
+            Error::<Test>::DaoNotFound
+        );
+
+        // Proposer can't afford the deposit.
+        assert_err!(
+            INV4::operate_public_multisig(
+                RawOrigin::Signed(DAVE).into(),
+                0u32,
+                None,
+                FeeAsset::Native,
+                Box::new(call.clone())
+            ),
+            TokenError::FundsUnavailable
+        );
+
+        assert_ok!(INV4::operate_public_multisig(
+            RawOrigin::Signed(BOB).into(),
+            0u32,
+            None,
+            FeeAsset::Native,
+            Box::new(call.clone())
+        ));
+
+        // Same call already proposed.
+        assert_err!(
+            INV4::operate_public_multisig(
+                RawOrigin::Signed(CHARLIE).into(),
+                0u32,
+                None,
+                FeeAsset::Native,
+                Box::new(call.clone())
+            ),
+            Error::<Test>::MultisigCallAlreadyExists
+        );
+    });
+}
+
+#[test]
+fn public_proposal_deposit_slashed_on_cancel() {
+    ExtBuilder::default().build().execute_with(|| {
+        INV4::create_dao(
+            RawOrigin::Signed(ALICE).into(),
+            Default::default(),
+            Perbill::from_percent(1),
+            Perbill::from_percent(1),
+            FeeAsset::Native,
+        )
+        .unwrap();
+
+        System::set_block_number(1);
+
+        let call: RuntimeCall = pallet::Call::token_mint {
+            amount: 1,
+            target: DAVE,
+        }
+        .into();
+
+        let call_hash = <<Test as frame_system::Config>::Hashing as Hash>::hash_of(&call);
+
+        assert_ok!(INV4::operate_public_multisig(
+            RawOrigin::Signed(BOB).into(),
+            0u32,
+            None,
+            FeeAsset::Native,
+            Box::new(call.clone())
+        ));
+
+        let bob_balance = Balances::free_balance(BOB);
+        let dao_balance = Balances::free_balance(INV4::derive_dao_account(0u32));
+
+        assert_ok!(INV4::cancel_multisig_proposal(
+            Origin::Multisig(MultisigInternalOrigin::new(0u32)).into(),
+            call_hash
+        ));
+
+        System::assert_has_event(
+            Event::PublicProposalDepositSlashed {
+                dao_id: 0u32,
+                proposer: BOB,
+                call_hash,
+                deposit: PublicProposalDeposit::get(),
+            }
+            .into(),
+        );
+
+        assert_eq!(Balances::free_balance(BOB), bob_balance);
+        assert_eq!(
+            Balances::balance_on_hold(&HoldReason::DaoManager.into(), &BOB),
+            0
+        );
+        assert_eq!(
+            Balances::free_balance(INV4::derive_dao_account(0u32)),
+            dao_balance + PublicProposalDeposit::get()
+        );
+        assert_eq!(INV4::multisig(0u32, call_hash), None);
+        assert_eq!(INV4::public_proposal(0u32, call_hash), None);
+    });
+}
+
+#[test]
+fn expire_public_proposal_works() {
+    ExtBuilder::default().build().execute_with(|| {
+        INV4::create_dao(
+            RawOrigin::Signed(ALICE).into(),
+            Default::default(),
+            Perbill::from_percent(1),
+            Perbill::from_percent(1),
+            FeeAsset::Native,
+        )
+        .unwrap();
+
+        System::set_block_number(1);
+
+        let call: RuntimeCall = pallet::Call::token_mint {
+            amount: 1,
+            target: DAVE,
+        }
+        .into();
+
+        let call_hash = <<Test as frame_system::Config>::Hashing as Hash>::hash_of(&call);
+
+        // Not a public proposal.
+        assert_err!(
+            INV4::expire_public_proposal(RawOrigin::Signed(CHARLIE).into(), 0u32, call_hash),
+            Error::<Test>::PublicProposalNotFound
+        );
+
+        assert_ok!(INV4::operate_public_multisig(
+            RawOrigin::Signed(BOB).into(),
+            0u32,
+            None,
+            FeeAsset::Native,
+            Box::new(call.clone())
+        ));
+
+        // Still within the voting period.
+        System::set_block_number(11);
+
+        assert_err!(
+            INV4::expire_public_proposal(RawOrigin::Signed(CHARLIE).into(), 0u32, call_hash),
+            Error::<Test>::PublicProposalNotExpired
+        );
+
+        System::set_block_number(12);
+
+        assert_err!(
+            INV4::vote_multisig(RawOrigin::Signed(ALICE).into(), 0u32, call_hash, true),
+            Error::<Test>::PublicProposalExpired
+        );
+
+        let dao_balance = Balances::free_balance(INV4::derive_dao_account(0u32));
+
+        assert_ok!(INV4::expire_public_proposal(
+            RawOrigin::Signed(CHARLIE).into(),
+            0u32,
+            call_hash
+        ));
+
+        System::assert_last_event(
+            Event::PublicProposalDepositSlashed {
+                dao_id: 0u32,
+                proposer: BOB,
+                call_hash,
+                deposit: PublicProposalDeposit::get(),
+            }
+            .into(),
+        );
+
+        assert_eq!(
+            Balances::free_balance(INV4::derive_dao_account(0u32)),
+            dao_balance + PublicProposalDeposit::get()
+        );
+        assert_eq!(
+            Balances::balance_on_hold(&HoldReason::DaoManager.into(), &BOB),
+            0
+        );
+        assert_eq!(INV4::multisig(0u32, call_hash), None);
+        assert_eq!(INV4::public_proposal(0u32, call_hash), None);
+        assert_eq!(CoreAssets::accounts(DAVE, 0u32).free, 0);
+    });
+}
+
+#[test]
+fn set_mirror_works() {
+    ExtBuilder::default().build().execute_with(|| {
+        INV4::create_dao(
+            RawOrigin::Signed(ALICE).into(),
+            Default::default(),
+            Perbill::from_percent(1),
+            Perbill::from_percent(1),
+            FeeAsset::Native,
+        )
+        .unwrap();
+
+        assert_ok!(INV4::set_mirror(
+            Origin::Multisig(MultisigInternalOrigin::new(0u32)).into(),
+            Some((2000, 5))
+        ));
+
+        assert_eq!(INV4::mirrored_dao(2000, 5), Some(0u32));
+        assert_eq!(INV4::mirror_source(0u32), Some((2000, 5)));
+
+        // Changing the source clears the old one.
+
+        assert_ok!(INV4::set_mirror(
+            Origin::Multisig(MultisigInternalOrigin::new(0u32)).into(),
+            Some((2000, 6))
+        ));
+
+        assert_eq!(INV4::mirrored_dao(2000, 5), None);
+        assert_eq!(INV4::mirrored_dao(2000, 6), Some(0u32));
+        assert_eq!(INV4::mirror_source(0u32), Some((2000, 6)));
+
+        // Removing the source.
+
+        assert_ok!(INV4::set_mirror(
+            Origin::Multisig(MultisigInternalOrigin::new(0u32)).into(),
+            None
+        ));
+
+        assert_eq!(INV4::mirrored_dao(2000, 6), None);
+        assert_eq!(INV4::mirror_source(0u32), None);
+    });
+}
+
+#[test]
+fn set_mirror_fails() {
+    ExtBuilder::default().build().execute_with(|| {
+        INV4::create_dao(
+            RawOrigin::Signed(ALICE).into(),
+            Default::default(),
+            Perbill::from_percent(1),
+            Perbill::from_percent(1),
+            FeeAsset::Native,
+        )
+        .unwrap();
+
+        INV4::create_dao(
+            RawOrigin::Signed(BOB).into(),
+            Default::default(),
+            Perbill::from_percent(1),
+            Perbill::from_percent(1),
+            FeeAsset::Native,
+        )
+        .unwrap();
+
+        // Wrong origin.
+
+        assert_err!(
+            INV4::set_mirror(RawOrigin::Signed(ALICE).into(), Some((2000, 5))),
+            BadOrigin
+        );
+
+        // DAO doesn't exist.
+
+        assert_err!(
+            INV4::set_mirror(
+                Origin::Multisig(MultisigInternalOrigin::new(2u32)).into(),
+                Some((2000, 5))
+            ),
+            Error::<Test>::DaoNotFound
+        );
+
+        // Source already mirrored by another DAO.
+
+        assert_ok!(INV4::set_mirror(
+            Origin::Multisig(MultisigInternalOrigin::new(0u32)).into(),
+            Some((2000, 5))
+        ));
+
+        assert_err!(
+            INV4::set_mirror(
+                Origin::Multisig(MultisigInternalOrigin::new(1u32)).into(),
+                Some((2000, 5))
+            ),
+            Error::<Test>::MirrorSourceAlreadyInUse
+        );
+
+        assert_eq!(INV4::mirrored_dao(2000, 5), Some(0u32));
+        assert_eq!(INV4::mirror_source(1u32), None);
+    });
+}
+
+#[test]
+fn mirrored_dao_origin_conversion_works() {
+    use crate::{origin::ensure_multisig, xcm_origin::DaoPluralityAsMirroredOrigin};
+    use frame_support::traits::{Everything, Nothing};
+    use xcm::v4::{BodyId, BodyPart, Junction, Location, OriginKind};
+    use xcm_executor::traits::ConvertOrigin;
+
+    type Converter = DaoPluralityAsMirroredOrigin<Everything, Test, RuntimeOrigin>;
+    type UntrustedConverter = DaoPluralityAsMirroredOrigin<Nothing, Test, RuntimeOrigin>;
+
+    let remote_dao = |para_id: u32, dao_id: u32| {
+        Location::new(
+            1,
+            [
+                Junction::Parachain(para_id),
+                Junction::Plurality {
+                    id: BodyId::Index(dao_id),
+                    part: BodyPart::Voice,
+                },
+            ],
+        )
+    };
+
+    ExtBuilder::default().build().execute_with(|| {
+        INV4::create_dao(
+            RawOrigin::Signed(ALICE).into(),
+            Default::default(),
+            Perbill::from_percent(1),
+            Perbill::from_percent(1),
+            FeeAsset::Native,
+        )
+        .unwrap();
+
+        // Not mirrored yet.
+
+        assert!(Converter::convert_origin(remote_dao(2000, 5), OriginKind::Native).is_err());
+
+        INV4::set_mirror(
+            Origin::Multisig(MultisigInternalOrigin::new(0u32)).into(),
+            Some((2000, 5)),
+        )
+        .unwrap();
+
+        let origin = Converter::convert_origin(remote_dao(2000, 5), OriginKind::Native).unwrap();
+
+        assert_eq!(
+            ensure_multisig::<Test, RuntimeOrigin>(origin),
+            Ok(MultisigInternalOrigin::new(0u32))
+        );
+
+        // Sovereign account transacts are left to the other converters.
+
+        assert!(
+            Converter::convert_origin(remote_dao(2000, 5), OriginKind::SovereignAccount).is_err()
+        );
+
+        // Different chain or dao.
+
+        assert!(Converter::convert_origin(remote_dao(2001, 5), OriginKind::Native).is_err());
+        assert!(Converter::convert_origin(remote_dao(2000, 6), OriginKind::Native).is_err());
+
+        // Chain not trusted.
+
+        assert!(
+            UntrustedConverter::convert_origin(remote_dao(2000, 5), OriginKind::Native).is_err()
+        );
+    });
+}
+
+#[test]
+fn bridged_mirrored_dao_origin_conversion_works() {
+    use crate::{
+        origin::ensure_multisig,
+        xcm_origin::{
+            BridgedDaoManagerChains, DaoPluralityAsMirroredOrigin, INVARCH_PARA_ID,
+            TINKERNET_PARA_ID,
+        },
+    };
+    use xcm::v4::{BodyId, BodyPart, Junction, Location, NetworkId, OriginKind};
+    use xcm_executor::traits::ConvertOrigin;
+
+    frame_support::parameter_types! {
+        pub const LocalNetwork: NetworkId = NetworkId::Polkadot;
+    }
+
+    type Converter =
+        DaoPluralityAsMirroredOrigin<BridgedDaoManagerChains<LocalNetwork>, Test, RuntimeOrigin>;
+
+    let remote_dao = |network: NetworkId, para_id: u32, dao_id: u32| {
+        Location::new(
+            2,
+            [
+                Junction::GlobalConsensus(network),
+                Junction::Parachain(para_id),
+                Junction::Plurality {
+                    id: BodyId::Index(dao_id),
+                    part: BodyPart::Voice,
+                },
+            ],
+        )
+    };
+    let sibling_dao = |para_id: u32, dao_id: u32| {
+        Location::new(
+            1,
+            [
+                Junction::Parachain(para_id),
+                Junction::Plurality {
+                    id: BodyId::Index(dao_id),
+                    part: BodyPart::Voice,
+                },
+            ],
+        )
+    };
+
+    ExtBuilder::default().build().execute_with(|| {
+        INV4::create_dao(
+            RawOrigin::Signed(ALICE).into(),
+            Default::default(),
+            Perbill::from_percent(1),
+            Perbill::from_percent(1),
+            FeeAsset::Native,
+        )
+        .unwrap();
+
+        INV4::set_mirror(
+            Origin::Multisig(MultisigInternalOrigin::new(0u32)).into(),
+            Some((TINKERNET_PARA_ID, 5)),
+        )
+        .unwrap();
+
+        // Tinkernet across the bridge.
+
+        let origin = Converter::convert_origin(
+            remote_dao(NetworkId::Kusama, TINKERNET_PARA_ID, 5),
+            OriginKind::Native,
+        )
+        .unwrap();
+
+        assert_eq!(
+            ensure_multisig::<Test, RuntimeOrigin>(origin),
+            Ok(MultisigInternalOrigin::new(0u32))
+        );
+
+        // A parachain with the same id in the local relay, or in another network, isn't Tinkernet.
+
+        assert!(
+            Converter::convert_origin(sibling_dao(TINKERNET_PARA_ID, 5), OriginKind::Native)
+                .is_err()
+        );
+        assert!(Converter::convert_origin(
+            remote_dao(NetworkId::Polkadot, TINKERNET_PARA_ID, 5),
+            OriginKind::Native
+        )
+        .is_err());
+        assert!(Converter::convert_origin(
+            remote_dao(NetworkId::Westend, TINKERNET_PARA_ID, 5),
+            OriginKind::Native
+        )
+        .is_err());
+
+        // The local chain itself isn't trusted either.
+
+        INV4::set_mirror(
+            Origin::Multisig(MultisigInternalOrigin::new(0u32)).into(),
+            Some((INVARCH_PARA_ID, 5)),
+        )
+        .unwrap();
+
+        assert!(
+            Converter::convert_origin(sibling_dao(INVARCH_PARA_ID, 5), OriginKind::Native).is_err()
+        );
+        assert!(Converter::convert_origin(
+            remote_dao(NetworkId::Polkadot, INVARCH_PARA_ID, 5),
+            OriginKind::Native
+        )
+        .is_err());
+    });
+}
+
+#[test]
+fn operate_emergency_multisig_works() {
+    ExtBuilder::default().build().execute_with(|| {
+        INV4::create_dao(
+            RawOrigin::Signed(ALICE).into(),
+            Default::default(),
+            Perbill::from_percent(1),
+            Perbill::from_percent(1),
+            FeeAsset::Native,
+        )
+        .unwrap();
+
+        System::set_block_number(1);
+
+        // Using this call now to add a second member to the multisig.
+        INV4::operate_multisig(
+            RawOrigin::Signed(ALICE).into(),
+            0u32,
+            None,
+            FeeAsset::Native,
+            Box::new(
+                pallet::Call::token_mint {
+                    amount: DaoSeedBalance::get(),
+                    target: BOB,
+                }
+                .into(),
+            ),
+        )
+        .unwrap();
+
+        let call: RuntimeCall = pallet::Call::set_parameters {
+            metadata: None,
+            minimum_support: None,
+            required_approval: None,
+            frozen_tokens: Some(false),
+        }
+        .into();
+
+        let call_hash = <<Test as frame_system::Config>::Hashing as Hash>::hash_of(&call);
+
+        // 50% of the supply is enough for a regular proposal but not for an emergency one.
+        assert_ok!(INV4::operate_emergency_multisig(
+            RawOrigin::Signed(ALICE).into(),
+            0u32,
+            None,
+            FeeAsset::Native,
+            Box::new(call.clone())
+        ));
+
+        System::assert_has_event(
+            Event::EmergencyVoteStarted {
+                dao_id: 0u32,
+                executor_account: INV4::derive_dao_account(0u32),
+                voter: ALICE,
+                votes_added: Vote::Aye(DaoSeedBalance::get()),
+                call_hash,
+            }
+            .into(),
+        );
+
+        assert!(INV4::multisig(0u32, call_hash).unwrap().emergency);
+
+        System::set_block_number(2);
+
+        assert_ok!(INV4::vote_multisig(
+            RawOrigin::Signed(BOB).into(),
+            0u32,
+            call_hash,
+            true
+        ));
+
+        System::assert_has_event(
+            Event::MultisigExecuted {
+                dao_id: 0u32,
+                executor_account: INV4::derive_dao_account(0u32),
+                voter: BOB,
+                call: call.clone(),
+                call_hash,
+                result: Ok(()),
+            }
+            .into(),
+        );
+
+        assert_eq!(INV4::multisig(0u32, call_hash), None);
+    });
+}
+
+#[test]
+fn operate_emergency_multisig_fails() {
+    ExtBuilder::default().build().execute_with(|| {
+        INV4::create_dao(
+            RawOrigin::Signed(ALICE).into(),
+            Default::default(),
+            Perbill::from_percent(1),
+            Perbill::from_percent(1),
+            FeeAsset::Native,
+        )
+        .unwrap();
+
+        System::set_block_number(1);
+
+        // Call not allowed as an emergency proposal.
+        assert_err!(
+            INV4::operate_emergency_multisig(
+                RawOrigin::Signed(ALICE).into(),
+                0u32,
+                None,
+                FeeAsset::Native,
+                Box::new(frame_system::pallet::Call::<Test>::remark { remark: vec![0] }.into())
+            ),
+            Error::<Test>::CallNotEmergencyEligible
+        );
+
+        // Not a member of the multisig
+        assert_err!(
+            INV4::operate_emergency_multisig(
+                RawOrigin::Signed(BOB).into(),
+                0u32,
+                None,
+                FeeAsset::Native,
+                Box::new(
+                    pallet::Call::token_mint {
+                        amount: DaoSeedBalance::get(),
+                        target: BOB,
+                    }
+                    .into()
+                )
+            ),
+            Error::<Test>::NoPermission
+        );
+    });
+}
+
+#[test]
+fn emergency_calls_filter_works() {
+    type Filter = crate::multisig::EmergencyCalls<Test, EmergencyFundsPallets>;
+
+    let allowed = |call: RuntimeCall| Filter::contains(&call.get_call_metadata());
+
+    assert!(allowed(
+        pallet::Call::token_burn {
+            amount: 1,
+            target: BOB,
+        }
+        .into()
+    ));
+    assert!(allowed(
+        pallet::Call::set_parameters {
+            metadata: None,
+            minimum_support: None,
+            required_approval: None,
+            frozen_tokens: Some(false),
+        }
+        .into()
+    ));
+    assert!(allowed(
+        pallet_balances::Call::<Test>::transfer_all {
+            dest: BOB,
+            keep_alive: false,
+        }
+        .into()
+    ));
+
+    assert!(!allowed(
+        pallet::Call::token_mint {
+            amount: 1,
+            target: BOB,
+        }
+        .into()
+    ));
+    assert!(!allowed(
+        orml_tokens::Call::<Test>::transfer_all {
+            dest: BOB,
+            currency_id: 0u32,
+            keep_alive: false,
+        }
+        .into()
+    ));
+}
+
+#[test]
+fn set_voting_strategy_works() {
+    ExtBuilder::default().build().execute_with(|| {
+        INV4::create_dao(
+            RawOrigin::Signed(ALICE).into(),
+            Default::default(),
+            Perbill::from_percent(1),
+            Perbill::from_percent(1),
+            FeeAsset::Native,
+        )
+        .unwrap();
+
+        System::set_block_number(1);
+
+        assert_eq!(INV4::voting_strategy(0u32), VotingStrategy::TokenBalance);
+
+        assert_ok!(INV4::set_voting_strategy(
+            Origin::Multisig(MultisigInternalOrigin::new(0u32)).into(),
+            VotingStrategy::OneMemberOneVote
+        ));
+
+        System::assert_last_event(
+            Event::VotingStrategySet {
+                dao_id: 0u32,
+                strategy: VotingStrategy::OneMemberOneVote,
+            }
+            .into(),
+        );
+
+        assert_eq!(
+            INV4::dao_storage(0u32).unwrap().voting_strategy,
+            VotingStrategy::OneMemberOneVote
+        );
+
+        // Not called by a dao origin.
+        assert_err!(
+            INV4::set_voting_strategy(RawOrigin::Signed(ALICE).into(), VotingStrategy::Quadratic),
+            BadOrigin
+        );
+
+        // Dao doesn't exist.
+        assert_err!(
+            INV4::set_voting_strategy(
+                Origin::Multisig(MultisigInternalOrigin::new(1u32)).into(),
+                VotingStrategy::Quadratic
+            ),
+            Error::<Test>::DaoNotFound
+        );
+    });
+}
+
+#[test]
+fn token_balance_voting_strategy_works() {
+    ExtBuilder::default().build().execute_with(|| {
+        INV4::create_dao(
+            RawOrigin::Signed(ALICE).into(),
+            Default::default(),
+            Perbill::from_percent(50),
+            Perbill::from_percent(50),
+            FeeAsset::Native,
+        )
+        .unwrap();
+
+        System::set_block_number(1);
+
+        INV4::token_mint(
+            Origin::Multisig(MultisigInternalOrigin::new(0u32)).into(),
+            DaoSeedBalance::get() * 3,
+            BOB,
+        )
+        .unwrap();
+
+        assert_eq!(INV4::voting_power(0u32, &ALICE), DaoSeedBalance::get());
+        assert_eq!(INV4::voting_power(0u32, &BOB), DaoSeedBalance::get() * 3);
+        assert_eq!(INV4::total_voting_power(0u32), DaoSeedBalance::get() * 4);
+
+        let call: RuntimeCall = frame_system::Call::<Test>::remark { remark: vec![0] }.into();
+        let call_hash = <<Test as frame_system::Config>::Hashing as Hash>::hash_of(&call);
+
+        // 25% of the supply is not enough.
+        assert_ok!(INV4::operate_multisig(
+            RawOrigin::Signed(ALICE).into(),
+            0u32,
+            None,
+            FeeAsset::Native,
+            Box::new(call.clone())
+        ));
+
+        assert!(INV4::multisig(0u32, call_hash).is_some());
+
+        // 75% of the supply is.
+        assert_ok!(INV4::vote_multisig(
+            RawOrigin::Signed(BOB).into(),
+            0u32,
+            call_hash,
+            true
+        ));
+
+        System::assert_has_event(
+            Event::MultisigExecuted {
+                dao_id: 0u32,
+                executor_account: INV4::derive_dao_account(0u32),
+                voter: BOB,
+                call,
+                call_hash,
+                result: Ok(()),
+            }
+            .into(),
+        );
+
+        assert_eq!(INV4::multisig(0u32, call_hash), None);
+    });
+}
+
+#[test]
+fn one_member_one_vote_voting_strategy_works() {
+    ExtBuilder::default().build().execute_with(|| {
+        INV4::create_dao(
+            RawOrigin::Signed(ALICE).into(),
+            Default::default(),
+            Perbill::from_percent(51),
+            Perbill::from_percent(51),
+            FeeAsset::Native,
+        )
+        .unwrap();
+
+        System::set_block_number(1);
+
+        INV4::token_mint(
+            Origin::Multisig(MultisigInternalOrigin::new(0u32)).into(),
+            DaoSeedBalance::get() * 3,
+            BOB,
+        )
+        .unwrap();
+
+        INV4::set_voting_strategy(
+            Origin::Multisig(MultisigInternalOrigin::new(0u32)).into(),
+            VotingStrategy::OneMemberOneVote,
+        )
+        .unwrap();
+
+        assert_eq!(INV4::dao_member_count(0u32), 2);
+        assert_eq!(INV4::voting_power(0u32, &ALICE), 1);
+        assert_eq!(INV4::voting_power(0u32, &BOB), 1);
+        assert_eq!(INV4::voting_power(0u32, &CHARLIE), 0);
+        assert_eq!(INV4::total_voting_power(0u32), 2);
+
+        let call: RuntimeCall = frame_system::Call::<Test>::remark { remark: vec![0] }.into();
+        let call_hash = <<Test as frame_system::Config>::Hashing as Hash>::hash_of(&call);
+
+        // Despite holding 75% of the supply, BOB only has half of the votes.
+        assert_ok!(INV4::operate_multisig(
+            RawOrigin::Signed(BOB).into(),
+            0u32,
+            None,
+            FeeAsset::Native,
+            Box::new(call.clone())
+        ));
+
+        System::assert_has_event(
+            Event::MultisigVoteStarted {
+                dao_id: 0u32,
+                executor_account: INV4::derive_dao_account(0u32),
+                voter: BOB,
+                votes_added: Vote::Aye(1),
+                call_hash,
+            }
+            .into(),
+        );
+
+        assert_ok!(INV4::vote_multisig(
+            RawOrigin::Signed(ALICE).into(),
+            0u32,
+            call_hash,
+            true
+        ));
+
+        System::assert_has_event(
+            Event::MultisigExecuted {
+                dao_id: 0u32,
+                executor_account: INV4::derive_dao_account(0u32),
+                voter: ALICE,
+                call,
+                call_hash,
+                result: Ok(()),
+            }
+            .into(),
+        );
+
+        // Burning all of a member's tokens removes their vote.
+        INV4::token_burn(
+            Origin::Multisig(MultisigInternalOrigin::new(0u32)).into(),
+            DaoSeedBalance::get() * 3,
+            BOB,
+        )
+        .unwrap();
+
+        assert_eq!(INV4::dao_member_count(0u32), 1);
+        assert_eq!(INV4::total_voting_power(0u32), 1);
+    });
+}
+
+#[test]
+fn quadratic_voting_strategy_works() {
+    ExtBuilder::default().build().execute_with(|| {
+        INV4::create_dao(
+            RawOrigin::Signed(ALICE).into(),
+            Default::default(),
+            Perbill::from_percent(50),
+            Perbill::from_percent(50),
+            FeeAsset::Native,
+        )
+        .unwrap();
+
+        System::set_block_number(1);
+
+        INV4::token_mint(
+            Origin::Multisig(MultisigInternalOrigin::new(0u32)).into(),
+            DaoSeedBalance::get() * 4,
+            BOB,
+        )
+        .unwrap();
+
+        INV4::token_mint(
+            Origin::Multisig(MultisigInternalOrigin::new(0u32)).into(),
+            DaoSeedBalance::get() * 4,
+            CHARLIE,
+        )
+        .unwrap();
+
+        INV4::set_voting_strategy(
+            Origin::Multisig(MultisigInternalOrigin::new(0u32)).into(),
+            VotingStrategy::Quadratic,
+        )
+        .unwrap();
+
+        // The seed balance is 1_000_000, so the square roots are 1000 and 2000.
+        assert_eq!(INV4::voting_power(0u32, &ALICE), 1000);
+        assert_eq!(INV4::voting_power(0u32, &BOB), 2000);
+        assert_eq!(INV4::voting_power(0u32, &CHARLIE), 2000);
+        assert_eq!(INV4::total_voting_power(0u32), 5000);
+
+        let call: RuntimeCall = frame_system::Call::<Test>::remark { remark: vec![0] }.into();
+        let call_hash = <<Test as frame_system::Config>::Hashing as Hash>::hash_of(&call);
+
+        // BOB holds 44% of the supply but only 40% of the voting power.
+        assert_ok!(INV4::operate_multisig(
+            RawOrigin::Signed(BOB).into(),
+            0u32,
+            None,
+            FeeAsset::Native,
+            Box::new(call.clone())
+        ));
+
+        System::assert_has_event(
+            Event::MultisigVoteStarted {
+                dao_id: 0u32,
+                executor_account: INV4::derive_dao_account(0u32),
+                voter: BOB,
+                votes_added: Vote::Aye(2000),
+                call_hash,
+            }
+            .into(),
+        );
+
+        assert_eq!(
+            INV4::multisig(0u32, call_hash).unwrap().tally.support(0u32),
+            Perbill::from_percent(40)
+        );
+
+        // ALICE's vote puts the support at 60%.
+        assert_ok!(INV4::vote_multisig(
+            RawOrigin::Signed(ALICE).into(),
+            0u32,
+            call_hash,
+            true
+        ));
+
+        System::assert_has_event(
+            Event::MultisigExecuted {
+                dao_id: 0u32,
+                executor_account: INV4::derive_dao_account(0u32),
+                voter: ALICE,
+                call,
+                call_hash,
+                result: Ok(()),
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn quadratic_voting_power_follows_transfers_and_burns() {
+    ExtBuilder::default().build().execute_with(|| {
+        INV4::create_dao(
+            RawOrigin::Signed(ALICE).into(),
+            Default::default(),
+            Perbill::from_percent(50),
+            Perbill::from_percent(50),
+            FeeAsset::Native,
+        )
+        .unwrap();
+
+        System::set_block_number(1);
+
+        INV4::set_voting_strategy(
+            Origin::Multisig(MultisigInternalOrigin::new(0u32)).into(),
+            VotingStrategy::Quadratic,
+        )
+        .unwrap();
+
+        assert_eq!(INV4::dao_quadratic_voting_power(0u32), 1000);
+
+        INV4::token_mint(
+            Origin::Multisig(MultisigInternalOrigin::new(0u32)).into(),
+            DaoSeedBalance::get() * 8,
+            BOB,
+        )
+        .unwrap();
+
+        // sqrt(1_000_000) + sqrt(8_000_000)
+        assert_eq!(INV4::dao_quadratic_voting_power(0u32), 1000 + 2828);
+
+        // BOB sends half of his tokens to CHARLIE, 4_000_000 each.
+        assert_ok!(CoreAssets::transfer(
+            RawOrigin::Signed(BOB).into(),
+            CHARLIE,
+            0u32,
+            DaoSeedBalance::get() * 4
+        ));
+
+        assert_eq!(INV4::dao_quadratic_voting_power(0u32), 5000);
+        assert_eq!(INV4::total_voting_power(0u32), 5000);
+
+        assert_ok!(INV4::token_burn(
+            Origin::Multisig(MultisigInternalOrigin::new(0u32)).into(),
+            DaoSeedBalance::get() * 4,
+            CHARLIE
+        ));
+
+        assert_eq!(INV4::dao_quadratic_voting_power(0u32), 3000);
+        assert_eq!(INV4::total_voting_power(0u32), 3000);
+    });
+}
+
+#[test]
+fn nft_items_voting_strategy_works() {
+    ExtBuilder::default().build().execute_with(|| {
+        INV4::create_dao(
+            RawOrigin::Signed(ALICE).into(),
+            Default::default(),
+            Perbill::from_percent(50),
+            Perbill::from_percent(50),
+            FeeAsset::Native,
+        )
+        .unwrap();
+
+        System::set_block_number(1);
+
+        let dao_account = INV4::derive_dao_account(0u32);
+
+        assert_ok!(Uniques::create(
+            RawOrigin::Signed(dao_account.clone()).into(),
+            0u32,
+            ALICE
+        ));
+        assert_ok!(Uniques::mint(
+            RawOrigin::Signed(ALICE).into(),
+            0u32,
+            0u32,
+            ALICE
+        ));
+        assert_ok!(Uniques::mint(
+            RawOrigin::Signed(ALICE).into(),
+            0u32,
+            1u32,
+            BOB
+        ));
+        assert_ok!(Uniques::mint(
+            RawOrigin::Signed(ALICE).into(),
+            0u32,
+            2u32,
+            BOB
+        ));
+
+        assert_ok!(INV4::set_voting_strategy(
+            Origin::Multisig(MultisigInternalOrigin::new(0u32)).into(),
+            VotingStrategy::NftItems(0u32)
+        ));
+
+        assert_eq!(INV4::nft_collection_dao(0u32), Some(0u32));
+
+        assert_eq!(INV4::voting_power(0u32, &ALICE), 1);
+        assert_eq!(INV4::voting_power(0u32, &BOB), 2);
+        assert_eq!(INV4::voting_power(0u32, &CHARLIE), 0);
+        assert_eq!(INV4::total_voting_power(0u32), 3);
+
+        let call: RuntimeCall = frame_system::Call::<Test>::remark { remark: vec![0] }.into();
+        let call_hash = <<Test as frame_system::Config>::Hashing as Hash>::hash_of(&call);
+
+        // Not holding any item.
+        assert_err!(
+            INV4::operate_multisig(
+                RawOrigin::Signed(CHARLIE).into(),
+                0u32,
+                None,
+                FeeAsset::Native,
+                Box::new(call.clone())
+            ),
+            Error::<Test>::NoPermission
+        );
+
+        // ALICE holds all the voting tokens but only a third of the items.
+        assert_ok!(INV4::operate_multisig(
+            RawOrigin::Signed(ALICE).into(),
+            0u32,
+            None,
+            FeeAsset::Native,
+            Box::new(call.clone())
+        ));
+
+        System::assert_has_event(
+            Event::MultisigVoteStarted {
+                dao_id: 0u32,
+                executor_account: dao_account.clone(),
+                voter: ALICE,
+                votes_added: Vote::Aye(1),
+                call_hash,
+            }
+            .into(),
+        );
+
+        assert_ok!(INV4::vote_multisig(
+            RawOrigin::Signed(BOB).into(),
+            0u32,
+            call_hash,
+            true
+        ));
+
+        System::assert_has_event(
+            Event::MultisigExecuted {
+                dao_id: 0u32,
+                executor_account: dao_account,
+                voter: BOB,
+                call,
+                call_hash,
+                result: Ok(()),
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn nft_attribute_voting_strategy_works() {
+    ExtBuilder::default().build().execute_with(|| {
+        INV4::create_dao(
+            RawOrigin::Signed(ALICE).into(),
+            Default::default(),
+            Perbill::from_percent(50),
+            Perbill::from_percent(50),
+            FeeAsset::Native,
+        )
+        .unwrap();
+
+        System::set_block_number(1);
+
+        let dao_account = INV4::derive_dao_account(0u32);
+
+        assert_ok!(Uniques::create(
+            RawOrigin::Signed(dao_account.clone()).into(),
+            0u32,
+            ALICE
+        ));
+        assert_ok!(Uniques::mint(
+            RawOrigin::Signed(ALICE).into(),
+            0u32,
+            0u32,
+            ALICE
+        ));
+        assert_ok!(Uniques::mint(
+            RawOrigin::Signed(ALICE).into(),
+            0u32,
+            1u32,
+            BOB
+        ));
+        assert_ok!(Uniques::mint(
+            RawOrigin::Signed(ALICE).into(),
+            0u32,
+            2u32,
+            CHARLIE
+        ));
+
+        // CHARLIE's item has no weight.
+        for (item, weight) in [(0u32, 30u32), (1u32, 70u32)] {
+            assert_ok!(Uniques::set_attribute(
+                RawOrigin::Signed(dao_account.clone()).into(),
+                0u32,
+                Some(item),
+                NFT_VOTING_WEIGHT_KEY.to_vec().try_into().unwrap(),
+                weight.encode().try_into().unwrap()
+            ));
+        }
+
+        assert_ok!(INV4::set_voting_strategy(
+            Origin::Multisig(MultisigInternalOrigin::new(0u32)).into(),
+            VotingStrategy::NftAttribute(0u32)
+        ));
+
+        assert_eq!(INV4::voting_power(0u32, &ALICE), 30);
+        assert_eq!(INV4::voting_power(0u32, &BOB), 70);
+        assert_eq!(INV4::voting_power(0u32, &CHARLIE), 0);
+        assert_eq!(INV4::total_voting_power(0u32), 100);
+
+        let call: RuntimeCall = frame_system::Call::<Test>::remark { remark: vec![0] }.into();
+        let call_hash = <<Test as frame_system::Config>::Hashing as Hash>::hash_of(&call);
+
+        assert_err!(
+            INV4::operate_multisig(
+                RawOrigin::Signed(CHARLIE).into(),
+                0u32,
+                None,
+                FeeAsset::Native,
+                Box::new(call.clone())
+            ),
+            Error::<Test>::NoPermission
+        );
+
+        // 70% of the voting weight is enough to execute right away.
+        assert_ok!(INV4::operate_multisig(
+            RawOrigin::Signed(BOB).into(),
+            0u32,
+            None,
+            FeeAsset::Native,
+            Box::new(call.clone())
+        ));
+
+        System::assert_has_event(
+            Event::MultisigExecuted {
+                dao_id: 0u32,
+                executor_account: dao_account,
+                voter: BOB,
+                call,
+                call_hash,
+                result: Ok(()),
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn nft_voting_power_is_noted_on_proposal_creation() {
+    ExtBuilder::default().build().execute_with(|| {
+        INV4::create_dao(
+            RawOrigin::Signed(ALICE).into(),
+            Default::default(),
+            Perbill::from_percent(50),
+            Perbill::from_percent(50),
+            FeeAsset::Native,
+        )
+        .unwrap();
+
+        System::set_block_number(1);
+
+        let dao_account = INV4::derive_dao_account(0u32);
+
+        assert_ok!(Uniques::create(
+            RawOrigin::Signed(dao_account.clone()).into(),
+            0u32,
+            ALICE
+        ));
+
+        for item in 0u32..2 {
+            assert_ok!(Uniques::mint(
+                RawOrigin::Signed(ALICE).into(),
+                0u32,
+                item,
+                BOB
+            ));
+        }
+
+        assert_ok!(INV4::set_voting_strategy(
+            Origin::Multisig(MultisigInternalOrigin::new(0u32)).into(),
+            VotingStrategy::NftItems(0u32)
+        ));
+
+        assert_eq!(INV4::nft_voting_power(0u32), 2);
+
+        // Minting doesn't update the stored total until a proposal is created.
+        assert_ok!(Uniques::mint(
+            RawOrigin::Signed(ALICE).into(),
+            0u32,
+            2u32,
+            CHARLIE
+        ));
+
+        assert_eq!(INV4::total_voting_power(0u32), 2);
+
+        let call: RuntimeCall = frame_system::Call::<Test>::remark { remark: vec![0] }.into();
+        let call_hash = <<Test as frame_system::Config>::Hashing as Hash>::hash_of(&call);
+
+        assert_ok!(INV4::operate_multisig(
+            RawOrigin::Signed(CHARLIE).into(),
+            0u32,
+            None,
+            FeeAsset::Native,
+            Box::new(call.clone())
+        ));
+
+        assert_eq!(INV4::total_voting_power(0u32), 3);
+
+        // CHARLIE's item is a third of the collection, not half of it.
+        assert_eq!(
+            INV4::multisig(0u32, call_hash).unwrap().tally.support(0u32),
+            Perbill::from_rational(1u32, 3u32)
+        );
+
+        // The collection can't grow over MaxNftVotingItems.
+        for item in 3u32..=MaxNftVotingItems::get() {
+            assert_ok!(Uniques::mint(
+                RawOrigin::Signed(ALICE).into(),
+                0u32,
+                item,
+                CHARLIE
+            ));
+        }
+
+        // Proposals can still be created, the collection keeps its last total.
+        assert_ok!(INV4::operate_public_multisig(
+            RawOrigin::Signed(BOB).into(),
+            0u32,
+            None,
+            FeeAsset::Native,
+            Box::new(frame_system::Call::<Test>::remark { remark: vec![1] }.into())
+        ));
+
+        assert_err!(
+            INV4::set_voting_strategy(
+                Origin::Multisig(MultisigInternalOrigin::new(0u32)).into(),
+                VotingStrategy::NftAttribute(0u32)
+            ),
+            Error::<Test>::TooManyNftItems
+        );
+
+        assert_eq!(INV4::total_voting_power(0u32), 3);
+
+        // Switching away from the collection removes its total.
+        assert_ok!(INV4::set_voting_strategy(
+            Origin::Multisig(MultisigInternalOrigin::new(0u32)).into(),
+            VotingStrategy::TokenBalance
+        ));
+
+        assert_eq!(INV4::nft_voting_power(0u32), 0);
+        assert_eq!(INV4::nft_collection_dao(0u32), None);
+    });
+}
+
+#[test]
+fn nft_voting_strategy_fails() {
+    ExtBuilder::default().build().execute_with(|| {
+        INV4::create_dao(
+            RawOrigin::Signed(ALICE).into(),
+            Default::default(),
+            Perbill::from_percent(50),
+            Perbill::from_percent(50),
+            FeeAsset::Native,
+        )
+        .unwrap();
+
+        // Collection doesn't exist.
+        assert_err!(
+            INV4::set_voting_strategy(
+                Origin::Multisig(MultisigInternalOrigin::new(0u32)).into(),
+                VotingStrategy::NftItems(0u32)
+            ),
+            Error::<Test>::NftCollectionNotFound
+        );
+
+        // Collection not owned by the dao.
+        assert_ok!(Uniques::create(
+            RawOrigin::Signed(ALICE).into(),
+            0u32,
+            ALICE
+        ));
+
+        assert_err!(
+            INV4::set_voting_strategy(
+                Origin::Multisig(MultisigInternalOrigin::new(0u32)).into(),
+                VotingStrategy::NftAttribute(0u32)
+            ),
+            Error::<Test>::NotNftCollectionOwner
+        );
+    });
+}
+
+#[test]
+fn nft_items_frozen_with_dao_tokens() {
+    ExtBuilder::default().build().execute_with(|| {
+        INV4::create_dao(
+            RawOrigin::Signed(ALICE).into(),
+            Default::default(),
+            Perbill::from_percent(50),
+            Perbill::from_percent(50),
+            FeeAsset::Native,
+        )
+        .unwrap();
+
+        assert_ok!(Uniques::create(
+            RawOrigin::Signed(INV4::derive_dao_account(0u32)).into(),
+            0u32,
+            ALICE
+        ));
+        assert_ok!(Uniques::mint(
+            RawOrigin::Signed(ALICE).into(),
+            0u32,
+            0u32,
+            BOB
+        ));
+
+        assert_ok!(INV4::set_voting_strategy(
+            Origin::Multisig(MultisigInternalOrigin::new(0u32)).into(),
+            VotingStrategy::NftItems(0u32)
+        ));
+
+        // Dao tokens are frozen by default.
+        assert_err!(
+            Uniques::transfer(RawOrigin::Signed(BOB).into(), 0u32, 0u32, CHARLIE),
+            pallet_uniques::Error::<Test>::Locked
+        );
+
+        assert_ok!(INV4::set_parameters(
+            Origin::Multisig(MultisigInternalOrigin::new(0u32)).into(),
+            None,
+            None,
+            None,
+            Some(false)
+        ));
+
+        assert_ok!(Uniques::transfer(
+            RawOrigin::Signed(BOB).into(),
+            0u32,
+            0u32,
+            CHARLIE
+        ));
+
+        assert_eq!(INV4::voting_power(0u32, &BOB), 0);
+        assert_eq!(INV4::voting_power(0u32, &CHARLIE), 1);
+
+        // Switching away from the collection unbinds it.
+        assert_ok!(INV4::set_parameters(
+            Origin::Multisig(MultisigInternalOrigin::new(0u32)).into(),
+            None,
+            None,
+            None,
+            Some(true)
+        ));
+
+        assert_ok!(INV4::set_voting_strategy(
+            Origin::Multisig(MultisigInternalOrigin::new(0u32)).into(),
+            VotingStrategy::TokenBalance
+        ));
+
+        assert_eq!(INV4::nft_collection_dao(0u32), None);
+
+        assert_ok!(Uniques::transfer(
+            RawOrigin::Signed(CHARLIE).into(),
+            0u32,
+            0u32,
+            BOB
+        ));
+    });
+}
+
+#[test]
+fn nft_items_vote_once_per_proposal() {
+    ExtBuilder::default().build().execute_with(|| {
+        INV4::create_dao(
+            RawOrigin::Signed(ALICE).into(),
+            Default::default(),
+            Perbill::from_percent(75),
+            Perbill::from_percent(50),
+            FeeAsset::Native,
+        )
+        .unwrap();
+
+        System::set_block_number(1);
+
+        let dao_account = INV4::derive_dao_account(0u32);
+
+        assert_ok!(Uniques::create(
+            RawOrigin::Signed(dao_account.clone()).into(),
+            0u32,
+            ALICE
+        ));
+
+        for (item, owner) in [(0u32, ALICE), (1u32, BOB), (2u32, BOB)] {
+            assert_ok!(Uniques::mint(
+                RawOrigin::Signed(ALICE).into(),
+                0u32,
+                item,
+                owner
+            ));
+        }
+
+        assert_ok!(INV4::set_voting_strategy(
+            Origin::Multisig(MultisigInternalOrigin::new(0u32)).into(),
+            VotingStrategy::NftItems(0u32)
+        ));
+
+        assert_ok!(INV4::set_parameters(
+            Origin::Multisig(MultisigInternalOrigin::new(0u32)).into(),
+            None,
+            None,
+            None,
+            Some(false)
+        ));
+
+        let call: RuntimeCall = frame_system::Call::<Test>::remark { remark: vec![0] }.into();
+        let call_hash = <<Test as frame_system::Config>::Hashing as Hash>::hash_of(&call);
+
+        assert_ok!(INV4::operate_multisig(
+            RawOrigin::Signed(ALICE).into(),
+            0u32,
+            None,
+            FeeAsset::Native,
+            Box::new(call.clone())
+        ));
+
+        assert_eq!(
+            INV4::nft_proposal_vote((0u32, call_hash, 0u32)),
+            Some(ALICE)
+        );
+
+        // The item already voted on the proposal, so it carries no votes for its new owner.
+        assert_ok!(Uniques::transfer(
+            RawOrigin::Signed(ALICE).into(),
+            0u32,
+            0u32,
+            CHARLIE
+        ));
+
+        assert_err!(
+            INV4::vote_multisig(RawOrigin::Signed(CHARLIE).into(), 0u32, call_hash, true),
+            Error::<Test>::NoPermission
+        );
+
+        // Once ALICE withdraws their vote the item can be voted with again.
+        assert_ok!(INV4::withdraw_vote_multisig(
+            RawOrigin::Signed(ALICE).into(),
+            0u32,
+            call_hash
+        ));
+
+        assert_eq!(INV4::nft_proposal_vote((0u32, call_hash, 0u32)), None);
+
+        assert_ok!(INV4::vote_multisig(
+            RawOrigin::Signed(CHARLIE).into(),
+            0u32,
+            call_hash,
+            true
+        ));
+
+        assert_eq!(
+            INV4::nft_proposal_vote((0u32, call_hash, 0u32)),
+            Some(CHARLIE)
+        );
+        assert_eq!(INV4::multisig(0u32, call_hash).unwrap().tally.ayes, 1);
+
+        assert_err!(
+            INV4::vote_multisig(RawOrigin::Signed(ALICE).into(), 0u32, call_hash, true),
+            Error::<Test>::NoPermission
+        );
+
+        assert_ok!(INV4::vote_multisig(
+            RawOrigin::Signed(BOB).into(),
+            0u32,
+            call_hash,
+            true
+        ));
+
+        System::assert_has_event(
+            Event::MultisigExecuted {
+                dao_id: 0u32,
+                executor_account: dao_account,
+                voter: BOB,
+                call,
+                call_hash,
+                result: Ok(()),
+            }
+            .into(),
+        );
+
+        // The records of the items go away with the proposal.
+        assert_eq!(
+            NftProposalVotes::<Test>::iter_prefix((0u32, call_hash)).count(),
+            0
+        );
+    });
+}
+
+#[test]
+fn joint_multisig_works() {
+    ExtBuilder::default().build().execute_with(|| {
+        INV4::create_dao(
+            RawOrigin::Signed(ALICE).into(),
+            Default::default(),
+            Perbill::from_percent(51),
+            Perbill::from_percent(51),
+            FeeAsset::Native,
+        )
+        .unwrap();
+
+        INV4::create_dao(
+            RawOrigin::Signed(BOB).into(),
+            Default::default(),
+            Perbill::from_percent(51),
+            Perbill::from_percent(51),
+            FeeAsset::Native,
+        )
+        .unwrap();
+
+        System::set_block_number(1);
+
+        Balances::transfer_keep_alive(
+            RawOrigin::Signed(BOB).into(),
+            INV4::derive_dao_account(1u32),
+            INITIAL_BALANCE / 10,
+        )
+        .unwrap();
+
+        // CHARLIE is a member of both daos.
+        INV4::token_mint(
+            Origin::Multisig(MultisigInternalOrigin::new(0u32)).into(),
+            DaoSeedBalance::get(),
+            CHARLIE,
+        )
+        .unwrap();
+
+        INV4::token_mint(
+            Origin::Multisig(MultisigInternalOrigin::new(1u32)).into(),
+            DaoSeedBalance::get(),
+            CHARLIE,
+        )
+        .unwrap();
+
+        let calls: BoundedVec<(u32, RuntimeCall), MaxJointDaos> = vec![
+            (
+                0u32,
+                pallet::Call::token_mint {
+                    amount: DaoSeedBalance::get(),
+                    target: DAVE,
+                }
+                .into(),
+            ),
+            (
+                1u32,
+                pallet::Call::token_mint {
+                    amount: DaoSeedBalance::get(),
+                    target: DAVE,
+                }
+                .into(),
+            ),
+        ]
+        .try_into()
+        .unwrap();
+
+        let proposal_hash = <<Test as frame_system::Config>::Hashing as Hash>::hash_of(&calls);
+
+        assert_ok!(INV4::operate_joint_multisig(
+            RawOrigin::Signed(ALICE).into(),
+            calls,
+            None,
+            FeeAsset::Native
+        ));
+
+        System::assert_has_event(
+            Event::JointMultisigVoteStarted {
+                daos: vec![0u32, 1u32],
+                voter: ALICE,
+                proposal_hash,
+            }
+            .into(),
+        );
+
+        // ALICE only has voting power in the first dao.
+        let proposal = INV4::joint_multisig(proposal_hash).unwrap();
+        assert_eq!(proposal.parts[0].tally.ayes, DaoSeedBalance::get());
+        assert_eq!(proposal.parts[1].tally.ayes, 0u128);
+
+        // The first dao approves the proposal, but the second one still has to.
+        assert_ok!(INV4::vote_joint_multisig(
+            RawOrigin::Signed(CHARLIE).into(),
+            0u32,
+            proposal_hash,
+            true
+        ));
+
+        System::assert_has_event(
+            Event::JointMultisigVoteAdded {
+                dao_id: 0u32,
+                voter: CHARLIE,
+                votes_added: Vote::Aye(DaoSeedBalance::get()),
+                current_votes: Tally::from_parts(
+                    DaoSeedBalance::get() * 2,
+                    Zero::zero(),
+                    BoundedBTreeMap::try_from(BTreeMap::from([
+                        (ALICE, Vote::Aye(DaoSeedBalance::get())),
+                        (CHARLIE, Vote::Aye(DaoSeedBalance::get())),
+                    ]))
+                    .unwrap(),
+                ),
+                proposal_hash,
+            }
+            .into(),
+        );
+
+        assert_ok!(INV4::vote_joint_multisig(
+            RawOrigin::Signed(BOB).into(),
+            1u32,
+            proposal_hash,
+            true
+        ));
+
+        assert!(INV4::joint_multisig(proposal_hash).is_some());
+        assert_eq!(CoreAssets::accounts(DAVE, 0u32).free, 0u128);
+
+        System::set_block_number(2);
+
+        assert_ok!(INV4::vote_joint_multisig(
+            RawOrigin::Signed(CHARLIE).into(),
+            1u32,
+            proposal_hash,
+            true
+        ));
+
+        System::assert_has_event(
+            Event::JointMultisigExecuted {
+                daos: vec![0u32, 1u32],
+                voter: CHARLIE,
+                proposal_hash,
+                result: Ok(()),
+            }
+            .into(),
+        );
+
+        assert_eq!(INV4::joint_multisig(proposal_hash), None);
+        assert_eq!(CoreAssets::accounts(DAVE, 0u32).free, DaoSeedBalance::get());
+        assert_eq!(CoreAssets::accounts(DAVE, 1u32).free, DaoSeedBalance::get());
+    });
+}
+
+#[test]
+fn joint_multisig_reverts_every_call_on_failure() {
+    ExtBuilder::default().build().execute_with(|| {
+        INV4::create_dao(
+            RawOrigin::Signed(ALICE).into(),
+            Default::default(),
+            Perbill::from_percent(1),
+            Perbill::from_percent(1),
+            FeeAsset::Native,
+        )
+        .unwrap();
+
+        INV4::create_dao(
+            RawOrigin::Signed(ALICE).into(),
+            Default::default(),
+            Perbill::from_percent(1),
+            Perbill::from_percent(1),
+            FeeAsset::Native,
+        )
+        .unwrap();
+
+        System::set_block_number(1);
+
+        Balances::transfer_keep_alive(
+            RawOrigin::Signed(ALICE).into(),
+            INV4::derive_dao_account(1u32),
+            INITIAL_BALANCE / 10,
+        )
+        .unwrap();
+
+        // The second call burns more tokens than DAVE holds.
+        let calls: BoundedVec<(u32, RuntimeCall), MaxJointDaos> = vec![
+            (
+                0u32,
+                pallet::Call::token_mint {
+                    amount: DaoSeedBalance::get(),
+                    target: DAVE,
+                }
+                .into(),
+            ),
+            (
+                1u32,
+                pallet::Call::token_burn {
+                    amount: DaoSeedBalance::get(),
+                    target: DAVE,
+                }
+                .into(),
+            ),
+        ]
+        .try_into()
+        .unwrap();
+
+        let proposal_hash = <<Test as frame_system::Config>::Hashing as Hash>::hash_of(&calls);
+
+        // ALICE alone meets the thresholds of both daos, so the proposal executes right away.
+        assert_ok!(INV4::operate_joint_multisig(
+            RawOrigin::Signed(ALICE).into(),
+            calls,
+            None,
+            FeeAsset::Native
+        ));
+
+        assert!(System::events().iter().any(|record| matches!(
+            record.event,
+            RuntimeEvent::INV4(Event::JointMultisigExecuted { result: Err(_), .. })
+        )));
+
+        assert_eq!(INV4::joint_multisig(proposal_hash), None);
+        assert_eq!(CoreAssets::accounts(DAVE, 0u32).free, 0u128);
+    });
+}
+
+#[test]
+fn joint_multisig_fails() {
+    ExtBuilder::default().build().execute_with(|| {
+        INV4::create_dao(
+            RawOrigin::Signed(ALICE).into(),
+            Default::default(),
+            Perbill::from_percent(51),
+            Perbill::from_percent(51),
+            FeeAsset::Native,
+        )
+        .unwrap();
+
+        INV4::create_dao(
+            RawOrigin::Signed(BOB).into(),
+            Default::default(),
+            Perbill::from_percent(51),
+            Perbill::from_percent(51),
+            FeeAsset::Native,
+        )
+        .unwrap();
+
+        INV4::create_dao(
+            RawOrigin::Signed(CHARLIE).into(),
+            Default::default(),
+            Perbill::from_percent(51),
+            Perbill::from_percent(51),
+            FeeAsset::Native,
+        )
+        .unwrap();
+
+        System::set_block_number(1);
+
+        let call: RuntimeCall = frame_system::Call::<Test>::remark { remark: vec![0] }.into();
+
+        // A single dao.
+        assert_err!(
+            INV4::operate_joint_multisig(
+                RawOrigin::Signed(ALICE).into(),
+                vec![(0u32, call.clone())].try_into().unwrap(),
+                None,
+                FeeAsset::Native
+            ),
+            Error::<Test>::TooFewJointDaos
+        );
+
+        // The same dao twice.
+        assert_err!(
+            INV4::operate_joint_multisig(
+                RawOrigin::Signed(ALICE).into(),
+                vec![(0u32, call.clone()), (0u32, call.clone())]
+                    .try_into()
+                    .unwrap(),
+                None,
+                FeeAsset::Native
+            ),
+            Error::<Test>::DuplicateJointDao
+        );
+
+        // Dao doesn't exist.
+        assert_err!(
+            INV4::operate_joint_multisig(
+                RawOrigin::Signed(ALICE).into(),
+                vec![(0u32, call.clone()), (5u32, call.clone())]
+                    .try_into()
+                    .unwrap(),
+                None,
+                FeeAsset::Native
+            ),
+            Error::<Test>::DaoNotFound
+        );
+
+        // Not a member of any of the daos.
+        assert_err!(
+            INV4::operate_joint_multisig(
+                RawOrigin::Signed(DAVE).into(),
+                vec![(0u32, call.clone()), (1u32, call.clone())]
+                    .try_into()
+                    .unwrap(),
+                None,
+                FeeAsset::Native
+            ),
+            Error::<Test>::NoPermission
+        );
+
+        let calls: BoundedVec<(u32, RuntimeCall), MaxJointDaos> =
+            vec![(0u32, call.clone()), (1u32, call.clone())]
+                .try_into()
+                .unwrap();
+        let proposal_hash = <<Test as frame_system::Config>::Hashing as Hash>::hash_of(&calls);
+
+        assert_ok!(INV4::operate_joint_multisig(
+            RawOrigin::Signed(ALICE).into(),
+            calls.clone(),
+            None,
+            FeeAsset::Native
+        ));
+
+        // Proposal already exists.
+        assert_err!(
+            INV4::operate_joint_multisig(
+                RawOrigin::Signed(ALICE).into(),
+                calls,
+                None,
+                FeeAsset::Native
+            ),
+            Error::<Test>::JointMultisigAlreadyExists
+        );
+
+        // Proposal doesn't exist.
+        assert_err!(
+            INV4::vote_joint_multisig(
+                RawOrigin::Signed(BOB).into(),
+                1u32,
+                <<Test as frame_system::Config>::Hashing as Hash>::hash_of(&call),
+                true
+            ),
+            Error::<Test>::JointMultisigNotFound
+        );
+
+        // Not a member of the dao being voted as.
+        assert_err!(
+            INV4::vote_joint_multisig(RawOrigin::Signed(BOB).into(), 0u32, proposal_hash, true),
+            Error::<Test>::NoPermission
+        );
+
+        // Dao not taking part in the proposal.
+        assert_err!(
+            INV4::vote_joint_multisig(RawOrigin::Signed(CHARLIE).into(), 2u32, proposal_hash, true),
+            Error::<Test>::NotJointMultisigDao
+        );
+
+        // Not a voter.
+        assert_err!(
+            INV4::withdraw_vote_joint_multisig(RawOrigin::Signed(BOB).into(), 1u32, proposal_hash),
+            Error::<Test>::NotAVoter
+        );
+
+        // Not called by a dao origin.
+        assert_err!(
+            INV4::cancel_joint_multisig_proposal(RawOrigin::Signed(ALICE).into(), proposal_hash),
+            BadOrigin
+        );
+
+        // Dao not taking part in the proposal.
+        assert_err!(
+            INV4::cancel_joint_multisig_proposal(
+                Origin::Multisig(MultisigInternalOrigin::new(2u32)).into(),
+                proposal_hash
+            ),
+            Error::<Test>::NotJointMultisigDao
+        );
+
+        assert_ok!(INV4::cancel_joint_multisig_proposal(
+            Origin::Multisig(MultisigInternalOrigin::new(1u32)).into(),
+            proposal_hash
+        ));
+
+        System::assert_has_event(
+            Event::JointMultisigCanceled {
+                dao_id: 1u32,
+                proposal_hash,
+            }
+            .into(),
+        );
+
+        assert_eq!(INV4::joint_multisig(proposal_hash), None);
+    });
+}
+
+#[test]
+fn dry_run_proposal_works() {
+    ExtBuilder::default().build().execute_with(|| {
+        INV4::create_dao(
+            RawOrigin::Signed(ALICE).into(),
+            Default::default(),
+            Perbill::from_percent(1),
+            Perbill::from_percent(1),
+            FeeAsset::Native,
+        )
+        .unwrap();
+
+        System::set_block_number(1);
+
+        let events_before = System::events();
+
+        let call: RuntimeCall = pallet::Call::token_mint {
+            amount: DaoSeedBalance::get(),
+            target: BOB,
+        }
+        .into();
+
+        let effects = INV4::dry_run_proposal(0u32, call.clone(), FeeAsset::Native, None, Vec::new);
+
+        assert_ok!(effects.execution_result);
+        assert_eq!(effects.actual_weight, call.get_dispatch_info().weight);
+        assert_eq!(effects.fee, 0u128);
+        assert!(effects.forwarded_xcms.is_empty());
+        assert!(effects.emitted_events.contains(
+            &Event::Minted {
+                dao_id: 0u32,
+                target: BOB,
+                amount: DaoSeedBalance::get(),
+            }
+            .into()
+        ));
+
+        // Nothing was persisted.
+        assert_eq!(CoreAssets::accounts(BOB, 0u32).free, 0u128);
+        assert_eq!(System::events(), events_before);
+
+        // Failing calls return the dispatch error.
+        let effects = INV4::dry_run_proposal(
+            0u32,
+            pallet::Call::token_burn {
+                amount: DaoSeedBalance::get(),
+                target: BOB,
+            }
+            .into(),
+            FeeAsset::Native,
+            None,
+            Vec::new,
+        );
+
+        assert!(effects.execution_result.is_err());
+        assert!(effects.emitted_events.is_empty());
+
+        assert_eq!(LastFeePayer::get(), Some(INV4::derive_dao_account(0u32)));
+
+        // Sponsored proposals charge the proposer.
+        let effects = INV4::dry_run_proposal(0u32, call, FeeAsset::Native, Some(ALICE), Vec::new);
+
+        assert_ok!(effects.execution_result);
+        assert_eq!(LastFeePayer::get(), Some(ALICE));
+    });
+}
+
+#[test]
+fn proposal_storage_fee_matches_charge() {
+    ExtBuilder::default().build().execute_with(|| {
+        INV4::create_dao(
+            RawOrigin::Signed(ALICE).into(),
+            Default::default(),
+            Perbill::from_percent(51),
+            Perbill::from_percent(51),
+            FeeAsset::Native,
+        )
+        .unwrap();
+
+        System::set_block_number(1);
+
+        INV4::token_mint(
+            Origin::Multisig(MultisigInternalOrigin::new(0u32)).into(),
+            DaoSeedBalance::get(),
+            BOB,
+        )
+        .unwrap();
+
+        let call: RuntimeCall = frame_system::Call::<Test>::remark {
+            remark: vec![0; 100],
+        }
+        .into();
+
+        let storage_fee = INV4::proposal_storage_fee(call.encoded_size() as u64 + 10);
+        assert!(!storage_fee.is_zero());
+
+        let balance_before = Balances::free_balance(ALICE);
+
+        assert_ok!(INV4::operate_multisig(
+            RawOrigin::Signed(ALICE).into(),
+            0u32,
+            Some(vec![0; 10].try_into().unwrap()),
+            FeeAsset::Native,
+            Box::new(call)
+        ));
+
+        assert_eq!(Balances::free_balance(ALICE), balance_before - storage_fee);
+    });
+}
+
+#[test]
+fn estimate_operate_multisig_fees_counts_extrinsic_overhead() {
+    ExtBuilder::default().build().execute_with(|| {
+        let call: RuntimeCall = frame_system::Call::<Test>::remark {
+            remark: vec![0; 100],
+        }
+        .into();
+        let call_len = call.encoded_size() as u32;
+
+        // Length based fee, so the overhead is visible in the extrinsic fee.
+        let compute_fee = |len: u32, _: &frame_support::dispatch::DispatchInfo| len as u128;
+
+        let fees = INV4::estimate_operate_multisig_fees(
+            0u32,
+            10,
+            call.clone(),
+            FeeAsset::Native,
+            0,
+            compute_fee,
+        );
+
+        assert_eq!(fees.call_fee, call_len as u128);
+        assert_eq!(
+            fees.storage_fee,
+            INV4::proposal_storage_fee(call_len as u64 + 10)
+        );
+
+        let signed_fees = INV4::estimate_operate_multisig_fees(
+            0u32,
+            10,
+            call,
+            FeeAsset::Native,
+            100,
+            compute_fee,
+        );
+
+        assert_eq!(signed_fees.extrinsic_fee, fees.extrinsic_fee + 100);
+        assert_eq!(signed_fees.call_fee, fees.call_fee);
+        assert_eq!(signed_fees.storage_fee, fees.storage_fee);
+    });
+}
+
+#[test]
+fn create_dao_with_registered_asset_works() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_ok!(INV4::create_dao(
+            RawOrigin::Signed(ALICE).into(),
+            Default::default(),
+            Perbill::from_percent(1),
+            Perbill::from_percent(1),
+            FeeAsset::Asset(RELAY_ASSET_ID)
+        ));
+
+        assert!(INV4::dao_storage(0u32).is_some());
+
+        // The creation fee is converted at the rate set by the fee handler.
+        assert_eq!(Balances::free_balance(ALICE), INITIAL_BALANCE);
+        assert_eq!(
+            Tokens::accounts(ALICE, RELAY_ASSET_ID).free,
+            INITIAL_BALANCE - DaoCreationFee::get() / 20
+        );
+
+        // Assets without a conversion rate can't be used.
+        assert_err!(
+            INV4::create_dao(
+                RawOrigin::Signed(ALICE).into(),
+                Default::default(),
+                Perbill::from_percent(1),
+                Perbill::from_percent(1),
+                FeeAsset::Asset(5u32)
+            ),
+            Error::<Test>::UnsupportedFeeAsset
+        );
+
+        assert_eq!(INV4::next_dao_id(), 1u32);
+    });
+}
+
+// SRLabs tests.
+#[test]
+fn vote_multisig_stack_overflow() {
+    ExtBuilder::default().build().execute_with(|| {
+        INV4::create_dao(
+            RawOrigin::Signed(ALICE).into(),
+            Default::default(),
+            Perbill::from_percent(100),
+            Perbill::from_percent(100),
+            FeeAsset::Native,
+        )
+        .unwrap();
+
+        System::set_block_number(1);
+
+        let call1: RuntimeCall = pallet::Call::token_mint {
+            amount: DaoSeedBalance::get(),
+            target: BOB,
+        }
+        .into();
+
+        let mut nested_call: RuntimeCall = pallet::Call::operate_multisig {
+            dao_id: 0u32,
+            metadata: None,
+            fee_asset: FeeAsset::Native,
+            call: Box::new(call1.clone()),
+        }
+        .into();
+
+        for _ in 0..(sp_api::MAX_EXTRINSIC_DEPTH / 4) + 1 {
+            nested_call = pallet::Call::operate_multisig {
+                dao_id: 0u32,
+                metadata: None,
+                fee_asset: FeeAsset::Native,
+                call: Box::new(nested_call.clone()),
+            }
+            .into();
+        }
+
+        INV4::operate_multisig(
+            RawOrigin::Signed(ALICE).into(),
+            0u32,
+            None,
+            FeeAsset::Native,
+            Box::new(call1.clone()),
+        )
+        .unwrap();
+
+        System::set_block_number(2);
+
+        INV4::operate_multisig(
+            RawOrigin::Signed(ALICE).into(),
+            0u32,
+            None,
+            FeeAsset::Native,
+            Box::new(nested_call.clone()),
+        )
+        .unwrap();
+
+        assert_eq!(
+            INV4::multisig(
+                0u32,
+                <<Test as frame_system::Config>::Hashing as Hash>::hash_of(&nested_call)
+            ),
+            Some(MultisigOperation {
+                actual_call: BoundedCallBytes::<Test>::try_from(nested_call.clone().encode())
+                    .unwrap(),
+                fee_asset: FeeAsset::Native,
+                original_caller: ALICE,
+                metadata: None,
+                tally: Tally::from_parts(
+                    DaoSeedBalance::get(),
+                    Zero::zero(),
+                    BoundedBTreeMap::try_from(BTreeMap::from([(
+                        ALICE,
+                        Vote::Aye(DaoSeedBalance::get())
+                    )]))
+                    .unwrap()
+                ),
+                emergency: false,
+                fee_payer: FeePayer::Dao,
+                second_tally: None,
+            })
+        );
+
+        assert_err!(
+            INV4::vote_multisig(
+                RawOrigin::Signed(BOB).into(),
+                0u32,
+                <<Test as frame_system::Config>::Hashing as Hash>::hash_of(&nested_call),
+                true
+            ),
+            Error::<Test>::FailedDecodingCall
+        );
+    });
+}
+
+#[test]
+fn v6_migration_keeps_metadata_not_fitting_the_description() {
+    ExtBuilder::default().build().execute_with(|| {
+        use crate::migrations::{v6, v7::OldDaoInfo};
+
+        let max = MaxDescriptionLength::get() as usize;
+
+        // A two byte character crosses the description length limit.
+        let long_metadata = [
+            vec![b'a'; max - 1],
+            "é".as_bytes().to_vec(),
+            b"rest".to_vec(),
+        ]
+        .concat();
+
+        for (dao_id, metadata) in [(0u32, long_metadata.clone()), (1u32, b"A dao".to_vec())] {
+            frame_support::storage::unhashed::put(
+                &CoreStorage::<Test>::hashed_key_for(dao_id),
+                &OldDaoInfo {
+                    account: ALICE,
+                    metadata: BoundedVec::<u8, MaxMetadata>::try_from(metadata).unwrap(),
+                    minimum_support: Perbill::from_percent(50),
+                    required_approval: Perbill::from_percent(50),
+                    frozen_tokens: true,
+                    voting_strategy: VotingStrategy::<u32>::TokenBalance,
+                },
+            );
+        }
+
+        v6::migrate_dao_metadata::<Test>();
+
+        let migrated = v6::CoreStorage::<Test>::get(0u32).unwrap();
+        assert_eq!(migrated.metadata.description.to_vec(), vec![b'a'; max - 1]);
+        assert!(migrated.metadata.is_valid());
+        assert_eq!(
+            INV4::legacy_dao_metadata(0u32).unwrap().to_vec(),
+            long_metadata
+        );
+
+        let migrated = v6::CoreStorage::<Test>::get(1u32).unwrap();
+        assert_eq!(migrated.metadata.description.to_vec(), b"A dao".to_vec());
+        assert_eq!(INV4::legacy_dao_metadata(1u32), None);
+    });
+}
@@ -0,0 +1,165 @@
+//! DAO Voting Tokens.
+//!
+//! ## Overview
+//!
+//! Allows DAOs to describe their voting token and to put a hard cap on its supply.
+//! The metadata lets wallets display the token with a name, a symbol and the right amount of decimals,
+//! and is exposed along with the supply through the [`DaoTokenApi`] runtime API.
+//! Once set, the supply cap can only be lowered, so a captured DAO can't inflate its supply past it.
+//!
+//! - `inner_set_token_metadata`: Sets the name, symbol and decimals of the voting token of a DAO.
+//! - `inner_set_token_supply_cap`: Sets or lowers the maximum supply of the voting token of a DAO.
+
+use crate::{
+    origin::{ensure_multisig, DaoOrigin},
+    pallet::*,
+};
+use codec::Codec;
+use frame_support::{pallet_prelude::*, traits::fungibles::Inspect};
+use frame_system::pallet_prelude::*;
+use sp_runtime::traits::CheckedAdd;
+use sp_std::vec::Vec;
+
+/// Metadata of the voting token of a DAO.
+#[derive(Clone, Encode, Decode, RuntimeDebug, MaxEncodedLen, TypeInfo, PartialEq, Eq, Default)]
+pub struct DaoTokenMetadata<Name, Symbol> {
+    /// Display name of the token.
+    pub name: Name,
+    /// Ticker of the token, made of ascii letters and digits.
+    pub symbol: Symbol,
+    /// Number of decimals used to display balances of the token.
+    pub decimals: u8,
+}
+
+pub type DaoTokenMetadataOf<T> = DaoTokenMetadata<
+    BoundedVec<u8, <T as Config>::MaxNameLength>,
+    BoundedVec<u8, <T as Config>::MaxSymbolLength>,
+>;
+
+/// Voting token details returned by the [`DaoTokenApi`] runtime API.
+#[derive(Clone, Encode, Decode, RuntimeDebug, TypeInfo, PartialEq, Eq)]
+pub struct DaoTokenInfo<Balance> {
+    /// Display name of the token, empty if the dao didn't set its token metadata.
+    pub name: Vec<u8>,
+    /// Ticker of the token, empty if the dao didn't set its token metadata.
+    pub symbol: Vec<u8>,
+    /// Number of decimals used to display balances of the token.
+    pub decimals: u8,
+    /// Current supply of the token.
+    pub total_issuance: Balance,
+    /// Maximum supply of the token, if the dao set one.
+    pub supply_cap: Option<Balance>,
+}
+
+sp_api::decl_runtime_apis! {
+    /// API for querying the voting tokens of daos.
+    pub trait DaoTokenApi<DaoId, Balance>
+    where
+        DaoId: Codec,
+        Balance: Codec,
+    {
+        /// Returns the metadata and supply of the voting token of `dao_id`, `None` if the dao doesn't exist.
+        fn token_info(dao_id: DaoId) -> Option<DaoTokenInfo<Balance>>;
+    }
+}
+
+impl<T: Config> Pallet<T>
+where
+    Result<DaoOrigin<T>, <T as frame_system::Config>::RuntimeOrigin>:
+        From<<T as frame_system::Config>::RuntimeOrigin>,
+    <T as frame_system::Config>::AccountId: From<[u8; 32]>,
+{
+    /// Inner function for the set_token_metadata call.
+    pub(crate) fn inner_set_token_metadata(
+        origin: OriginFor<T>,
+        metadata: DaoTokenMetadataOf<T>,
+    ) -> DispatchResult {
+        let dao_origin = ensure_multisig::<T, OriginFor<T>>(origin)?;
+        let dao_id = dao_origin.id;
+
+        ensure!(
+            CoreStorage::<T>::contains_key(dao_id),
+            Error::<T>::DaoNotFound
+        );
+
+        ensure!(
+            Self::is_valid_token_metadata(&metadata),
+            Error::<T>::InvalidTokenMetadata
+        );
+
+        TokenMetadata::<T>::insert(dao_id, metadata.clone());
+
+        Self::deposit_event(Event::TokenMetadataSet { dao_id, metadata });
+
+        Ok(())
+    }
+
+    /// Inner function for the set_token_supply_cap call.
+    pub(crate) fn inner_set_token_supply_cap(
+        origin: OriginFor<T>,
+        cap: BalanceOf<T>,
+    ) -> DispatchResult {
+        let dao_origin = ensure_multisig::<T, OriginFor<T>>(origin)?;
+        let dao_id = dao_origin.id;
+
+        ensure!(
+            CoreStorage::<T>::contains_key(dao_id),
+            Error::<T>::DaoNotFound
+        );
+
+        // The cap is only ever lowered, otherwise it wouldn't protect the token from inflation.
+        if let Some(current_cap) = TokenSupplyCap::<T>::get(dao_id) {
+            ensure!(cap <= current_cap, Error::<T>::SupplyCapCannotBeRaised);
+        }
+
+        ensure!(
+            T::AssetsProvider::total_issuance(dao_id) <= cap,
+            Error::<T>::SupplyCapBelowIssuance
+        );
+
+        TokenSupplyCap::<T>::insert(dao_id, cap);
+
+        Self::deposit_event(Event::TokenSupplyCapSet { dao_id, cap });
+
+        Ok(())
+    }
+}
+
+impl<T: Config> Pallet<T> {
+    /// Names must be UTF-8 and symbols a non-empty string of ascii letters and digits.
+    pub fn is_valid_token_metadata(metadata: &DaoTokenMetadataOf<T>) -> bool {
+        sp_std::str::from_utf8(&metadata.name).is_ok()
+            && !metadata.symbol.is_empty()
+            && metadata.symbol.iter().all(|c| c.is_ascii_alphanumeric())
+    }
+
+    /// Checks that minting `amount` of the voting token of `dao_id` won't push its supply over the cap.
+    pub fn ensure_within_supply_cap(dao_id: T::DaoId, amount: BalanceOf<T>) -> DispatchResult {
+        if let Some(cap) = TokenSupplyCap::<T>::get(dao_id) {
+            let new_issuance = T::AssetsProvider::total_issuance(dao_id)
+                .checked_add(&amount)
+                .ok_or(Error::<T>::SupplyCapExceeded)?;
+
+            ensure!(new_issuance <= cap, Error::<T>::SupplyCapExceeded);
+        }
+
+        Ok(())
+    }
+
+    /// Returns the metadata and supply of the voting token of a dao, used by the [`DaoTokenApi`] runtime API.
+    pub fn token_info(dao_id: T::DaoId) -> Option<DaoTokenInfo<BalanceOf<T>>> {
+        if !CoreStorage::<T>::contains_key(dao_id) {
+            return None;
+        }
+
+        let metadata = TokenMetadata::<T>::get(dao_id).unwrap_or_default();
+
+        Some(DaoTokenInfo {
+            name: metadata.name.into_inner(),
+            symbol: metadata.symbol.into_inner(),
+            decimals: metadata.decimals,
+            total_issuance: T::AssetsProvider::total_issuance(dao_id),
+            supply_cap: TokenSupplyCap::<T>::get(dao_id),
+        })
+    }
+}
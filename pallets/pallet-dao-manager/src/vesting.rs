@@ -0,0 +1,275 @@
+//! Vested Voting Tokens.
+//!
+//! ## Overview
+//!
+//! Allows DAOs to mint voting tokens that unlock over time, usually for contributor grants.
+//! The minted tokens are held by the target right away but can't be transferred until they unlock,
+//! this is enforced by the runtime through the `OnTransfer` hook of the voting tokens calling [`Pallet::ensure_unlocked`].
+//! Whether tokens that are still locked count towards the voting power of their holder is configurable per DAO.
+//!
+//! - `inner_token_mint_vested`: Mints voting tokens to a target locking them under a vesting schedule.
+//! - `inner_set_locked_tokens_vote`: Sets whether locked tokens count towards voting power in a DAO.
+
+use crate::{
+    origin::{ensure_multisig, DaoOrigin},
+    pallet::*,
+};
+use frame_support::{
+    pallet_prelude::*,
+    traits::fungibles::{Inspect, Mutate},
+};
+use frame_system::pallet_prelude::*;
+use sp_arithmetic::{helpers_128bit::multiply_by_rational_with_rounding, Rounding};
+use sp_runtime::{
+    traits::{AtLeast32BitUnsigned, SaturatedConversion, Saturating, Zero},
+    TokenError,
+};
+
+/// Schedule under which minted voting tokens unlock.
+#[derive(Clone, Copy, Encode, Decode, RuntimeDebug, MaxEncodedLen, TypeInfo, PartialEq, Eq)]
+pub enum VestingSchedule<Balance, BlockNumber> {
+    /// `amount` unlocks linearly from block `start` over `duration` blocks.
+    Linear {
+        amount: Balance,
+        start: BlockNumber,
+        duration: BlockNumber,
+    },
+    /// `amount` unlocks all at once at block `unlock_at`.
+    Cliff {
+        amount: Balance,
+        unlock_at: BlockNumber,
+    },
+}
+
+pub type VestingScheduleOf<T> = VestingSchedule<BalanceOf<T>, BlockNumberFor<T>>;
+
+impl<Balance, BlockNumber> VestingSchedule<Balance, BlockNumber>
+where
+    Balance: AtLeast32BitUnsigned + Copy,
+    BlockNumber: AtLeast32BitUnsigned + Copy,
+{
+    /// Total amount of tokens vested by the schedule.
+    pub fn amount(&self) -> Balance {
+        match self {
+            Self::Linear { amount, .. } | Self::Cliff { amount, .. } => *amount,
+        }
+    }
+
+    /// Amount of tokens still locked at block `now`.
+    pub fn locked_at(&self, now: BlockNumber) -> Balance {
+        match *self {
+            Self::Linear {
+                amount,
+                start,
+                duration,
+            } => {
+                let elapsed = now.saturating_sub(start);
+
+                if elapsed >= duration {
+                    return Zero::zero();
+                }
+
+                let unlocked = multiply_by_rational_with_rounding(
+                    amount.saturated_into(),
+                    elapsed.saturated_into(),
+                    duration.saturated_into(),
+                    Rounding::Down,
+                )
+                .unwrap_or_else(|| amount.saturated_into());
+
+                amount.saturating_sub(unlocked.saturated_into())
+            }
+
+            Self::Cliff { amount, unlock_at } => {
+                if now >= unlock_at {
+                    Zero::zero()
+                } else {
+                    amount
+                }
+            }
+        }
+    }
+
+    /// Schedule locking `cut` less tokens from block `now` on.
+    ///
+    /// What remains locked by a linear schedule keeps unlocking linearly until the schedule's original end.
+    pub fn reduced_by(&self, cut: Balance, now: BlockNumber) -> Self {
+        let locked = self.locked_at(now).saturating_sub(cut);
+
+        match *self {
+            Self::Linear {
+                start, duration, ..
+            } => {
+                let end = start.saturating_add(duration);
+                let start = start.max(now);
+
+                Self::Linear {
+                    amount: locked,
+                    start,
+                    duration: end.saturating_sub(start),
+                }
+            }
+
+            Self::Cliff { unlock_at, .. } => Self::Cliff {
+                amount: locked,
+                unlock_at,
+            },
+        }
+    }
+
+    /// Schedules must vest something and linear schedules need a duration.
+    pub fn is_valid(&self) -> bool {
+        match self {
+            Self::Linear {
+                amount, duration, ..
+            } => !amount.is_zero() && !duration.is_zero(),
+            Self::Cliff { amount, .. } => !amount.is_zero(),
+        }
+    }
+}
+
+impl<T: Config> Pallet<T>
+where
+    Result<DaoOrigin<T>, <T as frame_system::Config>::RuntimeOrigin>:
+        From<<T as frame_system::Config>::RuntimeOrigin>,
+    <T as frame_system::Config>::AccountId: From<[u8; 32]>,
+{
+    /// Inner function for the token_mint_vested call.
+    pub(crate) fn inner_token_mint_vested(
+        origin: OriginFor<T>,
+        target: T::AccountId,
+        schedule: VestingScheduleOf<T>,
+    ) -> DispatchResult {
+        let dao_origin = ensure_multisig::<T, OriginFor<T>>(origin)?;
+        let dao_id = dao_origin.id;
+
+        ensure!(schedule.is_valid(), Error::<T>::InvalidVestingSchedule);
+
+        let amount = schedule.amount();
+
+        Self::ensure_within_supply_cap(dao_id, amount)?;
+
+        // Fully unlocked schedules are dropped to make room for the new one.
+        let now = frame_system::Pallet::<T>::block_number();
+        VestingSchedules::<T>::try_mutate(dao_id, &target, |schedules| {
+            schedules.retain(|s| !s.locked_at(now).is_zero());
+            schedules
+                .try_push(schedule)
+                .map_err(|_| Error::<T>::TooManyVestingSchedules)
+        })?;
+
+        let old_balance = T::AssetsProvider::balance(dao_id, &target);
+        T::AssetsProvider::mint_into(dao_id, &target, amount)?;
+        Self::note_voting_balance_change(dao_id, &target, old_balance);
+
+        Self::deposit_event(Event::MintedVested {
+            dao_id,
+            target,
+            schedule,
+        });
+
+        Ok(())
+    }
+
+    /// Inner function for the set_locked_tokens_vote call.
+    pub(crate) fn inner_set_locked_tokens_vote(
+        origin: OriginFor<T>,
+        can_vote: bool,
+    ) -> DispatchResult {
+        let dao_origin = ensure_multisig::<T, OriginFor<T>>(origin)?;
+        let dao_id = dao_origin.id;
+
+        ensure!(
+            CoreStorage::<T>::contains_key(dao_id),
+            Error::<T>::DaoNotFound
+        );
+
+        LockedTokensVote::<T>::insert(dao_id, can_vote);
+
+        Self::deposit_event(Event::LockedTokensVoteSet { dao_id, can_vote });
+
+        Ok(())
+    }
+}
+
+impl<T: Config> Pallet<T> {
+    /// Returns the amount of voting tokens of a dao still locked by vesting schedules for an account.
+    pub fn vesting_locked(dao_id: T::DaoId, who: &T::AccountId) -> BalanceOf<T> {
+        let now = frame_system::Pallet::<T>::block_number();
+
+        VestingSchedules::<T>::get(dao_id, who)
+            .iter()
+            .fold(Zero::zero(), |locked: BalanceOf<T>, schedule| {
+                locked.saturating_add(schedule.locked_at(now))
+            })
+    }
+
+    /// Cuts the vesting schedules of an account down to its voting token balance, after tokens were burned from it.
+    ///
+    /// Burned tokens come out of the unlocked ones first, then out of the schedules added last.
+    pub fn trim_vesting_schedules(dao_id: T::DaoId, who: &T::AccountId) {
+        let now = frame_system::Pallet::<T>::block_number();
+        let mut excess = Self::vesting_locked(dao_id, who)
+            .saturating_sub(T::AssetsProvider::balance(dao_id, who));
+
+        if excess.is_zero() {
+            return;
+        }
+
+        VestingSchedules::<T>::mutate_exists(dao_id, who, |maybe_schedules| {
+            if let Some(schedules) = maybe_schedules {
+                for schedule in schedules.iter_mut().rev() {
+                    if excess.is_zero() {
+                        break;
+                    }
+
+                    let cut = schedule.locked_at(now).min(excess);
+                    *schedule = schedule.reduced_by(cut, now);
+                    excess = excess.saturating_sub(cut);
+                }
+
+                schedules.retain(|s| !s.locked_at(now).is_zero());
+
+                if schedules.is_empty() {
+                    *maybe_schedules = None;
+                }
+            }
+        });
+    }
+
+    /// Checks that `amount` of voting tokens can leave the account without touching the locked ones.
+    ///
+    /// Meant to be called by the `OnTransfer` hook of the voting tokens in the runtime.
+    pub fn ensure_unlocked(
+        dao_id: T::DaoId,
+        who: &T::AccountId,
+        amount: BalanceOf<T>,
+    ) -> DispatchResult {
+        let locked = Self::vesting_locked(dao_id, who);
+
+        if !locked.is_zero() {
+            ensure!(
+                T::AssetsProvider::balance(dao_id, who).saturating_sub(amount) >= locked,
+                TokenError::Frozen
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Returns whether tokens still locked by vesting schedules count towards voting power in a dao, `true` by default.
+    pub fn locked_tokens_vote(dao_id: T::DaoId) -> bool {
+        LockedTokensVote::<T>::get(dao_id).unwrap_or(true)
+    }
+
+    /// Returns the voting token balance of an account that counts towards its voting power.
+    pub fn voting_balance(dao_id: T::DaoId, who: &T::AccountId) -> BalanceOf<T> {
+        let balance = T::AssetsProvider::balance(dao_id, who);
+
+        if Self::locked_tokens_vote(dao_id) {
+            balance
+        } else {
+            balance.saturating_sub(Self::vesting_locked(dao_id, who))
+        }
+    }
+}
@@ -6,25 +6,40 @@
 //! Members each have a balance in voting tokens and this balance differentiate their voting power
 //! as every vote utilizes the entire `power` of the said member.
 //! This empowers decision-making where certain members possess greater influence.
+//!
+//! How the voting token balance translates into voting power is defined by the dao's [`VotingStrategy`],
+//! the support of a proposal is always computed against the total voting power of the dao.
 
-use crate::{origin::DaoOrigin, BalanceOf, Config, CoreStorage, Error, Multisig, Pallet};
+use crate::{
+    origin::DaoOrigin, weights::WeightInfo, BalanceOf, Config, CoreMemberCount,
+    CoreQuadraticVotingPower, CoreStorage, Error, Multisig, NftProposalVotes, NftVotingPower,
+    Pallet, VotingStrategyOf,
+};
 use codec::{Decode, Encode, HasCompact, MaxEncodedLen};
 use core::marker::PhantomData;
 use frame_support::{
+    ensure,
     pallet_prelude::{Member, RuntimeDebug},
-    traits::{fungibles::Inspect, PollStatus, VoteTally},
+    traits::{
+        fungibles::Inspect,
+        tokens::nonfungibles::{self, Inspect as _, InspectEnumerable as _},
+        PollStatus, VoteTally,
+    },
+    weights::Weight,
     BoundedBTreeMap, CloneNoBound, EqNoBound, Parameter, PartialEqNoBound, RuntimeDebugNoBound,
 };
 use frame_system::pallet_prelude::BlockNumberFor;
+use primitives::VotingStrategy;
 use scale_info::TypeInfo;
 use sp_runtime::{
-    traits::{One, Zero},
+    traits::{IntegerSquareRoot, One, Saturating, Zero},
     DispatchError, Perbill,
 };
 use sp_std::vec::Vec;
 
 pub type Votes<T> = BalanceOf<T>;
 pub type Dao<T> = <T as Config>::DaoId;
+pub type NftItemOf<T> = <T as Config>::NftItemId;
 
 /// Aggregated votes for an ongoing poll by members of a dao.
 #[derive(
@@ -109,7 +124,7 @@ impl<T: Config> VoteTally<Votes<T>, Dao<T>> for Tally<T> {
     }
 
     fn support(&self, class: Dao<T>) -> Perbill {
-        Perbill::from_rational(self.ayes, T::AssetsProvider::total_issuance(class))
+        Perbill::from_rational(self.ayes, Pallet::<T>::total_voting_power(class))
     }
 
     fn approval(&self, _: Dao<T>) -> Perbill {
@@ -237,3 +252,276 @@ where
         CoreStorage::<T>::get(dao_id).map(|dao| (dao.minimum_support, dao.required_approval))
     }
 }
+
+/// Attribute key holding the voting weight of an NFT, for daos using [`VotingStrategy::NftAttribute`].
+///
+/// The value is expected to be a SCALE encoded `u32`, items without it carry no voting power.
+pub const NFT_VOTING_WEIGHT_KEY: &[u8] = b"voting_weight";
+
+impl<T: Config> Pallet<T> {
+    /// Returns the voting strategy of a dao.
+    pub fn voting_strategy(dao_id: T::DaoId) -> VotingStrategyOf<T> {
+        CoreStorage::<T>::get(dao_id)
+            .map(|dao| dao.voting_strategy)
+            .unwrap_or(VotingStrategy::TokenBalance)
+    }
+
+    /// Returns the voting power of an account in a dao according to the dao's voting strategy.
+    pub fn voting_power(dao_id: T::DaoId, who: &T::AccountId) -> Votes<T> {
+        match Self::voting_strategy(dao_id) {
+            VotingStrategy::TokenBalance => Self::voting_balance(dao_id, who),
+            VotingStrategy::OneMemberOneVote => {
+                if Self::voting_balance(dao_id, who).is_zero() {
+                    Zero::zero()
+                } else {
+                    One::one()
+                }
+            }
+            VotingStrategy::Quadratic => Self::voting_balance(dao_id, who).integer_sqrt(),
+            VotingStrategy::NftItems(collection) => (T::Nfts::owned_in_collection(&collection, who)
+                .take(T::MaxNftVotingItems::get() as usize)
+                .count() as u32)
+                .into(),
+            VotingStrategy::NftAttribute(collection) => {
+                T::Nfts::owned_in_collection(&collection, who)
+                    .take(T::MaxNftVotingItems::get() as usize)
+                    .fold(Zero::zero(), |total: Votes<T>, item| {
+                        total.saturating_add(Self::nft_voting_weight(&collection, &item))
+                    })
+            }
+        }
+    }
+
+    /// Returns the total voting power of a dao according to the dao's voting strategy.
+    ///
+    /// For quadratic voting this is the sum of the square roots of the members' balances,
+    /// kept up to date as balances change, tokens locked by vesting are always part of it.
+    /// NFT strategies use the total stored for the collection, see [`Self::note_nft_voting_power`].
+    ///
+    /// Tokens locked by vesting always count towards the total when voting by token balance,
+    /// so excluding them from voting makes the minimum support harder to reach. The amount still locked
+    /// across the dao changes every block as schedules unlock, so it can't be left out of the total exactly.
+    pub fn total_voting_power(dao_id: T::DaoId) -> Votes<T> {
+        match Self::voting_strategy(dao_id) {
+            VotingStrategy::TokenBalance => T::AssetsProvider::total_issuance(dao_id),
+            VotingStrategy::OneMemberOneVote => CoreMemberCount::<T>::get(dao_id).into(),
+            VotingStrategy::Quadratic => CoreQuadraticVotingPower::<T>::get(dao_id),
+            VotingStrategy::NftItems(collection) | VotingStrategy::NftAttribute(collection) => {
+                NftVotingPower::<T>::get(collection)
+            }
+        }
+    }
+
+    /// Computes the total voting power of the NFT collection used by a voting strategy.
+    ///
+    /// Iterates over all the items of the collection, so it fails if there are more than
+    /// `T::MaxNftVotingItems`.
+    pub fn compute_nft_voting_power(
+        strategy: &VotingStrategyOf<T>,
+    ) -> Result<Votes<T>, DispatchError> {
+        let max_items = T::MaxNftVotingItems::get() as usize;
+
+        let (collection, items) = match strategy {
+            VotingStrategy::NftItems(collection) | VotingStrategy::NftAttribute(collection) => (
+                collection,
+                T::Nfts::items(collection).take(max_items.saturating_add(1)),
+            ),
+            _ => return Err(Error::<T>::NoNftVotingStrategy.into()),
+        };
+
+        let mut count: usize = 0;
+        let total = items.fold(Zero::zero(), |total: Votes<T>, item| {
+            count += 1;
+            match strategy {
+                VotingStrategy::NftAttribute(_) => {
+                    total.saturating_add(Self::nft_voting_weight(collection, &item))
+                }
+                _ => total.saturating_add(One::one()),
+            }
+        });
+
+        ensure!(count <= max_items, Error::<T>::TooManyNftItems);
+
+        Ok(total)
+    }
+
+    /// Recomputes the total voting power of the NFT collection a dao votes with, if any.
+    ///
+    /// Pallet uniques has no hooks for minting and burning, so the total is brought up to date every time
+    /// a proposal is created in the dao instead. Collections that grew over `T::MaxNftVotingItems` keep
+    /// their last total.
+    pub fn note_nft_voting_power(dao_id: T::DaoId) {
+        let strategy = Self::voting_strategy(dao_id);
+
+        if let Some(collection) = Self::nft_collection_of(&strategy) {
+            if let Ok(voting_power) = Self::compute_nft_voting_power(&strategy) {
+                NftVotingPower::<T>::insert(collection, voting_power);
+            }
+        }
+    }
+
+    /// Returns the voting power of an account in a proposal of a dao.
+    ///
+    /// NFT strategies only count the items no other account voted with in the same proposal,
+    /// the items counted are recorded for the account, replacing the ones it voted with before.
+    pub fn note_proposal_voting_power(
+        dao_id: T::DaoId,
+        proposal: T::Hash,
+        who: &T::AccountId,
+    ) -> Votes<T> {
+        let strategy = Self::voting_strategy(dao_id);
+
+        let collection = match Self::nft_collection_of(&strategy) {
+            Some(collection) => collection,
+            None => return Self::voting_power(dao_id, who),
+        };
+
+        Self::clear_proposal_nft_votes(dao_id, proposal, Some(who));
+
+        T::Nfts::owned_in_collection(&collection, who)
+            .take(T::MaxNftVotingItems::get() as usize)
+            .filter(|item| !NftProposalVotes::<T>::contains_key((dao_id, proposal, *item)))
+            .fold(Zero::zero(), |total: Votes<T>, item| {
+                NftProposalVotes::<T>::insert((dao_id, proposal, item), who);
+
+                match strategy {
+                    VotingStrategy::NftAttribute(_) => {
+                        total.saturating_add(Self::nft_voting_weight(&collection, &item))
+                    }
+                    _ => total.saturating_add(One::one()),
+                }
+            })
+    }
+
+    /// Removes the records of the NFT items used to vote on a proposal of a dao,
+    /// only the ones of `who` if given.
+    pub fn clear_proposal_nft_votes(
+        dao_id: T::DaoId,
+        proposal: T::Hash,
+        who: Option<&T::AccountId>,
+    ) {
+        match who {
+            Some(who) => {
+                let items = NftProposalVotes::<T>::iter_prefix((dao_id, proposal))
+                    .filter(|(_, voter)| voter == who)
+                    .map(|(item, _)| item)
+                    .collect::<Vec<_>>();
+
+                for item in items {
+                    NftProposalVotes::<T>::remove((dao_id, proposal, item));
+                }
+            }
+            None => {
+                let _ = NftProposalVotes::<T>::clear_prefix((dao_id, proposal), u32::MAX, None);
+            }
+        }
+    }
+
+    /// Worst case weight of bringing the NFT voting power of a dao up to date when a proposal is created
+    /// and recording the items of the proposer.
+    pub fn nft_proposal_creation_weight() -> Weight {
+        let max_items = T::MaxNftVotingItems::get();
+
+        T::WeightInfo::note_nft_voting_power(max_items)
+            .saturating_add(T::WeightInfo::note_proposal_voting_power(max_items))
+    }
+
+    /// Updates the quadratic voting power of a dao after the voting token balance of an account
+    /// changed from `old_balance`.
+    pub fn note_voting_balance_change(
+        dao_id: T::DaoId,
+        who: &T::AccountId,
+        old_balance: BalanceOf<T>,
+    ) {
+        let new_balance = T::AssetsProvider::balance(dao_id, who);
+
+        if new_balance != old_balance {
+            CoreQuadraticVotingPower::<T>::mutate(dao_id, |total| {
+                *total = total
+                    .saturating_sub(old_balance.integer_sqrt())
+                    .saturating_add(new_balance.integer_sqrt())
+            });
+        }
+    }
+
+    /// Updates the quadratic voting power of a dao after a transfer of its voting token.
+    ///
+    /// Meant to be called by the `PostTransfer` hook of the voting tokens in the runtime.
+    pub fn on_voting_token_transfer(
+        dao_id: T::DaoId,
+        from: &T::AccountId,
+        to: &T::AccountId,
+        amount: BalanceOf<T>,
+    ) {
+        if from == to {
+            return;
+        }
+
+        Self::note_voting_balance_change(
+            dao_id,
+            from,
+            T::AssetsProvider::balance(dao_id, from).saturating_add(amount),
+        );
+        Self::note_voting_balance_change(
+            dao_id,
+            to,
+            T::AssetsProvider::balance(dao_id, to).saturating_sub(amount),
+        );
+    }
+
+    /// Returns the NFT collection a voting strategy derives its voting power from.
+    pub fn nft_collection_of(strategy: &VotingStrategyOf<T>) -> Option<T::NftCollectionId> {
+        match strategy {
+            VotingStrategy::NftItems(collection) | VotingStrategy::NftAttribute(collection) => {
+                Some(*collection)
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the voting weight attribute of an NFT.
+    fn nft_voting_weight(collection: &T::NftCollectionId, item: &NftItemOf<T>) -> Votes<T> {
+        T::Nfts::attribute(collection, item, NFT_VOTING_WEIGHT_KEY)
+            .and_then(|value| u32::decode(&mut &value[..]).ok())
+            .map(Into::into)
+            .unwrap_or_else(Zero::zero)
+    }
+}
+
+/// NFT provider for runtimes without NFTs, daos can't enable NFT voting strategies with it.
+pub struct NoNfts<CollectionId>(PhantomData<CollectionId>);
+
+impl<AccountId, CollectionId> nonfungibles::Inspect<AccountId> for NoNfts<CollectionId> {
+    type ItemId = u32;
+    type CollectionId = CollectionId;
+
+    fn owner(_collection: &Self::CollectionId, _item: &Self::ItemId) -> Option<AccountId> {
+        None
+    }
+}
+
+impl<AccountId, CollectionId> nonfungibles::InspectEnumerable<AccountId> for NoNfts<CollectionId> {
+    type CollectionsIterator = core::iter::Empty<CollectionId>;
+    type ItemsIterator = core::iter::Empty<u32>;
+    type OwnedIterator = core::iter::Empty<(CollectionId, u32)>;
+    type OwnedInCollectionIterator = core::iter::Empty<u32>;
+
+    fn collections() -> Self::CollectionsIterator {
+        core::iter::empty()
+    }
+
+    fn items(_collection: &Self::CollectionId) -> Self::ItemsIterator {
+        core::iter::empty()
+    }
+
+    fn owned(_who: &AccountId) -> Self::OwnedIterator {
+        core::iter::empty()
+    }
+
+    fn owned_in_collection(
+        _collection: &Self::CollectionId,
+        _who: &AccountId,
+    ) -> Self::OwnedInCollectionIterator {
+        core::iter::empty()
+    }
+}
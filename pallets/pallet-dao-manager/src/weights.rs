@@ -41,6 +41,23 @@ pub trait WeightInfo {
 	fn vote_multisig() -> Weight;
 	fn withdraw_vote_multisig() -> Weight;
 	fn cancel_multisig_proposal() -> Weight;
+	fn set_mirror() -> Weight;
+	fn operate_emergency_multisig(m: u32, z: u32, ) -> Weight;
+	fn set_voting_strategy() -> Weight;
+	fn operate_joint_multisig(d: u32, m: u32, z: u32, ) -> Weight;
+	fn vote_joint_multisig(d: u32, ) -> Weight;
+	fn withdraw_vote_joint_multisig() -> Weight;
+	fn cancel_joint_multisig_proposal() -> Weight;
+	fn set_handle() -> Weight;
+	fn set_token_metadata() -> Weight;
+	fn set_token_supply_cap() -> Weight;
+	fn token_mint_vested() -> Weight;
+	fn set_locked_tokens_vote() -> Weight;
+	fn set_second_chamber() -> Weight;
+	fn operate_public_multisig(m: u32, z: u32, ) -> Weight;
+	fn expire_public_proposal() -> Weight;
+	fn note_nft_voting_power(n: u32, ) -> Weight;
+	fn note_proposal_voting_power(n: u32, ) -> Weight;
 }
 
 /// Weights for `pallet_dao_manager` using the Substrate node and recommended hardware.
@@ -94,13 +111,15 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// Storage: `INV4::CoreMembers` (r:0 w:1)
 	/// Proof: `INV4::CoreMembers` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	/// Storage: `INV4::TokenSupplyCap` (r:1 w:0)
+	/// Proof: `INV4::TokenSupplyCap` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
 	fn token_mint() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `148`
+		//  Measured:  `180`
 		//  Estimated: `3593`
-		// Minimum execution time: 21_000_000 picoseconds.
-		Weight::from_parts(21_000_000, 3593)
-			.saturating_add(T::DbWeight::get().reads(3_u64))
+		// Minimum execution time: 22_000_000 picoseconds.
+		Weight::from_parts(23_000_000, 3593)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
 			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
 	/// Storage: `CoreAssets::Accounts` (r:1 w:1)
@@ -111,14 +130,16 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// Storage: `INV4::CoreMembers` (r:0 w:1)
 	/// Proof: `INV4::CoreMembers` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	/// Storage: `INV4::VestingSchedules` (r:1 w:1)
+	/// Proof: `INV4::VestingSchedules` (`max_values`: None, `max_size`: Some(477), added: 2952, mode: `MaxEncodedLen`)
 	fn token_burn() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `308`
-		//  Estimated: `3593`
-		// Minimum execution time: 24_000_000 picoseconds.
-		Weight::from_parts(24_000_000, 3593)
-			.saturating_add(T::DbWeight::get().reads(3_u64))
-			.saturating_add(T::DbWeight::get().writes(4_u64))
+		//  Estimated: `3942`
+		// Minimum execution time: 26_000_000 picoseconds.
+		Weight::from_parts(27_000_000, 3942)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
 	}
 	/// Storage: `CoreAssets::Accounts` (r:1 w:0)
 	/// Proof: `CoreAssets::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
@@ -187,6 +208,311 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		Weight::from_parts(6_000_000, 0)
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: `INV4::CoreStorage` (r:1 w:0)
+	/// Proof: `INV4::CoreStorage` (`max_values`: None, `max_size`: Some(10063), added: 12538, mode: `MaxEncodedLen`)
+	/// Storage: `INV4::MirroredDaos` (r:1 w:1)
+	/// Proof: `INV4::MirroredDaos` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `INV4::MirrorSource` (r:1 w:1)
+	/// Proof: `INV4::MirrorSource` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	fn set_mirror() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `162`
+		//  Estimated: `13528`
+		// Minimum execution time: 11_000_000 picoseconds.
+		Weight::from_parts(12_000_000, 13528)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `CoreAssets::Accounts` (r:1 w:0)
+	/// Proof: `CoreAssets::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	/// Storage: `INV4::CoreStorage` (r:1 w:0)
+	/// Proof: `INV4::CoreStorage` (`max_values`: None, `max_size`: Some(10063), added: 12538, mode: `MaxEncodedLen`)
+	/// Storage: `CoreAssets::TotalIssuance` (r:1 w:0)
+	/// Proof: `CoreAssets::TotalIssuance` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `INV4::Multisig` (r:1 w:1)
+	/// Proof: `INV4::Multisig` (`max_values`: None, `max_size`: Some(551342), added: 553817, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainInfo::ParachainId` (r:1 w:0)
+	/// Proof: `ParachainInfo::ParachainId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// The range of component `m` is `[0, 10000]`.
+	/// The range of component `z` is `[0, 51190]`.
+	fn operate_emergency_multisig(m: u32, z: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `368`
+		//  Estimated: `554807`
+		// Minimum execution time: 36_000_000 picoseconds.
+		Weight::from_parts(31_214_604, 554807)
+			// Standard Error: 16
+			.saturating_add(Weight::from_parts(459, 0).saturating_mul(m.into()))
+			// Standard Error: 3
+			.saturating_add(Weight::from_parts(1_511, 0).saturating_mul(z.into()))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `INV4::CoreStorage` (r:1 w:1)
+	/// Proof: `INV4::CoreStorage` (`max_values`: None, `max_size`: Some(10069), added: 12544, mode: `MaxEncodedLen`)
+	/// Storage: `Uniques::Class` (r:1 w:0)
+	/// Proof: `Uniques::Class` (`max_values`: None, `max_size`: Some(178), added: 2653, mode: `MaxEncodedLen`)
+	/// Storage: `INV4::NftCollectionDao` (r:1 w:2)
+	/// Proof: `INV4::NftCollectionDao` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	fn set_voting_strategy() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `421`
+		//  Estimated: `13534`
+		// Minimum execution time: 16_000_000 picoseconds.
+		Weight::from_parts(17_000_000, 13534)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `INV4::JointMultisig` (r:1 w:1)
+	/// Proof: `INV4::JointMultisig` (`max_values`: None, `max_size`: Some(6120742), added: 6123217, mode: `MaxEncodedLen`)
+	/// Storage: `INV4::CoreStorage` (r:10 w:0)
+	/// Proof: `INV4::CoreStorage` (`max_values`: None, `max_size`: Some(10069), added: 12544, mode: `MaxEncodedLen`)
+	/// Storage: `CoreAssets::Accounts` (r:10 w:0)
+	/// Proof: `CoreAssets::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	/// Storage: `CoreAssets::TotalIssuance` (r:10 w:0)
+	/// Proof: `CoreAssets::TotalIssuance` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// The range of component `d` is `[2, 10]`.
+	/// The range of component `m` is `[0, 10000]`.
+	/// The range of component `z` is `[0, 51200]`.
+	fn operate_joint_multisig(d: u32, m: u32, z: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `412 + d * (129 ±0)`
+		//  Estimated: `6124207 + d * (12544 ±0)`
+		// Minimum execution time: 48_000_000 picoseconds.
+		Weight::from_parts(29_412_331, 6124207)
+			// Standard Error: 31_207
+			.saturating_add(Weight::from_parts(9_173_552, 0).saturating_mul(d.into()))
+			// Standard Error: 16
+			.saturating_add(Weight::from_parts(462, 0).saturating_mul(m.into()))
+			// Standard Error: 3
+			.saturating_add(Weight::from_parts(1_517, 0).saturating_mul(z.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(d.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(Weight::from_parts(0, 12544).saturating_mul(d.into()))
+	}
+	/// Storage: `INV4::JointMultisig` (r:1 w:1)
+	/// Proof: `INV4::JointMultisig` (`max_values`: None, `max_size`: Some(6120742), added: 6123217, mode: `MaxEncodedLen`)
+	/// Storage: `CoreAssets::Accounts` (r:1 w:0)
+	/// Proof: `CoreAssets::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	/// Storage: `INV4::CoreStorage` (r:10 w:0)
+	/// Proof: `INV4::CoreStorage` (`max_values`: None, `max_size`: Some(10069), added: 12544, mode: `MaxEncodedLen`)
+	/// Storage: `CoreAssets::TotalIssuance` (r:10 w:0)
+	/// Proof: `CoreAssets::TotalIssuance` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// The range of component `d` is `[2, 10]`.
+	fn vote_joint_multisig(d: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `702 + d * (129 ±0)`
+		//  Estimated: `6124207 + d * (12544 ±0)`
+		// Minimum execution time: 31_000_000 picoseconds.
+		Weight::from_parts(22_518_004, 6124207)
+			// Standard Error: 27_114
+			.saturating_add(Weight::from_parts(6_204_913, 0).saturating_mul(d.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(d.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(Weight::from_parts(0, 12544).saturating_mul(d.into()))
+	}
+	/// Storage: `INV4::JointMultisig` (r:1 w:1)
+	/// Proof: `INV4::JointMultisig` (`max_values`: None, `max_size`: Some(6120742), added: 6123217, mode: `MaxEncodedLen`)
+	fn withdraw_vote_joint_multisig() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `585`
+		//  Estimated: `6124207`
+		// Minimum execution time: 14_000_000 picoseconds.
+		Weight::from_parts(15_000_000, 6124207)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `INV4::JointMultisig` (r:1 w:1)
+	/// Proof: `INV4::JointMultisig` (`max_values`: None, `max_size`: Some(6120742), added: 6123217, mode: `MaxEncodedLen`)
+	fn cancel_joint_multisig_proposal() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `585`
+		//  Estimated: `6124207`
+		// Minimum execution time: 12_000_000 picoseconds.
+		Weight::from_parts(13_000_000, 6124207)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `INV4::CoreStorage` (r:1 w:0)
+	/// Proof: `INV4::CoreStorage` (`max_values`: None, `max_size`: Some(10063), added: 12538, mode: `MaxEncodedLen`)
+	/// Storage: `INV4::DaoByHandle` (r:1 w:2)
+	/// Proof: `INV4::DaoByHandle` (`max_values`: None, `max_size`: Some(53), added: 2528, mode: `MaxEncodedLen`)
+	/// Storage: `INV4::DaoHandles` (r:1 w:1)
+	/// Proof: `INV4::DaoHandles` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	fn set_handle() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `412`
+		//  Estimated: `13528`
+		// Minimum execution time: 24_000_000 picoseconds.
+		Weight::from_parts(26_000_000, 13528)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `INV4::CoreStorage` (r:1 w:0)
+	/// Proof: `INV4::CoreStorage` (`max_values`: None, `max_size`: Some(583), added: 3058, mode: `MaxEncodedLen`)
+	/// Storage: `INV4::TokenMetadata` (r:0 w:1)
+	/// Proof: `INV4::TokenMetadata` (`max_values`: None, `max_size`: Some(58), added: 2533, mode: `MaxEncodedLen`)
+	fn set_token_metadata() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `233`
+		//  Estimated: `4048`
+		// Minimum execution time: 13_000_000 picoseconds.
+		Weight::from_parts(14_000_000, 4048)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `INV4::CoreStorage` (r:1 w:0)
+	/// Proof: `INV4::CoreStorage` (`max_values`: None, `max_size`: Some(583), added: 3058, mode: `MaxEncodedLen`)
+	/// Storage: `INV4::TokenSupplyCap` (r:1 w:1)
+	/// Proof: `INV4::TokenSupplyCap` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `CoreAssets::TotalIssuance` (r:1 w:0)
+	/// Proof: `CoreAssets::TotalIssuance` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	fn set_token_supply_cap() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `286`
+		//  Estimated: `4048`
+		// Minimum execution time: 15_000_000 picoseconds.
+		Weight::from_parts(16_000_000, 4048)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `INV4::TokenSupplyCap` (r:1 w:0)
+	/// Proof: `INV4::TokenSupplyCap` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `INV4::VestingSchedules` (r:1 w:1)
+	/// Proof: `INV4::VestingSchedules` (`max_values`: None, `max_size`: Some(477), added: 2952, mode: `MaxEncodedLen`)
+	/// Storage: `CoreAssets::Accounts` (r:1 w:1)
+	/// Proof: `CoreAssets::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	/// Storage: `CoreAssets::TotalIssuance` (r:1 w:1)
+	/// Proof: `CoreAssets::TotalIssuance` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `INV4::CoreMembers` (r:0 w:1)
+	/// Proof: `INV4::CoreMembers` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	fn token_mint_vested() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `612`
+		//  Estimated: `3942`
+		// Minimum execution time: 27_000_000 picoseconds.
+		Weight::from_parts(29_000_000, 3942)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: `INV4::CoreStorage` (r:1 w:0)
+	/// Proof: `INV4::CoreStorage` (`max_values`: None, `max_size`: Some(583), added: 3058, mode: `MaxEncodedLen`)
+	/// Storage: `INV4::LockedTokensVote` (r:0 w:1)
+	/// Proof: `INV4::LockedTokensVote` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+	fn set_locked_tokens_vote() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `233`
+		//  Estimated: `4048`
+		// Minimum execution time: 11_000_000 picoseconds.
+		Weight::from_parts(12_000_000, 4048)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `INV4::CoreStorage` (r:1 w:1)
+	/// Proof: `INV4::CoreStorage` (`max_values`: None, `max_size`: Some(10721), added: 13196, mode: `MaxEncodedLen`)
+	fn set_second_chamber() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `233`
+		//  Estimated: `14186`
+		// Minimum execution time: 14_000_000 picoseconds.
+		Weight::from_parts(16_000_000, 14186)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `INV4::CoreStorage` (r:1 w:0)
+	/// Proof: `INV4::CoreStorage` (`max_values`: None, `max_size`: Some(10721), added: 13196, mode: `MaxEncodedLen`)
+	/// Storage: `INV4::Multisig` (r:1 w:1)
+	/// Proof: `INV4::Multisig` (`max_values`: None, `max_size`: Some(551342), added: 553817, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(103), added: 2578, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainInfo::ParachainId` (r:1 w:0)
+	/// Proof: `ParachainInfo::ParachainId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `INV4::PublicProposals` (r:0 w:1)
+	/// Proof: `INV4::PublicProposals` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+	/// The range of component `m` is `[0, 10000]`.
+	/// The range of component `z` is `[0, 51190]`.
+	fn operate_public_multisig(m: u32, z: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `412`
+		//  Estimated: `554807`
+		// Minimum execution time: 42_000_000 picoseconds.
+		Weight::from_parts(37_912_301, 554807)
+			// Standard Error: 16
+			.saturating_add(Weight::from_parts(461, 0).saturating_mul(m.into()))
+			// Standard Error: 3
+			.saturating_add(Weight::from_parts(1_514, 0).saturating_mul(z.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `INV4::PublicProposals` (r:1 w:1)
+	/// Proof: `INV4::PublicProposals` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+	/// Storage: `INV4::Multisig` (r:0 w:1)
+	/// Proof: `INV4::Multisig` (`max_values`: None, `max_size`: Some(551342), added: 553817, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(103), added: 2578, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainInfo::ParachainId` (r:1 w:0)
+	/// Proof: `ParachainInfo::ParachainId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	fn expire_public_proposal() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `598`
+		//  Estimated: `6196`
+		// Minimum execution time: 38_000_000 picoseconds.
+		Weight::from_parts(40_000_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: `INV4::CoreStorage` (r:1 w:0)
+	/// Proof: `INV4::CoreStorage` (`max_values`: None, `max_size`: Some(10069), added: 12544, mode: `MaxEncodedLen`)
+	/// Storage: `Uniques::Asset` (r:1 w:0)
+	/// Proof: `Uniques::Asset` (`max_values`: None, `max_size`: Some(122), added: 2597, mode: `MaxEncodedLen`)
+	/// Storage: `Uniques::Attribute` (r:1 w:0)
+	/// Proof: `Uniques::Attribute` (`max_values`: None, `max_size`: Some(172), added: 2647, mode: `MaxEncodedLen`)
+	/// Storage: `INV4::NftVotingPower` (r:0 w:1)
+	/// Proof: `INV4::NftVotingPower` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 1000]`.
+	fn note_nft_voting_power(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `421`
+		//  Estimated: `13534`
+		// Minimum execution time: 12_000_000 picoseconds.
+		Weight::from_parts(13_000_000, 13534)
+			// Standard Error: 2_000
+			.saturating_add(Weight::from_parts(9_500_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(Weight::from_parts(0, 5244).saturating_mul(n.into()))
+	}
+	/// Storage: `INV4::CoreStorage` (r:1 w:0)
+	/// Proof: `INV4::CoreStorage` (`max_values`: None, `max_size`: Some(10069), added: 12544, mode: `MaxEncodedLen`)
+	/// Storage: `INV4::NftProposalVotes` (r:2 w:2)
+	/// Proof: `INV4::NftProposalVotes` (`max_values`: None, `max_size`: Some(120), added: 2595, mode: `MaxEncodedLen`)
+	/// Storage: `Uniques::Account` (r:1 w:0)
+	/// Proof: `Uniques::Account` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `Uniques::Attribute` (r:1 w:0)
+	/// Proof: `Uniques::Attribute` (`max_values`: None, `max_size`: Some(172), added: 2647, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 1000]`.
+	fn note_proposal_voting_power(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `421`
+		//  Estimated: `13534`
+		// Minimum execution time: 14_000_000 picoseconds.
+		Weight::from_parts(15_000_000, 13534)
+			// Standard Error: 3_000
+			.saturating_add(Weight::from_parts(16_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().reads((4_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 7805).saturating_mul(n.into()))
+	}
 }
 
 // For backwards compatibility and tests.
@@ -239,13 +565,15 @@ impl WeightInfo for () {
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// Storage: `INV4::CoreMembers` (r:0 w:1)
 	/// Proof: `INV4::CoreMembers` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	/// Storage: `INV4::TokenSupplyCap` (r:1 w:0)
+	/// Proof: `INV4::TokenSupplyCap` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
 	fn token_mint() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `148`
+		//  Measured:  `180`
 		//  Estimated: `3593`
-		// Minimum execution time: 21_000_000 picoseconds.
-		Weight::from_parts(21_000_000, 3593)
-			.saturating_add(RocksDbWeight::get().reads(3_u64))
+		// Minimum execution time: 22_000_000 picoseconds.
+		Weight::from_parts(23_000_000, 3593)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
 			.saturating_add(RocksDbWeight::get().writes(4_u64))
 	}
 	/// Storage: `CoreAssets::Accounts` (r:1 w:1)
@@ -256,14 +584,16 @@ impl WeightInfo for () {
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// Storage: `INV4::CoreMembers` (r:0 w:1)
 	/// Proof: `INV4::CoreMembers` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	/// Storage: `INV4::VestingSchedules` (r:1 w:1)
+	/// Proof: `INV4::VestingSchedules` (`max_values`: None, `max_size`: Some(477), added: 2952, mode: `MaxEncodedLen`)
 	fn token_burn() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `308`
-		//  Estimated: `3593`
-		// Minimum execution time: 24_000_000 picoseconds.
-		Weight::from_parts(24_000_000, 3593)
-			.saturating_add(RocksDbWeight::get().reads(3_u64))
-			.saturating_add(RocksDbWeight::get().writes(4_u64))
+		//  Estimated: `3942`
+		// Minimum execution time: 26_000_000 picoseconds.
+		Weight::from_parts(27_000_000, 3942)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
 	}
 	/// Storage: `CoreAssets::Accounts` (r:1 w:0)
 	/// Proof: `CoreAssets::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
@@ -332,4 +662,309 @@ impl WeightInfo for () {
 		Weight::from_parts(6_000_000, 0)
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Storage: `INV4::CoreStorage` (r:1 w:0)
+	/// Proof: `INV4::CoreStorage` (`max_values`: None, `max_size`: Some(10063), added: 12538, mode: `MaxEncodedLen`)
+	/// Storage: `INV4::MirroredDaos` (r:1 w:1)
+	/// Proof: `INV4::MirroredDaos` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `INV4::MirrorSource` (r:1 w:1)
+	/// Proof: `INV4::MirrorSource` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	fn set_mirror() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `162`
+		//  Estimated: `13528`
+		// Minimum execution time: 11_000_000 picoseconds.
+		Weight::from_parts(12_000_000, 13528)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `CoreAssets::Accounts` (r:1 w:0)
+	/// Proof: `CoreAssets::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	/// Storage: `INV4::CoreStorage` (r:1 w:0)
+	/// Proof: `INV4::CoreStorage` (`max_values`: None, `max_size`: Some(10063), added: 12538, mode: `MaxEncodedLen`)
+	/// Storage: `CoreAssets::TotalIssuance` (r:1 w:0)
+	/// Proof: `CoreAssets::TotalIssuance` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `INV4::Multisig` (r:1 w:1)
+	/// Proof: `INV4::Multisig` (`max_values`: None, `max_size`: Some(551342), added: 553817, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainInfo::ParachainId` (r:1 w:0)
+	/// Proof: `ParachainInfo::ParachainId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// The range of component `m` is `[0, 10000]`.
+	/// The range of component `z` is `[0, 51190]`.
+	fn operate_emergency_multisig(m: u32, z: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `368`
+		//  Estimated: `554807`
+		// Minimum execution time: 36_000_000 picoseconds.
+		Weight::from_parts(31_214_604, 554807)
+			// Standard Error: 16
+			.saturating_add(Weight::from_parts(459, 0).saturating_mul(m.into()))
+			// Standard Error: 3
+			.saturating_add(Weight::from_parts(1_511, 0).saturating_mul(z.into()))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `INV4::CoreStorage` (r:1 w:1)
+	/// Proof: `INV4::CoreStorage` (`max_values`: None, `max_size`: Some(10069), added: 12544, mode: `MaxEncodedLen`)
+	/// Storage: `Uniques::Class` (r:1 w:0)
+	/// Proof: `Uniques::Class` (`max_values`: None, `max_size`: Some(178), added: 2653, mode: `MaxEncodedLen`)
+	/// Storage: `INV4::NftCollectionDao` (r:1 w:2)
+	/// Proof: `INV4::NftCollectionDao` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	fn set_voting_strategy() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `421`
+		//  Estimated: `13534`
+		// Minimum execution time: 16_000_000 picoseconds.
+		Weight::from_parts(17_000_000, 13534)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `INV4::JointMultisig` (r:1 w:1)
+	/// Proof: `INV4::JointMultisig` (`max_values`: None, `max_size`: Some(6120742), added: 6123217, mode: `MaxEncodedLen`)
+	/// Storage: `INV4::CoreStorage` (r:10 w:0)
+	/// Proof: `INV4::CoreStorage` (`max_values`: None, `max_size`: Some(10069), added: 12544, mode: `MaxEncodedLen`)
+	/// Storage: `CoreAssets::Accounts` (r:10 w:0)
+	/// Proof: `CoreAssets::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	/// Storage: `CoreAssets::TotalIssuance` (r:10 w:0)
+	/// Proof: `CoreAssets::TotalIssuance` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// The range of component `d` is `[2, 10]`.
+	/// The range of component `m` is `[0, 10000]`.
+	/// The range of component `z` is `[0, 51200]`.
+	fn operate_joint_multisig(d: u32, m: u32, z: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `412 + d * (129 ±0)`
+		//  Estimated: `6124207 + d * (12544 ±0)`
+		// Minimum execution time: 48_000_000 picoseconds.
+		Weight::from_parts(29_412_331, 6124207)
+			// Standard Error: 31_207
+			.saturating_add(Weight::from_parts(9_173_552, 0).saturating_mul(d.into()))
+			// Standard Error: 16
+			.saturating_add(Weight::from_parts(462, 0).saturating_mul(m.into()))
+			// Standard Error: 3
+			.saturating_add(Weight::from_parts(1_517, 0).saturating_mul(z.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().reads((3_u64).saturating_mul(d.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(Weight::from_parts(0, 12544).saturating_mul(d.into()))
+	}
+	/// Storage: `INV4::JointMultisig` (r:1 w:1)
+	/// Proof: `INV4::JointMultisig` (`max_values`: None, `max_size`: Some(6120742), added: 6123217, mode: `MaxEncodedLen`)
+	/// Storage: `CoreAssets::Accounts` (r:1 w:0)
+	/// Proof: `CoreAssets::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	/// Storage: `INV4::CoreStorage` (r:10 w:0)
+	/// Proof: `INV4::CoreStorage` (`max_values`: None, `max_size`: Some(10069), added: 12544, mode: `MaxEncodedLen`)
+	/// Storage: `CoreAssets::TotalIssuance` (r:10 w:0)
+	/// Proof: `CoreAssets::TotalIssuance` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// The range of component `d` is `[2, 10]`.
+	fn vote_joint_multisig(d: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `702 + d * (129 ±0)`
+		//  Estimated: `6124207 + d * (12544 ±0)`
+		// Minimum execution time: 31_000_000 picoseconds.
+		Weight::from_parts(22_518_004, 6124207)
+			// Standard Error: 27_114
+			.saturating_add(Weight::from_parts(6_204_913, 0).saturating_mul(d.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(d.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(Weight::from_parts(0, 12544).saturating_mul(d.into()))
+	}
+	/// Storage: `INV4::JointMultisig` (r:1 w:1)
+	/// Proof: `INV4::JointMultisig` (`max_values`: None, `max_size`: Some(6120742), added: 6123217, mode: `MaxEncodedLen`)
+	fn withdraw_vote_joint_multisig() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `585`
+		//  Estimated: `6124207`
+		// Minimum execution time: 14_000_000 picoseconds.
+		Weight::from_parts(15_000_000, 6124207)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `INV4::JointMultisig` (r:1 w:1)
+	/// Proof: `INV4::JointMultisig` (`max_values`: None, `max_size`: Some(6120742), added: 6123217, mode: `MaxEncodedLen`)
+	fn cancel_joint_multisig_proposal() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `585`
+		//  Estimated: `6124207`
+		// Minimum execution time: 12_000_000 picoseconds.
+		Weight::from_parts(13_000_000, 6124207)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `INV4::CoreStorage` (r:1 w:0)
+	/// Proof: `INV4::CoreStorage` (`max_values`: None, `max_size`: Some(10063), added: 12538, mode: `MaxEncodedLen`)
+	/// Storage: `INV4::DaoByHandle` (r:1 w:2)
+	/// Proof: `INV4::DaoByHandle` (`max_values`: None, `max_size`: Some(53), added: 2528, mode: `MaxEncodedLen`)
+	/// Storage: `INV4::DaoHandles` (r:1 w:1)
+	/// Proof: `INV4::DaoHandles` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	fn set_handle() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `412`
+		//  Estimated: `13528`
+		// Minimum execution time: 24_000_000 picoseconds.
+		Weight::from_parts(26_000_000, 13528)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `INV4::CoreStorage` (r:1 w:0)
+	/// Proof: `INV4::CoreStorage` (`max_values`: None, `max_size`: Some(583), added: 3058, mode: `MaxEncodedLen`)
+	/// Storage: `INV4::TokenMetadata` (r:0 w:1)
+	/// Proof: `INV4::TokenMetadata` (`max_values`: None, `max_size`: Some(58), added: 2533, mode: `MaxEncodedLen`)
+	fn set_token_metadata() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `233`
+		//  Estimated: `4048`
+		// Minimum execution time: 13_000_000 picoseconds.
+		Weight::from_parts(14_000_000, 4048)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `INV4::CoreStorage` (r:1 w:0)
+	/// Proof: `INV4::CoreStorage` (`max_values`: None, `max_size`: Some(583), added: 3058, mode: `MaxEncodedLen`)
+	/// Storage: `INV4::TokenSupplyCap` (r:1 w:1)
+	/// Proof: `INV4::TokenSupplyCap` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `CoreAssets::TotalIssuance` (r:1 w:0)
+	/// Proof: `CoreAssets::TotalIssuance` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	fn set_token_supply_cap() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `286`
+		//  Estimated: `4048`
+		// Minimum execution time: 15_000_000 picoseconds.
+		Weight::from_parts(16_000_000, 4048)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `INV4::TokenSupplyCap` (r:1 w:0)
+	/// Proof: `INV4::TokenSupplyCap` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `INV4::VestingSchedules` (r:1 w:1)
+	/// Proof: `INV4::VestingSchedules` (`max_values`: None, `max_size`: Some(477), added: 2952, mode: `MaxEncodedLen`)
+	/// Storage: `CoreAssets::Accounts` (r:1 w:1)
+	/// Proof: `CoreAssets::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	/// Storage: `CoreAssets::TotalIssuance` (r:1 w:1)
+	/// Proof: `CoreAssets::TotalIssuance` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `INV4::CoreMembers` (r:0 w:1)
+	/// Proof: `INV4::CoreMembers` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	fn token_mint_vested() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `612`
+		//  Estimated: `3942`
+		// Minimum execution time: 27_000_000 picoseconds.
+		Weight::from_parts(29_000_000, 3942)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: `INV4::CoreStorage` (r:1 w:0)
+	/// Proof: `INV4::CoreStorage` (`max_values`: None, `max_size`: Some(583), added: 3058, mode: `MaxEncodedLen`)
+	/// Storage: `INV4::LockedTokensVote` (r:0 w:1)
+	/// Proof: `INV4::LockedTokensVote` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+	fn set_locked_tokens_vote() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `233`
+		//  Estimated: `4048`
+		// Minimum execution time: 11_000_000 picoseconds.
+		Weight::from_parts(12_000_000, 4048)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `INV4::CoreStorage` (r:1 w:1)
+	/// Proof: `INV4::CoreStorage` (`max_values`: None, `max_size`: Some(10721), added: 13196, mode: `MaxEncodedLen`)
+	fn set_second_chamber() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `233`
+		//  Estimated: `14186`
+		// Minimum execution time: 14_000_000 picoseconds.
+		Weight::from_parts(16_000_000, 14186)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `INV4::CoreStorage` (r:1 w:0)
+	/// Proof: `INV4::CoreStorage` (`max_values`: None, `max_size`: Some(10721), added: 13196, mode: `MaxEncodedLen`)
+	/// Storage: `INV4::Multisig` (r:1 w:1)
+	/// Proof: `INV4::Multisig` (`max_values`: None, `max_size`: Some(551342), added: 553817, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(103), added: 2578, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainInfo::ParachainId` (r:1 w:0)
+	/// Proof: `ParachainInfo::ParachainId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `INV4::PublicProposals` (r:0 w:1)
+	/// Proof: `INV4::PublicProposals` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+	/// The range of component `m` is `[0, 10000]`.
+	/// The range of component `z` is `[0, 51190]`.
+	fn operate_public_multisig(m: u32, z: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `412`
+		//  Estimated: `554807`
+		// Minimum execution time: 42_000_000 picoseconds.
+		Weight::from_parts(37_912_301, 554807)
+			// Standard Error: 16
+			.saturating_add(Weight::from_parts(461, 0).saturating_mul(m.into()))
+			// Standard Error: 3
+			.saturating_add(Weight::from_parts(1_514, 0).saturating_mul(z.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `INV4::PublicProposals` (r:1 w:1)
+	/// Proof: `INV4::PublicProposals` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+	/// Storage: `INV4::Multisig` (r:0 w:1)
+	/// Proof: `INV4::Multisig` (`max_values`: None, `max_size`: Some(551342), added: 553817, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(103), added: 2578, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainInfo::ParachainId` (r:1 w:0)
+	/// Proof: `ParachainInfo::ParachainId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	fn expire_public_proposal() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `598`
+		//  Estimated: `6196`
+		// Minimum execution time: 38_000_000 picoseconds.
+		Weight::from_parts(40_000_000, 6196)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: `INV4::CoreStorage` (r:1 w:0)
+	/// Proof: `INV4::CoreStorage` (`max_values`: None, `max_size`: Some(10069), added: 12544, mode: `MaxEncodedLen`)
+	/// Storage: `Uniques::Asset` (r:1 w:0)
+	/// Proof: `Uniques::Asset` (`max_values`: None, `max_size`: Some(122), added: 2597, mode: `MaxEncodedLen`)
+	/// Storage: `Uniques::Attribute` (r:1 w:0)
+	/// Proof: `Uniques::Attribute` (`max_values`: None, `max_size`: Some(172), added: 2647, mode: `MaxEncodedLen`)
+	/// Storage: `INV4::NftVotingPower` (r:0 w:1)
+	/// Proof: `INV4::NftVotingPower` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 1000]`.
+	fn note_nft_voting_power(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `421`
+		//  Estimated: `13534`
+		// Minimum execution time: 12_000_000 picoseconds.
+		Weight::from_parts(13_000_000, 13534)
+			// Standard Error: 2_000
+			.saturating_add(Weight::from_parts(9_500_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(Weight::from_parts(0, 5244).saturating_mul(n.into()))
+	}
+	/// Storage: `INV4::CoreStorage` (r:1 w:0)
+	/// Proof: `INV4::CoreStorage` (`max_values`: None, `max_size`: Some(10069), added: 12544, mode: `MaxEncodedLen`)
+	/// Storage: `INV4::NftProposalVotes` (r:2 w:2)
+	/// Proof: `INV4::NftProposalVotes` (`max_values`: None, `max_size`: Some(120), added: 2595, mode: `MaxEncodedLen`)
+	/// Storage: `Uniques::Account` (r:1 w:0)
+	/// Proof: `Uniques::Account` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `Uniques::Attribute` (r:1 w:0)
+	/// Proof: `Uniques::Attribute` (`max_values`: None, `max_size`: Some(172), added: 2647, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 1000]`.
+	fn note_proposal_voting_power(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `421`
+		//  Estimated: `13534`
+		// Minimum execution time: 14_000_000 picoseconds.
+		Weight::from_parts(15_000_000, 13534)
+			// Standard Error: 3_000
+			.saturating_add(Weight::from_parts(16_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().reads((4_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 7805).saturating_mul(n.into()))
+	}
 }
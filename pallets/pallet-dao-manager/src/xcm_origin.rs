@@ -0,0 +1,152 @@
+//! Mirrored DAO XCM origins.
+//!
+//! ## Overview
+//!
+//! DAOs send XCM messages to other chains from their plurality location (`Plurality { id: BodyId::Index(dao_id), part: Voice }`),
+//! which by default only resolves to a sovereign `Signed` origin in the destination chain.
+//! This module allows a local DAO to opt in to being operated by a DAO living in a trusted remote chain, referred to as its mirror source,
+//! and provides the [`DaoPluralityAsMirroredOrigin`] converter used by the runtime's XCM config to turn the
+//! remote plurality into the local [`DaoOrigin`].
+//!
+//! - `inner_set_mirror`: Sets or removes the remote DAO allowed to operate a local DAO.
+//! - [`DaoPluralityAsMirroredOrigin`]: XCM origin converter matching `OriginKind::Native` transacts from a mirrored DAO.
+//! - [`BridgedDaoManagerChains`]: The remote chains trusted by InvArch and Tinkernet, each other through the Polkadot <> Kusama bridge.
+
+use crate::{
+    origin::{ensure_multisig, DaoOrigin, MultisigInternalOrigin},
+    pallet::{self, *},
+};
+use core::marker::PhantomData;
+use frame_support::{pallet_prelude::*, traits::Contains};
+use frame_system::pallet_prelude::*;
+use xcm::v4::{BodyId, BodyPart, Junction, Location, NetworkId, OriginKind};
+use xcm_executor::traits::ConvertOrigin;
+
+impl<T: Config> Pallet<T>
+where
+    Result<DaoOrigin<T>, <T as frame_system::Config>::RuntimeOrigin>:
+        From<<T as frame_system::Config>::RuntimeOrigin>,
+    <T as frame_system::Config>::AccountId: From<[u8; 32]>,
+{
+    /// Inner function for the set_mirror call.
+    pub(crate) fn inner_set_mirror(
+        origin: OriginFor<T>,
+        source: Option<(u32, u32)>,
+    ) -> DispatchResult {
+        let dao_origin = ensure_multisig::<T, OriginFor<T>>(origin)?;
+        let dao_id = dao_origin.id;
+
+        ensure!(
+            CoreStorage::<T>::contains_key(dao_id),
+            Error::<T>::DaoNotFound
+        );
+
+        // A remote dao can only be mirrored by a single local dao.
+        if let Some((para_id, remote_dao_id)) = source {
+            ensure!(
+                MirroredDaos::<T>::get(para_id, remote_dao_id)
+                    .filter(|mirror| *mirror != dao_id)
+                    .is_none(),
+                Error::<T>::MirrorSourceAlreadyInUse
+            );
+        }
+
+        // Clear the previous source before setting the new one.
+        if let Some((para_id, remote_dao_id)) = MirrorSource::<T>::take(dao_id) {
+            MirroredDaos::<T>::remove(para_id, remote_dao_id);
+        }
+
+        if let Some((para_id, remote_dao_id)) = source {
+            MirroredDaos::<T>::insert(para_id, remote_dao_id, dao_id);
+            MirrorSource::<T>::insert(dao_id, (para_id, remote_dao_id));
+        }
+
+        Self::deposit_event(Event::MirrorSet { dao_id, source });
+
+        Ok(())
+    }
+}
+
+/// InvArch parachain id, on Polkadot.
+pub const INVARCH_PARA_ID: u32 = 3340;
+/// Tinkernet parachain id, on Kusama.
+pub const TINKERNET_PARA_ID: u32 = 2125;
+
+/// The chains running the DAO manager whose DAOs can be mirrored, InvArch on Polkadot and Tinkernet on Kusama,
+/// reached through the bridge between both relay chains.
+///
+/// Chains in `LocalNetwork` are never trusted, as the para id would either be the local chain itself or
+/// an unrelated parachain.
+pub struct BridgedDaoManagerChains<LocalNetwork>(PhantomData<LocalNetwork>);
+
+impl<LocalNetwork: Get<NetworkId>> Contains<Location> for BridgedDaoManagerChains<LocalNetwork> {
+    fn contains(location: &Location) -> bool {
+        match location.unpack() {
+            (2, [Junction::GlobalConsensus(network), Junction::Parachain(para_id)]) => {
+                *network != LocalNetwork::get()
+                    && matches!(
+                        (network, *para_id),
+                        (NetworkId::Polkadot, INVARCH_PARA_ID)
+                            | (NetworkId::Kusama, TINKERNET_PARA_ID)
+                    )
+            }
+            _ => false,
+        }
+    }
+}
+
+/// Converts a `Plurality` origin of a DAO in a trusted remote chain into the [`DaoOrigin`] of the local DAO mirroring it.
+///
+/// `TrustedChains` matches the location of the remote chain, which has to end in its `Parachain` junction.
+/// Only `OriginKind::Native` transacts are matched, sovereign account transacts keep being handled by the
+/// usual `SovereignSignedViaLocation` converter.
+pub struct DaoPluralityAsMirroredOrigin<TrustedChains, T, RuntimeOrigin>(
+    PhantomData<(TrustedChains, T, RuntimeOrigin)>,
+);
+
+impl<TrustedChains, T, RuntimeOrigin> ConvertOrigin<RuntimeOrigin>
+    for DaoPluralityAsMirroredOrigin<TrustedChains, T, RuntimeOrigin>
+where
+    TrustedChains: Contains<Location>,
+    T: pallet::Config,
+    T::AccountId: From<[u8; 32]>,
+    RuntimeOrigin: From<DaoOrigin<T>>,
+{
+    fn convert_origin(
+        origin: impl Into<Location>,
+        kind: OriginKind,
+    ) -> Result<RuntimeOrigin, Location> {
+        let origin = origin.into();
+
+        log::trace!(
+            target: "xcm::origin_conversion",
+            "DaoPluralityAsMirroredOrigin origin: {:?}, kind: {:?}",
+            origin, kind,
+        );
+
+        let mirror = match (kind, origin.last()) {
+            (
+                OriginKind::Native,
+                Some(Junction::Plurality {
+                    id: BodyId::Index(remote_dao_id),
+                    part: BodyPart::Voice,
+                }),
+            ) => {
+                let (chain, _) = origin.clone().split_last_interior();
+
+                match chain.last() {
+                    Some(Junction::Parachain(para_id)) if TrustedChains::contains(&chain) => {
+                        MirroredDaos::<T>::get(para_id, remote_dao_id)
+                    }
+                    _ => None,
+                }
+            }
+            _ => None,
+        };
+
+        match mirror {
+            Some(dao_id) => Ok(DaoOrigin::Multisig(MultisigInternalOrigin::new(dao_id)).into()),
+            None => Err(origin),
+        }
+    }
+}
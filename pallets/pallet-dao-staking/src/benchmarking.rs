@@ -3,15 +3,15 @@
 use super::*;
 use crate::Pallet as OcifStaking;
 use core::ops::Add;
-use frame_benchmarking::{benchmarks, whitelisted_caller};
+use frame_benchmarking::{account, benchmarks, whitelisted_caller};
 use frame_support::{
     assert_ok,
-    traits::{fungible::Mutate, Get, OnFinalize, OnInitialize},
-    BoundedVec,
+    traits::{fungible::Mutate, fungibles, Get, OnFinalize, OnInitialize},
 };
 use frame_system::{Pallet as System, RawOrigin};
 use pallet_dao_manager::{
     account_derivation::DaoAccountDerivation,
+    dao_manager_core::DaoMetadata,
     origin::{DaoOrigin, MultisigInternalOrigin},
 };
 use sp_runtime::traits::One;
@@ -21,6 +21,10 @@ fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
     frame_system::Pallet::<T>::assert_last_event(generic_event.into());
 }
 
+fn assert_has_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
+    frame_system::Pallet::<T>::assert_has_event(generic_event.into());
+}
+
 fn derive_account<T>(dao_id: <T as pallet_dao_manager::Config>::DaoId) -> T::AccountId
 where
     T: pallet_dao_manager::Config,
@@ -39,6 +43,21 @@ fn advance_to_era<T: Config>(n: Era) {
     }
 }
 
+fn mock_metadata<T: Config>(n: u32, d: u32, i: u32) -> DaoMetadataOf<T> {
+    DaoMetadata {
+        name: vec![b'a'; n as usize].try_into().unwrap(),
+        description: vec![b'a'; d as usize].try_into().unwrap(),
+        logo: (i > 0).then(|| vec![b'a'; i as usize].try_into().unwrap()),
+        website: (i > 8).then(|| {
+            [b"https://".to_vec(), vec![b'a'; i as usize - 8]]
+                .concat()
+                .try_into()
+                .unwrap()
+        }),
+        ipfs_cid: (i > 0).then(|| vec![b'a'; i as usize].try_into().unwrap()),
+    }
+}
+
 fn mock_register<T: Config>() -> DispatchResultWithPostInfo
 where
     Result<DaoOrigin<T>, <T as frame_system::Config>::RuntimeOrigin>:
@@ -53,9 +72,7 @@ where
 
     OcifStaking::<T>::register_dao(
         DaoOrigin::Multisig(MultisigInternalOrigin::new(0u32.into())).into(),
-        vec![].try_into().unwrap(),
-        vec![].try_into().unwrap(),
-        vec![].try_into().unwrap(),
+        Some(Default::default()),
     )
 }
 
@@ -73,9 +90,7 @@ where
 
     OcifStaking::<T>::register_dao(
         DaoOrigin::Multisig(MultisigInternalOrigin::new(1u32.into())).into(),
-        vec![].try_into().unwrap(),
-        vec![].try_into().unwrap(),
-        vec![].try_into().unwrap(),
+        Some(Default::default()),
     )
 }
 
@@ -114,6 +129,105 @@ where
     )
 }
 
+fn mock_register_and_stake_many<T: Config>(daos: u32)
+where
+    Result<DaoOrigin<T>, <T as frame_system::Config>::RuntimeOrigin>:
+        From<<T as frame_system::Config>::RuntimeOrigin>,
+    <T as frame_system::Config>::RuntimeOrigin: From<DaoOrigin<T>>,
+    T::AccountId: From<[u8; 32]>,
+{
+    let amount = T::StakeThresholdForActiveDao::get() + T::StakeThresholdForActiveDao::get();
+
+    <<T as pallet::Config>::Currency as Mutate<T::AccountId>>::set_balance(
+        &whitelisted_caller(),
+        amount * (daos + 1).into(),
+    );
+
+    for dao in 0..daos {
+        <<T as pallet::Config>::Currency as Mutate<T::AccountId>>::set_balance(
+            &derive_account::<T>(dao.into()),
+            T::RegisterDeposit::get() + T::RegisterDeposit::get(),
+        );
+
+        assert_ok!(OcifStaking::<T>::register_dao(
+            DaoOrigin::Multisig(MultisigInternalOrigin::new(dao.into())).into(),
+            Some(Default::default()),
+        ));
+
+        assert_ok!(OcifStaking::<T>::stake(
+            RawOrigin::Signed(whitelisted_caller()).into(),
+            dao.into(),
+            amount,
+        ));
+    }
+}
+
+fn mock_create_pool<T: Config>(creator: &T::AccountId) -> DispatchResultWithPostInfo
+where
+    Result<DaoOrigin<T>, <T as frame_system::Config>::RuntimeOrigin>:
+        From<<T as frame_system::Config>::RuntimeOrigin>,
+    <T as frame_system::Config>::RuntimeOrigin: From<DaoOrigin<T>>,
+    T::AccountId: From<[u8; 32]>,
+{
+    <<T as pallet::Config>::Currency as Mutate<T::AccountId>>::set_balance(
+        creator,
+        T::StakeThresholdForActiveDao::get()
+            + T::StakeThresholdForActiveDao::get()
+            + T::StakeThresholdForActiveDao::get()
+            + T::PoolDeposit::get(),
+    );
+
+    OcifStaking::<T>::create_pool(
+        RawOrigin::Signed(creator.clone()).into(),
+        0u32.into(),
+        T::StakeThresholdForActiveDao::get() + T::StakeThresholdForActiveDao::get(),
+    )
+}
+
+fn mock_liquid_stake<T: Config>(staker: &T::AccountId) -> DispatchResultWithPostInfo
+where
+    Result<DaoOrigin<T>, <T as frame_system::Config>::RuntimeOrigin>:
+        From<<T as frame_system::Config>::RuntimeOrigin>,
+    <T as frame_system::Config>::RuntimeOrigin: From<DaoOrigin<T>>,
+    T::AccountId: From<[u8; 32]>,
+{
+    <<T as pallet::Config>::Currency as Mutate<T::AccountId>>::set_balance(
+        staker,
+        T::StakeThresholdForActiveDao::get()
+            + T::StakeThresholdForActiveDao::get()
+            + T::StakeThresholdForActiveDao::get(),
+    );
+
+    OcifStaking::<T>::liquid_stake(
+        RawOrigin::Signed(staker.clone()).into(),
+        0u32.into(),
+        T::StakeThresholdForActiveDao::get() + T::StakeThresholdForActiveDao::get(),
+    )
+}
+
+fn mock_era_rewards<T: Config>() {
+    let reward = T::StakeThresholdForActiveDao::get();
+
+    <<T as pallet::Config>::Currency as Mutate<T::AccountId>>::set_balance(
+        &OcifStaking::<T>::account_id(),
+        reward + reward + reward,
+    );
+
+    RewardAccumulator::<T>::put(RewardInfo {
+        stakers: reward,
+        dao: reward,
+    });
+}
+
+fn pending_staker_rewards<T: Config>(
+    dao_id: <T as pallet_dao_manager::Config>::DaoId,
+    staker: &T::AccountId,
+) -> BalanceOf<T> {
+    let dao_stake_info = OcifStaking::<T>::accrued_dao_stake_info(dao_id);
+
+    OcifStaking::<T>::accrued_staker_info(dao_id, staker, &dao_stake_info).pending_rewards
+}
+
 benchmarks! {
     where_clause {
     where
@@ -123,20 +237,19 @@ benchmarks! {
             >: From<<T as frame_system::Config>::RuntimeOrigin>,
     <T as frame_system::Config>::RuntimeOrigin: From<DaoOrigin<T>>,
     T::AccountId: From<[u8; 32]>,
+    ReceiptAssetIdOf<T>: From<u32>,
 
 }
 
     register_dao {
         let n in 0 .. T::MaxNameLength::get();
         let d in 0 .. T::MaxDescriptionLength::get();
-        let i in 0 .. T::MaxImageUrlLength::get();
+        let i in 0 .. T::MaxUrlLength::get();
 
-        let name: BoundedVec<u8, T::MaxNameLength> = vec![u8::MAX; n as usize].try_into().unwrap();
-        let description: BoundedVec<u8, T::MaxDescriptionLength> = vec![u8::MAX; d as usize].try_into().unwrap();
-        let image: BoundedVec<u8, T::MaxImageUrlLength> = vec![u8::MAX; i as usize].try_into().unwrap();
+        let metadata = mock_metadata::<T>(n, d, i);
 
         <<T as pallet::Config>::Currency as Mutate<T::AccountId>>::set_balance(&derive_account::<T>(0u32.into()), T::RegisterDeposit::get() + T::RegisterDeposit::get());
-    }: _(DaoOrigin::Multisig(MultisigInternalOrigin::new(0u32.into())), name, description, image)
+    }: _(DaoOrigin::Multisig(MultisigInternalOrigin::new(0u32.into())), Some(metadata))
     verify {
         assert_last_event::<T>(Event::<T>::DaoRegistered {
             dao: 0u32.into()
@@ -146,28 +259,18 @@ benchmarks! {
     change_dao_metadata {
         let n in 0 .. T::MaxNameLength::get();
         let d in 0 .. T::MaxDescriptionLength::get();
-        let i in 0 .. T::MaxImageUrlLength::get();
+        let i in 0 .. T::MaxUrlLength::get();
 
-        let name: BoundedVec<u8, T::MaxNameLength> = vec![u8::MAX; n as usize].try_into().unwrap();
-        let description: BoundedVec<u8, T::MaxDescriptionLength> = vec![u8::MAX; d as usize].try_into().unwrap();
-        let image: BoundedVec<u8, T::MaxImageUrlLength> = vec![u8::MAX; i as usize].try_into().unwrap();
+        let metadata = mock_metadata::<T>(n, d, i);
 
         mock_register().unwrap();
 
-    }: _(DaoOrigin::Multisig(MultisigInternalOrigin::new(0u32.into())), name.clone(), description.clone(), image.clone())
+    }: _(DaoOrigin::Multisig(MultisigInternalOrigin::new(0u32.into())), metadata.clone())
         verify {
             assert_last_event::<T>(Event::<T>::MetadataChanged {
                 dao: 0u32.into(),
-                old_metadata: DaoMetadata {
-                    name: vec![],
-                    description: vec![],
-                    image: vec![]
-                },
-                new_metadata: DaoMetadata {
-                    name: name.to_vec(),
-                    description: description.to_vec(),
-                    image: image.to_vec()
-                }
+                old_metadata: Default::default(),
+                new_metadata: metadata
             }.into());
         }
 
@@ -234,46 +337,39 @@ benchmarks! {
 
     staker_claim_rewards {
         mock_register().unwrap();
-        mock_stake().unwrap();
         advance_to_era::<T>(One::one());
+        mock_stake().unwrap();
+        mock_era_rewards::<T>();
+        advance_to_era::<T>(2);
 
         let staker: T::AccountId = whitelisted_caller();
-        let amount = T::StakeThresholdForActiveDao::get() + T::StakeThresholdForActiveDao::get();
-
-        let dao_stake_info = OcifStaking::<T>::dao_stake_info::<<T as pallet_dao_manager::Config>::DaoId, Era>(0u32.into(), 0u32).unwrap();
-        let era_info = OcifStaking::<T>::general_era_info::<Era>(0u32).unwrap();
+        let reward = pending_staker_rewards::<T>(0u32.into(), &staker);
 
-        let (_, reward) = OcifStaking::<T>::dao_stakers_split(&dao_stake_info, &era_info);
+        RewardDestinations::<T>::insert(&staker, RewardDestination::Restake);
 
     }: _(RawOrigin::Signed(staker.clone()), 0u32.into())
         verify {
             assert_last_event::<T>(Event::<T>::StakerClaimed {
                 staker,
                 dao: 0u32.into(),
-                era: 0u32.into(),
                 amount: reward
             }.into());
         }
 
     dao_claim_rewards {
         mock_register().unwrap();
-        mock_stake().unwrap();
         advance_to_era::<T>(One::one());
+        mock_stake().unwrap();
+        mock_era_rewards::<T>();
+        advance_to_era::<T>(2);
 
-        let staker: T::AccountId = whitelisted_caller();
-        let amount = T::StakeThresholdForActiveDao::get() + T::StakeThresholdForActiveDao::get();
-
-        let dao_stake_info = OcifStaking::<T>::dao_stake_info::<<T as pallet_dao_manager::Config>::DaoId, Era>(0u32.into(), 0u32).unwrap();
-        let era_info = OcifStaking::<T>::general_era_info::<Era>(0u32).unwrap();
-
-        let (reward, _) = OcifStaking::<T>::dao_stakers_split(&dao_stake_info, &era_info);
+        let reward = OcifStaking::<T>::accrued_dao_stake_info(0u32.into()).pending_rewards;
 
-    }: _(DaoOrigin::Multisig(MultisigInternalOrigin::new(0u32.into())), 0u32.into(), 0u32.into())
+    }: _(DaoOrigin::Multisig(MultisigInternalOrigin::new(0u32.into())), 0u32.into())
         verify {
             assert_last_event::<T>(Event::<T>::DaoClaimed {
                 dao: 0u32.into(),
                 destination_account: derive_account::<T>(0u32.into()),
-                era: 0u32.into(),
                 amount: reward
             }.into());
         }
@@ -302,4 +398,271 @@ benchmarks! {
                 to_dao: 1u32.into()
             }.into());
         }
+
+    claim_all {
+        mock_register().unwrap();
+        advance_to_era::<T>(One::one());
+        mock_stake().unwrap();
+        mock_era_rewards::<T>();
+        advance_to_era::<T>(2);
+
+        let staker: T::AccountId = whitelisted_caller();
+        let reward = pending_staker_rewards::<T>(0u32.into(), &staker);
+
+        RewardDestinations::<T>::insert(&staker, RewardDestination::Restake);
+
+    }: _(RawOrigin::Signed(staker.clone()), 0u32.into())
+        verify {
+            assert_last_event::<T>(Event::<T>::StakerClaimedAll {
+                staker,
+                dao: 0u32.into(),
+                amount: reward
+            }.into());
+        }
+
+    claim_all_daos {
+        let d in 1 .. T::MaxDaosPerClaim::get();
+
+        advance_to_era::<T>(One::one());
+        mock_register_and_stake_many::<T>(d);
+        mock_era_rewards::<T>();
+        advance_to_era::<T>(2);
+
+        let staker: T::AccountId = whitelisted_caller();
+        let amount = (0..d)
+            .map(|dao| pending_staker_rewards::<T>(dao.into(), &staker))
+            .fold(Zero::zero(), |total: BalanceOf<T>, reward| total.saturating_add(reward));
+        let dao_ids: BoundedVec<<T as pallet_dao_manager::Config>::DaoId, T::MaxDaosPerClaim> =
+            (0..d).map(Into::into).collect::<Vec<_>>().try_into().unwrap();
+
+        RewardDestinations::<T>::insert(&staker, RewardDestination::Restake);
+
+    }: _(RawOrigin::Signed(staker.clone()), dao_ids.clone())
+        verify {
+            assert_last_event::<T>(Event::<T>::StakerClaimedAllDaos {
+                staker,
+                daos: dao_ids,
+                amount
+            }.into());
+        }
+
+    set_reward_destination {
+        let staker: T::AccountId = whitelisted_caller();
+
+    }: _(RawOrigin::Signed(staker.clone()), RewardDestination::Restake)
+        verify {
+            assert_last_event::<T>(Event::<T>::RewardDestinationSet {
+                staker,
+                destination: RewardDestination::Restake
+            }.into());
+        }
+
+    set_dao_reward_destination {
+        mock_register().unwrap();
+
+        let destination: T::AccountId = whitelisted_caller();
+
+    }: _(DaoOrigin::Multisig(MultisigInternalOrigin::new(0u32.into())), Some(destination.clone()))
+        verify {
+            assert_last_event::<T>(Event::<T>::DaoRewardDestinationSet {
+                dao: 0u32.into(),
+                destination: Some(destination)
+            }.into());
+        }
+
+    rebond {
+        mock_register().unwrap();
+        mock_stake().unwrap();
+        mock_unstake().unwrap();
+
+        let staker: T::AccountId = whitelisted_caller();
+        let amount = T::StakeThresholdForActiveDao::get() + T::StakeThresholdForActiveDao::get();
+
+    }: _(RawOrigin::Signed(staker.clone()), 0u32.into(), amount)
+        verify {
+            assert_last_event::<T>(Event::<T>::Rebonded {
+                staker,
+                dao: 0u32.into(),
+                amount
+            }.into());
+        }
+
+    instant_unstake {
+        mock_register().unwrap();
+        mock_stake().unwrap();
+
+        let staker: T::AccountId = whitelisted_caller();
+        let amount = T::StakeThresholdForActiveDao::get() + T::StakeThresholdForActiveDao::get();
+
+    }: _(RawOrigin::Signed(staker.clone()), Some(0u32.into()), amount)
+        verify {
+            assert_last_event::<T>(Event::<T>::InstantUnstaked {
+                staker,
+                dao: Some(0u32.into()),
+                amount,
+                penalty: T::InstantUnstakePenalty::get() * amount
+            }.into());
+        }
+
+    transfer_stake {
+        mock_register().unwrap();
+        mock_stake().unwrap();
+
+        let staker: T::AccountId = whitelisted_caller();
+        let to: T::AccountId = account("to", 0, 0);
+        let amount = T::StakeThresholdForActiveDao::get() + T::StakeThresholdForActiveDao::get();
+
+    }: _(RawOrigin::Signed(staker.clone()), 0u32.into(), amount, to.clone())
+        verify {
+            assert_last_event::<T>(Event::<T>::StakeTransferred {
+                from: staker,
+                to,
+                dao: 0u32.into(),
+                amount
+            }.into());
+        }
+
+    create_pool {
+        mock_register().unwrap();
+
+        let creator: T::AccountId = whitelisted_caller();
+        let amount = T::StakeThresholdForActiveDao::get() + T::StakeThresholdForActiveDao::get();
+
+        <<T as pallet::Config>::Currency as Mutate<T::AccountId>>::set_balance(
+            &creator,
+            amount + T::StakeThresholdForActiveDao::get() + T::PoolDeposit::get(),
+        );
+
+    }: _(RawOrigin::Signed(creator.clone()), 0u32.into(), amount)
+        verify {
+            assert_last_event::<T>(Event::<T>::PoolJoined {
+                member: creator,
+                pool: 0,
+                amount
+            }.into());
+        }
+
+    join_pool {
+        mock_register().unwrap();
+        mock_create_pool::<T>(&account("creator", 0, 0)).unwrap();
+
+        let member: T::AccountId = whitelisted_caller();
+        let amount = T::StakeThresholdForActiveDao::get();
+
+        <<T as pallet::Config>::Currency as Mutate<T::AccountId>>::set_balance(
+            &member,
+            amount + amount,
+        );
+
+    }: _(RawOrigin::Signed(member.clone()), 0, amount)
+        verify {
+            assert_last_event::<T>(Event::<T>::PoolJoined {
+                member,
+                pool: 0,
+                amount
+            }.into());
+        }
+
+    leave_pool {
+        let member: T::AccountId = whitelisted_caller();
+
+        mock_register().unwrap();
+        mock_create_pool::<T>(&member).unwrap();
+
+        // Leaving with the whole stake removes the pool.
+        let amount = T::StakeThresholdForActiveDao::get() + T::StakeThresholdForActiveDao::get();
+
+    }: _(RawOrigin::Signed(member.clone()), 0, amount)
+        verify {
+            assert_has_event::<T>(Event::<T>::PoolLeft {
+                member,
+                pool: 0,
+                amount
+            }.into());
+            assert_last_event::<T>(Event::<T>::PoolRemoved { pool: 0 }.into());
+        }
+
+    claim_pool_rewards {
+        let member: T::AccountId = whitelisted_caller();
+
+        mock_register().unwrap();
+        advance_to_era::<T>(One::one());
+        mock_create_pool::<T>(&member).unwrap();
+        mock_era_rewards::<T>();
+        advance_to_era::<T>(2);
+
+        let mut pool = OcifStaking::<T>::pool_info(0).unwrap();
+        pool.distribute(pending_staker_rewards::<T>(0u32.into(), &OcifStaking::<T>::pool_account(0)));
+        let mut member_info = OcifStaking::<T>::pool_member_info(0, &member);
+        member_info.accrue_to(pool.reward_per_stake);
+        let reward = member_info.pending_rewards;
+
+        // Claiming the last rewards of a pool without stake removes the pool.
+        assert_ok!(OcifStaking::<T>::leave_pool(
+            RawOrigin::Signed(member.clone()).into(),
+            0,
+            T::StakeThresholdForActiveDao::get() + T::StakeThresholdForActiveDao::get(),
+        ));
+
+    }: _(RawOrigin::Signed(member.clone()), 0)
+        verify {
+            assert_has_event::<T>(Event::<T>::PoolMemberClaimed {
+                member,
+                pool: 0,
+                amount: reward
+            }.into());
+            assert_last_event::<T>(Event::<T>::PoolRemoved { pool: 0 }.into());
+        }
+
+    enable_liquid_staking {
+        mock_register().unwrap();
+
+    }: _(RawOrigin::Root, 0u32.into(), u32::MAX.into())
+        verify {
+            assert_last_event::<T>(Event::<T>::LiquidStakingEnabled {
+                dao: 0u32.into(),
+                receipt_token: u32::MAX.into()
+            }.into());
+        }
+
+    liquid_stake {
+        mock_register().unwrap();
+        assert_ok!(OcifStaking::<T>::enable_liquid_staking(RawOrigin::Root.into(), 0u32.into(), u32::MAX.into()));
+        advance_to_era::<T>(One::one());
+        mock_liquid_stake::<T>(&account("staker", 0, 0)).unwrap();
+        mock_era_rewards::<T>();
+        advance_to_era::<T>(2);
+
+        let staker: T::AccountId = whitelisted_caller();
+        let amount = T::StakeThresholdForActiveDao::get();
+
+        <<T as pallet::Config>::Currency as Mutate<T::AccountId>>::set_balance(
+            &staker,
+            amount + amount,
+        );
+
+    }: _(RawOrigin::Signed(staker.clone()), 0u32.into(), amount)
+        verify {
+            assert!(!<T::ReceiptAssets as fungibles::Inspect<T::AccountId>>::balance(u32::MAX.into(), &staker).is_zero());
+        }
+
+    redeem {
+        let staker: T::AccountId = whitelisted_caller();
+
+        mock_register().unwrap();
+        assert_ok!(OcifStaking::<T>::enable_liquid_staking(RawOrigin::Root.into(), 0u32.into(), u32::MAX.into()));
+        advance_to_era::<T>(One::one());
+        mock_liquid_stake::<T>(&staker).unwrap();
+        mock_era_rewards::<T>();
+        advance_to_era::<T>(2);
+
+        let receipts = T::StakeThresholdForActiveDao::get();
+
+    }: _(RawOrigin::Signed(staker.clone()), 0u32.into(), receipts)
+        verify {
+            assert_eq!(
+                <T::ReceiptAssets as fungibles::Inspect<T::AccountId>>::balance(u32::MAX.into(), &staker),
+                receipts
+            );
+        }
 }
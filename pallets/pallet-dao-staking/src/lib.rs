@@ -21,6 +21,22 @@
 //! Unlike DAOs, Stakers get their fraction of the rewards based on their own stake and regardless of
 //! the `active` state of the DAO they staked towards.
 //!
+//! ### Nomination pools
+//! Pools stake towards a DAO with their own account on behalf of their members, counting as a single Staker.
+//! Members join and leave a pool with any amount, regardless of `MinimumStakingAmount`,
+//! and share the rewards of the pool pro-rata to their stake.
+//!
+//! ### Liquid staking
+//! DAOs opted in by Root stake through a liquid staking account on behalf of the holders of a receipt token, counting as a single Staker.
+//! Receipt tokens are transferable and the rewards of the liquid staking account are staked back towards the DAO,
+//! so they accrue to whoever holds the receipt tokens through a growing exchange rate. Redeeming them starts the unbonding period.
+//!
+//! ### Rewards
+//! Rewards are tracked with cumulative reward per stake indexes, one for Stakers and one for DAOs, that grow at every era end
+//! by the era's rewards divided by the stake entitled to them.
+//! Stakers and DAOs accrue their rewards whenever their stake changes, so claiming is independent of the number of eras passed
+//! and era rotation doesn't need to touch any DAO.
+//!
 //! ## Relevant runtime configs
 //!
 //! * `BlocksPerEra` - Defines how many blocks constitute an era.
@@ -30,6 +46,10 @@
 //! * `UnbondingPeriod` - Defines the period, in eras, that it takes to unbond a stake.
 //! * `RewardRatio` - Defines the ratio of balance from the pot to distribute to DAOs and Stakers, respectively.
 //! * `StakeThresholdForActiveDao` - Defines the threshold of stake a DAO needs to surpass to become active.
+//! * `MaxDaosPerClaim` - Defines the maximum amount of DAOs a Staker can claim from in a single call.
+//! * `HistoryDepth` - Defines the number of eras of information kept in storage, older eras are pruned in spare block weight.
+//! * `InstantUnstakePenalty` - Defines the fraction of the value charged for skipping the unbonding period.
+//! * `PoolDeposit` - Defines the deposit amount held from the creator of a nomination pool until the pool is removed.
 //!
 //! **Example Runtime implementation can be found in [src/testing/mock.rs](./src/testing/mock.rs)**
 //!
@@ -41,8 +61,22 @@
 //! * `stake` - Stakes tokens towards a DAO.
 //! * `unstake` - Unstakes tokens from a DAO and starts the unbonding period for those tokens.
 //! * `withdraw_unstaked` - Withdraws tokens that have already been through the unbonding period.
+//! * `rebond` - Stakes tokens that are still unbonding back towards a DAO.
+//! * `instant_unstake` - Unstakes tokens from a DAO, or takes tokens that are still unbonding, and unlocks them immediately for a penalty.
+//! * `transfer_stake` - Transfers staked tokens towards a DAO to another account, keeping them staked.
+//! * `create_pool` - Creates a nomination pool staking towards a DAO and joins it.
+//! * `join_pool` - Stakes tokens towards the DAO of a nomination pool through the pool.
+//! * `leave_pool` - Unstakes tokens from a nomination pool and starts the unbonding period for those tokens.
+//! * `claim_pool_rewards` - Claims rewards available for a member of a nomination pool.
+//! * `enable_liquid_staking` - Allows Root to enable liquid staking on a DAO, setting its receipt token.
+//! * `liquid_stake` - Stakes tokens towards a DAO through its liquid staking account, minting receipt tokens.
+//! * `redeem` - Burns receipt tokens of a DAO and starts the unbonding period for the tokens they're worth.
 //! * `staker_claim_rewards` - Claims rewards available for a Staker.
 //! * `dao_claim_rewards` - Claims rewards available for a DAO.
+//! * `claim_all` - Claims every reward a Staker accrued on a DAO since their last claim.
+//! * `claim_all_daos` - Claims rewards available for a Staker from multiple DAOs.
+//! * `set_reward_destination` - Sets where a Staker's rewards are paid to.
+//! * `set_dao_reward_destination` - Sets the account a DAO's rewards are paid to.
 //! * `halt_unhalt_pallet` - Allows Root to trigger a halt of the system, eras will stop counting and rewards won't be distributed.
 //!
 //! [`Call`]: ./enum.Call.html
@@ -58,11 +92,12 @@ use frame_support::{
         fungible::{
             Balanced, Credit, Inspect, InspectFreeze, InspectHold, Mutate, MutateFreeze, MutateHold,
         },
+        fungibles,
         tokens::{Fortitude, Precision, Preservation},
         Get, HandleMessage, Imbalance, InspectLockableCurrency, LockIdentifier, LockableCurrency,
         OnUnbalanced, ProcessMessage, QueuePausedQuery, ReservableCurrency,
     },
-    weights::{Weight, WeightToFee},
+    weights::{Weight, WeightMeter, WeightToFee},
     BoundedSlice, PalletId,
 };
 use frame_system::{ensure_signed, pallet_prelude::*};
@@ -114,17 +149,13 @@ pub mod pallet {
         Credit<<T as frame_system::Config>::AccountId, <T as Config>::Currency>;
 
     /// The current storage version.
-    const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(4);
     #[pallet::pallet]
     #[pallet::storage_version(STORAGE_VERSION)]
     pub struct Pallet<T>(PhantomData<T>);
 
-    /// The dao metadata type of this pallet.
-    pub type DaoMetadataOf<T> = DaoMetadata<
-        BoundedVec<u8, <T as Config>::MaxNameLength>,
-        BoundedVec<u8, <T as Config>::MaxDescriptionLength>,
-        BoundedVec<u8, <T as Config>::MaxImageUrlLength>,
-    >;
+    /// The dao metadata type of this pallet, shared with the dao manager.
+    pub type DaoMetadataOf<T> = pallet_dao_manager::dao_manager_core::DaoMetadataOf<T>;
 
     /// The dao information type, containing a dao's AccountId and DaoMetadataOf.
     pub type DaoInfoOf<T> = DaoInfo<<T as frame_system::Config>::AccountId, DaoMetadataOf<T>>;
@@ -132,6 +163,21 @@ pub mod pallet {
     /// Alias type for the era identifier type.
     pub type Era = u32;
 
+    /// Alias type for the nomination pool identifier type.
+    pub type PoolId = u32;
+
+    /// The nomination pool information type of this pallet.
+    pub type PoolInfoOf<T> = PoolInfo<
+        <T as frame_system::Config>::AccountId,
+        <T as pallet_dao_manager::Config>::DaoId,
+        BalanceOf<T>,
+    >;
+
+    /// The asset identifier type of the liquid staking receipt tokens.
+    pub type ReceiptAssetIdOf<T> = <<T as Config>::ReceiptAssets as fungibles::Inspect<
+        <T as frame_system::Config>::AccountId,
+    >>::AssetId;
+
     #[pallet::config]
     pub trait Config:
         frame_system::Config + pallet_dao_manager::Config + pallet_message_queue::Config
@@ -202,16 +248,6 @@ pub mod pallet {
         #[pallet::constant]
         type UnbondingPeriod: Get<u32>;
 
-        /// Max number of unique `EraStake` values that can exist for a `(staker, dao)` pairing.
-        ///
-        /// When stakers claims rewards, they will either keep the number of `EraStake` values the same or they will reduce them by one.
-        /// Stakers cannot add an additional `EraStake` value by calling `bond&stake` or `unbond&unstake` if they've reached the max number of values.
-        ///
-        /// This ensures that history doesn't grow indefinitely - if there are too many chunks, stakers should first claim their former rewards
-        /// before adding additional `EraStake` values.
-        #[pallet::constant]
-        type MaxEraStakeValues: Get<u32>;
-
         /// Reward ratio of the pot to be distributed between the dao and stakers, respectively.
         #[pallet::constant]
         type RewardRatio: Get<(u32, u32)>;
@@ -220,17 +256,28 @@ pub mod pallet {
         #[pallet::constant]
         type StakeThresholdForActiveDao: Get<BalanceOf<Self>>;
 
-        /// Maximum length of a dao's name.
+        /// Maximum number of daos a staker can claim rewards from in a single call.
         #[pallet::constant]
-        type MaxNameLength: Get<u32>;
+        type MaxDaosPerClaim: Get<u32>;
 
-        /// Maximum length of a dao's description.
+        /// Number of past eras whose information is kept in storage.
+        /// Older eras are pruned when blocks have weight to spare, rewards don't depend on them.
         #[pallet::constant]
-        type MaxDescriptionLength: Get<u32>;
+        type HistoryDepth: Get<u32>;
 
-        /// Maximum length of a dao's image URL.
+        /// Fraction of the value charged for unlocking it without going through the unbonding period.
+        /// The penalty is handed to `OnUnbalanced`.
         #[pallet::constant]
-        type MaxImageUrlLength: Get<u32>;
+        type InstantUnstakePenalty: Get<Perbill>;
+
+        /// Deposit held from the creator of a nomination pool.
+        /// It's released once the pool has no stake and no member with rewards left, removing the pool.
+        #[pallet::constant]
+        type PoolDeposit: Get<BalanceOf<Self>>;
+
+        /// Assets the liquid staking receipt tokens are minted in.
+        type ReceiptAssets: fungibles::Inspect<Self::AccountId, Balance = BalanceOf<Self>>
+            + fungibles::Mutate<Self::AccountId>;
 
         /// Weight information for extrinsics in this pallet.
         type WeightInfo: WeightInfo;
@@ -277,17 +324,26 @@ pub mod pallet {
     #[pallet::getter(fn general_era_info)]
     pub type GeneralEraInfo<T: Config> = StorageMap<_, Twox64Concat, Era, EraInfo<BalanceOf<T>>>;
 
-    /// Staking information about a dao in a particular era.
+    /// Oldest era whose general information is still in storage.
+    #[pallet::storage]
+    #[pallet::getter(fn oldest_era)]
+    pub type OldestEra<T> = StorageValue<_, Era, ValueQuery>;
+
+    /// Cursor of the removal of the legacy dao stake rows, present while the removal is in progress.
+    #[pallet::storage]
+    pub(crate) type LegacyEraStakeCursor<T> =
+        StorageValue<_, BoundedVec<u8, ConstU32<128>>, OptionQuery>;
+
+    /// Cumulative rewards distributed per unit of stake.
+    #[pallet::storage]
+    #[pallet::getter(fn reward_per_stake)]
+    pub type RewardPerStakeIndex<T> = StorageValue<_, RewardPerStake, ValueQuery>;
+
+    /// Staking information about a dao.
     #[pallet::storage]
     #[pallet::getter(fn dao_stake_info)]
-    pub type CoreEraStake<T: Config> = StorageDoubleMap<
-        _,
-        Blake2_128Concat,
-        T::DaoId,
-        Twox64Concat,
-        Era,
-        DaoStakeInfo<BalanceOf<T>>,
-    >;
+    pub type CoreStake<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::DaoId, DaoStakeInfo<BalanceOf<T>>, ValueQuery>;
 
     /// Info about staker's stakes on a particular dao.
     #[pallet::storage]
@@ -302,6 +358,47 @@ pub mod pallet {
         ValueQuery,
     >;
 
+    /// Nomination pools.
+    #[pallet::storage]
+    #[pallet::getter(fn pool_info)]
+    pub type Pools<T: Config> = StorageMap<_, Twox64Concat, PoolId, PoolInfoOf<T>, OptionQuery>;
+
+    /// Id of the next nomination pool to be created.
+    #[pallet::storage]
+    #[pallet::getter(fn next_pool_id)]
+    pub type NextPoolId<T> = StorageValue<_, PoolId, ValueQuery>;
+
+    /// Info about members' stakes on a particular nomination pool.
+    #[pallet::storage]
+    #[pallet::getter(fn pool_member_info)]
+    pub type PoolMembers<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        PoolId,
+        Blake2_128Concat,
+        T::AccountId,
+        StakerInfo<BalanceOf<T>>,
+        ValueQuery,
+    >;
+
+    /// Receipt token of the daos liquid staking is enabled on.
+    #[pallet::storage]
+    #[pallet::getter(fn receipt_token)]
+    pub type ReceiptTokens<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::DaoId, ReceiptAssetIdOf<T>, OptionQuery>;
+
+    /// Where a staker's rewards are paid to.
+    #[pallet::storage]
+    #[pallet::getter(fn reward_destination)]
+    pub type RewardDestinations<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, RewardDestination<T::AccountId>, ValueQuery>;
+
+    /// Account a dao's rewards are paid to, if not the dao account.
+    #[pallet::storage]
+    #[pallet::getter(fn dao_reward_destination)]
+    pub type DaoRewardDestinations<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::DaoId, T::AccountId, OptionQuery>;
+
     /// Denotes whether the pallet is halted (disabled).
     #[pallet::storage]
     #[pallet::getter(fn is_halted)]
@@ -360,15 +457,46 @@ pub mod pallet {
         StakerClaimed {
             staker: T::AccountId,
             dao: T::DaoId,
-            era: u32,
             amount: BalanceOf<T>,
         },
 
+        /// Staker claimed every reward accrued on a dao since their last claim.
+        StakerClaimedAll {
+            staker: T::AccountId,
+            dao: T::DaoId,
+            amount: BalanceOf<T>,
+        },
+
+        /// Staker claimed the rewards accrued on multiple daos, `amount` is the total claimed.
+        StakerClaimedAllDaos {
+            staker: T::AccountId,
+            daos: BoundedVec<T::DaoId, T::MaxDaosPerClaim>,
+            amount: BalanceOf<T>,
+        },
+
+        /// Staker's claimed rewards were staked towards the dao they were earned from.
+        RewardRestaked {
+            staker: T::AccountId,
+            dao: T::DaoId,
+            amount: BalanceOf<T>,
+        },
+
+        /// Staker changed the destination of their rewards.
+        RewardDestinationSet {
+            staker: T::AccountId,
+            destination: RewardDestination<T::AccountId>,
+        },
+
+        /// DAO changed the account its rewards are paid to.
+        DaoRewardDestinationSet {
+            dao: T::DaoId,
+            destination: Option<T::AccountId>,
+        },
+
         /// Rewards claimed for dao.
         DaoClaimed {
             dao: T::DaoId,
             destination_account: T::AccountId,
-            era: u32,
             amount: BalanceOf<T>,
         },
 
@@ -378,8 +506,8 @@ pub mod pallet {
         /// DAO metadata changed.
         MetadataChanged {
             dao: T::DaoId,
-            old_metadata: DaoMetadata<Vec<u8>, Vec<u8>, Vec<u8>>,
-            new_metadata: DaoMetadata<Vec<u8>, Vec<u8>, Vec<u8>>,
+            old_metadata: DaoMetadataOf<T>,
+            new_metadata: DaoMetadataOf<T>,
         },
 
         /// Staker moved an amount of stake to another dao.
@@ -389,6 +517,71 @@ pub mod pallet {
             to_dao: T::DaoId,
             amount: BalanceOf<T>,
         },
+        /// Staker rebonded unbonding funds to a dao.
+        Rebonded {
+            staker: T::AccountId,
+            dao: T::DaoId,
+            amount: BalanceOf<T>,
+        },
+        /// Staker unlocked funds without going through the unbonding period.
+        InstantUnstaked {
+            staker: T::AccountId,
+            dao: Option<T::DaoId>,
+            amount: BalanceOf<T>,
+            penalty: BalanceOf<T>,
+        },
+        /// Staker transferred an amount of stake on a dao to another account.
+        StakeTransferred {
+            from: T::AccountId,
+            to: T::AccountId,
+            dao: T::DaoId,
+            amount: BalanceOf<T>,
+        },
+        /// Nomination pool created.
+        PoolCreated {
+            pool: PoolId,
+            dao: T::DaoId,
+            creator: T::AccountId,
+        },
+        /// Account has staked funds through a nomination pool.
+        PoolJoined {
+            member: T::AccountId,
+            pool: PoolId,
+            amount: BalanceOf<T>,
+        },
+        /// Account has unstaked funds from a nomination pool.
+        PoolLeft {
+            member: T::AccountId,
+            pool: PoolId,
+            amount: BalanceOf<T>,
+        },
+        /// Nomination pool member claimed rewards.
+        PoolMemberClaimed {
+            member: T::AccountId,
+            pool: PoolId,
+            amount: BalanceOf<T>,
+        },
+        /// Nomination pool removed after its last member left, releasing the creator's deposit.
+        PoolRemoved { pool: PoolId },
+        /// Liquid staking enabled on a dao.
+        LiquidStakingEnabled {
+            dao: T::DaoId,
+            receipt_token: ReceiptAssetIdOf<T>,
+        },
+        /// Account has staked funds to a dao through its liquid staking account.
+        LiquidStaked {
+            staker: T::AccountId,
+            dao: T::DaoId,
+            amount: BalanceOf<T>,
+            receipts: BalanceOf<T>,
+        },
+        /// Account has redeemed receipt tokens of a dao, unstaking the funds they're worth.
+        Redeemed {
+            staker: T::AccountId,
+            dao: T::DaoId,
+            receipts: BalanceOf<T>,
+            amount: BalanceOf<T>,
+        },
         /// DAO is being unregistered.
         DaoUnregistrationQueueStarted { dao: T::DaoId },
         /// DAO ungregistration chunk was processed.
@@ -426,18 +619,8 @@ pub mod pallet {
         NothingToWithdraw,
         /// DAO already registered.
         DaoAlreadyRegistered,
-        /// Unknown rewards for era.
-        UnknownEraReward,
-        /// Unexpected stake info for era.
-        UnexpectedStakeInfoEra,
         /// Too many unlocking chunks.
         TooManyUnlockingChunks,
-        /// Reward already claimed.
-        RewardAlreadyClaimed,
-        /// Incorrect era.
-        IncorrectEra,
-        /// Too many era stake values.
-        TooManyEraStakeValues,
         /// Not a staker.
         NotAStaker,
         /// No permission.
@@ -456,12 +639,37 @@ pub mod pallet {
         NoHaltChange,
         /// Attempted to move stake to the same dao.
         MoveStakeToSameDao,
+        /// DAO metadata is not valid.
+        InvalidMetadata,
+        /// No claimable rewards available.
+        NothingToClaim,
+        /// No unbonding balance available to rebond.
+        NothingToRebond,
+        /// Attempted to transfer stake to the same account.
+        TransferStakeToSameAccount,
+        /// Nomination pool not found.
+        PoolNotFound,
+        /// Attempted to transfer stake to a nomination pool account.
+        TransferStakeToPool,
+        /// Liquid staking is not enabled on the dao.
+        LiquidStakingNotEnabled,
+        /// Liquid staking is already enabled on the dao.
+        LiquidStakingAlreadyEnabled,
+        /// Receipt token already has issuance.
+        ReceiptTokenInUse,
+        /// Amount is worth less than a unit of the receipt token.
+        ReceiptAmountTooLow,
+        /// Attempted to transfer stake to a liquid staking account.
+        TransferStakeToLiquidAccount,
+        /// The stake is being migrated, the halt state can't be changed until it's done.
+        MigrationInProgress,
     }
 
     /// A reason for the pallet contracts placing a hold on funds.
     #[pallet::composite_enum]
     pub enum HoldReason {
         DaoStaking,
+        PoolDeposit,
     }
 
     #[pallet::hooks]
@@ -483,17 +691,34 @@ pub mod pallet {
 
                 NextEraStartingBlock::<T>::put(now + blocks_per_era);
 
+                // Rotating the era only touches global storage, dao and staker infos are
+                // accrued lazily from the reward per stake index the next time they're accessed.
                 let reward = RewardAccumulator::<T>::take();
-                let (consumed_weight, new_active_stake) = Self::rotate_staking_info(previous_era);
-                Self::reward_balance_snapshot(previous_era, reward, new_active_stake);
+                Self::reward_balance_snapshot(previous_era, reward);
 
                 Self::deposit_event(Event::<T>::NewEra { era: next_era });
 
-                consumed_weight + T::DbWeight::get().reads_writes(5, 3)
+                T::DbWeight::get().reads_writes(6, 6)
             } else {
                 T::DbWeight::get().reads(3)
             }
         }
+
+        fn on_idle(_now: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+            let mut meter = WeightMeter::with_limit(remaining_weight);
+
+            if meter.try_consume(T::DbWeight::get().reads(1)).is_err() {
+                return Weight::zero();
+            }
+
+            // History is only pruned after the stake migration, which reads the legacy rows and past eras.
+            match migrations::v3::RewardPerStakeMigration::<T>::get() {
+                Some(progress) => migrations::v3::migrate_step::<T>(progress, &mut meter),
+                None => meter.consume(Self::prune_history(meter.remaining())),
+            }
+
+            meter.consumed()
+        }
     }
 
     #[pallet::call]
@@ -509,22 +734,26 @@ pub mod pallet {
         ///
         /// As part of this call, `RegisterDeposit` will be reserved from the dao account.
         ///
-        /// - `name`: Name of the dao.
-        /// - `description`: Description of the dao.
-        /// - `image`: Image URL of the dao.
+        /// - `metadata`: Metadata of the dao, if `None` the metadata set in the dao manager is used.
         #[pallet::call_index(0)]
         #[pallet::weight(
-            <T as Config>::WeightInfo::register_dao(
-                name.len() as u32,
-                description.len() as u32,
-                image.len() as u32
-            )
+            match metadata {
+                Some(metadata) => <T as Config>::WeightInfo::register_dao(
+                    metadata.name.len() as u32,
+                    metadata.description.len() as u32,
+                    Pallet::<T>::longest_metadata_link(metadata)
+                ),
+                None => <T as Config>::WeightInfo::register_dao(
+                    T::MaxNameLength::get(),
+                    T::MaxDescriptionLength::get(),
+                    T::MaxUrlLength::get()
+                )
+                .saturating_add(T::DbWeight::get().reads(1)),
+            }
         )]
         pub fn register_dao(
             origin: OriginFor<T>,
-            name: BoundedVec<u8, T::MaxNameLength>,
-            description: BoundedVec<u8, T::MaxDescriptionLength>,
-            image: BoundedVec<u8, T::MaxImageUrlLength>,
+            metadata: Option<DaoMetadataOf<T>>,
         ) -> DispatchResultWithPostInfo {
             Self::ensure_not_halted()?;
 
@@ -537,10 +766,16 @@ pub mod pallet {
                 Error::<T>::DaoAlreadyRegistered,
             );
 
-            let metadata: DaoMetadataOf<T> = DaoMetadata {
-                name,
-                description,
-                image,
+            let metadata = match metadata {
+                Some(metadata) => {
+                    ensure!(metadata.is_valid(), Error::<T>::InvalidMetadata);
+                    metadata
+                }
+                None => {
+                    pallet_dao_manager::CoreStorage::<T>::get(dao_id)
+                        .ok_or(Error::<T>::DaoNotFound)?
+                        .metadata
+                }
             };
 
             <T as pallet::Config>::Currency::hold(
@@ -602,15 +837,20 @@ pub mod pallet {
 
             UnregisteredCoreStakers::<T>::insert(dao_id, all_stakers);
 
-            let mut dao_stake_info = Self::dao_stake_info(dao_id, current_era).unwrap_or_default();
-            UnregisteredCoreStakeInfo::<T>::insert(dao_id, dao_stake_info.clone());
+            // The stakers reward per stake index of the dao stays frozen from here onwards.
+            let mut dao_stake_info = Self::accrued_dao_stake_info(dao_id);
             GeneralEraInfo::<T>::mutate(current_era, |value| {
                 if let Some(x) = value {
                     x.staked = x.staked.saturating_sub(dao_stake_info.total);
+                    if dao_stake_info.active {
+                        x.active_stake = x.active_stake.saturating_sub(dao_stake_info.total);
+                    }
                 }
             });
+            dao_stake_info.active = false;
+            UnregisteredCoreStakeInfo::<T>::insert(dao_id, dao_stake_info.clone());
             dao_stake_info.total = Zero::zero();
-            CoreEraStake::<T>::insert(dao_id, current_era, dao_stake_info.clone());
+            CoreStake::<T>::insert(dao_id, dao_stake_info.clone());
 
             let reserve_deposit = T::RegisterDeposit::get();
 
@@ -660,55 +900,39 @@ pub mod pallet {
         ///
         /// The origin has to be the dao origin.
         ///
-        /// - `name`: Name of the dao.
-        /// - `description`: Description of the dao.
-        /// - `image`: Image URL of the dao.
+        /// - `metadata`: New metadata of the dao.
         #[pallet::call_index(2)]
         #[pallet::weight(
             <T as Config>::WeightInfo::change_dao_metadata(
-                name.len() as u32,
-                description.len() as u32,
-                image.len() as u32
+                metadata.name.len() as u32,
+                metadata.description.len() as u32,
+                Pallet::<T>::longest_metadata_link(metadata)
             )
         )]
         pub fn change_dao_metadata(
             origin: OriginFor<T>,
-            name: BoundedVec<u8, T::MaxNameLength>,
-            description: BoundedVec<u8, T::MaxDescriptionLength>,
-            image: BoundedVec<u8, T::MaxImageUrlLength>,
+            metadata: DaoMetadataOf<T>,
         ) -> DispatchResultWithPostInfo {
             Self::ensure_not_halted()?;
 
             let dao_origin = ensure_multisig::<T, OriginFor<T>>(origin)?;
             let dao_id = dao_origin.id;
 
+            ensure!(metadata.is_valid(), Error::<T>::InvalidMetadata);
+
             RegisteredCore::<T>::try_mutate(dao_id, |dao| {
                 let mut new_dao = dao.take().ok_or(Error::<T>::NotRegistered)?;
 
-                let new_metadata: DaoMetadataOf<T> = DaoMetadata {
-                    name: name.clone(),
-                    description: description.clone(),
-                    image: image.clone(),
-                };
-
                 let old_metadata = new_dao.metadata;
 
-                new_dao.metadata = new_metadata;
+                new_dao.metadata = metadata.clone();
 
                 *dao = Some(new_dao);
 
                 Self::deposit_event(Event::<T>::MetadataChanged {
                     dao: dao_id,
-                    old_metadata: DaoMetadata {
-                        name: old_metadata.name.into_inner(),
-                        description: old_metadata.description.into_inner(),
-                        image: old_metadata.image.into_inner(),
-                    },
-                    new_metadata: DaoMetadata {
-                        name: name.to_vec(),
-                        description: description.to_vec(),
-                        image: image.to_vec(),
-                    },
+                    old_metadata,
+                    new_metadata: metadata,
                 });
 
                 Ok(().into())
@@ -744,14 +968,15 @@ pub mod pallet {
             ensure!(value_to_stake > Zero::zero(), Error::<T>::StakingNothing);
 
             let current_era = Self::current_era();
-            let mut staking_info = Self::dao_stake_info(dao_id, current_era).unwrap_or_default();
-            let mut staker_info = Self::staker_info(dao_id, &staker);
+            let mut staking_info = Self::accrued_dao_stake_info(dao_id);
+            let mut staker_info = Self::accrued_staker_info(dao_id, &staker, &staking_info);
 
             Self::internal_stake(
+                dao_id,
                 &mut staker_info,
                 &mut staking_info,
                 value_to_stake,
-                current_era,
+                T::MinimumStakingAmount::get(),
             )?;
 
             ledger.locked = ledger.locked.saturating_add(value_to_stake);
@@ -765,7 +990,7 @@ pub mod pallet {
 
             Self::update_ledger(&staker, ledger)?;
             Self::update_staker_info(&staker, dao_id, staker_info);
-            CoreEraStake::<T>::insert(dao_id, current_era, staking_info);
+            CoreStake::<T>::insert(dao_id, staking_info);
 
             Self::deposit_event(Event::<T>::Staked {
                 staker,
@@ -800,11 +1025,16 @@ pub mod pallet {
             ensure!(Self::dao_info(dao_id).is_some(), Error::<T>::NotRegistered);
 
             let current_era = Self::current_era();
-            let mut staker_info = Self::staker_info(dao_id, &staker);
-            let mut dao_stake_info = Self::dao_stake_info(dao_id, current_era).unwrap_or_default();
+            let mut dao_stake_info = Self::accrued_dao_stake_info(dao_id);
+            let mut staker_info = Self::accrued_staker_info(dao_id, &staker, &dao_stake_info);
 
-            let value_to_unstake =
-                Self::internal_unstake(&mut staker_info, &mut dao_stake_info, value, current_era)?;
+            let value_to_unstake = Self::internal_unstake(
+                dao_id,
+                &mut staker_info,
+                &mut dao_stake_info,
+                value,
+                T::MinimumStakingAmount::get(),
+            )?;
 
             let mut ledger = Self::ledger(&staker);
             ledger.unbonding_info.add(UnlockingChunk {
@@ -825,7 +1055,7 @@ pub mod pallet {
                 }
             });
             Self::update_staker_info(&staker, dao_id, staker_info);
-            CoreEraStake::<T>::insert(dao_id, current_era, dao_stake_info);
+            CoreStake::<T>::insert(dao_id, dao_stake_info);
 
             Self::deposit_event(Event::<T>::Unstaked {
                 staker,
@@ -877,7 +1107,7 @@ pub mod pallet {
 
         /// Claim the staker's rewards.
         ///
-        /// In case reward cannot be claimed or was already claimed, an error is raised.
+        /// Every reward accrued since the last claim is paid out at once, in case there's none an error is raised.
         ///
         /// The dispatch origin for this call must be _Signed_ by the staker's account.
         ///
@@ -892,83 +1122,32 @@ pub mod pallet {
 
             let staker = ensure_signed(origin)?;
 
-            let mut staker_info = Self::staker_info(dao_id, &staker);
-            let (era, staked) = staker_info.claim();
-            ensure!(staked > Zero::zero(), Error::<T>::NoStakeAvailable);
-
-            let current_era = Self::current_era();
-            ensure!(era < current_era, Error::<T>::IncorrectEra);
-
-            let staking_info = Self::dao_stake_info(dao_id, era).unwrap_or_default();
-
-            let mut staker_reward = Zero::zero();
-
-            if staking_info.total > Zero::zero() {
-                let reward_and_stake =
-                    Self::general_era_info(era).ok_or(Error::<T>::UnknownEraReward)?;
-
-                let (_, stakers_joint_reward) =
-                    Self::dao_stakers_split(&staking_info, &reward_and_stake);
-                staker_reward =
-                    Perbill::from_rational(staked, staking_info.total) * stakers_joint_reward;
-
-                let reward_imbalance = <T as pallet::Config>::Currency::withdraw(
-                    &Self::account_id(),
-                    staker_reward,
-                    Precision::Exact,
-                    Preservation::Expendable,
-                    Fortitude::Force,
-                )?;
-
-                <T as pallet::Config>::Currency::resolve(&staker, reward_imbalance)
-                    .map_err(|_| Error::<T>::NothingToWithdraw)?;
-                Self::update_staker_info(&staker, dao_id, staker_info);
-            }
+            let staker_reward = Self::internal_claim(&staker, dao_id)?;
 
-            Self::deposit_event(Event::<T>::StakerClaimed {
-                staker,
-                dao: dao_id,
-                era,
-                amount: staker_reward,
-            });
+            ensure!(!staker_reward.is_zero(), Error::<T>::NothingToClaim);
 
             Ok(().into())
         }
 
-        /// Claim dao reward for the specified era.
+        /// Claim the dao's rewards.
         ///
-        /// In case reward cannot be claimed or was already claimed, an error is raised.
+        /// Every reward accrued since the last claim is paid out at once, in case there's none an error is raised.
         ///
         /// - `dao_id`: Id of the dao to claim rewards from.
-        /// - `era`: Era for which rewards are to be claimed.
         #[pallet::call_index(7)]
         #[pallet::weight(<T as Config>::WeightInfo::dao_claim_rewards())]
         pub fn dao_claim_rewards(
             origin: OriginFor<T>,
             dao_id: T::DaoId,
-            #[pallet::compact] era: Era,
         ) -> DispatchResultWithPostInfo {
             Self::ensure_not_halted()?;
 
             ensure_signed(origin)?;
 
-            let current_era = Self::current_era();
-            ensure!(era < current_era, Error::<T>::IncorrectEra);
-
-            let mut dao_stake_info = Self::dao_stake_info(dao_id, era).unwrap_or_default();
-            ensure!(
-                !dao_stake_info.reward_claimed,
-                Error::<T>::RewardAlreadyClaimed,
-            );
-            ensure!(
-                dao_stake_info.total > Zero::zero(),
-                Error::<T>::NoStakeAvailable,
-            );
+            let mut dao_stake_info = Self::accrued_dao_stake_info(dao_id);
+            let reward = sp_std::mem::take(&mut dao_stake_info.pending_rewards);
 
-            let reward_and_stake =
-                Self::general_era_info(era).ok_or(Error::<T>::UnknownEraReward)?;
-
-            let (reward, _) = Self::dao_stakers_split(&dao_stake_info, &reward_and_stake);
+            ensure!(!reward.is_zero(), Error::<T>::NothingToClaim);
 
             let reward_imbalance = <T as pallet::Config>::Currency::withdraw(
                 &Self::account_id(),
@@ -978,22 +1157,21 @@ pub mod pallet {
                 Fortitude::Force,
             )?;
 
-            let dao_account =
+            let destination_account = Self::dao_reward_destination(dao_id).unwrap_or_else(|| {
                 <pallet_dao_manager::Pallet<T> as DaoAccountDerivation<T>>::derive_dao_account(
                     dao_id,
-                );
+                )
+            });
 
-            <T as pallet::Config>::Currency::resolve(&dao_account, reward_imbalance)
+            <T as pallet::Config>::Currency::resolve(&destination_account, reward_imbalance)
                 .map_err(|_| Error::<T>::NothingToWithdraw)?;
             Self::deposit_event(Event::<T>::DaoClaimed {
                 dao: dao_id,
-                destination_account: dao_account,
-                era,
+                destination_account,
                 amount: reward,
             });
 
-            dao_stake_info.reward_claimed = true;
-            CoreEraStake::<T>::insert(dao_id, era, dao_stake_info);
+            CoreStake::<T>::insert(dao_id, dao_stake_info);
 
             Ok(().into())
         }
@@ -1008,6 +1186,11 @@ pub mod pallet {
         pub fn halt_unhalt_pallet(origin: OriginFor<T>, halt: bool) -> DispatchResultWithPostInfo {
             ensure_root(origin)?;
 
+            ensure!(
+                !migrations::v3::RewardPerStakeMigration::<T>::exists(),
+                Error::<T>::MigrationInProgress
+            );
+
             let is_halted = Self::is_halted();
 
             ensure!(is_halted ^ halt, Error::<T>::NoHaltChange);
@@ -1041,31 +1224,41 @@ pub mod pallet {
             ensure!(from_dao != to_dao, Error::<T>::MoveStakeToSameDao);
             ensure!(Self::dao_info(to_dao).is_some(), Error::<T>::NotRegistered);
 
-            let current_era = Self::current_era();
-            let mut from_staker_info = Self::staker_info(from_dao, &staker);
-            let mut from_dao_info = Self::dao_stake_info(from_dao, current_era).unwrap_or_default();
+            let mut from_dao_info = Self::accrued_dao_stake_info(from_dao);
+            let mut from_staker_info = Self::accrued_staker_info(from_dao, &staker, &from_dao_info);
 
             let unstaked_amount = Self::internal_unstake(
+                from_dao,
                 &mut from_staker_info,
                 &mut from_dao_info,
                 amount,
-                current_era,
+                T::MinimumStakingAmount::get(),
             )?;
 
-            let mut to_staker_info = Self::staker_info(to_dao, &staker);
-            let mut to_dao_info = Self::dao_stake_info(to_dao, current_era).unwrap_or_default();
+            let mut to_dao_info = Self::accrued_dao_stake_info(to_dao);
+            let mut to_staker_info = Self::accrued_staker_info(to_dao, &staker, &to_dao_info);
 
             Self::internal_stake(
+                to_dao,
                 &mut to_staker_info,
                 &mut to_dao_info,
                 unstaked_amount,
-                current_era,
+                T::MinimumStakingAmount::get(),
             )?;
 
-            CoreEraStake::<T>::insert(from_dao, current_era, from_dao_info);
+            // Stake of an unregistered dao was already taken out of the era's stake.
+            if Self::dao_info(from_dao).is_none() {
+                GeneralEraInfo::<T>::mutate(Self::current_era(), |value| {
+                    if let Some(x) = value {
+                        x.staked = x.staked.saturating_add(unstaked_amount);
+                    }
+                });
+            }
+
+            CoreStake::<T>::insert(from_dao, from_dao_info);
             Self::update_staker_info(&staker, from_dao, from_staker_info);
 
-            CoreEraStake::<T>::insert(to_dao, current_era, to_dao_info);
+            CoreStake::<T>::insert(to_dao, to_dao_info);
             Self::update_staker_info(&staker, to_dao, to_staker_info);
 
             Self::deposit_event(Event::<T>::StakeMoved {
@@ -1077,133 +1270,1450 @@ pub mod pallet {
 
             Ok(().into())
         }
-    }
 
-    impl<T: Config> Pallet<T> {
-        /// Internal function responsible for validating a stake and updating in-place
-        /// both the staker's and dao staking info.
-        fn internal_stake(
-            staker_info: &mut StakerInfo<BalanceOf<T>>,
-            staking_info: &mut DaoStakeInfo<BalanceOf<T>>,
-            amount: BalanceOf<T>,
-            current_era: Era,
-        ) -> Result<(), Error<T>> {
-            ensure!(
-                !staker_info.latest_staked_value().is_zero()
-                    || staking_info.number_of_stakers < T::MaxStakersPerDao::get(),
-                Error::<T>::MaxStakersReached
-            );
-            if staker_info.latest_staked_value().is_zero() {
-                staking_info.number_of_stakers = staking_info.number_of_stakers.saturating_add(1);
-            }
+        /// Claim every reward the staker accrued on a dao since their last claim.
+        ///
+        /// Rewards are accrued through the reward per stake index, so this is the same as `staker_claim_rewards`
+        /// regardless of the number of eras passed, in case there's nothing to claim an error is raised.
+        ///
+        /// The dispatch origin for this call must be _Signed_ by the staker's account.
+        ///
+        /// - `dao_id`: Id of the dao to claim rewards from.
+        #[pallet::call_index(10)]
+        #[pallet::weight(<T as Config>::WeightInfo::claim_all())]
+        pub fn claim_all(origin: OriginFor<T>, dao_id: T::DaoId) -> DispatchResultWithPostInfo {
+            Self::ensure_not_halted()?;
 
-            staker_info
-                .stake(current_era, amount)
-                .map_err(|_| Error::<T>::UnexpectedStakeInfoEra)?;
-            ensure!(
-                staker_info.len() < T::MaxEraStakeValues::get(),
-                Error::<T>::TooManyEraStakeValues
-            );
-            ensure!(
-                staker_info.latest_staked_value() >= T::MinimumStakingAmount::get(),
-                Error::<T>::InsufficientBalance,
-            );
+            let staker = ensure_signed(origin)?;
 
-            let new_total = staking_info.total.saturating_add(amount);
+            let staker_reward = Self::internal_claim(&staker, dao_id)?;
 
-            staking_info.total = new_total;
+            ensure!(!staker_reward.is_zero(), Error::<T>::NothingToClaim);
 
-            Ok(())
+            Self::deposit_event(Event::<T>::StakerClaimedAll {
+                staker,
+                dao: dao_id,
+                amount: staker_reward,
+            });
+
+            Ok(().into())
         }
 
-        /// Internal function responsible for validating an unstake and updating in-place
-        /// both the staker's and dao staking info.
-        fn internal_unstake(
-            staker_info: &mut StakerInfo<BalanceOf<T>>,
-            dao_stake_info: &mut DaoStakeInfo<BalanceOf<T>>,
-            amount: BalanceOf<T>,
-            current_era: Era,
-        ) -> Result<BalanceOf<T>, Error<T>> {
-            let staked_value = staker_info.latest_staked_value();
-            ensure!(staked_value > Zero::zero(), Error::<T>::NoStakeAvailable);
+        /// Claim the staker's rewards from multiple daos.
+        ///
+        /// In case there are no rewards to claim from any of the daos, an error is raised.
+        /// Besides the claim event of every dao, a summary event with the total claimed is emitted.
+        ///
+        /// The dispatch origin for this call must be _Signed_ by the staker's account.
+        ///
+        /// - `dao_ids`: Ids of the daos to claim rewards from.
+        #[pallet::call_index(11)]
+        #[pallet::weight(<T as Config>::WeightInfo::claim_all_daos(dao_ids.len() as u32))]
+        pub fn claim_all_daos(
+            origin: OriginFor<T>,
+            dao_ids: BoundedVec<T::DaoId, T::MaxDaosPerClaim>,
+        ) -> DispatchResultWithPostInfo {
+            Self::ensure_not_halted()?;
 
-            let remaining = staked_value.saturating_sub(amount);
-            let value_to_unstake = if remaining < T::MinimumStakingAmount::get() {
-                dao_stake_info.number_of_stakers =
-                    dao_stake_info.number_of_stakers.saturating_sub(1);
-                staked_value
-            } else {
-                amount
-            };
+            let staker = ensure_signed(origin)?;
 
-            let new_total = dao_stake_info.total.saturating_sub(value_to_unstake);
+            let mut staker_reward: BalanceOf<T> = Zero::zero();
 
-            dao_stake_info.total = new_total;
+            for dao_id in dao_ids.iter() {
+                staker_reward =
+                    staker_reward.saturating_add(Self::internal_claim(&staker, *dao_id)?);
+            }
 
-            ensure!(
-                value_to_unstake > Zero::zero(),
-                Error::<T>::UnstakingNothing
-            );
+            ensure!(!staker_reward.is_zero(), Error::<T>::NothingToClaim);
 
-            staker_info
-                .unstake(current_era, value_to_unstake)
-                .map_err(|_| Error::<T>::UnexpectedStakeInfoEra)?;
-            ensure!(
-                staker_info.len() < T::MaxEraStakeValues::get(),
-                Error::<T>::TooManyEraStakeValues
-            );
+            Self::deposit_event(Event::<T>::StakerClaimedAllDaos {
+                staker,
+                daos: dao_ids,
+                amount: staker_reward,
+            });
 
-            Ok(value_to_unstake)
+            Ok(().into())
         }
 
-        pub(crate) fn account_id() -> T::AccountId {
-            T::PotId::get().into_account_truncating()
-        }
+        /// Set where the staker's rewards are paid to.
+        ///
+        /// The dispatch origin for this call must be _Signed_ by the staker's account.
+        ///
+        /// - `destination`: Destination of the staker's future reward claims.
+        #[pallet::call_index(12)]
+        #[pallet::weight(<T as Config>::WeightInfo::set_reward_destination())]
+        pub fn set_reward_destination(
+            origin: OriginFor<T>,
+            destination: RewardDestination<T::AccountId>,
+        ) -> DispatchResultWithPostInfo {
+            let staker = ensure_signed(origin)?;
 
-        /// Update the ledger for a staker. This will also update the stash lock.
-        /// This lock will lock the entire funds except paying for further transactions.
-        fn update_ledger(
-            staker: &T::AccountId,
-            ledger: AccountLedger<BalanceOf<T>>,
-        ) -> DispatchResult {
-            if ledger.is_empty() {
-                Ledger::<T>::remove(staker);
-                let _ = <T as pallet::Config>::Currency::thaw(&LOCK_ID, staker);
+            if destination == RewardDestination::FreeBalance {
+                RewardDestinations::<T>::remove(&staker);
             } else {
-                <T as pallet::Config>::Currency::set_freeze(&LOCK_ID, staker, ledger.locked)?;
-                Ledger::<T>::insert(staker, ledger);
+                RewardDestinations::<T>::insert(&staker, destination.clone());
             }
-            Ok(())
+
+            Self::deposit_event(Event::<T>::RewardDestinationSet {
+                staker,
+                destination,
+            });
+
+            Ok(().into())
         }
 
-        /// The block rewards are accumulated on the pallet's account during an era.
-        /// This function takes a snapshot of the pallet's balance accrued during current era
-        /// and stores it for future distribution
+        /// Set the account the dao's rewards are paid to.
         ///
-        /// This is called just at the beginning of an era.
-        fn reward_balance_snapshot(
-            era: Era,
-            rewards: RewardInfo<BalanceOf<T>>,
-            new_active_stake: BalanceOf<T>,
+        /// The origin has to be the dao origin.
+        ///
+        /// - `destination`: Account to pay the dao's future reward claims to, `None` for the dao account.
+        #[pallet::call_index(13)]
+        #[pallet::weight(<T as Config>::WeightInfo::set_dao_reward_destination())]
+        pub fn set_dao_reward_destination(
+            origin: OriginFor<T>,
+            destination: Option<T::AccountId>,
+        ) -> DispatchResultWithPostInfo {
+            let dao_origin = ensure_multisig::<T, OriginFor<T>>(origin)?;
+            let dao_id = dao_origin.id;
+
+            ensure!(Self::dao_info(dao_id).is_some(), Error::<T>::NotRegistered);
+
+            DaoRewardDestinations::<T>::set(dao_id, destination.clone());
+
+            Self::deposit_event(Event::<T>::DaoRewardDestinationSet {
+                dao: dao_id,
+                destination,
+            });
+
+            Ok(().into())
+        }
+
+        /// Stake balance that is still unbonding back towards a dao.
+        ///
+        /// The value is taken out of the unlocking chunks starting from the newest one,
+        /// chunks that already finished unbonding can be rebonded as long as they haven't been withdrawn.
+        ///
+        /// The dispatch origin for this call must be _Signed_ by the staker's account.
+        ///
+        /// - `dao_id`: Id of the dao to stake towards.
+        /// - `value`: Maximum amount to rebond.
+        #[pallet::call_index(14)]
+        #[pallet::weight(<T as Config>::WeightInfo::rebond())]
+        pub fn rebond(
+            origin: OriginFor<T>,
+            dao_id: T::DaoId,
+            #[pallet::compact] value: BalanceOf<T>,
+        ) -> DispatchResultWithPostInfo {
+            Self::ensure_not_halted()?;
+
+            let staker = ensure_signed(origin)?;
+
+            ensure!(Self::dao_info(dao_id).is_some(), Error::<T>::NotRegistered);
+
+            let mut ledger = Self::ledger(&staker);
+            let value_to_rebond = ledger.unbonding_info.rebond(value);
+
+            ensure!(value_to_rebond > Zero::zero(), Error::<T>::NothingToRebond);
+
+            let current_era = Self::current_era();
+            let mut dao_stake_info = Self::accrued_dao_stake_info(dao_id);
+            let mut staker_info = Self::accrued_staker_info(dao_id, &staker, &dao_stake_info);
+
+            Self::internal_stake(
+                dao_id,
+                &mut staker_info,
+                &mut dao_stake_info,
+                value_to_rebond,
+                T::MinimumStakingAmount::get(),
+            )?;
+
+            // Unbonding balance is still part of the locked balance, so only the staked amount grows.
+            GeneralEraInfo::<T>::mutate(current_era, |value| {
+                if let Some(x) = value {
+                    x.staked = x.staked.saturating_add(value_to_rebond);
+                }
+            });
+
+            Self::update_ledger(&staker, ledger)?;
+            Self::update_staker_info(&staker, dao_id, staker_info);
+            CoreStake::<T>::insert(dao_id, dao_stake_info);
+
+            Self::deposit_event(Event::<T>::Rebonded {
+                staker,
+                dao: dao_id,
+                amount: value_to_rebond,
+            });
+
+            Ok(().into())
+        }
+
+        /// Unlock funds immediately instead of going through the unbonding period, paying a penalty.
+        ///
+        /// With a `dao_id` the value is unstaked from that dao, following the same rules as `unstake`,
+        /// otherwise it's taken out of the staker's chunks still unbonding starting from the newest one,
+        /// and the chunks already past the unbonding period are withdrawn without a penalty.
+        /// `InstantUnstakePenalty` of the unlocked value is withdrawn from the staker and handed to `OnUnbalanced`.
+        ///
+        /// The dispatch origin for this call must be _Signed_ by the staker's account.
+        ///
+        /// - `dao_id`: Id of the dao to unstake from, `None` to take the value from the unlocking chunks.
+        /// - `value`: Amount to unlock.
+        #[pallet::call_index(15)]
+        #[pallet::weight(<T as Config>::WeightInfo::instant_unstake())]
+        pub fn instant_unstake(
+            origin: OriginFor<T>,
+            dao_id: Option<T::DaoId>,
+            #[pallet::compact] value: BalanceOf<T>,
+        ) -> DispatchResultWithPostInfo {
+            Self::ensure_not_halted()?;
+
+            let staker = ensure_signed(origin)?;
+
+            ensure!(value > Zero::zero(), Error::<T>::UnstakingNothing);
+
+            let current_era = Self::current_era();
+            let mut ledger = Self::ledger(&staker);
+            let mut withdraw_amount: BalanceOf<T> = Zero::zero();
+
+            let value_to_unlock = if let Some(dao_id) = dao_id {
+                ensure!(Self::dao_info(dao_id).is_some(), Error::<T>::NotRegistered);
+
+                let mut dao_stake_info = Self::accrued_dao_stake_info(dao_id);
+                let mut staker_info = Self::accrued_staker_info(dao_id, &staker, &dao_stake_info);
+
+                let value_to_unstake = Self::internal_unstake(
+                    dao_id,
+                    &mut staker_info,
+                    &mut dao_stake_info,
+                    value,
+                    T::MinimumStakingAmount::get(),
+                )?;
+
+                GeneralEraInfo::<T>::mutate(current_era, |value| {
+                    if let Some(x) = value {
+                        x.staked = x.staked.saturating_sub(value_to_unstake);
+                    }
+                });
+                Self::update_staker_info(&staker, dao_id, staker_info);
+                CoreStake::<T>::insert(dao_id, dao_stake_info);
+
+                value_to_unstake
+            } else {
+                let (valid_chunks, mut future_chunks) =
+                    sp_std::mem::take(&mut ledger.unbonding_info).partition(current_era);
+                withdraw_amount = valid_chunks.sum();
+
+                // Same order as rebonding, leaving the chunks closest to unlocking in place.
+                let value_to_take = future_chunks.rebond(value);
+
+                ensure!(
+                    !value_to_take.is_zero() || !withdraw_amount.is_zero(),
+                    Error::<T>::NothingToWithdraw
+                );
+
+                ledger.unbonding_info = future_chunks;
+
+                value_to_take
+            };
+
+            let total_unlocked = value_to_unlock.saturating_add(withdraw_amount);
+            ledger.locked = ledger.locked.saturating_sub(total_unlocked);
+
+            Self::update_ledger(&staker, ledger)?;
+            GeneralEraInfo::<T>::mutate(current_era, |value| {
+                if let Some(x) = value {
+                    x.locked = x.locked.saturating_sub(total_unlocked)
+                }
+            });
+
+            if !withdraw_amount.is_zero() {
+                Self::deposit_event(Event::<T>::Withdrawn {
+                    staker: staker.clone(),
+                    amount: withdraw_amount,
+                });
+            }
+
+            if value_to_unlock.is_zero() {
+                return Ok(().into());
+            }
+
+            let penalty = T::InstantUnstakePenalty::get() * value_to_unlock;
+
+            if !penalty.is_zero() {
+                T::OnUnbalanced::on_unbalanced(<T as Config>::Currency::withdraw(
+                    &staker,
+                    penalty,
+                    Precision::Exact,
+                    Preservation::Expendable,
+                    Fortitude::Polite,
+                )?);
+            }
+
+            Self::deposit_event(Event::<T>::InstantUnstaked {
+                staker,
+                dao: dao_id,
+                amount: value_to_unlock,
+                penalty,
+            });
+
+            Ok(().into())
+        }
+
+        /// Transfer stake on a dao to another account.
+        ///
+        /// The staked funds are transferred and locked in the receiving account right away, skipping the unbonding period.
+        /// Rewards accrued up to this point stay with the sender.
+        /// Stake can't be transferred to nomination pool or liquid staking accounts, as none of their members would own it.
+        ///
+        /// The dispatch origin for this call must be _Signed_ by the staker's account.
+        ///
+        /// - `dao_id`: Id of the dao the stake is on.
+        /// - `amount`: Amount to transfer.
+        /// - `to`: Account to transfer the stake to.
+        #[pallet::call_index(16)]
+        #[pallet::weight(<T as Config>::WeightInfo::transfer_stake())]
+        pub fn transfer_stake(
+            origin: OriginFor<T>,
+            dao_id: T::DaoId,
+            #[pallet::compact] amount: BalanceOf<T>,
+            to: T::AccountId,
+        ) -> DispatchResultWithPostInfo {
+            Self::ensure_not_halted()?;
+
+            let staker = ensure_signed(origin)?;
+
+            ensure!(staker != to, Error::<T>::TransferStakeToSameAccount);
+            ensure!(!Self::is_pool_account(&to), Error::<T>::TransferStakeToPool);
+            ensure!(
+                !Self::is_liquid_account(&to),
+                Error::<T>::TransferStakeToLiquidAccount
+            );
+            ensure!(Self::dao_info(dao_id).is_some(), Error::<T>::NotRegistered);
+
+            let mut dao_stake_info = Self::accrued_dao_stake_info(dao_id);
+            let mut from_staker_info = Self::accrued_staker_info(dao_id, &staker, &dao_stake_info);
+            let mut to_staker_info = Self::accrued_staker_info(dao_id, &to, &dao_stake_info);
+
+            let transferred_amount = Self::internal_unstake(
+                dao_id,
+                &mut from_staker_info,
+                &mut dao_stake_info,
+                amount,
+                T::MinimumStakingAmount::get(),
+            )?;
+
+            Self::internal_stake(
+                dao_id,
+                &mut to_staker_info,
+                &mut dao_stake_info,
+                transferred_amount,
+                T::MinimumStakingAmount::get(),
+            )?;
+
+            // The sender's lock has to be lowered before the funds can leave the account.
+            let mut from_ledger = Self::ledger(&staker);
+            from_ledger.locked = from_ledger.locked.saturating_sub(transferred_amount);
+            Self::update_ledger(&staker, from_ledger)?;
+
+            <T as Config>::Currency::transfer(
+                &staker,
+                &to,
+                transferred_amount,
+                Preservation::Preserve,
+            )?;
+
+            let mut to_ledger = Self::ledger(&to);
+            to_ledger.locked = to_ledger.locked.saturating_add(transferred_amount);
+            Self::update_ledger(&to, to_ledger)?;
+
+            Self::update_staker_info(&staker, dao_id, from_staker_info);
+            Self::update_staker_info(&to, dao_id, to_staker_info);
+            CoreStake::<T>::insert(dao_id, dao_stake_info);
+
+            Self::deposit_event(Event::<T>::StakeTransferred {
+                from: staker,
+                to,
+                dao: dao_id,
+                amount: transferred_amount,
+            });
+
+            Ok(().into())
+        }
+
+        /// Create a nomination pool staking towards a dao, joining it with an initial stake.
+        ///
+        /// The initial stake has to be at least `MinimumStakingAmount`, after that members can join and leave with any amount.
+        /// `PoolDeposit` is held from the creator until the pool is removed, once it has no stake and no member with rewards left.
+        ///
+        /// The dispatch origin for this call must be _Signed_.
+        ///
+        /// - `dao_id`: Id of the dao the pool stakes towards.
+        /// - `value`: Initial stake of the creator.
+        #[pallet::call_index(17)]
+        #[pallet::weight(<T as Config>::WeightInfo::create_pool())]
+        pub fn create_pool(
+            origin: OriginFor<T>,
+            dao_id: T::DaoId,
+            #[pallet::compact] value: BalanceOf<T>,
+        ) -> DispatchResultWithPostInfo {
+            Self::ensure_not_halted()?;
+
+            let creator = ensure_signed(origin)?;
+
+            ensure!(Self::dao_info(dao_id).is_some(), Error::<T>::NotRegistered);
+
+            let available_balance =
+                Self::available_staking_balance(&creator, &Self::ledger(&creator))
+                    .saturating_sub(T::PoolDeposit::get());
+
+            ensure!(
+                value.min(available_balance) >= T::MinimumStakingAmount::get(),
+                Error::<T>::InsufficientBalance
+            );
+
+            <T as pallet::Config>::Currency::hold(
+                &HoldReason::PoolDeposit.into(),
+                &creator,
+                T::PoolDeposit::get(),
+            )?;
+
+            let pool_id = Self::next_pool_id();
+
+            Pools::<T>::insert(
+                pool_id,
+                PoolInfo {
+                    creator: creator.clone(),
+                    dao: dao_id,
+                    total: Zero::zero(),
+                    number_of_members: 0,
+                    reward_per_stake: Default::default(),
+                },
+            );
+            NextPoolId::<T>::put(pool_id.saturating_add(1));
+
+            Self::deposit_event(Event::<T>::PoolCreated {
+                pool: pool_id,
+                dao: dao_id,
+                creator: creator.clone(),
+            });
+
+            Self::internal_join_pool(&creator, pool_id, value)?;
+
+            Ok(().into())
+        }
+
+        /// Stake towards the dao of a nomination pool through the pool.
+        ///
+        /// The funds are transferred to the pool account and locked there, any amount is accepted.
+        ///
+        /// The dispatch origin for this call must be _Signed_ by the member's account.
+        ///
+        /// - `pool_id`: Id of the pool to join.
+        /// - `value`: Amount to stake.
+        #[pallet::call_index(18)]
+        #[pallet::weight(<T as Config>::WeightInfo::join_pool())]
+        pub fn join_pool(
+            origin: OriginFor<T>,
+            pool_id: PoolId,
+            #[pallet::compact] value: BalanceOf<T>,
+        ) -> DispatchResultWithPostInfo {
+            Self::ensure_not_halted()?;
+
+            let member = ensure_signed(origin)?;
+
+            Self::internal_join_pool(&member, pool_id, value)?;
+
+            Ok(().into())
+        }
+
+        /// Unstake funds from a nomination pool.
+        ///
+        /// The funds are transferred back to the member and go through the unbonding period
+        /// in the member's own ledger, to be withdrawn via the `withdraw_unstaked` call.
+        ///
+        /// The dispatch origin for this call must be _Signed_ by the member's account.
+        ///
+        /// - `pool_id`: Id of the pool to leave.
+        /// - `value`: Maximum amount to unstake.
+        #[pallet::call_index(19)]
+        #[pallet::weight(<T as Config>::WeightInfo::leave_pool())]
+        pub fn leave_pool(
+            origin: OriginFor<T>,
+            pool_id: PoolId,
+            #[pallet::compact] value: BalanceOf<T>,
+        ) -> DispatchResultWithPostInfo {
+            Self::ensure_not_halted()?;
+
+            let member = ensure_signed(origin)?;
+
+            let (mut pool, mut dao_stake_info, mut pool_staker_info) = Self::accrued_pool(pool_id)?;
+            let mut member_info = Self::pool_member_info(pool_id, &member);
+            member_info.accrue_to(pool.reward_per_stake);
+
+            ensure!(
+                member_info.staked > Zero::zero(),
+                Error::<T>::NoStakeAvailable
+            );
+
+            let value_to_unstake = value.min(member_info.staked);
+
+            ensure!(
+                value_to_unstake > Zero::zero(),
+                Error::<T>::UnstakingNothing
+            );
+
+            let current_era = Self::current_era();
+            let pool_account = Self::pool_account(pool_id);
+            let mut pool_ledger = Self::ledger(&pool_account);
+
+            if !pool_staker_info.staked.is_zero() {
+                Self::internal_unstake(
+                    pool.dao,
+                    &mut pool_staker_info,
+                    &mut dao_stake_info,
+                    value_to_unstake,
+                    Zero::zero(),
+                )?;
+
+                // Stake of an unregistered dao was already taken out of the era's stake.
+                if Self::dao_info(pool.dao).is_some() {
+                    GeneralEraInfo::<T>::mutate(current_era, |value| {
+                        if let Some(x) = value {
+                            x.staked = x.staked.saturating_sub(value_to_unstake);
+                        }
+                    });
+                }
+            } else {
+                // The dao was unregistered and the pool's stake is already unbonding.
+                ensure!(
+                    pool_ledger.unbonding_info.rebond(value_to_unstake) == value_to_unstake,
+                    Error::<T>::NoStakeAvailable
+                );
+            }
+
+            pool_ledger.locked = pool_ledger.locked.saturating_sub(value_to_unstake);
+            Self::update_ledger(&pool_account, pool_ledger)?;
+
+            <T as Config>::Currency::transfer(
+                &pool_account,
+                &member,
+                value_to_unstake,
+                Preservation::Expendable,
+            )?;
+
+            let mut ledger = Self::ledger(&member);
+            ledger.locked = ledger.locked.saturating_add(value_to_unstake);
+            ledger.unbonding_info.add(UnlockingChunk {
+                amount: value_to_unstake,
+                unlock_era: current_era + T::UnbondingPeriod::get(),
+            });
+
+            ensure!(
+                ledger.unbonding_info.len() <= T::MaxUnlocking::get(),
+                Error::<T>::TooManyUnlockingChunks
+            );
+
+            Self::update_ledger(&member, ledger)?;
+
+            member_info.staked = member_info.staked.saturating_sub(value_to_unstake);
+            if member_info.staked.is_zero() {
+                pool.number_of_members = pool.number_of_members.saturating_sub(1);
+            }
+            pool.total = pool.total.saturating_sub(value_to_unstake);
+
+            Self::update_pool_member(pool_id, &member, member_info);
+            Self::update_staker_info(&pool_account, pool.dao, pool_staker_info);
+            CoreStake::<T>::insert(pool.dao, dao_stake_info);
+
+            Self::deposit_event(Event::<T>::PoolLeft {
+                member,
+                pool: pool_id,
+                amount: value_to_unstake,
+            });
+
+            Self::update_pool(pool_id, pool)?;
+
+            Ok(().into())
+        }
+
+        /// Claim the rewards of a nomination pool member.
+        ///
+        /// Every reward accrued since the last claim is paid out at once to the member's account,
+        /// in case there's none an error is raised.
+        ///
+        /// The dispatch origin for this call must be _Signed_ by the member's account.
+        ///
+        /// - `pool_id`: Id of the pool to claim rewards from.
+        #[pallet::call_index(20)]
+        #[pallet::weight(<T as Config>::WeightInfo::claim_pool_rewards())]
+        pub fn claim_pool_rewards(
+            origin: OriginFor<T>,
+            pool_id: PoolId,
+        ) -> DispatchResultWithPostInfo {
+            Self::ensure_not_halted()?;
+
+            let member = ensure_signed(origin)?;
+
+            let (pool, dao_stake_info, pool_staker_info) = Self::accrued_pool(pool_id)?;
+            let mut member_info = Self::pool_member_info(pool_id, &member);
+            member_info.accrue_to(pool.reward_per_stake);
+
+            let reward = member_info.take_rewards();
+
+            ensure!(!reward.is_zero(), Error::<T>::NothingToClaim);
+
+            let reward_imbalance = <T as pallet::Config>::Currency::withdraw(
+                &Self::account_id(),
+                reward,
+                Precision::Exact,
+                Preservation::Expendable,
+                Fortitude::Force,
+            )?;
+
+            <T as pallet::Config>::Currency::resolve(&member, reward_imbalance)
+                .map_err(|_| Error::<T>::NothingToWithdraw)?;
+
+            Self::update_pool_member(pool_id, &member, member_info);
+            Self::update_staker_info(&Self::pool_account(pool_id), pool.dao, pool_staker_info);
+            CoreStake::<T>::insert(pool.dao, dao_stake_info);
+
+            Self::deposit_event(Event::<T>::PoolMemberClaimed {
+                member,
+                pool: pool_id,
+                amount: reward,
+            });
+
+            Self::update_pool(pool_id, pool)?;
+
+            Ok(().into())
+        }
+
+        /// Enable liquid staking on a dao, setting the receipt token minted to its liquid stakers.
+        ///
+        /// The receipt token has to be an asset dedicated to the dao, it can't have any issuance
+        /// and from here onwards it's only minted and burned by this pallet.
+        ///
+        /// The dispatch origin for this call must be _Root_.
+        ///
+        /// - `dao_id`: Id of the dao to enable liquid staking on.
+        /// - `receipt_token`: Id of the asset to mint as receipt token.
+        #[pallet::call_index(21)]
+        #[pallet::weight(<T as Config>::WeightInfo::enable_liquid_staking())]
+        pub fn enable_liquid_staking(
+            origin: OriginFor<T>,
+            dao_id: T::DaoId,
+            receipt_token: ReceiptAssetIdOf<T>,
+        ) -> DispatchResultWithPostInfo {
+            ensure_root(origin)?;
+
+            ensure!(Self::dao_info(dao_id).is_some(), Error::<T>::NotRegistered);
+            ensure!(
+                !ReceiptTokens::<T>::contains_key(dao_id),
+                Error::<T>::LiquidStakingAlreadyEnabled
+            );
+            ensure!(
+                <T::ReceiptAssets as fungibles::Inspect<T::AccountId>>::total_issuance(
+                    receipt_token.clone()
+                )
+                .is_zero(),
+                Error::<T>::ReceiptTokenInUse
+            );
+
+            ReceiptTokens::<T>::insert(dao_id, receipt_token.clone());
+
+            Self::deposit_event(Event::<T>::LiquidStakingEnabled {
+                dao: dao_id,
+                receipt_token,
+            });
+
+            Ok(().into())
+        }
+
+        /// Stake towards a dao through its liquid staking account, minting receipt tokens of the dao.
+        ///
+        /// The funds are transferred to the liquid staking account and locked there.
+        /// While no receipt tokens are issued the stake has to be at least `MinimumStakingAmount`, after that any amount is accepted.
+        /// The receipt tokens minted are worth the staked amount at the current exchange rate.
+        ///
+        /// The dispatch origin for this call must be _Signed_ by the staker's account.
+        ///
+        /// - `dao_id`: Id of the dao to stake towards.
+        /// - `value`: Amount to stake.
+        #[pallet::call_index(22)]
+        #[pallet::weight(<T as Config>::WeightInfo::liquid_stake())]
+        pub fn liquid_stake(
+            origin: OriginFor<T>,
+            dao_id: T::DaoId,
+            #[pallet::compact] value: BalanceOf<T>,
+        ) -> DispatchResultWithPostInfo {
+            Self::ensure_not_halted()?;
+
+            let staker = ensure_signed(origin)?;
+
+            ensure!(Self::dao_info(dao_id).is_some(), Error::<T>::NotRegistered);
+
+            let receipt_token =
+                Self::receipt_token(dao_id).ok_or(Error::<T>::LiquidStakingNotEnabled)?;
+
+            let available_balance =
+                Self::available_staking_balance(&staker, &Self::ledger(&staker));
+            let value_to_stake = value.min(available_balance);
+
+            ensure!(value_to_stake > Zero::zero(), Error::<T>::StakingNothing);
+
+            let receipt_issuance =
+                <T::ReceiptAssets as fungibles::Inspect<T::AccountId>>::total_issuance(
+                    receipt_token.clone(),
+                );
+
+            // A tiny first stake would let its staker inflate the exchange rate against the next ones.
+            ensure!(
+                !receipt_issuance.is_zero() || value_to_stake >= T::MinimumStakingAmount::get(),
+                Error::<T>::InsufficientBalance
+            );
+
+            let (mut dao_stake_info, mut liquid_staker_info, mut liquid_ledger) =
+                Self::accrued_liquid_stake(dao_id)?;
+
+            let receipts = convert_share(value_to_stake, liquid_ledger.locked, receipt_issuance);
+
+            ensure!(!receipts.is_zero(), Error::<T>::ReceiptAmountTooLow);
+
+            Self::internal_stake(
+                dao_id,
+                &mut liquid_staker_info,
+                &mut dao_stake_info,
+                value_to_stake,
+                Zero::zero(),
+            )?;
+
+            let liquid_account = Self::liquid_account(dao_id);
+
+            <T as Config>::Currency::transfer(
+                &staker,
+                &liquid_account,
+                value_to_stake,
+                Preservation::Preserve,
+            )?;
+
+            liquid_ledger.locked = liquid_ledger.locked.saturating_add(value_to_stake);
+            Self::update_ledger(&liquid_account, liquid_ledger)?;
+
+            GeneralEraInfo::<T>::mutate(Self::current_era(), |value| {
+                if let Some(x) = value {
+                    x.staked = x.staked.saturating_add(value_to_stake);
+                    x.locked = x.locked.saturating_add(value_to_stake);
+                }
+            });
+
+            <T::ReceiptAssets as fungibles::Mutate<T::AccountId>>::mint_into(
+                receipt_token,
+                &staker,
+                receipts,
+            )?;
+
+            Self::update_staker_info(&liquid_account, dao_id, liquid_staker_info);
+            CoreStake::<T>::insert(dao_id, dao_stake_info);
+
+            Self::deposit_event(Event::<T>::LiquidStaked {
+                staker,
+                dao: dao_id,
+                amount: value_to_stake,
+                receipts,
+            });
+
+            Ok(().into())
+        }
+
+        /// Redeem receipt tokens of a dao, unstaking the funds they're worth at the current exchange rate.
+        ///
+        /// The funds are transferred to the staker and go through the unbonding period
+        /// in the staker's own ledger, to be withdrawn via the `withdraw_unstaked` call.
+        ///
+        /// The dispatch origin for this call must be _Signed_ by the staker's account.
+        ///
+        /// - `dao_id`: Id of the dao the receipt tokens were minted for.
+        /// - `receipts`: Amount of receipt tokens to burn.
+        #[pallet::call_index(23)]
+        #[pallet::weight(<T as Config>::WeightInfo::redeem())]
+        pub fn redeem(
+            origin: OriginFor<T>,
+            dao_id: T::DaoId,
+            #[pallet::compact] receipts: BalanceOf<T>,
+        ) -> DispatchResultWithPostInfo {
+            Self::ensure_not_halted()?;
+
+            let staker = ensure_signed(origin)?;
+
+            let receipt_token =
+                Self::receipt_token(dao_id).ok_or(Error::<T>::LiquidStakingNotEnabled)?;
+
+            ensure!(!receipts.is_zero(), Error::<T>::UnstakingNothing);
+
+            let (mut dao_stake_info, mut liquid_staker_info, mut liquid_ledger) =
+                Self::accrued_liquid_stake(dao_id)?;
+
+            let value_to_unstake = convert_share(
+                receipts,
+                <T::ReceiptAssets as fungibles::Inspect<T::AccountId>>::total_issuance(
+                    receipt_token.clone(),
+                ),
+                liquid_ledger.locked,
+            )
+            .min(liquid_ledger.locked);
+
+            ensure!(
+                value_to_unstake > Zero::zero(),
+                Error::<T>::UnstakingNothing
+            );
+
+            <T::ReceiptAssets as fungibles::Mutate<T::AccountId>>::burn_from(
+                receipt_token,
+                &staker,
+                receipts,
+                Preservation::Expendable,
+                Precision::Exact,
+                Fortitude::Polite,
+            )?;
+
+            let current_era = Self::current_era();
+
+            // Whatever isn't staked is already unbonding in the liquid staking ledger,
+            // which is the case once the dao is unregistered.
+            let value_from_stake = value_to_unstake.min(liquid_staker_info.staked);
+            let value_from_unbonding = value_to_unstake.saturating_sub(value_from_stake);
+
+            if !value_from_stake.is_zero() {
+                Self::internal_unstake(
+                    dao_id,
+                    &mut liquid_staker_info,
+                    &mut dao_stake_info,
+                    value_from_stake,
+                    Zero::zero(),
+                )?;
+
+                // Stake of an unregistered dao was already taken out of the era's stake.
+                if Self::dao_info(dao_id).is_some() {
+                    GeneralEraInfo::<T>::mutate(current_era, |value| {
+                        if let Some(x) = value {
+                            x.staked = x.staked.saturating_sub(value_from_stake);
+                        }
+                    });
+                }
+            }
+
+            ensure!(
+                liquid_ledger.unbonding_info.rebond(value_from_unbonding) == value_from_unbonding,
+                Error::<T>::NoStakeAvailable
+            );
+
+            let liquid_account = Self::liquid_account(dao_id);
+
+            liquid_ledger.locked = liquid_ledger.locked.saturating_sub(value_to_unstake);
+            Self::update_ledger(&liquid_account, liquid_ledger)?;
+
+            <T as Config>::Currency::transfer(
+                &liquid_account,
+                &staker,
+                value_to_unstake,
+                Preservation::Expendable,
+            )?;
+
+            let mut ledger = Self::ledger(&staker);
+            ledger.locked = ledger.locked.saturating_add(value_to_unstake);
+            ledger.unbonding_info.add(UnlockingChunk {
+                amount: value_to_unstake,
+                unlock_era: current_era + T::UnbondingPeriod::get(),
+            });
+
+            ensure!(
+                ledger.unbonding_info.len() <= T::MaxUnlocking::get(),
+                Error::<T>::TooManyUnlockingChunks
+            );
+
+            Self::update_ledger(&staker, ledger)?;
+
+            Self::update_staker_info(&liquid_account, dao_id, liquid_staker_info);
+            CoreStake::<T>::insert(dao_id, dao_stake_info);
+
+            Self::deposit_event(Event::<T>::Redeemed {
+                staker,
+                dao: dao_id,
+                receipts,
+                amount: value_to_unstake,
+            });
+
+            Ok(().into())
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// Internal function responsible for validating a stake and updating in-place
+        /// both the staker's and dao staking info.
+        ///
+        /// Both infos are expected to have their rewards accrued.
+        /// `minimum_stake` is the least the staker can have staked, nomination pools aren't bound by it.
+        fn internal_stake(
+            dao_id: T::DaoId,
+            staker_info: &mut StakerInfo<BalanceOf<T>>,
+            staking_info: &mut DaoStakeInfo<BalanceOf<T>>,
+            amount: BalanceOf<T>,
+            minimum_stake: BalanceOf<T>,
+        ) -> Result<(), Error<T>> {
+            ensure!(
+                !staker_info.staked.is_zero()
+                    || staking_info.number_of_stakers < T::MaxStakersPerDao::get(),
+                Error::<T>::MaxStakersReached
+            );
+            ensure!(
+                staker_info.staked.saturating_add(amount) >= minimum_stake,
+                Error::<T>::InsufficientBalance,
+            );
+
+            if staker_info.staked.is_zero() {
+                staking_info.number_of_stakers = staking_info.number_of_stakers.saturating_add(1);
+            }
+
+            staker_info.staked = staker_info.staked.saturating_add(amount);
+
+            Self::set_dao_total(
+                dao_id,
+                staking_info,
+                staking_info.total.saturating_add(amount),
+            );
+
+            Ok(())
+        }
+
+        /// Internal function responsible for validating an unstake and updating in-place
+        /// both the staker's and dao staking info.
+        ///
+        /// Both infos are expected to have their rewards accrued.
+        /// In case the remaining stake would be below `minimum_stake` the entire stake is unstaked.
+        fn internal_unstake(
+            dao_id: T::DaoId,
+            staker_info: &mut StakerInfo<BalanceOf<T>>,
+            dao_stake_info: &mut DaoStakeInfo<BalanceOf<T>>,
+            amount: BalanceOf<T>,
+            minimum_stake: BalanceOf<T>,
+        ) -> Result<BalanceOf<T>, Error<T>> {
+            let staked_value = staker_info.staked;
+            ensure!(staked_value > Zero::zero(), Error::<T>::NoStakeAvailable);
+
+            let remaining = staked_value.saturating_sub(amount);
+            let value_to_unstake = if remaining < minimum_stake {
+                staked_value
+            } else {
+                amount
+            };
+
+            ensure!(
+                value_to_unstake > Zero::zero(),
+                Error::<T>::UnstakingNothing
+            );
+
+            if value_to_unstake == staked_value {
+                dao_stake_info.number_of_stakers =
+                    dao_stake_info.number_of_stakers.saturating_sub(1);
+            }
+
+            staker_info.staked = staked_value.saturating_sub(value_to_unstake);
+
+            Self::set_dao_total(
+                dao_id,
+                dao_stake_info,
+                dao_stake_info.total.saturating_sub(value_to_unstake),
+            );
+
+            Ok(value_to_unstake)
+        }
+
+        /// Sets the total stake of a dao, updating its active state and the active stake of the current era.
+        fn set_dao_total(
+            dao_id: T::DaoId,
+            dao_stake_info: &mut DaoStakeInfo<BalanceOf<T>>,
+            total: BalanceOf<T>,
         ) {
+            let active_stake_of = |info: &DaoStakeInfo<BalanceOf<T>>| {
+                if info.active {
+                    info.total
+                } else {
+                    Zero::zero()
+                }
+            };
+
+            let old_active_stake = active_stake_of(dao_stake_info);
+
+            dao_stake_info.total = total;
+            dao_stake_info.active = total >= <T as Config>::StakeThresholdForActiveDao::get()
+                && RegisteredCore::<T>::contains_key(dao_id);
+
+            let new_active_stake = active_stake_of(dao_stake_info);
+
+            if old_active_stake != new_active_stake {
+                GeneralEraInfo::<T>::mutate(Self::current_era(), |value| {
+                    if let Some(x) = value {
+                        x.active_stake = x
+                            .active_stake
+                            .saturating_sub(old_active_stake)
+                            .saturating_add(new_active_stake);
+                    }
+                });
+            }
+        }
+
+        /// Returns the staking info of a dao with its rewards accrued up to the current reward per stake index.
+        pub(crate) fn accrued_dao_stake_info(dao_id: T::DaoId) -> DaoStakeInfo<BalanceOf<T>> {
+            let mut dao_stake_info = Self::dao_stake_info(dao_id);
+            dao_stake_info.accrue(
+                &Self::reward_per_stake(),
+                RegisteredCore::<T>::contains_key(dao_id),
+            );
+            dao_stake_info
+        }
+
+        /// Account a nomination pool stakes with and holds its members' funds in.
+        pub fn pool_account(pool_id: PoolId) -> T::AccountId {
+            T::PotId::get().into_sub_account_truncating((*b"pool", pool_id))
+        }
+
+        /// Whether the account is the account of a nomination pool, existing or not.
+        pub(crate) fn is_pool_account(account: &T::AccountId) -> bool {
+            matches!(
+                PalletId::try_from_sub_account::<([u8; 4], PoolId)>(account),
+                Some((pot_id, (tag, _))) if pot_id == T::PotId::get() && tag == *b"pool"
+            )
+        }
+
+        /// Stores a nomination pool, removing it and releasing the creator's deposit
+        /// once it has no stake and no member with rewards left.
+        fn update_pool(pool_id: PoolId, pool: PoolInfoOf<T>) -> DispatchResult {
+            if pool.total.is_zero() && PoolMembers::<T>::iter_key_prefix(pool_id).next().is_none() {
+                <T as pallet::Config>::Currency::release(
+                    &HoldReason::PoolDeposit.into(),
+                    &pool.creator,
+                    T::PoolDeposit::get(),
+                    Precision::BestEffort,
+                )?;
+
+                Pools::<T>::remove(pool_id);
+
+                Self::deposit_event(Event::<T>::PoolRemoved { pool: pool_id });
+            } else {
+                Pools::<T>::insert(pool_id, pool);
+            }
+
+            Ok(())
+        }
+
+        /// Returns a nomination pool along with the accrued staking infos of its dao and of the pool on the dao,
+        /// the rewards accrued by the pool being shared among its members.
+        ///
+        /// Rewards shared among the members stay in the pot until each member claims them.
+        fn accrued_pool(
+            pool_id: PoolId,
+        ) -> Result<
+            (
+                PoolInfoOf<T>,
+                DaoStakeInfo<BalanceOf<T>>,
+                StakerInfo<BalanceOf<T>>,
+            ),
+            Error<T>,
+        > {
+            let mut pool = Self::pool_info(pool_id).ok_or(Error::<T>::PoolNotFound)?;
+            let dao_stake_info = Self::accrued_dao_stake_info(pool.dao);
+            let mut pool_staker_info =
+                Self::accrued_staker_info(pool.dao, &Self::pool_account(pool_id), &dao_stake_info);
+
+            if !pool.total.is_zero() {
+                pool.distribute(pool_staker_info.take_rewards());
+            }
+
+            Ok((pool, dao_stake_info, pool_staker_info))
+        }
+
+        /// Internal function responsible for staking through a nomination pool.
+        fn internal_join_pool(
+            member: &T::AccountId,
+            pool_id: PoolId,
+            value: BalanceOf<T>,
+        ) -> DispatchResult {
+            let (mut pool, mut dao_stake_info, mut pool_staker_info) = Self::accrued_pool(pool_id)?;
+
+            ensure!(
+                Self::dao_info(pool.dao).is_some(),
+                Error::<T>::NotRegistered
+            );
+
+            let available_balance = Self::available_staking_balance(member, &Self::ledger(member));
+            let value_to_stake = value.min(available_balance);
+
+            ensure!(value_to_stake > Zero::zero(), Error::<T>::StakingNothing);
+
+            let mut member_info = Self::pool_member_info(pool_id, member);
+            member_info.accrue_to(pool.reward_per_stake);
+
+            Self::internal_stake(
+                pool.dao,
+                &mut pool_staker_info,
+                &mut dao_stake_info,
+                value_to_stake,
+                Zero::zero(),
+            )?;
+
+            let pool_account = Self::pool_account(pool_id);
+
+            <T as Config>::Currency::transfer(
+                member,
+                &pool_account,
+                value_to_stake,
+                Preservation::Preserve,
+            )?;
+
+            let mut pool_ledger = Self::ledger(&pool_account);
+            pool_ledger.locked = pool_ledger.locked.saturating_add(value_to_stake);
+            Self::update_ledger(&pool_account, pool_ledger)?;
+
+            GeneralEraInfo::<T>::mutate(Self::current_era(), |value| {
+                if let Some(x) = value {
+                    x.staked = x.staked.saturating_add(value_to_stake);
+                    x.locked = x.locked.saturating_add(value_to_stake);
+                }
+            });
+
+            if member_info.staked.is_zero() {
+                pool.number_of_members = pool.number_of_members.saturating_add(1);
+            }
+            member_info.staked = member_info.staked.saturating_add(value_to_stake);
+            pool.total = pool.total.saturating_add(value_to_stake);
+
+            Self::update_pool_member(pool_id, member, member_info);
+            Self::update_staker_info(&pool_account, pool.dao, pool_staker_info);
+            CoreStake::<T>::insert(pool.dao, dao_stake_info);
+            Pools::<T>::insert(pool_id, pool);
+
+            Self::deposit_event(Event::<T>::PoolJoined {
+                member: member.clone(),
+                pool: pool_id,
+                amount: value_to_stake,
+            });
+
+            Ok(())
+        }
+
+        /// Account a dao's liquid staking stakes with and holds the funds of the receipt tokens in.
+        pub fn liquid_account(dao_id: T::DaoId) -> T::AccountId {
+            T::PotId::get().into_sub_account_truncating((*b"liquid", dao_id))
+        }
+
+        /// Whether the account is the liquid staking account of a dao, enabled or not.
+        pub(crate) fn is_liquid_account(account: &T::AccountId) -> bool {
+            matches!(
+                PalletId::try_from_sub_account::<([u8; 6], T::DaoId)>(account),
+                Some((pot_id, (tag, _))) if pot_id == T::PotId::get() && tag == *b"liquid"
+            )
+        }
+
+        /// Returns the accrued staking infos of a dao and of its liquid staking account on the dao,
+        /// along with the ledger of the liquid staking account.
+        ///
+        /// The rewards accrued by the liquid staking account are staked back towards the dao, raising the
+        /// exchange rate of the receipt tokens, or left unbonding in the ledger if the dao was unregistered.
+        fn accrued_liquid_stake(
+            dao_id: T::DaoId,
+        ) -> Result<
+            (
+                DaoStakeInfo<BalanceOf<T>>,
+                StakerInfo<BalanceOf<T>>,
+                AccountLedger<BalanceOf<T>>,
+            ),
+            DispatchError,
+        > {
+            let liquid_account = Self::liquid_account(dao_id);
+            let mut dao_stake_info = Self::accrued_dao_stake_info(dao_id);
+            let mut liquid_staker_info =
+                Self::accrued_staker_info(dao_id, &liquid_account, &dao_stake_info);
+            let mut liquid_ledger = Self::ledger(&liquid_account);
+
+            let reward = liquid_staker_info.take_rewards();
+
+            if !reward.is_zero() {
+                let reward_imbalance = <T as pallet::Config>::Currency::withdraw(
+                    &Self::account_id(),
+                    reward,
+                    Precision::Exact,
+                    Preservation::Expendable,
+                    Fortitude::Force,
+                )?;
+
+                <T as pallet::Config>::Currency::resolve(&liquid_account, reward_imbalance)
+                    .map_err(|_| Error::<T>::NothingToWithdraw)?;
+
+                let current_era = Self::current_era();
+                let is_registered = Self::dao_info(dao_id).is_some();
+
+                if is_registered {
+                    Self::internal_stake(
+                        dao_id,
+                        &mut liquid_staker_info,
+                        &mut dao_stake_info,
+                        reward,
+                        Zero::zero(),
+                    )?;
+                } else {
+                    liquid_ledger.unbonding_info.add(UnlockingChunk {
+                        amount: reward,
+                        unlock_era: current_era,
+                    });
+                }
+
+                liquid_ledger.locked = liquid_ledger.locked.saturating_add(reward);
+
+                GeneralEraInfo::<T>::mutate(current_era, |value| {
+                    if let Some(x) = value {
+                        if is_registered {
+                            x.staked = x.staked.saturating_add(reward);
+                        }
+                        x.locked = x.locked.saturating_add(reward);
+                    }
+                });
+            }
+
+            Ok((dao_stake_info, liquid_staker_info, liquid_ledger))
+        }
+
+        /// Returns the staking info of a staker on a dao with its rewards accrued
+        /// up to the stakers reward per stake index of the dao.
+        pub(crate) fn accrued_staker_info(
+            dao_id: T::DaoId,
+            staker: &T::AccountId,
+            dao_stake_info: &DaoStakeInfo<BalanceOf<T>>,
+        ) -> StakerInfo<BalanceOf<T>> {
+            let mut staker_info = Self::staker_info(dao_id, staker);
+            staker_info.accrue(dao_stake_info);
+            staker_info
+        }
+
+        /// Internal function responsible for paying out the rewards a staker accrued on a dao.
+        ///
+        /// Returns the amount claimed.
+        fn internal_claim(
+            staker: &T::AccountId,
+            dao_id: T::DaoId,
+        ) -> Result<BalanceOf<T>, DispatchError> {
+            let mut dao_stake_info = Self::accrued_dao_stake_info(dao_id);
+            let mut staker_info = Self::accrued_staker_info(dao_id, staker, &dao_stake_info);
+
+            let staker_reward = staker_info.take_rewards();
+
+            if !staker_reward.is_zero() {
+                Self::pay_staker_reward(
+                    staker,
+                    dao_id,
+                    staker_reward,
+                    &mut staker_info,
+                    &mut dao_stake_info,
+                )?;
+
+                Self::update_staker_info(staker, dao_id, staker_info);
+                CoreStake::<T>::insert(dao_id, dao_stake_info);
+
+                Self::deposit_event(Event::<T>::StakerClaimed {
+                    staker: staker.clone(),
+                    dao: dao_id,
+                    amount: staker_reward,
+                });
+            }
+
+            Ok(staker_reward)
+        }
+
+        /// Pays a staker's rewards out of the pot to their reward destination.
+        ///
+        /// Restaked rewards are added to `staker_info` and `dao_stake_info`, which the caller is responsible for storing.
+        fn pay_staker_reward(
+            staker: &T::AccountId,
+            dao_id: T::DaoId,
+            reward: BalanceOf<T>,
+            staker_info: &mut StakerInfo<BalanceOf<T>>,
+            dao_stake_info: &mut DaoStakeInfo<BalanceOf<T>>,
+        ) -> DispatchResult {
+            let reward_imbalance = <T as pallet::Config>::Currency::withdraw(
+                &Self::account_id(),
+                reward,
+                Precision::Exact,
+                Preservation::Expendable,
+                Fortitude::Force,
+            )?;
+
+            let reward_destination = Self::reward_destination(staker);
+
+            let destination_account = match &reward_destination {
+                RewardDestination::Account(account) => account.clone(),
+                _ => staker.clone(),
+            };
+
+            <T as pallet::Config>::Currency::resolve(&destination_account, reward_imbalance)
+                .map_err(|_| Error::<T>::NothingToWithdraw)?;
+
+            if reward_destination == RewardDestination::Restake
+                && Self::restake_reward(staker, dao_id, reward, staker_info, dao_stake_info).is_ok()
+            {
+                Self::deposit_event(Event::<T>::RewardRestaked {
+                    staker: staker.clone(),
+                    dao: dao_id,
+                    amount: reward,
+                });
+            }
+
+            Ok(())
+        }
+
+        /// Stakes a reward already paid to the staker towards the dao it was earned from,
+        /// leaving the reward in the staker's free balance if the stake isn't possible.
+        fn restake_reward(
+            staker: &T::AccountId,
+            dao_id: T::DaoId,
+            reward: BalanceOf<T>,
+            staker_info: &mut StakerInfo<BalanceOf<T>>,
+            dao_stake_info: &mut DaoStakeInfo<BalanceOf<T>>,
+        ) -> DispatchResult {
+            ensure!(Self::dao_info(dao_id).is_some(), Error::<T>::NotRegistered);
+
+            frame_support::storage::with_storage_layer(|| {
+                let current_era = Self::current_era();
+                let mut new_staker_info = staker_info.clone();
+                let mut new_dao_stake_info = dao_stake_info.clone();
+
+                Self::internal_stake(
+                    dao_id,
+                    &mut new_staker_info,
+                    &mut new_dao_stake_info,
+                    reward,
+                    T::MinimumStakingAmount::get(),
+                )?;
+
+                let mut ledger = Self::ledger(staker);
+                ledger.locked = ledger.locked.saturating_add(reward);
+                Self::update_ledger(staker, ledger)?;
+
+                GeneralEraInfo::<T>::mutate(current_era, |value| {
+                    if let Some(x) = value {
+                        x.staked = x.staked.saturating_add(reward);
+                        x.locked = x.locked.saturating_add(reward);
+                    }
+                });
+
+                *staker_info = new_staker_info;
+                *dao_stake_info = new_dao_stake_info;
+
+                Ok(())
+            })
+        }
+
+        pub(crate) fn account_id() -> T::AccountId {
+            T::PotId::get().into_account_truncating()
+        }
+
+        /// Update the ledger for a staker. This will also update the stash lock.
+        /// This lock will lock the entire funds except paying for further transactions.
+        fn update_ledger(
+            staker: &T::AccountId,
+            ledger: AccountLedger<BalanceOf<T>>,
+        ) -> DispatchResult {
+            if ledger.is_empty() {
+                Ledger::<T>::remove(staker);
+                let _ = <T as pallet::Config>::Currency::thaw(&LOCK_ID, staker);
+            } else {
+                <T as pallet::Config>::Currency::set_freeze(&LOCK_ID, staker, ledger.locked)?;
+                Ledger::<T>::insert(staker, ledger);
+            }
+            Ok(())
+        }
+
+        /// The block rewards are accumulated on the pallet's account during an era.
+        /// This function takes a snapshot of the pallet's balance accrued during current era
+        /// and distributes it through the reward per stake index.
+        ///
+        /// This is called just at the beginning of an era.
+        fn reward_balance_snapshot(era: Era, rewards: RewardInfo<BalanceOf<T>>) {
             let mut era_info = Self::general_era_info(era).unwrap_or_default();
 
+            RewardPerStakeIndex::<T>::mutate(|index| {
+                index.accrue(&rewards, era_info.staked, era_info.active_stake)
+            });
+
             GeneralEraInfo::<T>::insert(
                 era + 1,
                 EraInfo {
                     rewards: Default::default(),
                     staked: era_info.staked,
-                    active_stake: new_active_stake,
+                    active_stake: era_info.active_stake,
                     locked: era_info.locked,
                 },
             );
 
             era_info.rewards = rewards;
-            era_info.active_stake = new_active_stake;
 
             GeneralEraInfo::<T>::insert(era, era_info);
         }
 
+        /// Removes the information of eras older than `HistoryDepth` and the legacy dao stake rows,
+        /// consuming at most `remaining_weight`.
+        pub(crate) fn prune_history(remaining_weight: Weight) -> Weight {
+            let db_weight = T::DbWeight::get();
+            let mut meter = WeightMeter::with_limit(remaining_weight);
+
+            if meter.try_consume(db_weight.reads(3)).is_err() {
+                return Weight::zero();
+            }
+
+            let prune_until = Self::current_era().saturating_sub(T::HistoryDepth::get());
+            let mut oldest_era = Self::oldest_era();
+
+            if oldest_era < prune_until && meter.try_consume(db_weight.writes(1)).is_ok() {
+                while oldest_era < prune_until && meter.try_consume(db_weight.writes(1)).is_ok() {
+                    GeneralEraInfo::<T>::remove(oldest_era);
+                    oldest_era += 1;
+                }
+
+                OldestEra::<T>::put(oldest_era);
+            }
+
+            if let Some(cursor) = LegacyEraStakeCursor::<T>::get() {
+                let limit = meter
+                    .remaining()
+                    .ref_time()
+                    .saturating_sub(db_weight.writes(1).ref_time())
+                    .checked_div(db_weight.reads_writes(1, 1).ref_time())
+                    .unwrap_or(u64::MAX)
+                    .min(u32::MAX.into()) as u32;
+
+                if !limit.is_zero() {
+                    let result = migrations::v3::CoreEraStake::<T>::clear(
+                        limit,
+                        (!cursor.is_empty()).then_some(cursor.as_slice()),
+                    );
+
+                    meter.consume(
+                        db_weight
+                            .reads_writes(result.loops.into(), result.unique.into())
+                            .saturating_add(db_weight.writes(1)),
+                    );
+
+                    match result.maybe_cursor {
+                        // Removed rows are gone, so starting over is fine if the cursor doesn't fit.
+                        Some(cursor) => LegacyEraStakeCursor::<T>::put(
+                            BoundedVec::try_from(cursor).unwrap_or_default(),
+                        ),
+                        None => LegacyEraStakeCursor::<T>::kill(),
+                    }
+                }
+            }
+
+            meter.consumed()
+        }
+
         /// Adds `stakers` and `DAOs` rewards to the reward pool.
         ///
         /// - `inflation`: Total inflation for the era.
@@ -1235,6 +2745,19 @@ pub mod pallet {
             }
         }
 
+        /// Updates member info for a nomination pool.
+        fn update_pool_member(
+            pool_id: PoolId,
+            member: &T::AccountId,
+            member_info: StakerInfo<BalanceOf<T>>,
+        ) {
+            if member_info.is_empty() {
+                PoolMembers::<T>::remove(pool_id, member)
+            } else {
+                PoolMembers::<T>::insert(pool_id, member, member_info)
+            }
+        }
+
         /// Returns available staking balance for the potential staker.
         fn available_staking_balance(
             staker: &T::AccountId,
@@ -1261,63 +2784,20 @@ pub mod pallet {
             }
         }
 
-        /// Calculate reward split between dao and stakers.
-        ///
-        /// Returns (DAO reward, joint stakers reward)
-        pub(crate) fn dao_stakers_split(
-            dao_info: &DaoStakeInfo<BalanceOf<T>>,
-            era_info: &EraInfo<BalanceOf<T>>,
-        ) -> (BalanceOf<T>, BalanceOf<T>) {
-            let dao_stake_portion = if dao_info.active {
-                Perbill::from_rational(dao_info.total, era_info.active_stake)
-            } else {
-                Perbill::zero()
-            };
-            let stakers_stake_portion = Perbill::from_rational(dao_info.total, era_info.staked);
-
-            let dao_reward_part = dao_stake_portion * era_info.rewards.dao;
-            let stakers_joint_reward = stakers_stake_portion * era_info.rewards.stakers;
-
-            (dao_reward_part, stakers_joint_reward)
-        }
-
-        /// Used to copy all `DaoStakeInfo` from the ending era over to the next era.
-        fn rotate_staking_info(current_era: Era) -> (Weight, BalanceOf<T>) {
-            let next_era = current_era + 1;
-
-            let mut consumed_weight = Weight::zero();
-
-            let mut new_active_stake: BalanceOf<T> = Zero::zero();
-
-            for dao_id in RegisteredCore::<T>::iter_keys() {
-                consumed_weight = consumed_weight.saturating_add(T::DbWeight::get().reads(1));
-
-                if let Some(mut staking_info) = Self::dao_stake_info(dao_id, current_era) {
-                    if staking_info.total >= <T as Config>::StakeThresholdForActiveDao::get() {
-                        staking_info.active = true;
-                        new_active_stake += staking_info.total;
-                    } else {
-                        staking_info.active = false;
-                    }
-
-                    staking_info.reward_claimed = false;
-                    CoreEraStake::<T>::insert(dao_id, next_era, staking_info);
-
-                    consumed_weight =
-                        consumed_weight.saturating_add(T::DbWeight::get().reads_writes(1, 1));
-                } else {
-                    consumed_weight = consumed_weight.saturating_add(T::DbWeight::get().reads(1));
-                }
-            }
-
-            (consumed_weight, new_active_stake)
-        }
-
         /// Sets the halt state of the pallet.
         pub fn internal_halt_unhalt(halt: bool) {
             Halted::<T>::put(halt);
         }
 
+        /// Length of the longest link in a dao's metadata, out of the logo, website and IPFS CID.
+        pub fn longest_metadata_link(metadata: &DaoMetadataOf<T>) -> u32 {
+            [&metadata.logo, &metadata.website, &metadata.ipfs_cid]
+                .iter()
+                .map(|link| link.as_ref().map_or(0, |link| link.len()))
+                .max()
+                .unwrap_or(0) as u32
+        }
+
         /// Ensure the pallet is not halted.
         pub fn ensure_not_halted() -> Result<(), Error<T>> {
             if Self::is_halted() {
@@ -1346,15 +2826,16 @@ pub mod pallet {
 
             let mut unsteked_count: u64 = 0;
             while let Some(staker) = staker_info_prefix.pop() {
-                let mut staker_info = Self::staker_info(dao_id, &staker);
+                let mut staker_info = Self::accrued_staker_info(dao_id, &staker, &dao_stake_info);
 
-                let latest_staked_value = staker_info.latest_staked_value();
+                let staked_value = staker_info.staked;
 
                 if let Ok(value_to_unstake) = Self::internal_unstake(
+                    dao_id,
                     &mut staker_info,
                     &mut dao_stake_info,
-                    latest_staked_value,
-                    start_era,
+                    staked_value,
+                    T::MinimumStakingAmount::get(),
                 ) {
                     UnregisteredCoreStakeInfo::<T>::insert(dao_id, dao_stake_info.clone());
                     let mut ledger = Self::ledger(&staker);
@@ -1,6 +1,6 @@
 use super::*;
 use frame_support::{
-    pallet_prelude::GetStorageVersion,
+    pallet_prelude::{GetStorageVersion, StorageVersion},
     traits::{Get, OnRuntimeUpgrade},
     weights::Weight,
 };
@@ -70,7 +70,7 @@ pub mod v1 {
         #[cfg(feature = "try-runtime")]
         fn pre_upgrade() -> Result<sp_runtime::Vec<u8>, sp_runtime::DispatchError> {
             frame_support::ensure!(
-                Pallet::<T>::on_chain_storage_version() <= Pallet::<T>::in_code_storage_version(),
+                Pallet::<T>::on_chain_storage_version() == 0,
                 "Required v0 before upgrading to v1"
             );
 
@@ -79,18 +79,17 @@ pub mod v1 {
 
         fn on_runtime_upgrade() -> Weight {
             let mut weight = Weight::zero();
-            let current = Pallet::<T>::in_code_storage_version();
 
             let chain_version = Pallet::<T>::on_chain_storage_version();
 
             weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 0));
 
-            if current > chain_version {
+            if chain_version == 0 {
                 weight.saturating_accrue(migrate_locks_to_freeze::<T>());
 
                 weight.saturating_accrue(migrate_holds::<T>());
 
-                current.put::<Pallet<T>>();
+                StorageVersion::new(1).put::<Pallet<T>>();
 
                 info!("v1 applied successfully");
                 T::DbWeight::get().reads_writes(0, 1)
@@ -111,3 +110,533 @@ pub mod v1 {
         }
     }
 }
+
+pub mod v2 {
+    use super::*;
+    use codec::{Decode, Encode};
+    use pallet_dao_manager::dao_manager_core::DaoMetadata;
+
+    /// Dao metadata as stored before it was shared with the dao manager.
+    #[derive(Encode, Decode)]
+    pub struct OldDaoMetadata {
+        pub name: Vec<u8>,
+        pub description: Vec<u8>,
+        pub image: Vec<u8>,
+    }
+
+    pub type OldDaoInfoOf<T> = DaoInfo<<T as frame_system::Config>::AccountId, OldDaoMetadata>;
+
+    /// Moves the metadata of every registered dao to the shared metadata type, the image becomes the logo
+    /// if it's an IPFS CID and the website otherwise.
+    pub fn migrate_dao_metadata<T: Config>() -> Weight {
+        let mut count: u64 = 0;
+
+        RegisteredCore::<T>::translate::<OldDaoInfoOf<T>, _>(|_, old| {
+            count += 1;
+
+            let image = old.metadata.image;
+            let cid = image.strip_prefix(b"ipfs://").unwrap_or(&image);
+            let is_cid = !cid.is_empty() && cid.iter().all(|c| c.is_ascii_alphanumeric());
+
+            let mut metadata: DaoMetadataOf<T> = DaoMetadata {
+                name: BoundedVec::truncate_from(old.metadata.name),
+                description: BoundedVec::truncate_from(old.metadata.description),
+                ..Default::default()
+            };
+
+            if is_cid {
+                metadata.logo = Some(BoundedVec::truncate_from(cid.to_vec()));
+            } else if !image.is_empty() {
+                metadata.website = Some(BoundedVec::truncate_from(image));
+            }
+
+            Some(DaoInfo {
+                account: old.account,
+                metadata,
+            })
+        });
+
+        info!("Migrated {} daos", count);
+        T::DbWeight::get().reads_writes(count, count)
+    }
+
+    pub struct MigrateToV2<T>(sp_std::marker::PhantomData<T>);
+    impl<T: Config> OnRuntimeUpgrade for MigrateToV2<T> {
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<sp_runtime::Vec<u8>, sp_runtime::DispatchError> {
+            frame_support::ensure!(
+                Pallet::<T>::on_chain_storage_version() == 1,
+                "Required v1 before upgrading to v2"
+            );
+
+            Ok((RegisteredCore::<T>::iter_keys().count() as u32).encode())
+        }
+
+        fn on_runtime_upgrade() -> Weight {
+            let mut weight = T::DbWeight::get().reads(1);
+
+            if Pallet::<T>::on_chain_storage_version() == 1 {
+                weight.saturating_accrue(migrate_dao_metadata::<T>());
+
+                StorageVersion::new(2).put::<Pallet<T>>();
+
+                info!("v2 applied successfully");
+                weight.saturating_add(T::DbWeight::get().writes(1))
+            } else {
+                warn!("Skipping v2, should be removed");
+                weight
+            }
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: sp_std::vec::Vec<u8>) -> Result<(), sp_runtime::DispatchError> {
+            frame_support::ensure!(
+                Pallet::<T>::on_chain_storage_version() == 2,
+                "v2 not applied"
+            );
+
+            let old_count = u32::decode(&mut &state[..])
+                .map_err(|_| sp_runtime::DispatchError::Other("Failed decoding state"))?;
+
+            frame_support::ensure!(
+                RegisteredCore::<T>::iter_values().count() as u32 == old_count,
+                "Daos lost during migration"
+            );
+
+            Ok(())
+        }
+    }
+}
+
+pub mod v3 {
+    use super::*;
+    use crate::pallet::BalanceOf;
+    use codec::{Decode, Encode, HasCompact};
+    use frame_support::{storage::unhashed, storage_alias, weights::WeightMeter};
+    use sp_runtime::{traits::AtLeast32BitUnsigned, Perbill, RuntimeDebug};
+
+    /// Stake of a staker starting from an era, as stored before reward per stake accounting.
+    #[derive(Encode, Decode)]
+    pub struct OldEraStake<Balance: HasCompact> {
+        #[codec(compact)]
+        pub staked: Balance,
+        #[codec(compact)]
+        pub era: Era,
+    }
+
+    /// Staker info as stored before reward per stake accounting.
+    #[derive(Encode, Decode)]
+    pub struct OldStakerInfo<Balance: HasCompact> {
+        pub stakes: Vec<OldEraStake<Balance>>,
+    }
+
+    /// Dao stake info of an era as stored before reward per stake accounting.
+    #[derive(Encode, Decode, Clone, Default)]
+    pub struct OldDaoStakeInfo<Balance: HasCompact> {
+        #[codec(compact)]
+        pub total: Balance,
+        #[codec(compact)]
+        pub number_of_stakers: u32,
+        pub reward_claimed: bool,
+        pub active: bool,
+    }
+
+    /// Staking information about a dao in a particular era, replaced by [`CoreStake`].
+    #[storage_alias]
+    pub type CoreEraStake<T: Config> = StorageDoubleMap<
+        Pallet<T>,
+        Blake2_128Concat,
+        <T as pallet_dao_manager::Config>::DaoId,
+        Twox64Concat,
+        Era,
+        OldDaoStakeInfo<BalanceOf<T>>,
+    >;
+
+    /// Calculates the dao reward and the joint stakers reward of a dao in an era, as they were paid before the migration.
+    fn dao_stakers_split<Balance: AtLeast32BitUnsigned + Copy + HasCompact + MaxEncodedLen>(
+        dao_info: &OldDaoStakeInfo<Balance>,
+        era_info: &EraInfo<Balance>,
+    ) -> (Balance, Balance) {
+        let dao_stake_portion = if dao_info.active {
+            Perbill::from_rational(dao_info.total, era_info.active_stake)
+        } else {
+            Perbill::zero()
+        };
+        let stakers_stake_portion = Perbill::from_rational(dao_info.total, era_info.staked);
+
+        (
+            dao_stake_portion * era_info.rewards.dao,
+            stakers_stake_portion * era_info.rewards.stakers,
+        )
+    }
+
+    /// Part of the storage the migration is going through.
+    #[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug)]
+    pub enum MigrationStage {
+        DaoStakes,
+        UnregisteredDaos,
+        Stakers,
+    }
+
+    /// Progress of the migration, kept in storage between blocks.
+    #[derive(Encode, Decode, Clone, RuntimeDebug)]
+    pub struct MigrationProgress<Balance> {
+        pub stage: MigrationStage,
+        /// Raw key of the last migrated entry of the stage, empty when the stage hasn't started.
+        pub cursor: Vec<u8>,
+        /// Stake of the daos active in the current era, summed while going through the dao stakes.
+        pub active_stake: Balance,
+        /// Whether the pallet was halted before the migration, restored once it's done.
+        pub was_halted: bool,
+    }
+
+    /// Progress of the migration, present while the migration is in progress.
+    #[storage_alias]
+    pub type RewardPerStakeMigration<T: Config> =
+        StorageValue<Pallet<T>, MigrationProgress<BalanceOf<T>>>;
+
+    /// Moves the stake of every dao in the current era to [`CoreStake`], turning the unclaimed rewards of past eras
+    /// into pending rewards, and sums the stake of the active daos.
+    ///
+    /// Returns whether every row was migrated, the legacy rows are kept in storage.
+    fn migrate_dao_stakes<T: Config>(
+        current_era: Era,
+        progress: &mut MigrationProgress<BalanceOf<T>>,
+        meter: &mut WeightMeter,
+    ) -> bool {
+        let row_weight = T::DbWeight::get().reads_writes(4, 1);
+        let mut rows = if progress.cursor.is_empty() {
+            CoreEraStake::<T>::iter()
+        } else {
+            CoreEraStake::<T>::iter_from(progress.cursor.clone())
+        };
+
+        while meter.try_consume(row_weight).is_ok() {
+            let Some((dao_id, era, old)) = rows.next() else {
+                return true;
+            };
+
+            if era == current_era {
+                let active = old.total >= <T as Config>::StakeThresholdForActiveDao::get()
+                    && RegisteredCore::<T>::contains_key(dao_id);
+
+                if active {
+                    progress.active_stake = progress.active_stake.saturating_add(old.total);
+                }
+
+                CoreStake::<T>::mutate(dao_id, |dao_stake_info| {
+                    dao_stake_info.total = old.total;
+                    dao_stake_info.number_of_stakers = old.number_of_stakers;
+                    dao_stake_info.active = active;
+                });
+            } else if era < current_era && !old.reward_claimed {
+                if let Some(era_info) = GeneralEraInfo::<T>::get(era) {
+                    let (dao_reward, _) = dao_stakers_split(&old, &era_info);
+
+                    CoreStake::<T>::mutate(dao_id, |dao_stake_info| {
+                        dao_stake_info.pending_rewards =
+                            dao_stake_info.pending_rewards.saturating_add(dao_reward);
+                    });
+                }
+            }
+
+            progress.cursor = rows.last_raw_key().to_vec();
+        }
+
+        false
+    }
+
+    /// Moves the stake of the daos being unregistered to the new stake info,
+    /// their stakers reward per stake index stays frozen at zero.
+    ///
+    /// Returns whether every dao was migrated.
+    fn migrate_unregistered_daos<T: Config>(
+        progress: &mut MigrationProgress<BalanceOf<T>>,
+        meter: &mut WeightMeter,
+    ) -> bool {
+        let dao_weight = T::DbWeight::get().reads_writes(1, 1);
+        let mut dao_ids = if progress.cursor.is_empty() {
+            UnregisteredCoreStakeInfo::<T>::iter_keys()
+        } else {
+            UnregisteredCoreStakeInfo::<T>::iter_keys_from(progress.cursor.clone())
+        };
+
+        while meter.try_consume(dao_weight).is_ok() {
+            let Some(dao_id) = dao_ids.next() else {
+                return true;
+            };
+
+            let key = UnregisteredCoreStakeInfo::<T>::hashed_key_for(dao_id);
+
+            if let Some(old) = unhashed::get::<OldDaoStakeInfo<BalanceOf<T>>>(&key) {
+                UnregisteredCoreStakeInfo::<T>::insert(
+                    dao_id,
+                    DaoStakeInfo {
+                        total: old.total,
+                        number_of_stakers: old.number_of_stakers,
+                        ..Default::default()
+                    },
+                );
+            }
+
+            progress.cursor = key;
+        }
+
+        false
+    }
+
+    /// Moves every staker to the reward per stake staker info, turning the rewards of every unclaimed era into pending rewards.
+    ///
+    /// A staker is migrated within a single block, reading the dao stake and the information of every era since its first stake.
+    ///
+    /// Returns whether every staker was migrated.
+    fn migrate_stakers<T: Config>(
+        current_era: Era,
+        progress: &mut MigrationProgress<BalanceOf<T>>,
+        meter: &mut WeightMeter,
+    ) -> bool {
+        let db_weight = T::DbWeight::get();
+        let mut stakers = if progress.cursor.is_empty() {
+            GeneralStakerInfo::<T>::iter_keys()
+        } else {
+            GeneralStakerInfo::<T>::iter_keys_from(progress.cursor.clone())
+        };
+
+        while meter.try_consume(db_weight.reads(1)).is_ok() {
+            let Some((dao_id, staker)) = stakers.next() else {
+                return true;
+            };
+
+            let key = GeneralStakerInfo::<T>::hashed_key_for(dao_id, &staker);
+            let old = unhashed::get::<OldStakerInfo<BalanceOf<T>>>(&key)
+                .unwrap_or(OldStakerInfo { stakes: Vec::new() });
+
+            let first_era = old.stakes.first().map_or(current_era, |x| x.era);
+            let eras: u64 = current_era.saturating_sub(first_era).into();
+
+            // The cursor isn't moved, so the staker is migrated in a later block.
+            if meter
+                .try_consume(db_weight.reads_writes(eras.saturating_mul(2), 1))
+                .is_err()
+            {
+                return false;
+            }
+
+            let mut pending_rewards: BalanceOf<T> = Zero::zero();
+
+            if let Some(first) = old.stakes.first() {
+                let mut stakes = old.stakes.iter().peekable();
+                let mut staked = first.staked;
+
+                for era in first.era..current_era {
+                    while let Some(era_stake) = stakes.next_if(|x| x.era <= era) {
+                        staked = era_stake.staked;
+                    }
+
+                    if let (Some(dao_stake), Some(era_info)) = (
+                        CoreEraStake::<T>::get(dao_id, era),
+                        GeneralEraInfo::<T>::get(era),
+                    ) {
+                        if !dao_stake.total.is_zero() {
+                            let (_, stakers_joint_reward) =
+                                dao_stakers_split(&dao_stake, &era_info);
+                            pending_rewards = pending_rewards.saturating_add(
+                                Perbill::from_rational(staked, dao_stake.total)
+                                    * stakers_joint_reward,
+                            );
+                        }
+                    }
+                }
+            }
+
+            let staker_info = StakerInfo {
+                staked: old.stakes.last().map_or(Zero::zero(), |x| x.staked),
+                reward_per_stake_paid: Default::default(),
+                pending_rewards,
+            };
+
+            if staker_info.is_empty() {
+                GeneralStakerInfo::<T>::remove(dao_id, &staker);
+            } else {
+                GeneralStakerInfo::<T>::insert(dao_id, &staker, staker_info);
+            }
+
+            progress.cursor = key;
+        }
+
+        false
+    }
+
+    /// Migrates as many entries as `meter` allows, called from `on_idle` until the migration is done.
+    ///
+    /// Once every staker is migrated the active stake of the current era is set and the pallet goes back
+    /// to the halt state it had before the migration.
+    pub fn migrate_step<T: Config>(
+        mut progress: MigrationProgress<BalanceOf<T>>,
+        meter: &mut WeightMeter,
+    ) {
+        let db_weight = T::DbWeight::get();
+
+        // Reading the current era and storing the progress.
+        if meter.try_consume(db_weight.reads_writes(1, 1)).is_err() {
+            return;
+        }
+
+        let current_era = Pallet::<T>::current_era();
+
+        loop {
+            let stage_done = match progress.stage {
+                MigrationStage::DaoStakes => {
+                    migrate_dao_stakes::<T>(current_era, &mut progress, meter)
+                }
+                MigrationStage::UnregisteredDaos => {
+                    migrate_unregistered_daos::<T>(&mut progress, meter)
+                }
+                MigrationStage::Stakers => migrate_stakers::<T>(current_era, &mut progress, meter),
+            };
+
+            if !stage_done {
+                break;
+            }
+
+            match progress.stage {
+                MigrationStage::DaoStakes => {
+                    if meter.try_consume(db_weight.reads_writes(1, 1)).is_err() {
+                        break;
+                    }
+
+                    GeneralEraInfo::<T>::mutate(current_era, |value| {
+                        if let Some(x) = value {
+                            x.active_stake = progress.active_stake;
+                        }
+                    });
+
+                    info!("Migrated dao stakes");
+                    progress.stage = MigrationStage::UnregisteredDaos;
+                }
+                MigrationStage::UnregisteredDaos => {
+                    info!("Migrated unregistered dao stakes");
+                    progress.stage = MigrationStage::Stakers;
+                }
+                MigrationStage::Stakers => {
+                    if meter.try_consume(db_weight.writes(1)).is_err() {
+                        break;
+                    }
+
+                    Pallet::<T>::internal_halt_unhalt(progress.was_halted);
+                    RewardPerStakeMigration::<T>::kill();
+
+                    info!("v3 migration finished");
+                    return;
+                }
+            }
+
+            progress.cursor = Vec::new();
+        }
+
+        RewardPerStakeMigration::<T>::put(progress);
+    }
+
+    /// Starts the migration to reward per stake accounting.
+    ///
+    /// The pallet is halted and the stake is migrated in `on_idle` using the block's spare weight,
+    /// eras don't advance and calls are rejected until it's done.
+    pub struct MigrateToV3<T>(sp_std::marker::PhantomData<T>);
+    impl<T: Config> OnRuntimeUpgrade for MigrateToV3<T> {
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<sp_runtime::Vec<u8>, sp_runtime::DispatchError> {
+            frame_support::ensure!(
+                Pallet::<T>::on_chain_storage_version() == 2,
+                "Required v2 before upgrading to v3"
+            );
+
+            Ok(Default::default())
+        }
+
+        fn on_runtime_upgrade() -> Weight {
+            if Pallet::<T>::on_chain_storage_version() == 2 {
+                RewardPerStakeMigration::<T>::put(MigrationProgress {
+                    stage: MigrationStage::DaoStakes,
+                    cursor: Vec::new(),
+                    active_stake: Zero::zero(),
+                    was_halted: Pallet::<T>::is_halted(),
+                });
+
+                Pallet::<T>::internal_halt_unhalt(true);
+
+                StorageVersion::new(3).put::<Pallet<T>>();
+
+                info!("v3 applied successfully, stake is migrated in on_idle");
+                T::DbWeight::get().reads_writes(2, 3)
+            } else {
+                warn!("Skipping v3, should be removed");
+                T::DbWeight::get().reads(1)
+            }
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(_state: sp_std::vec::Vec<u8>) -> Result<(), sp_runtime::DispatchError> {
+            frame_support::ensure!(
+                Pallet::<T>::on_chain_storage_version() == 3,
+                "v3 not applied"
+            );
+
+            frame_support::ensure!(
+                RewardPerStakeMigration::<T>::exists() && Pallet::<T>::is_halted(),
+                "Stake migration not started"
+            );
+
+            Ok(())
+        }
+    }
+}
+
+pub mod v4 {
+    use super::*;
+
+    /// Starts the removal of the legacy dao stake rows left by v3.
+    ///
+    /// The rows and the information of eras older than `HistoryDepth` are removed in `on_idle`
+    /// using the block's spare weight, after the v3 stake migration is done with them.
+    pub struct MigrateToV4<T>(sp_std::marker::PhantomData<T>);
+    impl<T: Config> OnRuntimeUpgrade for MigrateToV4<T> {
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<sp_runtime::Vec<u8>, sp_runtime::DispatchError> {
+            frame_support::ensure!(
+                Pallet::<T>::on_chain_storage_version() == 3,
+                "Required v3 before upgrading to v4"
+            );
+
+            Ok(Default::default())
+        }
+
+        fn on_runtime_upgrade() -> Weight {
+            if Pallet::<T>::on_chain_storage_version() == 3 {
+                LegacyEraStakeCursor::<T>::put(BoundedVec::default());
+
+                StorageVersion::new(4).put::<Pallet<T>>();
+
+                info!("v4 applied successfully");
+                T::DbWeight::get().reads_writes(1, 2)
+            } else {
+                warn!("Skipping v4, should be removed");
+                T::DbWeight::get().reads(1)
+            }
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(_state: sp_std::vec::Vec<u8>) -> Result<(), sp_runtime::DispatchError> {
+            frame_support::ensure!(
+                Pallet::<T>::on_chain_storage_version() == 4,
+                "v4 not applied"
+            );
+
+            frame_support::ensure!(
+                LegacyEraStakeCursor::<T>::exists(),
+                "Legacy dao stake removal not started"
+            );
+
+            Ok(())
+        }
+    }
+}
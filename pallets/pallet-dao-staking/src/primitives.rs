@@ -7,17 +7,18 @@
 //! ## Types overview:
 //!
 //! - `BalanceOf` - A type alias for the balance of a currency in the system.
-//! - `DaoMetadata` - A struct that holds metadata for a DAO entity in the system.
 //! - `DaoInfo` - A struct that holds information about a DAO entity, including its account ID and metadata.
 //! - `RewardInfo` - A struct that holds information about rewards, including the balance for stakers and the DAO.
 //! - `EraInfo` - A struct that holds information about a specific era, including rewards, staked balance, active stake, and locked balance.
+//! - `RewardPerStake` - A struct that holds the cumulative rewards distributed per unit of stake to stakers and DAOs.
 //! - `DaoStakeInfo` - A struct that holds information about a DAO's stake, including the total balance,
-//!    number of stakers, and whether a reward has been claimed.
-//! - `EraStake` - A struct that holds information about the stake for a specific era.
-//! - `StakerInfo` - A struct that holds information about a staker's stakes across different eras.
+//!    number of stakers, whether it's active and its unclaimed rewards.
+//! - `StakerInfo` - A struct that holds information about a staker's stake on a DAO and its unclaimed rewards.
+//! - `PoolInfo` - A struct that holds information about a nomination pool, including its creator, DAO, total stake and number of members.
 //! - `UnlockingChunk` - A struct that holds information about an unlocking chunk of balance.
 //! - `UnbondingInfo` - A struct that holds information about unbonding chunks of balance.
 //! - `AccountLedger` - A struct that holds information about an account's locked balance and unbonding information.
+//! - `RewardDestination` - An enum that defines where a staker's rewards are paid to.
 
 use codec::{Decode, Encode, FullCodec, HasCompact, MaxEncodedLen};
 use cumulus_primitives_core::{AggregateMessageOrigin, Location, ParaId};
@@ -28,8 +29,9 @@ use frame_support::{
 use pallet_message_queue::OnQueueChanged;
 use scale_info::{prelude::marker::PhantomData, TypeInfo};
 use sp_runtime::{
-    traits::{AtLeast32BitUnsigned, Zero},
-    Perbill, RuntimeDebug,
+    helpers_128bit::multiply_by_rational_with_rounding,
+    traits::{AtLeast32BitUnsigned, Saturating, UniqueSaturatedFrom, UniqueSaturatedInto, Zero},
+    FixedPointNumber, FixedU128, Perbill, Rounding, RuntimeDebug,
 };
 use sp_std::{fmt::Debug, ops::Add, prelude::*};
 
@@ -42,14 +44,6 @@ pub type BalanceOf<T> =
 
 const MAX_ASSUMED_VEC_LEN: u32 = 10;
 
-/// Metadata for a dao entity in the system.
-#[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
-pub struct DaoMetadata<Name, Description, Image> {
-    pub name: Name,
-    pub description: Description,
-    pub image: Image,
-}
-
 /// Information about a dao entity, including its account ID and metadata.
 #[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
 pub struct DaoInfo<AccountId, Metadata> {
@@ -78,142 +72,182 @@ pub struct EraInfo<Balance: HasCompact + MaxEncodedLen> {
     pub(crate) locked: Balance,
 }
 
-/// Information about a dao's stake, including the total balance, number of stakers, and whether a reward has been claimed.
+/// Cumulative rewards distributed per unit of stake, to stakers and daos respectively.
+///
+/// Every era end adds the era's rewards divided by the stake entitled to them, so the reward of a position
+/// between two points in time is its stake multiplied by the difference of the index at those points.
+#[derive(
+    PartialEq, Eq, Clone, Copy, Default, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen,
+)]
+pub struct RewardPerStake {
+    pub(crate) stakers: FixedU128,
+    pub(crate) dao: FixedU128,
+}
+
+impl RewardPerStake {
+    /// Adds an era's rewards to the index, `staked` being the stake of all stakers and `active_stake`
+    /// the stake of all active daos at the end of the era.
+    pub(crate) fn accrue<Balance: AtLeast32BitUnsigned + Copy + HasCompact + MaxEncodedLen>(
+        &mut self,
+        rewards: &RewardInfo<Balance>,
+        staked: Balance,
+        active_stake: Balance,
+    ) {
+        let per_stake = |reward: Balance, stake: Balance| {
+            FixedU128::checked_from_rational(
+                reward.unique_saturated_into(),
+                stake.unique_saturated_into(),
+            )
+            .unwrap_or_default()
+        };
+
+        self.stakers = self
+            .stakers
+            .saturating_add(per_stake(rewards.stakers, staked));
+        self.dao = self
+            .dao
+            .saturating_add(per_stake(rewards.dao, active_stake));
+    }
+}
+
+/// Returns the reward earned by `stake` while a reward per stake index went from `from` to `to`.
+pub(crate) fn reward_for_stake<Balance: AtLeast32BitUnsigned + Copy>(
+    stake: Balance,
+    from: FixedU128,
+    to: FixedU128,
+) -> Balance {
+    Balance::unique_saturated_from(
+        to.saturating_sub(from)
+            .saturating_mul_int(stake.unique_saturated_into()),
+    )
+}
+
+/// Information about a dao's stake, including the total balance, number of stakers, whether it's active and its rewards.
 #[derive(Clone, PartialEq, Eq, Encode, Decode, Default, RuntimeDebug, TypeInfo, MaxEncodedLen)]
 pub struct DaoStakeInfo<Balance: HasCompact + MaxEncodedLen> {
     #[codec(compact)]
     pub(crate) total: Balance,
     #[codec(compact)]
     pub(crate) number_of_stakers: u32,
-    pub(crate) reward_claimed: bool,
     pub(crate) active: bool,
-}
-
-/// Information about the stake for a specific era.
-#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
-pub(crate) struct EraStake<Balance: AtLeast32BitUnsigned + Copy + MaxEncodedLen> {
+    /// Stakers reward per stake index of the dao, follows the global index while the dao is registered.
+    pub(crate) reward_per_stake: FixedU128,
+    /// Dao reward per stake index the dao's pending rewards were accrued up to.
+    pub(crate) dao_reward_per_stake_paid: FixedU128,
+    /// Rewards accrued to the dao and not claimed yet.
     #[codec(compact)]
-    pub(crate) staked: Balance,
-    #[codec(compact)]
-    pub(crate) era: Era,
+    pub(crate) pending_rewards: Balance,
 }
 
-/// Information about a staker's stakes across different eras.
-#[derive(Encode, Decode, Clone, Default, PartialEq, Eq, RuntimeDebug, TypeInfo)]
-pub struct StakerInfo<Balance: AtLeast32BitUnsigned + Copy + MaxEncodedLen> {
-    pub(crate) stakes: Vec<EraStake<Balance>>,
-}
+impl<Balance: AtLeast32BitUnsigned + Copy + HasCompact + MaxEncodedLen> DaoStakeInfo<Balance> {
+    /// Accrues the dao's rewards up to the given index, `registered` tells whether the dao's stakers
+    /// are still entitled to rewards.
+    pub(crate) fn accrue(&mut self, index: &RewardPerStake, registered: bool) {
+        if self.active {
+            self.pending_rewards = self.pending_rewards.saturating_add(reward_for_stake(
+                self.total,
+                self.dao_reward_per_stake_paid,
+                index.dao,
+            ));
+        }
+        self.dao_reward_per_stake_paid = index.dao;
 
-impl<Balance: AtLeast32BitUnsigned + Copy + MaxEncodedLen> MaxEncodedLen for StakerInfo<Balance> {
-    fn max_encoded_len() -> usize {
-        codec::Compact(MAX_ASSUMED_VEC_LEN)
-            .encoded_size()
-            .saturating_add(
-                (MAX_ASSUMED_VEC_LEN as usize)
-                    .saturating_mul(EraStake::<Balance>::max_encoded_len()),
-            )
+        if registered {
+            self.reward_per_stake = index.stakers;
+        }
     }
 }
 
-impl<Balance: AtLeast32BitUnsigned + Copy + MaxEncodedLen> StakerInfo<Balance> {
+/// Information about a staker's stake on a dao and its rewards.
+#[derive(Encode, Decode, Clone, Default, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct StakerInfo<Balance: HasCompact + MaxEncodedLen> {
+    #[codec(compact)]
+    pub(crate) staked: Balance,
+    /// Dao stakers reward per stake index the staker's pending rewards were accrued up to.
+    pub(crate) reward_per_stake_paid: FixedU128,
+    /// Rewards accrued to the staker and not claimed yet.
+    #[codec(compact)]
+    pub(crate) pending_rewards: Balance,
+}
+
+impl<Balance: AtLeast32BitUnsigned + Copy + HasCompact + MaxEncodedLen> StakerInfo<Balance> {
     pub(crate) fn is_empty(&self) -> bool {
-        self.stakes.is_empty()
+        self.staked.is_zero() && self.pending_rewards.is_zero()
     }
 
-    pub(crate) fn len(&self) -> u32 {
-        self.stakes.len() as u32
+    /// Accrues the staker's rewards up to the stakers reward per stake index of the dao.
+    pub(crate) fn accrue(&mut self, dao_stake_info: &DaoStakeInfo<Balance>) {
+        self.accrue_to(dao_stake_info.reward_per_stake);
     }
 
-    /// Stakes the given value in the current era, mutates StakerInfo in-place.
-    pub(crate) fn stake(&mut self, current_era: Era, value: Balance) -> Result<(), &str> {
-        if let Some(era_stake) = self.stakes.last_mut() {
-            if era_stake.era > current_era {
-                return Err("Unexpected era");
-            }
-
-            let new_stake_value = era_stake.staked.saturating_add(value);
-
-            if current_era == era_stake.era {
-                *era_stake = EraStake {
-                    staked: new_stake_value,
-                    era: current_era,
-                }
-            } else {
-                self.stakes.push(EraStake {
-                    staked: new_stake_value,
-                    era: current_era,
-                })
-            }
-        } else {
-            self.stakes.push(EraStake {
-                staked: value,
-                era: current_era,
-            });
-        }
-
-        Ok(())
+    /// Accrues the staker's rewards up to the given reward per stake index.
+    pub(crate) fn accrue_to(&mut self, reward_per_stake: FixedU128) {
+        self.pending_rewards = self.pending_rewards.saturating_add(reward_for_stake(
+            self.staked,
+            self.reward_per_stake_paid,
+            reward_per_stake,
+        ));
+        self.reward_per_stake_paid = reward_per_stake;
     }
 
-    /// Unstakes the given value in the current era, mutates StakerInfo in-place.
-    pub(crate) fn unstake(&mut self, current_era: Era, value: Balance) -> Result<(), &str> {
-        if let Some(era_stake) = self.stakes.last_mut() {
-            if era_stake.era > current_era {
-                return Err("Unexpected era");
-            }
-
-            let new_stake_value = era_stake.staked.saturating_sub(value);
-            if current_era == era_stake.era {
-                *era_stake = EraStake {
-                    staked: new_stake_value,
-                    era: current_era,
-                }
-            } else {
-                self.stakes.push(EraStake {
-                    staked: new_stake_value,
-                    era: current_era,
-                })
-            }
-
-            if !self.stakes.is_empty() && self.stakes[0].staked.is_zero() {
-                self.stakes.remove(0);
-            }
-        }
-
-        Ok(())
+    /// Takes the staker's pending rewards, leaving none.
+    pub(crate) fn take_rewards(&mut self) -> Balance {
+        sp_std::mem::take(&mut self.pending_rewards)
     }
+}
 
-    /// Claims the stake for the current era, mutates StakerInfo in-place.  
-    /// Returns the era and the staked balance.
-    pub(crate) fn claim(&mut self) -> (Era, Balance) {
-        if let Some(era_stake) = self.stakes.first() {
-            let era_stake = *era_stake;
-
-            // this checks if the last claim was from an older era or if the latest staking info is from
-            // a newer era compared to the last claim, allowing the user to increase their stake while not losing
-            // or messing with their stake from the previous eras.
-            if self.stakes.len() == 1 || self.stakes[1].era > era_stake.era + 1 {
-                self.stakes[0] = EraStake {
-                    staked: era_stake.staked,
-                    era: era_stake.era.saturating_add(1),
-                }
-            } else {
-                self.stakes.remove(0);
-            }
-
-            if !self.stakes.is_empty() && self.stakes[0].staked.is_zero() {
-                self.stakes.remove(0);
-            }
+/// Information about a nomination pool, which stakes on a dao as a single staker on behalf of its members.
+#[derive(Encode, Decode, Clone, Default, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct PoolInfo<AccountId, DaoId, Balance: HasCompact + MaxEncodedLen> {
+    /// Account the pool deposit is held from.
+    pub(crate) creator: AccountId,
+    pub(crate) dao: DaoId,
+    /// Sum of the members' stake.
+    #[codec(compact)]
+    pub(crate) total: Balance,
+    #[codec(compact)]
+    pub(crate) number_of_members: u32,
+    /// Reward per stake index of the pool's members, grows with every reward claimed by the pool.
+    pub(crate) reward_per_stake: FixedU128,
+}
 
-            (era_stake.era, era_stake.staked)
-        } else {
-            (0, Zero::zero())
-        }
+impl<AccountId, DaoId, Balance: AtLeast32BitUnsigned + Copy + HasCompact + MaxEncodedLen>
+    PoolInfo<AccountId, DaoId, Balance>
+{
+    /// Shares a reward claimed by the pool among its members, pro-rata to their stake.
+    pub(crate) fn distribute(&mut self, reward: Balance) {
+        self.reward_per_stake = self.reward_per_stake.saturating_add(
+            FixedU128::checked_from_rational(
+                reward.unique_saturated_into(),
+                self.total.unique_saturated_into(),
+            )
+            .unwrap_or_default(),
+        );
     }
+}
 
-    /// Returns the latest staked balance.
-    pub(crate) fn latest_staked_value(&self) -> Balance {
-        self.stakes.last().map_or(Zero::zero(), |x| x.staked)
+/// Converts an amount between the two sides of an exchange, rounding down.
+///
+/// `from_total` is the total of the side the amount is in and `to_total` the total of the side it's converted to,
+/// in case either is zero the exchange is one to one.
+pub(crate) fn convert_share<Balance: AtLeast32BitUnsigned + Copy>(
+    amount: Balance,
+    from_total: Balance,
+    to_total: Balance,
+) -> Balance {
+    if from_total.is_zero() || to_total.is_zero() {
+        return amount;
     }
+
+    Balance::unique_saturated_from(
+        multiply_by_rational_with_rounding(
+            amount.unique_saturated_into(),
+            to_total.unique_saturated_into(),
+            from_total.unique_saturated_into(),
+            Rounding::Down,
+        )
+        .unwrap_or_default(),
+    )
 }
 
 /// A chunk of balance that is unlocking until a specific era.
@@ -288,6 +322,29 @@ where
         }
     }
 
+    /// Takes up to `amount` out of the chunks starting from the newest one, removing the emptied chunks.
+    ///
+    /// Returns the amount taken.
+    pub(crate) fn rebond(&mut self, amount: Balance) -> Balance {
+        let mut remaining = amount;
+
+        while let Some(chunk) = self.unlocking_chunks.last_mut() {
+            if remaining.is_zero() {
+                break;
+            }
+
+            if chunk.amount <= remaining {
+                remaining = remaining.saturating_sub(chunk.amount);
+                self.unlocking_chunks.pop();
+            } else {
+                chunk.amount = chunk.amount.saturating_sub(remaining);
+                remaining = Zero::zero();
+            }
+        }
+
+        amount.saturating_sub(remaining)
+    }
+
     /// returns the chucks before and after a given era.
     pub(crate) fn partition(self, era: Era) -> (Self, Self) {
         let (matching_chunks, other_chunks): (
@@ -323,6 +380,20 @@ impl<Balance: AtLeast32BitUnsigned + Default + Copy + MaxEncodedLen> AccountLedg
     }
 }
 
+/// Destination of a staker's rewards.
+#[derive(Clone, PartialEq, Eq, Encode, Decode, Default, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum RewardDestination<AccountId> {
+    /// Rewards are paid to the staker's free balance.
+    #[default]
+    FreeBalance,
+    /// Rewards are paid to another account's free balance.
+    Account(AccountId),
+    /// Rewards are staked towards the dao they were earned from.
+    ///
+    /// Falls back to the staker's free balance if the dao is no longer registered or the stake isn't possible.
+    Restake,
+}
+
 #[derive(Encode, Decode, MaxEncodedLen, Clone, Eq, PartialEq, TypeInfo, Debug)]
 pub enum CustomAggregateMessageOrigin<XcmOrigin> {
     Aggregate(XcmOrigin),
@@ -38,7 +38,11 @@ pub(crate) const _MAX_NUMBER_OF_STAKERS_TINKERNET: u32 = 10000;
 pub(crate) const MINIMUM_STAKING_AMOUNT: Balance = 10;
 pub(crate) const MAX_UNLOCKING: u32 = 4;
 pub(crate) const UNBONDING_PERIOD: EraIndex = 3;
-pub(crate) const MAX_ERA_STAKE_VALUES: u32 = 8;
+pub(crate) const MAX_DAOS_PER_CLAIM: u32 = 3;
+pub(crate) const HISTORY_DEPTH: u32 = 5;
+pub(crate) const INSTANT_UNSTAKE_PENALTY: Perbill = Perbill::from_percent(10);
+pub(crate) const POOL_DEPOSIT: Balance = 5;
+pub(crate) const RECEIPT_TOKEN: u32 = u32::MAX;
 pub(crate) const BLOCKS_PER_ERA: BlockNumber = 3;
 pub(crate) const REGISTER_DEPOSIT: Balance = 10;
 const MICROUNIT: Balance = 1_000_000;
@@ -128,7 +132,10 @@ parameter_types! {
     pub const PotId: PalletId = PalletId(*b"ocif-pot");
     pub const MaxUnlocking: u32 = MAX_UNLOCKING;
     pub const UnbondingPeriod: EraIndex = UNBONDING_PERIOD;
-    pub const MaxEraStakeValues: u32 = MAX_ERA_STAKE_VALUES;
+    pub const MaxDaosPerClaim: u32 = MAX_DAOS_PER_CLAIM;
+    pub const HistoryDepth: u32 = HISTORY_DEPTH;
+    pub const InstantUnstakePenalty: Perbill = INSTANT_UNSTAKE_PENALTY;
+    pub const PoolDeposit: Balance = POOL_DEPOSIT;
     pub const RewardRatio: (u32, u32) = (50, 50);
 }
 
@@ -146,6 +153,7 @@ parameter_types! {
         243, 115, 218, 162, 0, 9, 138, 232, 68, 55, 129, 106, 210,
     ]);
     pub const RelayAssetId: u32 = 9999;
+    pub const EmergencySupport: Perbill = Perbill::from_percent(80);
     pub const UnregisterOrigin: CustomAggregateMessageOrigin<AggregateMessageOrigin> = CustomAggregateMessageOrigin::UnregisterMessageOrigin;
 }
 
@@ -178,6 +186,7 @@ impl pallet_dao_manager::fee_handling::MultisigFeeHandler<Test> for FeeCharger {
             match fee_asset {
                 pallet_dao_manager::fee_handling::FeeAsset::Native => None,
                 pallet_dao_manager::fee_handling::FeeAsset::Relay => Some(1u32),
+                pallet_dao_manager::fee_handling::FeeAsset::Asset(asset_id) => Some(*asset_id),
             },
         ))
     }
@@ -193,6 +202,10 @@ impl pallet_dao_manager::fee_handling::MultisigFeeHandler<Test> for FeeCharger {
         Ok(())
     }
 
+    fn native_to_asset_fee(_asset_id: u32, _fee: Balance) -> Option<Balance> {
+        None
+    }
+
     fn handle_creation_fee(
         _imbalance: pallet_dao_manager::fee_handling::FeeAssetNegativeImbalance<
             Credit<AccountId, Balances>,
@@ -233,6 +246,9 @@ impl orml_tokens::Config for Test {
 
 impl pallet_dao_manager::Config for Test {
     type MaxMetadata = MaxMetadata;
+    type MaxNameLength = ConstU32<20>;
+    type MaxDescriptionLength = ConstU32<300>;
+    type MaxUrlLength = ConstU32<100>;
     type DaoId = u32;
     type RuntimeEvent = RuntimeEvent;
     type Currency = Balances;
@@ -252,6 +268,20 @@ impl pallet_dao_manager::Config for Test {
 
     type ParaId = ConstU32<2125>;
     type LengthToFee = ConstantMultiplier<Balance, TransactionByteFee>;
+    type EmergencySupport = EmergencySupport;
+    type EmergencyCalls = frame_support::traits::Everything;
+    type NftCollectionId = u32;
+    type NftItemId = u32;
+    type Nfts = pallet_dao_manager::voting::NoNfts<u32>;
+    type MaxNftVotingItems = ConstU32<100>;
+    type MaxJointDaos = ConstU32<10>;
+    type MaxHandleLength = ConstU32<32>;
+    type HandleDeposit = frame_support::traits::ConstU128<1000>;
+    type MaxSymbolLength = ConstU32<10>;
+    type MaxVestingSchedules = ConstU32<10>;
+    type MaxCouncilMembers = ConstU32<10>;
+    type PublicProposalDeposit = frame_support::traits::ConstU128<1000>;
+    type PublicProposalDuration = frame_support::traits::ConstU64<100>;
 }
 
 impl pallet_dao_staking::Config for Test {
@@ -267,12 +297,13 @@ impl pallet_dao_staking::Config for Test {
     type ExistentialDeposit = ExistentialDeposit;
     type MaxUnlocking = MaxUnlocking;
     type UnbondingPeriod = UnbondingPeriod;
-    type MaxEraStakeValues = MaxEraStakeValues;
-    type MaxDescriptionLength = ConstU32<300>;
-    type MaxNameLength = ConstU32<20>;
-    type MaxImageUrlLength = ConstU32<60>;
     type RewardRatio = RewardRatio;
     type StakeThresholdForActiveDao = ConstU128<THRESHOLD>;
+    type MaxDaosPerClaim = MaxDaosPerClaim;
+    type HistoryDepth = HistoryDepth;
+    type InstantUnstakePenalty = InstantUnstakePenalty;
+    type PoolDeposit = PoolDeposit;
+    type ReceiptAssets = CoreAssets;
     type WeightInfo = crate::weights::SubstrateWeight<Test>;
     type StakingMessage = frame_support::traits::EnqueueWithOrigin<MessageQueue, UnregisterOrigin>;
     type WeightToFee = ConstantMultiplier<Balance, ZeroFee>;
@@ -18,7 +18,7 @@ impl MemorySnapshot {
         Self {
             era_info: OcifStaking::general_era_info(era).unwrap(),
             staker_info: GeneralStakerInfo::<Test>::get(dao, &account),
-            dao_stake_info: OcifStaking::dao_stake_info(dao, era).unwrap_or_default(),
+            dao_stake_info: OcifStaking::dao_stake_info(dao),
             ledger: OcifStaking::ledger(&account),
             free_balance: <Test as Config>::Currency::free_balance(&account),
         }
@@ -37,9 +37,7 @@ pub(crate) fn assert_register(dao: mock::DaoId) {
             pallet_dao_manager::origin::MultisigInternalOrigin::new(dao)
         )
         .into(),
-        vec![].try_into().unwrap(),
-        vec![].try_into().unwrap(),
-        vec![].try_into().unwrap()
+        Some(Default::default())
     ));
 
     let dao_info = RegisteredCore::<Test>::get(dao).unwrap();
@@ -87,7 +85,7 @@ pub(crate) fn assert_stake(staker: AccountId, dao: &DaoId, value: Balance) {
 
     let final_state = MemorySnapshot::all(current_era, &dao, staker.clone());
 
-    if init_state.staker_info.latest_staked_value() == 0 {
+    if init_state.staker_info.staked == 0 {
         assert!(GeneralStakerInfo::<Test>::contains_key(
             dao,
             &staker.clone()
@@ -111,8 +109,8 @@ pub(crate) fn assert_stake(staker: AccountId, dao: &DaoId, value: Balance) {
         init_state.dao_stake_info.total + staking_value
     );
     assert_eq!(
-        final_state.staker_info.latest_staked_value(),
-        init_state.staker_info.latest_staked_value() + staking_value
+        final_state.staker_info.staked,
+        init_state.staker_info.staked + staking_value
     );
     assert_eq!(
         final_state.ledger.locked,
@@ -124,16 +122,13 @@ pub(crate) fn assert_unstake(staker: AccountId, dao: &DaoId, value: Balance) {
     let current_era = OcifStaking::current_era();
     let init_state = MemorySnapshot::all(current_era, &dao, staker.clone());
 
-    let remaining_staked = init_state
-        .staker_info
-        .latest_staked_value()
-        .saturating_sub(value);
+    let remaining_staked = init_state.staker_info.staked.saturating_sub(value);
     let expected_unbond_amount = if remaining_staked < MINIMUM_STAKING_AMOUNT {
-        init_state.staker_info.latest_staked_value()
+        init_state.staker_info.staked
     } else {
         value
     };
-    let remaining_staked = init_state.staker_info.latest_staked_value() - expected_unbond_amount;
+    let remaining_staked = init_state.staker_info.staked - expected_unbond_amount;
 
     assert_ok!(OcifStaking::unstake(
         RuntimeOrigin::signed(staker.clone()),
@@ -185,8 +180,8 @@ pub(crate) fn assert_unstake(staker: AccountId, dao: &DaoId, value: Balance) {
         final_state.dao_stake_info.total
     );
     assert_eq!(
-        init_state.staker_info.latest_staked_value() - expected_unbond_amount,
-        final_state.staker_info.latest_staked_value()
+        init_state.staker_info.staked - expected_unbond_amount,
+        final_state.staker_info.staked
     );
 
     let delta = if remaining_staked > 0 { 0 } else { 1 };
@@ -202,6 +197,624 @@ pub(crate) fn assert_unstake(staker: AccountId, dao: &DaoId, value: Balance) {
     assert_eq!(init_state.era_info.locked, final_state.era_info.locked);
 }
 
+pub(crate) fn assert_rebond(staker: AccountId, dao: &DaoId, value: Balance) {
+    let current_era = OcifStaking::current_era();
+    let init_state = MemorySnapshot::all(current_era, &dao, staker.clone());
+
+    let expected_rebond_amount = init_state.ledger.unbonding_info.sum().min(value);
+
+    assert_ok!(OcifStaking::rebond(
+        RuntimeOrigin::signed(staker.clone()),
+        dao.clone(),
+        value
+    ));
+    System::assert_last_event(mock::RuntimeEvent::OcifStaking(Event::Rebonded {
+        staker: staker.clone(),
+        dao: dao.clone(),
+        amount: expected_rebond_amount,
+    }));
+
+    let final_state = MemorySnapshot::all(current_era, &dao, staker.clone());
+
+    assert_eq!(
+        init_state.ledger.unbonding_info.sum() - expected_rebond_amount,
+        final_state.ledger.unbonding_info.sum()
+    );
+    assert_eq!(init_state.ledger.locked, final_state.ledger.locked);
+
+    assert_eq!(
+        init_state.era_info.staked + expected_rebond_amount,
+        final_state.era_info.staked
+    );
+    assert_eq!(init_state.era_info.locked, final_state.era_info.locked);
+
+    assert_eq!(
+        init_state.dao_stake_info.total + expected_rebond_amount,
+        final_state.dao_stake_info.total
+    );
+    assert_eq!(
+        init_state.staker_info.staked + expected_rebond_amount,
+        final_state.staker_info.staked
+    );
+}
+
+pub(crate) fn assert_instant_unstake(staker: AccountId, dao: Option<DaoId>, value: Balance) {
+    let current_era = OcifStaking::current_era();
+
+    let init_era_info = GeneralEraInfo::<Test>::get(current_era).unwrap();
+    let init_ledger = Ledger::<Test>::get(&staker);
+    let init_free_balance = <Test as Config>::Currency::free_balance(&staker);
+    let init_state = dao.map(|dao| MemorySnapshot::all(current_era, &dao, staker.clone()));
+
+    let expected_unlock_amount = match &init_state {
+        Some(init_state) => {
+            if init_state.staker_info.staked.saturating_sub(value) < MINIMUM_STAKING_AMOUNT {
+                init_state.staker_info.staked
+            } else {
+                value
+            }
+        }
+        None => init_ledger
+            .unbonding_info
+            .clone()
+            .partition(current_era)
+            .1
+            .sum()
+            .min(value),
+    };
+    // Chunks past the unbonding period are withdrawn without a penalty.
+    let expected_withdraw_amount = match dao {
+        Some(_) => 0,
+        None => init_ledger
+            .unbonding_info
+            .clone()
+            .partition(current_era)
+            .0
+            .sum(),
+    };
+    let expected_penalty = INSTANT_UNSTAKE_PENALTY * expected_unlock_amount;
+
+    assert_ok!(OcifStaking::instant_unstake(
+        RuntimeOrigin::signed(staker.clone()),
+        dao,
+        value
+    ));
+    if expected_unlock_amount == 0 {
+        System::assert_last_event(mock::RuntimeEvent::OcifStaking(Event::Withdrawn {
+            staker: staker.clone(),
+            amount: expected_withdraw_amount,
+        }));
+    } else {
+        System::assert_last_event(mock::RuntimeEvent::OcifStaking(Event::InstantUnstaked {
+            staker: staker.clone(),
+            dao,
+            amount: expected_unlock_amount,
+            penalty: expected_penalty,
+        }));
+    }
+
+    let final_era_info = GeneralEraInfo::<Test>::get(current_era).unwrap();
+    let final_ledger = Ledger::<Test>::get(&staker);
+
+    assert_eq!(
+        init_ledger.locked - expected_unlock_amount - expected_withdraw_amount,
+        final_ledger.locked
+    );
+    assert_eq!(
+        init_era_info.locked - expected_unlock_amount - expected_withdraw_amount,
+        final_era_info.locked
+    );
+    assert_eq!(
+        init_free_balance - expected_penalty,
+        <Test as Config>::Currency::free_balance(&staker)
+    );
+    if final_ledger.is_empty() {
+        assert!(!Ledger::<Test>::contains_key(&staker));
+    }
+
+    match (dao, init_state) {
+        (Some(dao), Some(init_state)) => {
+            let final_state = MemorySnapshot::all(current_era, &dao, staker.clone());
+
+            assert_eq!(init_ledger.unbonding_info, final_ledger.unbonding_info);
+            assert_eq!(
+                init_state.dao_stake_info.total - expected_unlock_amount,
+                final_state.dao_stake_info.total
+            );
+            assert_eq!(
+                init_state.staker_info.staked - expected_unlock_amount,
+                final_state.staker_info.staked
+            );
+            assert_eq!(
+                init_era_info.staked - expected_unlock_amount,
+                final_era_info.staked
+            );
+        }
+        _ => {
+            assert_eq!(
+                init_ledger.unbonding_info.sum()
+                    - expected_unlock_amount
+                    - expected_withdraw_amount,
+                final_ledger.unbonding_info.sum()
+            );
+            assert_eq!(init_era_info.staked, final_era_info.staked);
+        }
+    }
+}
+
+pub(crate) fn assert_transfer_stake(from: AccountId, dao: &DaoId, value: Balance, to: AccountId) {
+    let current_era = OcifStaking::current_era();
+    let init_from_state = MemorySnapshot::all(current_era, &dao, from.clone());
+    let init_to_state = MemorySnapshot::all(current_era, &dao, to.clone());
+
+    let expected_transfer_amount =
+        if init_from_state.staker_info.staked.saturating_sub(value) < MINIMUM_STAKING_AMOUNT {
+            init_from_state.staker_info.staked
+        } else {
+            value
+        };
+
+    assert_ok!(OcifStaking::transfer_stake(
+        RuntimeOrigin::signed(from.clone()),
+        dao.clone(),
+        value,
+        to.clone()
+    ));
+    System::assert_last_event(mock::RuntimeEvent::OcifStaking(Event::StakeTransferred {
+        from: from.clone(),
+        to: to.clone(),
+        dao: dao.clone(),
+        amount: expected_transfer_amount,
+    }));
+
+    let final_from_state = MemorySnapshot::all(current_era, &dao, from.clone());
+    let final_to_state = MemorySnapshot::all(current_era, &dao, to.clone());
+
+    assert_eq!(
+        init_from_state.staker_info.staked - expected_transfer_amount,
+        final_from_state.staker_info.staked
+    );
+    assert_eq!(
+        init_to_state.staker_info.staked + expected_transfer_amount,
+        final_to_state.staker_info.staked
+    );
+    assert_eq!(
+        init_from_state.ledger.locked - expected_transfer_amount,
+        final_from_state.ledger.locked
+    );
+    assert_eq!(
+        init_to_state.ledger.locked + expected_transfer_amount,
+        final_to_state.ledger.locked
+    );
+    assert_eq!(
+        init_from_state.free_balance - expected_transfer_amount,
+        final_from_state.free_balance
+    );
+    assert_eq!(
+        init_to_state.free_balance + expected_transfer_amount,
+        final_to_state.free_balance
+    );
+
+    let delta_from = if final_from_state.staker_info.staked > 0 {
+        0
+    } else {
+        1
+    };
+    let delta_to = if init_to_state.staker_info.staked > 0 {
+        0
+    } else {
+        1
+    };
+    assert_eq!(
+        init_from_state.dao_stake_info.number_of_stakers + delta_to - delta_from,
+        final_from_state.dao_stake_info.number_of_stakers
+    );
+    assert_eq!(
+        init_from_state.dao_stake_info.total,
+        final_from_state.dao_stake_info.total
+    );
+
+    assert_eq!(init_from_state.era_info, final_from_state.era_info);
+}
+
+pub(crate) fn assert_create_pool(creator: AccountId, dao: DaoId, value: Balance) -> PoolId {
+    let pool_id = OcifStaking::next_pool_id();
+    let init_reserved_balance = <Test as Config>::Currency::reserved_balance(&creator);
+
+    assert_ok!(OcifStaking::create_pool(
+        RuntimeOrigin::signed(creator.clone()),
+        dao,
+        value
+    ));
+    System::assert_has_event(mock::RuntimeEvent::OcifStaking(Event::PoolCreated {
+        pool: pool_id,
+        dao,
+        creator: creator.clone(),
+    }));
+    System::assert_last_event(mock::RuntimeEvent::OcifStaking(Event::PoolJoined {
+        member: creator.clone(),
+        pool: pool_id,
+        amount: value,
+    }));
+
+    let pool = OcifStaking::pool_info(pool_id).unwrap();
+    assert_eq!(pool.creator, creator);
+    assert_eq!(pool.dao, dao);
+    assert_eq!(pool.total, value);
+    assert_eq!(pool.number_of_members, 1);
+    assert_eq!(
+        OcifStaking::pool_member_info(pool_id, &creator).staked,
+        value
+    );
+    assert_eq!(OcifStaking::next_pool_id(), pool_id + 1);
+    assert_eq!(
+        init_reserved_balance + POOL_DEPOSIT,
+        <Test as Config>::Currency::reserved_balance(&creator)
+    );
+
+    pool_id
+}
+
+pub(crate) fn assert_join_pool(member: AccountId, pool_id: PoolId, value: Balance) {
+    let current_era = OcifStaking::current_era();
+    let pool_account = OcifStaking::pool_account(pool_id);
+
+    let init_pool = OcifStaking::pool_info(pool_id).unwrap();
+    let init_pool_state = MemorySnapshot::all(current_era, &init_pool.dao, pool_account.clone());
+    let init_member_info = OcifStaking::pool_member_info(pool_id, &member);
+    let init_member_ledger = Ledger::<Test>::get(&member);
+    let init_member_free_balance = <Test as Config>::Currency::free_balance(&member);
+
+    let available_for_staking = init_member_free_balance
+        - init_member_ledger.locked
+        - <Test as Config>::ExistentialDeposit::get();
+    let staking_value = available_for_staking.min(value);
+
+    assert_ok!(OcifStaking::join_pool(
+        RuntimeOrigin::signed(member.clone()),
+        pool_id,
+        value
+    ));
+    System::assert_last_event(mock::RuntimeEvent::OcifStaking(Event::PoolJoined {
+        member: member.clone(),
+        pool: pool_id,
+        amount: staking_value,
+    }));
+
+    let final_pool = OcifStaking::pool_info(pool_id).unwrap();
+    let final_pool_state = MemorySnapshot::all(current_era, &init_pool.dao, pool_account);
+    let final_member_info = OcifStaking::pool_member_info(pool_id, &member);
+
+    assert_eq!(
+        init_member_info.staked + staking_value,
+        final_member_info.staked
+    );
+    assert_eq!(init_pool.total + staking_value, final_pool.total);
+    let delta = if init_member_info.staked == 0 { 1 } else { 0 };
+    assert_eq!(
+        init_pool.number_of_members + delta,
+        final_pool.number_of_members
+    );
+    assert_eq!(
+        init_member_free_balance - staking_value,
+        <Test as Config>::Currency::free_balance(&member)
+    );
+    assert_eq!(init_member_ledger, Ledger::<Test>::get(&member));
+
+    assert_eq!(
+        init_pool_state.staker_info.staked + staking_value,
+        final_pool_state.staker_info.staked
+    );
+    assert_eq!(
+        init_pool_state.ledger.locked + staking_value,
+        final_pool_state.ledger.locked
+    );
+    assert_eq!(
+        init_pool_state.dao_stake_info.total + staking_value,
+        final_pool_state.dao_stake_info.total
+    );
+    let delta = if init_pool_state.staker_info.staked == 0 {
+        1
+    } else {
+        0
+    };
+    assert_eq!(
+        init_pool_state.dao_stake_info.number_of_stakers + delta,
+        final_pool_state.dao_stake_info.number_of_stakers
+    );
+    assert_eq!(
+        init_pool_state.era_info.staked + staking_value,
+        final_pool_state.era_info.staked
+    );
+    assert_eq!(
+        init_pool_state.era_info.locked + staking_value,
+        final_pool_state.era_info.locked
+    );
+}
+
+pub(crate) fn assert_leave_pool(member: AccountId, pool_id: PoolId, value: Balance) {
+    let current_era = OcifStaking::current_era();
+    let pool_account = OcifStaking::pool_account(pool_id);
+
+    let init_pool = OcifStaking::pool_info(pool_id).unwrap();
+    let init_pool_state = MemorySnapshot::all(current_era, &init_pool.dao, pool_account.clone());
+    let init_member_info = OcifStaking::pool_member_info(pool_id, &member);
+    let init_member_ledger = Ledger::<Test>::get(&member);
+    let init_member_free_balance = <Test as Config>::Currency::free_balance(&member);
+
+    let expected_unbond_amount = init_member_info.staked.min(value);
+
+    assert_ok!(OcifStaking::leave_pool(
+        RuntimeOrigin::signed(member.clone()),
+        pool_id,
+        value
+    ));
+    System::assert_has_event(mock::RuntimeEvent::OcifStaking(Event::PoolLeft {
+        member: member.clone(),
+        pool: pool_id,
+        amount: expected_unbond_amount,
+    }));
+
+    // The pool is removed once its last member leaves.
+    let final_pool = OcifStaking::pool_info(pool_id).unwrap_or_else(|| {
+        System::assert_last_event(mock::RuntimeEvent::OcifStaking(Event::PoolRemoved {
+            pool: pool_id,
+        }));
+        PoolInfo {
+            total: 0,
+            number_of_members: 0,
+            ..init_pool.clone()
+        }
+    });
+    let final_pool_state = MemorySnapshot::all(current_era, &init_pool.dao, pool_account);
+    let final_member_info = OcifStaking::pool_member_info(pool_id, &member);
+    let final_member_ledger = Ledger::<Test>::get(&member);
+
+    assert_eq!(
+        init_member_info.staked - expected_unbond_amount,
+        final_member_info.staked
+    );
+    assert_eq!(init_pool.total - expected_unbond_amount, final_pool.total);
+    let delta = if final_member_info.staked == 0 { 1 } else { 0 };
+    assert_eq!(
+        init_pool.number_of_members - delta,
+        final_pool.number_of_members
+    );
+
+    // The unstaked funds go through the unbonding period in the member's own ledger.
+    assert_eq!(
+        init_member_free_balance + expected_unbond_amount,
+        <Test as Config>::Currency::free_balance(&member)
+    );
+    assert_eq!(
+        init_member_ledger.locked + expected_unbond_amount,
+        final_member_ledger.locked
+    );
+    let mut unbonding_info = init_member_ledger.unbonding_info.clone();
+    unbonding_info.add(UnlockingChunk {
+        amount: expected_unbond_amount,
+        unlock_era: current_era + UNBONDING_PERIOD,
+    });
+    assert_eq!(unbonding_info, final_member_ledger.unbonding_info);
+
+    assert_eq!(
+        init_pool_state.staker_info.staked - expected_unbond_amount,
+        final_pool_state.staker_info.staked
+    );
+    assert_eq!(
+        init_pool_state.ledger.locked - expected_unbond_amount,
+        final_pool_state.ledger.locked
+    );
+    assert_eq!(
+        init_pool_state.dao_stake_info.total - expected_unbond_amount,
+        final_pool_state.dao_stake_info.total
+    );
+    assert_eq!(
+        init_pool_state.era_info.staked - expected_unbond_amount,
+        final_pool_state.era_info.staked
+    );
+    assert_eq!(
+        init_pool_state.era_info.locked,
+        final_pool_state.era_info.locked
+    );
+}
+
+pub(crate) fn assert_claim_pool(member: AccountId, pool_id: PoolId) -> Balance {
+    let mut pool = OcifStaking::pool_info(pool_id).unwrap();
+    let dao_stake_info = OcifStaking::accrued_dao_stake_info(pool.dao);
+    pool.distribute(
+        OcifStaking::accrued_staker_info(
+            pool.dao,
+            &OcifStaking::pool_account(pool_id),
+            &dao_stake_info,
+        )
+        .pending_rewards,
+    );
+    let mut member_info = OcifStaking::pool_member_info(pool_id, &member);
+    member_info.accrue_to(pool.reward_per_stake);
+    let calculated_reward = member_info.pending_rewards;
+
+    let init_free_balance = <Test as Config>::Currency::free_balance(&member);
+    let issuance_before_claim = <Test as Config>::Currency::total_issuance();
+
+    assert_ok!(OcifStaking::claim_pool_rewards(
+        RuntimeOrigin::signed(member.clone()),
+        pool_id
+    ));
+    System::assert_has_event(mock::RuntimeEvent::OcifStaking(Event::PoolMemberClaimed {
+        member: member.clone(),
+        pool: pool_id,
+        amount: calculated_reward,
+    }));
+
+    assert_eq!(
+        init_free_balance + calculated_reward,
+        <Test as Config>::Currency::free_balance(&member)
+    );
+    assert!(OcifStaking::pool_member_info(pool_id, &member)
+        .pending_rewards
+        .is_zero());
+    assert_eq!(
+        issuance_before_claim,
+        <Test as Config>::Currency::total_issuance()
+    );
+
+    calculated_reward
+}
+
+pub(crate) fn assert_enable_liquid_staking(dao: DaoId, receipt_token: u32) {
+    assert_ok!(OcifStaking::enable_liquid_staking(
+        RuntimeOrigin::root(),
+        dao,
+        receipt_token
+    ));
+    System::assert_last_event(mock::RuntimeEvent::OcifStaking(
+        Event::LiquidStakingEnabled { dao, receipt_token },
+    ));
+
+    assert_eq!(OcifStaking::receipt_token(dao), Some(receipt_token));
+}
+
+/// Returns the receipt tokens and the value backing them after the pending rewards of the liquid staking account are restaked.
+fn liquid_stake_exchange(dao: DaoId) -> (Balance, Balance) {
+    let liquid_account = OcifStaking::liquid_account(dao);
+    let dao_stake_info = OcifStaking::accrued_dao_stake_info(dao);
+    let pending_rewards =
+        OcifStaking::accrued_staker_info(dao, &liquid_account, &dao_stake_info).pending_rewards;
+
+    (
+        <CoreAssets as fungibles::Inspect<AccountId>>::total_issuance(
+            OcifStaking::receipt_token(dao).unwrap(),
+        ),
+        OcifStaking::ledger(&liquid_account).locked + pending_rewards,
+    )
+}
+
+pub(crate) fn assert_liquid_stake(staker: AccountId, dao: DaoId, value: Balance) -> Balance {
+    let current_era = OcifStaking::current_era();
+    let receipt_token = OcifStaking::receipt_token(dao).unwrap();
+    let liquid_account = OcifStaking::liquid_account(dao);
+
+    let (supply, backing) = liquid_stake_exchange(dao);
+    let dao_stake_info = OcifStaking::accrued_dao_stake_info(dao);
+    let pending_rewards =
+        OcifStaking::accrued_staker_info(dao, &liquid_account, &dao_stake_info).pending_rewards;
+
+    let init_state = MemorySnapshot::all(current_era, &dao, liquid_account.clone());
+    let init_staker_free_balance = <Test as Config>::Currency::free_balance(&staker);
+    let init_receipts =
+        <CoreAssets as fungibles::Inspect<AccountId>>::balance(receipt_token, &staker);
+
+    let available_for_staking = init_staker_free_balance
+        - OcifStaking::ledger(&staker).locked
+        - <Test as Config>::ExistentialDeposit::get();
+    let staking_value = available_for_staking.min(value);
+    let expected_receipts = convert_share(staking_value, backing, supply);
+
+    assert_ok!(OcifStaking::liquid_stake(
+        RuntimeOrigin::signed(staker.clone()),
+        dao,
+        value
+    ));
+    System::assert_last_event(mock::RuntimeEvent::OcifStaking(Event::LiquidStaked {
+        staker: staker.clone(),
+        dao,
+        amount: staking_value,
+        receipts: expected_receipts,
+    }));
+
+    let final_state = MemorySnapshot::all(current_era, &dao, liquid_account);
+
+    assert_eq!(
+        init_staker_free_balance - staking_value,
+        <Test as Config>::Currency::free_balance(&staker)
+    );
+    assert_eq!(
+        init_receipts + expected_receipts,
+        <CoreAssets as fungibles::Inspect<AccountId>>::balance(receipt_token, &staker)
+    );
+
+    // The pending rewards of the liquid staking account are staked back towards the dao.
+    assert_eq!(
+        init_state.staker_info.staked + pending_rewards + staking_value,
+        final_state.staker_info.staked
+    );
+    assert!(final_state.staker_info.pending_rewards.is_zero());
+    assert_eq!(
+        init_state.ledger.locked + pending_rewards + staking_value,
+        final_state.ledger.locked
+    );
+    assert_eq!(
+        init_state.dao_stake_info.total + pending_rewards + staking_value,
+        final_state.dao_stake_info.total
+    );
+    assert_eq!(
+        init_state.era_info.staked + pending_rewards + staking_value,
+        final_state.era_info.staked
+    );
+    assert_eq!(
+        init_state.era_info.locked + pending_rewards + staking_value,
+        final_state.era_info.locked
+    );
+
+    expected_receipts
+}
+
+pub(crate) fn assert_redeem(staker: AccountId, dao: DaoId, receipts: Balance) -> Balance {
+    let current_era = OcifStaking::current_era();
+    let receipt_token = OcifStaking::receipt_token(dao).unwrap();
+
+    let (supply, backing) = liquid_stake_exchange(dao);
+
+    let init_staker_free_balance = <Test as Config>::Currency::free_balance(&staker);
+    let init_staker_ledger = OcifStaking::ledger(&staker);
+    let init_receipts =
+        <CoreAssets as fungibles::Inspect<AccountId>>::balance(receipt_token, &staker);
+
+    let expected_unbond_amount = convert_share(receipts, supply, backing);
+
+    assert_ok!(OcifStaking::redeem(
+        RuntimeOrigin::signed(staker.clone()),
+        dao,
+        receipts
+    ));
+    System::assert_last_event(mock::RuntimeEvent::OcifStaking(Event::Redeemed {
+        staker: staker.clone(),
+        dao,
+        receipts,
+        amount: expected_unbond_amount,
+    }));
+
+    let final_staker_ledger = OcifStaking::ledger(&staker);
+
+    assert_eq!(
+        init_receipts - receipts,
+        <CoreAssets as fungibles::Inspect<AccountId>>::balance(receipt_token, &staker)
+    );
+    assert_eq!(
+        (supply - receipts, backing - expected_unbond_amount),
+        liquid_stake_exchange(dao)
+    );
+
+    // The redeemed funds go through the unbonding period in the staker's own ledger.
+    assert_eq!(
+        init_staker_free_balance + expected_unbond_amount,
+        <Test as Config>::Currency::free_balance(&staker)
+    );
+    assert_eq!(
+        init_staker_ledger.locked + expected_unbond_amount,
+        final_staker_ledger.locked
+    );
+    let mut unbonding_info = init_staker_ledger.unbonding_info.clone();
+    unbonding_info.add(UnlockingChunk {
+        amount: expected_unbond_amount,
+        unlock_era: current_era + UNBONDING_PERIOD,
+    });
+    assert_eq!(unbonding_info, final_staker_ledger.unbonding_info);
+
+    expected_unbond_amount
+}
+
 pub(crate) fn assert_withdraw_unbonded(staker: AccountId) {
     let current_era = OcifStaking::current_era();
 
@@ -268,24 +881,15 @@ pub(crate) fn assert_unregister(dao: DaoId) {
 }
 
 pub(crate) fn assert_claim_staker(claimer: AccountId, dao: DaoId) {
-    let (claim_era, _) = OcifStaking::staker_info(dao, &claimer).claim();
     let current_era = OcifStaking::current_era();
 
     System::reset_events();
 
-    let init_state_claim_era = MemorySnapshot::all(claim_era, &dao, claimer.clone());
-    let init_state_current_era = MemorySnapshot::all(current_era, &dao, claimer.clone());
-
-    let (_, stakers_joint_reward) = OcifStaking::dao_stakers_split(
-        &init_state_claim_era.dao_stake_info,
-        &init_state_claim_era.era_info,
-    );
-
-    let (claim_era, staked) = init_state_claim_era.staker_info.clone().claim();
+    let init_state = MemorySnapshot::all(current_era, &dao, claimer.clone());
 
     let calculated_reward =
-        Perbill::from_rational(staked, init_state_claim_era.dao_stake_info.total)
-            * stakers_joint_reward;
+        OcifStaking::accrued_staker_info(dao, &claimer, &OcifStaking::accrued_dao_stake_info(dao))
+            .pending_rewards;
     let issuance_before_claim = <Test as Config>::Currency::total_issuance();
 
     assert_ok!(OcifStaking::staker_claim_rewards(
@@ -293,94 +897,73 @@ pub(crate) fn assert_claim_staker(claimer: AccountId, dao: DaoId) {
         dao
     ));
 
-    let final_state_current_era = MemorySnapshot::all(current_era, &dao, claimer.clone());
+    let final_state = MemorySnapshot::all(current_era, &dao, claimer.clone());
 
-    assert_reward(
-        &init_state_current_era,
-        &final_state_current_era,
-        calculated_reward,
-    );
+    assert_reward(&init_state, &final_state, calculated_reward);
 
     System::assert_last_event(mock::RuntimeEvent::OcifStaking(Event::StakerClaimed {
         staker: claimer.clone(),
         dao,
-        era: claim_era,
         amount: calculated_reward,
     }));
 
-    let (new_era, _) = final_state_current_era.staker_info.clone().claim();
-    if final_state_current_era.staker_info.is_empty() {
-        assert!(new_era.is_zero());
-        assert!(!GeneralStakerInfo::<Test>::contains_key(
-            dao,
-            &claimer.clone()
-        ));
-    } else {
-        assert!(new_era > claim_era);
+    assert!(final_state.staker_info.pending_rewards.is_zero());
+    assert_eq!(
+        init_state.staker_info.staked,
+        final_state.staker_info.staked
+    );
+    if final_state.staker_info.is_empty() {
+        assert!(!GeneralStakerInfo::<Test>::contains_key(dao, &claimer));
     }
-    assert!(new_era.is_zero() || new_era > claim_era);
 
     let issuance_after_claim = <Test as Config>::Currency::total_issuance();
     assert_eq!(issuance_before_claim, issuance_after_claim);
-
-    let final_state_claim_era = MemorySnapshot::all(claim_era, &dao, claimer);
-    assert_eq!(
-        init_state_claim_era.dao_stake_info,
-        final_state_claim_era.dao_stake_info
-    );
 }
 
-pub(crate) fn assert_claim_dao(dao: DaoId, claim_era: EraIndex) {
-    let init_state = MemorySnapshot::all(claim_era, &dao, account(dao));
-    assert!(!init_state.dao_stake_info.reward_claimed);
+pub(crate) fn assert_claim_dao(dao: DaoId) {
+    let current_era = OcifStaking::current_era();
+    let init_state = MemorySnapshot::all(current_era, &dao, account(dao));
 
-    let (calculated_reward, _) =
-        OcifStaking::dao_stakers_split(&init_state.dao_stake_info, &init_state.era_info);
+    let calculated_reward = OcifStaking::accrued_dao_stake_info(dao).pending_rewards;
+    assert!(!calculated_reward.is_zero());
 
     assert_ok!(OcifStaking::dao_claim_rewards(
         RuntimeOrigin::signed(account(dao)),
         dao,
-        claim_era,
     ));
     System::assert_last_event(mock::RuntimeEvent::OcifStaking(Event::DaoClaimed {
         dao,
         destination_account: account(dao),
-        era: claim_era,
         amount: calculated_reward,
     }));
 
-    let final_state = MemorySnapshot::all(claim_era, &dao, account(dao));
+    let final_state = MemorySnapshot::all(current_era, &dao, account(dao));
     assert_eq!(
         init_state.free_balance + calculated_reward,
         final_state.free_balance
     );
 
-    assert!(final_state.dao_stake_info.reward_claimed);
+    assert!(final_state.dao_stake_info.pending_rewards.is_zero());
+    assert_eq!(
+        init_state.dao_stake_info.total,
+        final_state.dao_stake_info.total
+    );
 
     assert_eq!(init_state.staker_info, final_state.staker_info);
     assert_eq!(init_state.ledger, final_state.ledger);
 }
 
-fn assert_reward(
-    init_state_current_era: &MemorySnapshot,
-    final_state_current_era: &MemorySnapshot,
-    reward: Balance,
-) {
-    assert_eq!(
-        init_state_current_era.free_balance + reward,
-        final_state_current_era.free_balance
-    );
-    assert_eq!(
-        init_state_current_era.era_info.staked,
-        final_state_current_era.era_info.staked
-    );
+fn assert_reward(init_state: &MemorySnapshot, final_state: &MemorySnapshot, reward: Balance) {
+    assert_eq!(init_state.free_balance + reward, final_state.free_balance);
+    assert_eq!(init_state.era_info.staked, final_state.era_info.staked);
+    assert_eq!(init_state.era_info.locked, final_state.era_info.locked);
     assert_eq!(
-        init_state_current_era.era_info.locked,
-        final_state_current_era.era_info.locked
+        init_state.dao_stake_info.total,
+        final_state.dao_stake_info.total
     );
     assert_eq!(
-        init_state_current_era.dao_stake_info,
-        final_state_current_era.dao_stake_info
+        init_state.dao_stake_info.number_of_stakers,
+        final_state.dao_stake_info.number_of_stakers
     );
 }
 
@@ -394,7 +977,7 @@ pub(crate) fn assert_move_stake(
     let from_init_state = MemorySnapshot::all(current_era, &from_dao, staker.clone());
     let to_init_state = MemorySnapshot::all(current_era, &to_dao, staker.clone());
 
-    let init_staked_value = from_init_state.staker_info.latest_staked_value();
+    let init_staked_value = from_init_state.staker_info.staked;
     let expected_transfer_amount = if init_staked_value - amount >= MINIMUM_STAKING_AMOUNT {
         amount
     } else {
@@ -418,12 +1001,12 @@ pub(crate) fn assert_move_stake(
     let to_final_state = MemorySnapshot::all(current_era, &to_dao, staker.clone());
 
     assert_eq!(
-        from_final_state.staker_info.latest_staked_value(),
+        from_final_state.staker_info.staked,
         init_staked_value - expected_transfer_amount
     );
     assert_eq!(
-        to_final_state.staker_info.latest_staked_value(),
-        to_init_state.staker_info.latest_staked_value() + expected_transfer_amount
+        to_final_state.staker_info.staked,
+        to_init_state.staker_info.staked + expected_transfer_amount
     );
 
     assert_eq!(
@@ -443,7 +1026,7 @@ pub(crate) fn assert_move_stake(
         );
     }
 
-    let no_init_stake_on_to_dao = to_init_state.staker_info.latest_staked_value().is_zero();
+    let no_init_stake_on_to_dao = to_init_state.staker_info.staked.is_zero();
     if no_init_stake_on_to_dao {
         assert_eq!(
             to_final_state.dao_stake_info.number_of_stakers,
@@ -452,8 +1035,8 @@ pub(crate) fn assert_move_stake(
     }
 
     let fully_unstaked_and_nothing_to_claim =
-        from_dao_fully_unstaked && to_final_state.staker_info.clone().claim() == (0, 0);
+        from_dao_fully_unstaked && from_final_state.staker_info.is_empty();
     if fully_unstaked_and_nothing_to_claim {
-        assert!(!GeneralStakerInfo::<Test>::contains_key(&to_dao, &staker));
+        assert!(!GeneralStakerInfo::<Test>::contains_key(&from_dao, &staker));
     }
 }
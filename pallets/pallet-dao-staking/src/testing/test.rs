@@ -5,7 +5,8 @@ use crate::{
 };
 use frame_support::{assert_noop, assert_ok};
 use mock::Balances;
-use sp_runtime::{traits::Zero, Perbill};
+use pallet_dao_manager::dao_manager_core::DaoMetadata;
+use sp_runtime::{traits::Zero, FixedU128, Perbill};
 
 #[test]
 fn on_initialize_when_dao_staking_enabled_in_mid_of_an_era_is_ok() {
@@ -136,6 +137,344 @@ fn new_era_is_ok() {
     })
 }
 
+#[test]
+fn new_era_weight_is_independent_of_dao_count() {
+    ExternalityBuilder::build().execute_with(|| {
+        initialize_first_block();
+
+        let weight_without_daos =
+            OcifStaking::on_initialize(OcifStaking::next_era_starting_block());
+
+        let staker = account(C);
+        for dao_id in [A, B, G, H] {
+            assert_register(dao_id);
+            assert_stake(staker.clone(), &dao_id, 100);
+        }
+
+        let dao_stake_info = OcifStaking::dao_stake_info(A);
+
+        issue_rewards(ISSUE_PER_ERA);
+        let weight_with_daos = OcifStaking::on_initialize(OcifStaking::next_era_starting_block());
+
+        assert_eq!(weight_without_daos, weight_with_daos);
+
+        // Daos aren't touched by the era change, their rewards are accrued on access.
+        assert_eq!(dao_stake_info, OcifStaking::dao_stake_info(A));
+        assert!(
+            OcifStaking::accrued_dao_stake_info(A).pending_rewards > dao_stake_info.pending_rewards
+        );
+    })
+}
+
+#[test]
+fn active_stake_follows_activation_threshold_across_eras() {
+    ExternalityBuilder::build().execute_with(|| {
+        initialize_first_block();
+
+        let staker = account(C);
+        let (first_dao_id, second_dao_id) = (A, B);
+        let active_stake = || {
+            OcifStaking::general_era_info(OcifStaking::current_era())
+                .unwrap()
+                .active_stake
+        };
+
+        assert_register(first_dao_id);
+        assert_register(second_dao_id);
+
+        assert_stake(staker.clone(), &first_dao_id, 100);
+        assert_eq!(active_stake(), 100);
+
+        // Below the threshold, the dao isn't active.
+        assert_stake(staker.clone(), &second_dao_id, THRESHOLD - 20);
+        assert!(!OcifStaking::dao_stake_info(second_dao_id).active);
+        assert_eq!(active_stake(), 100);
+
+        advance_to_era(OcifStaking::current_era() + 1);
+        assert_eq!(active_stake(), 100);
+
+        // Reaching the threshold activates the dao right away.
+        assert_stake(staker.clone(), &second_dao_id, 20);
+        assert!(OcifStaking::dao_stake_info(second_dao_id).active);
+        assert_eq!(active_stake(), 100 + THRESHOLD);
+
+        advance_to_era(OcifStaking::current_era() + 1);
+        assert_eq!(active_stake(), 100 + THRESHOLD);
+
+        // Going back under the threshold deactivates it.
+        assert_unstake(staker.clone(), &second_dao_id, 10);
+        assert!(!OcifStaking::dao_stake_info(second_dao_id).active);
+        assert_eq!(active_stake(), 100);
+
+        assert_unregister(first_dao_id);
+        assert!(active_stake().is_zero());
+        assert_eq!(
+            OcifStaking::general_era_info(OcifStaking::current_era())
+                .unwrap()
+                .staked,
+            THRESHOLD - 10
+        );
+    })
+}
+
+#[test]
+fn era_history_is_pruned_on_idle() {
+    ExternalityBuilder::build().execute_with(|| {
+        initialize_first_block();
+
+        let staker = account(C);
+        assert_register(A);
+        assert_stake(staker.clone(), &A, 100);
+
+        advance_to_era(HISTORY_DEPTH + 5);
+        let current_era = OcifStaking::current_era();
+        let prune_until = current_era - HISTORY_DEPTH;
+
+        assert!(GeneralEraInfo::<Test>::contains_key(0));
+        assert!(OcifStaking::oldest_era().is_zero());
+
+        let pending_rewards =
+            OcifStaking::accrued_staker_info(A, &staker, &OcifStaking::accrued_dao_stake_info(A))
+                .pending_rewards;
+
+        OcifStaking::on_idle(System::block_number(), Weight::MAX);
+
+        for era in 0..prune_until {
+            assert!(!GeneralEraInfo::<Test>::contains_key(era));
+        }
+        for era in prune_until..=current_era {
+            assert!(GeneralEraInfo::<Test>::contains_key(era));
+        }
+        assert_eq!(OcifStaking::oldest_era(), prune_until);
+        assert_eq!(OcifStaking::tvl(), 100);
+
+        // Nothing else to prune until the next era.
+        OcifStaking::on_idle(System::block_number(), Weight::MAX);
+        assert_eq!(OcifStaking::oldest_era(), prune_until);
+
+        advance_to_era(current_era + 1);
+        OcifStaking::on_idle(System::block_number(), Weight::MAX);
+        assert!(!GeneralEraInfo::<Test>::contains_key(prune_until));
+        assert_eq!(OcifStaking::oldest_era(), prune_until + 1);
+
+        // Rewards don't depend on pruned eras.
+        assert!(
+            OcifStaking::accrued_staker_info(A, &staker, &OcifStaking::accrued_dao_stake_info(A))
+                .pending_rewards
+                > pending_rewards
+        );
+        assert_claim_staker(staker, A);
+    })
+}
+
+#[test]
+fn legacy_era_stakes_are_pruned_on_idle_after_migration() {
+    use crate::migrations::{v3::CoreEraStake, v4::MigrateToV4};
+    use frame_support::traits::{OnRuntimeUpgrade, StorageVersion};
+
+    ExternalityBuilder::build().execute_with(|| {
+        initialize_first_block();
+
+        for dao_id in [A, B, C] {
+            for era in 0..10 {
+                CoreEraStake::<Test>::insert(
+                    dao_id,
+                    era,
+                    migrations::v3::OldDaoStakeInfo::default(),
+                );
+            }
+        }
+
+        StorageVersion::new(3).put::<OcifStaking>();
+        MigrateToV4::<Test>::on_runtime_upgrade();
+
+        assert_eq!(OcifStaking::on_chain_storage_version(), 4);
+        assert!(LegacyEraStakeCursor::<Test>::exists());
+        assert_eq!(CoreEraStake::<Test>::iter().count(), 30);
+
+        OcifStaking::on_idle(System::block_number(), Weight::MAX);
+
+        assert_eq!(CoreEraStake::<Test>::iter().count(), 0);
+        assert!(!LegacyEraStakeCursor::<Test>::exists());
+    })
+}
+
+#[test]
+fn v3_migration_turns_unclaimed_eras_into_pending_rewards() {
+    use crate::migrations::v3::{
+        CoreEraStake, MigrateToV3, OldDaoStakeInfo, OldEraStake, OldStakerInfo,
+        RewardPerStakeMigration,
+    };
+    use frame_support::{
+        storage::unhashed,
+        traits::{OnRuntimeUpgrade, StorageVersion},
+    };
+
+    ExternalityBuilder::build().execute_with(|| {
+        initialize_first_block();
+
+        let (registered_dao, unregistered_dao) = (A, B);
+        let (first_staker, second_staker, third_staker) = (account(C), account(D), account(E));
+        let current_era = 4;
+
+        assert_register(registered_dao);
+        StorageVersion::new(2).put::<OcifStaking>();
+        CurrentEra::<Test>::put(current_era);
+
+        for era in 1..current_era {
+            GeneralEraInfo::<Test>::insert(
+                era,
+                EraInfo {
+                    rewards: RewardInfo {
+                        stakers: 1000,
+                        dao: 600,
+                    },
+                    staked: 400,
+                    active_stake: 300,
+                    locked: 400,
+                },
+            );
+        }
+        GeneralEraInfo::<Test>::insert(
+            current_era,
+            EraInfo {
+                staked: 300,
+                locked: 400,
+                ..Default::default()
+            },
+        );
+
+        let dao_stake = |total, reward_claimed, active| OldDaoStakeInfo {
+            total,
+            number_of_stakers: 2,
+            reward_claimed,
+            active,
+        };
+        CoreEraStake::<Test>::insert(registered_dao, 1, dao_stake(300, false, true));
+        CoreEraStake::<Test>::insert(registered_dao, 2, dao_stake(300, true, true));
+        CoreEraStake::<Test>::insert(registered_dao, 3, dao_stake(200, false, true));
+        CoreEraStake::<Test>::insert(registered_dao, 4, dao_stake(200, false, true));
+        for era in 1..=current_era {
+            CoreEraStake::<Test>::insert(unregistered_dao, era, dao_stake(100, false, false));
+        }
+
+        let put_stakes = |dao_id, staker: &AccountId, stakes: Vec<(Balance, EraIndex)>| {
+            unhashed::put(
+                &GeneralStakerInfo::<Test>::hashed_key_for(dao_id, staker),
+                &OldStakerInfo {
+                    stakes: stakes
+                        .into_iter()
+                        .map(|(staked, era)| OldEraStake { staked, era })
+                        .collect(),
+                },
+            )
+        };
+        put_stakes(registered_dao, &first_staker, vec![(200, 1), (100, 3)]);
+        put_stakes(registered_dao, &second_staker, vec![(100, 1)]);
+        // Unstaked everything in the current era.
+        put_stakes(unregistered_dao, &third_staker, vec![(100, 1), (0, 4)]);
+
+        // What `staker_claim_rewards` and `dao_claim_rewards` paid for an era before the migration.
+        let old_staker_reward = |dao_id, era, staked| {
+            let dao_stake = CoreEraStake::<Test>::get(dao_id, era).unwrap();
+            let era_info = OcifStaking::general_era_info(era).unwrap();
+            Perbill::from_rational(staked, dao_stake.total)
+                * (Perbill::from_rational(dao_stake.total, era_info.staked)
+                    * era_info.rewards.stakers)
+        };
+        let old_dao_reward = |dao_id, era| {
+            let dao_stake = CoreEraStake::<Test>::get(dao_id, era).unwrap();
+            let era_info = OcifStaking::general_era_info(era).unwrap();
+            Perbill::from_rational(dao_stake.total, era_info.active_stake) * era_info.rewards.dao
+        };
+
+        let first_staker_rewards = old_staker_reward(registered_dao, 1, 200)
+            + old_staker_reward(registered_dao, 2, 200)
+            + old_staker_reward(registered_dao, 3, 100);
+        let second_staker_rewards = (1..current_era)
+            .map(|era| old_staker_reward(registered_dao, era, 100))
+            .sum::<Balance>();
+        let third_staker_rewards = (1..current_era)
+            .map(|era| old_staker_reward(unregistered_dao, era, 100))
+            .sum::<Balance>();
+        // The dao already claimed the second era.
+        let dao_rewards = old_dao_reward(registered_dao, 1) + old_dao_reward(registered_dao, 3);
+
+        assert_eq!(
+            (
+                first_staker_rewards,
+                second_staker_rewards,
+                third_staker_rewards,
+                dao_rewards
+            ),
+            (1250, 750, 750, 1000)
+        );
+
+        MigrateToV3::<Test>::on_runtime_upgrade();
+
+        assert_eq!(OcifStaking::on_chain_storage_version(), 3);
+        assert!(RewardPerStakeMigration::<Test>::exists());
+        assert!(OcifStaking::is_halted());
+        assert_noop!(
+            OcifStaking::halt_unhalt_pallet(RuntimeOrigin::root(), false),
+            Error::<Test>::MigrationInProgress
+        );
+
+        OcifStaking::on_idle(System::block_number(), Weight::MAX);
+
+        assert!(!RewardPerStakeMigration::<Test>::exists());
+        assert!(!OcifStaking::is_halted());
+
+        let staker_info = |staked, pending_rewards| StakerInfo {
+            staked,
+            reward_per_stake_paid: Default::default(),
+            pending_rewards,
+        };
+        assert_eq!(
+            OcifStaking::staker_info(registered_dao, &first_staker),
+            staker_info(100, first_staker_rewards)
+        );
+        assert_eq!(
+            OcifStaking::staker_info(registered_dao, &second_staker),
+            staker_info(100, second_staker_rewards)
+        );
+        assert_eq!(
+            OcifStaking::staker_info(unregistered_dao, &third_staker),
+            staker_info(0, third_staker_rewards)
+        );
+
+        let dao_stake_info = OcifStaking::dao_stake_info(registered_dao);
+        assert_eq!(dao_stake_info.total, 200);
+        assert!(dao_stake_info.active);
+        assert_eq!(dao_stake_info.pending_rewards, dao_rewards);
+
+        let dao_stake_info = OcifStaking::dao_stake_info(unregistered_dao);
+        assert_eq!(dao_stake_info.total, 100);
+        assert!(!dao_stake_info.active);
+        assert!(dao_stake_info.pending_rewards.is_zero());
+
+        assert_eq!(
+            OcifStaking::general_era_info(current_era)
+                .unwrap()
+                .active_stake,
+            200
+        );
+
+        // The migrated rewards are paid out by the usual claims from the unclaimed rewards left in the pot.
+        assert_ok!(<Balances as frame_support::traits::fungible::Mutate<
+            AccountId,
+        >>::mint_into(
+            &OcifStaking::account_id(),
+            first_staker_rewards + dao_rewards
+        ));
+        assert_claim_staker(first_staker, registered_dao);
+        assert_ok!(OcifStaking::dao_claim_rewards(
+            RuntimeOrigin::signed(second_staker),
+            registered_dao
+        ));
+    })
+}
+
 #[test]
 fn general_staker_info_is_ok() {
     ExternalityBuilder::build().execute_with(|| {
@@ -153,46 +492,37 @@ fn general_staker_info_is_ok() {
         assert_stake(staker_1.clone(), &A, amount);
         assert_stake(staker_2.clone(), &A, amount);
 
+        assert_eq!(2, OcifStaking::dao_stake_info(&A).number_of_stakers);
+        assert!(!CoreStake::<Test>::contains_key(&B));
+
         let mid_era = 7;
         advance_to_era(mid_era);
         assert_unstake(staker_2.clone(), &A, amount);
         assert_stake(staker_3.clone(), &A, amount);
         assert_stake(staker_3.clone(), &B, amount);
 
+        let second_staker_pending = OcifStaking::staker_info(&A, &staker_2).pending_rewards;
+        assert!(!second_staker_pending.is_zero());
+
         let final_era = 12;
         advance_to_era(final_era);
 
-        let mut first_staker_info = OcifStaking::staker_info(&A, &staker_1.clone());
-        let mut second_staker_info = OcifStaking::staker_info(&A, &staker_2.clone());
-        let mut third_staker_info = OcifStaking::staker_info(&A, &staker_3.clone());
+        let first_staker_info = OcifStaking::staker_info(&A, &staker_1);
+        let second_staker_info = OcifStaking::staker_info(&A, &staker_2);
+        let third_staker_info = OcifStaking::staker_info(&A, &staker_3);
 
-        for era in starting_era..mid_era {
-            let dao_info = OcifStaking::dao_stake_info(&A, era).unwrap();
-            assert_eq!(2, dao_info.number_of_stakers);
+        assert_eq!(amount, first_staker_info.staked);
+        assert!(second_staker_info.staked.is_zero());
+        assert_eq!(amount, third_staker_info.staked);
 
-            assert_eq!((era, amount), first_staker_info.claim());
-            assert_eq!((era, amount), second_staker_info.claim());
-
-            assert!(!CoreEraStake::<Test>::contains_key(&B, era));
-        }
-
-        for era in mid_era..=final_era {
-            let first_dao_info = OcifStaking::dao_stake_info(&A, era).unwrap();
-            assert_eq!(2, first_dao_info.number_of_stakers);
-
-            assert_eq!((era, amount), first_staker_info.claim());
-            assert_eq!((era, amount), third_staker_info.claim());
-
-            assert_eq!(
-                OcifStaking::dao_stake_info(&B, era)
-                    .unwrap()
-                    .number_of_stakers,
-                1
-            );
-        }
+        assert_eq!(
+            second_staker_pending,
+            OcifStaking::accrued_staker_info(A, &staker_2, &OcifStaking::accrued_dao_stake_info(A))
+                .pending_rewards
+        );
 
-        assert!(!CoreEraStake::<Test>::contains_key(&A, starting_era - 1));
-        assert!(!CoreEraStake::<Test>::contains_key(&B, starting_era - 1));
+        assert_eq!(2, OcifStaking::dao_stake_info(&A).number_of_stakers);
+        assert_eq!(1, OcifStaking::dao_stake_info(&B).number_of_stakers);
     })
 }
 
@@ -231,9 +561,7 @@ fn register_twice_with_same_account_fails() {
                     pallet_dao_manager::origin::MultisigInternalOrigin::new(A)
                 )
                 .into(),
-                Vec::default().try_into().unwrap(),
-                Vec::default().try_into().unwrap(),
-                Vec::default().try_into().unwrap()
+                Some(Default::default())
             ),
             Error::<Test>::DaoAlreadyRegistered
         );
@@ -257,18 +585,15 @@ fn change_metadata() {
             OcifStaking::dao_info(dao_id),
             Some(DaoInfo {
                 account: account(dao_id),
-                metadata: DaoMetadata {
-                    name: BoundedVec::default(),
-                    description: BoundedVec::default(),
-                    image: BoundedVec::default()
-                }
+                metadata: Default::default()
             })
         );
 
         let new_metadata: DaoMetadataOf<Test> = DaoMetadata {
             name: b"Test CORE".to_vec().try_into().unwrap(),
             description: b"Description of the test CORE".to_vec().try_into().unwrap(),
-            image: b"https://test.dao".to_vec().try_into().unwrap(),
+            website: Some(b"https://test.dao".to_vec().try_into().unwrap()),
+            ..Default::default()
         };
 
         assert_ok!(OcifStaking::change_dao_metadata(
@@ -276,16 +601,83 @@ fn change_metadata() {
                 pallet_dao_manager::origin::MultisigInternalOrigin::new(dao_id)
             )
             .into(),
-            b"Test CORE".to_vec().try_into().unwrap(),
-            b"Description of the test CORE".to_vec().try_into().unwrap(),
-            b"https://test.dao".to_vec().try_into().unwrap(),
+            new_metadata.clone(),
         ));
 
         assert_eq!(
             OcifStaking::dao_info(dao_id),
             Some(DaoInfo {
                 account: account(dao_id),
-                metadata: new_metadata
+                metadata: new_metadata.clone()
+            })
+        );
+
+        System::assert_last_event(mock::RuntimeEvent::OcifStaking(Event::MetadataChanged {
+            dao: dao_id,
+            old_metadata: Default::default(),
+            new_metadata,
+        }));
+
+        assert_noop!(
+            OcifStaking::change_dao_metadata(
+                pallet_dao_manager::Origin::Multisig(
+                    pallet_dao_manager::origin::MultisigInternalOrigin::new(dao_id)
+                )
+                .into(),
+                DaoMetadata {
+                    website: Some(b"test.dao".to_vec().try_into().unwrap()),
+                    ..Default::default()
+                },
+            ),
+            Error::<Test>::InvalidMetadata
+        );
+    })
+}
+
+#[test]
+fn register_reuses_dao_manager_metadata() {
+    ExternalityBuilder::build().execute_with(|| {
+        initialize_first_block();
+
+        let dao_id = A;
+        let metadata: DaoMetadataOf<Test> = DaoMetadata {
+            name: b"Test CORE".to_vec().try_into().unwrap(),
+            ..Default::default()
+        };
+
+        let origin = || -> RuntimeOrigin {
+            pallet_dao_manager::Origin::Multisig(
+                pallet_dao_manager::origin::MultisigInternalOrigin::new(dao_id),
+            )
+            .into()
+        };
+
+        // Dao doesn't exist in the dao manager.
+        assert_noop!(
+            OcifStaking::register_dao(origin(), None),
+            Error::<Test>::DaoNotFound
+        );
+
+        pallet_dao_manager::CoreStorage::<Test>::insert(
+            dao_id,
+            pallet_dao_manager::DaoInfoOf::<Test> {
+                account: account(dao_id),
+                metadata: metadata.clone(),
+                minimum_support: Perbill::one(),
+                required_approval: Perbill::one(),
+                frozen_tokens: true,
+                voting_strategy: Default::default(),
+                second_chamber: None,
+            },
+        );
+
+        assert_ok!(OcifStaking::register_dao(origin(), None));
+
+        assert_eq!(
+            OcifStaking::dao_info(dao_id),
+            Some(DaoInfo {
+                account: account(dao_id),
+                metadata
             })
         );
     })
@@ -360,28 +752,22 @@ fn withdraw_from_unregistered_is_ok() {
 
         assert_unregister(dao_id);
 
-        for era in 1..OcifStaking::current_era() {
-            assert_claim_staker(staker_1.clone(), dao_id);
-            assert_claim_staker(staker_2.clone(), dao_id);
+        assert_claim_staker(staker_1.clone(), dao_id);
+        assert_claim_staker(staker_2.clone(), dao_id);
 
-            assert_claim_dao(dao_id, era);
-        }
+        assert_claim_dao(dao_id);
 
         assert_noop!(
             OcifStaking::staker_claim_rewards(RuntimeOrigin::signed(staker_1.clone()), dao_id),
-            Error::<Test>::NoStakeAvailable
+            Error::<Test>::NothingToClaim
         );
         assert_noop!(
             OcifStaking::staker_claim_rewards(RuntimeOrigin::signed(staker_2.clone()), dao_id),
-            Error::<Test>::NoStakeAvailable
+            Error::<Test>::NothingToClaim
         );
         assert_noop!(
-            OcifStaking::dao_claim_rewards(
-                RuntimeOrigin::signed(account(dao_id)),
-                dao_id,
-                OcifStaking::current_era()
-            ),
-            Error::<Test>::IncorrectEra
+            OcifStaking::dao_claim_rewards(RuntimeOrigin::signed(account(dao_id)), dao_id),
+            Error::<Test>::NothingToClaim
         );
 
         advance_to_era(8);
@@ -401,7 +787,7 @@ fn bond_and_stake_different_eras_is_ok() {
         assert_register(dao_id);
 
         let current_era = OcifStaking::current_era();
-        assert!(OcifStaking::dao_stake_info(&dao_id, current_era).is_none());
+        assert!(!CoreStake::<Test>::contains_key(&dao_id));
 
         assert_stake(staker_id.clone(), &dao_id, 100);
 
@@ -547,24 +933,23 @@ fn bond_and_stake_too_many_stakers_per_dao() {
 }
 
 #[test]
-fn bond_and_stake_too_many_era_stakes() {
+fn bond_and_stake_many_eras_without_claiming_is_ok() {
     ExternalityBuilder::build().execute_with(|| {
         initialize_first_block();
 
-        let staker_id = account(B);
+        let staker_id = account(C);
         let dao_id = A;
         assert_register(dao_id);
 
         let start_era = OcifStaking::current_era();
-        for offset in 1..MAX_ERA_STAKE_VALUES {
+        for offset in 1..20 {
             assert_stake(staker_id.clone(), &dao_id, 100);
             advance_to_era(start_era + offset);
         }
 
-        assert_noop!(
-            OcifStaking::stake(RuntimeOrigin::signed(staker_id.into()), dao_id, 100),
-            Error::<Test>::TooManyEraStakeValues
-        );
+        assert_stake(staker_id.clone(), &dao_id, 100);
+        assert_unstake(staker_id.clone(), &dao_id, 10);
+        assert_claim_staker(staker_id, dao_id);
     })
 }
 
@@ -729,28 +1114,6 @@ fn unbond_and_unstake_on_not_staked_dao_is_not_ok() {
     })
 }
 
-#[test]
-fn unbond_and_unstake_too_many_era_stakes() {
-    ExternalityBuilder::build().execute_with(|| {
-        initialize_first_block();
-
-        let staker_id = account(B);
-        let dao_id = A;
-        assert_register(dao_id);
-
-        let start_era = OcifStaking::current_era();
-        for offset in 1..MAX_ERA_STAKE_VALUES {
-            assert_stake(staker_id.clone(), &dao_id, 100);
-            advance_to_era(start_era + offset);
-        }
-
-        assert_noop!(
-            OcifStaking::unstake(RuntimeOrigin::signed(staker_id), dao_id, 10),
-            Error::<Test>::TooManyEraStakeValues
-        );
-    })
-}
-
 #[test]
 fn withdraw_unbonded_is_ok() {
     ExternalityBuilder::build().execute_with(|| {
@@ -853,88 +1216,912 @@ fn withdraw_unbonded_no_value_is_not_ok() {
 }
 
 #[test]
-fn claim_not_staked_dao() {
+fn rebond_is_ok() {
     ExternalityBuilder::build().execute_with(|| {
         initialize_first_block();
 
         let staker = account(B);
         let dao_id = A;
+        let other_dao_id = C;
 
         assert_register(dao_id);
+        assert_register(other_dao_id);
+        assert_stake(staker.clone(), &dao_id, 100);
 
-        assert_noop!(
-            OcifStaking::staker_claim_rewards(RuntimeOrigin::signed(staker), dao_id),
-            Error::<Test>::NoStakeAvailable
+        let first_unstake_era = OcifStaking::current_era();
+        assert_unstake(staker.clone(), &dao_id, 30);
+        advance_to_era(first_unstake_era + 1);
+        assert_unstake(staker.clone(), &dao_id, 20);
+
+        // The newest chunk is emptied first, then the remaining value comes out of the older one.
+        assert_rebond(staker.clone(), &dao_id, 25);
+        assert_eq!(
+            OcifStaking::ledger(&staker).unbonding_info.unlocking_chunks,
+            vec![UnlockingChunk {
+                amount: 25,
+                unlock_era: first_unstake_era + UNBONDING_PERIOD
+            }]
         );
 
-        advance_to_era(OcifStaking::current_era() + 1);
+        // Rebonding more than is unbonding only rebonds what's left, into any dao.
+        assert_rebond(staker.clone(), &other_dao_id, 100);
+        assert!(OcifStaking::ledger(&staker).unbonding_info.is_empty());
+        assert_eq!(OcifStaking::staker_info(dao_id, &staker).staked, 75);
+        assert_eq!(OcifStaking::staker_info(other_dao_id, &staker).staked, 25);
+
         assert_noop!(
-            OcifStaking::dao_claim_rewards(RuntimeOrigin::signed(account(dao_id)), dao_id, 1),
-            Error::<Test>::NoStakeAvailable
+            OcifStaking::withdraw_unstaked(RuntimeOrigin::signed(staker)),
+            Error::<Test>::NothingToWithdraw
         );
     })
 }
 
 #[test]
-fn claim_not_registered_dao() {
+fn rebond_is_not_ok() {
     ExternalityBuilder::build().execute_with(|| {
         initialize_first_block();
 
         let staker = account(B);
         let dao_id = A;
+        let other_dao_id = C;
 
         assert_register(dao_id);
-        assert_stake(staker.clone(), &dao_id, 100);
+        assert_register(other_dao_id);
 
-        advance_to_era(OcifStaking::current_era() + 1);
-        assert_unregister(dao_id);
+        assert_noop!(
+            OcifStaking::rebond(RuntimeOrigin::signed(staker.clone()), dao_id, 10),
+            Error::<Test>::NothingToRebond
+        );
+
+        assert_stake(staker.clone(), &dao_id, 100);
+        assert_unstake(staker.clone(), &dao_id, 20);
 
-        assert_claim_staker(staker.clone(), dao_id);
         assert_noop!(
-            OcifStaking::staker_claim_rewards(RuntimeOrigin::signed(staker.clone()), dao_id),
-            Error::<Test>::NoStakeAvailable
+            OcifStaking::rebond(RuntimeOrigin::signed(staker.clone()), dao_id, 0),
+            Error::<Test>::NothingToRebond
+        );
+        assert_noop!(
+            OcifStaking::rebond(RuntimeOrigin::signed(staker.clone()), B, 10),
+            Error::<Test>::NotRegistered
+        );
+        assert_noop!(
+            OcifStaking::rebond(
+                RuntimeOrigin::signed(staker.clone()),
+                other_dao_id,
+                MINIMUM_STAKING_AMOUNT - 1
+            ),
+            Error::<Test>::InsufficientBalance
         );
 
-        assert_claim_dao(dao_id, 1);
+        assert_ok!(OcifStaking::halt_unhalt_pallet(RuntimeOrigin::root(), true));
         assert_noop!(
-            OcifStaking::dao_claim_rewards(RuntimeOrigin::signed(account(dao_id)), dao_id, 2),
-            Error::<Test>::IncorrectEra
+            OcifStaking::rebond(RuntimeOrigin::signed(staker), dao_id, 10),
+            Error::<Test>::Halted
         );
     })
 }
 
 #[test]
-fn claim_invalid_era() {
+fn instant_unstake_is_ok() {
     ExternalityBuilder::build().execute_with(|| {
         initialize_first_block();
 
         let staker = account(B);
         let dao_id = A;
 
-        let start_era = OcifStaking::current_era();
         assert_register(dao_id);
         assert_stake(staker.clone(), &dao_id, 100);
-        advance_to_era(start_era + 5);
 
-        for era in start_era..OcifStaking::current_era() {
-            assert_claim_staker(staker.clone(), dao_id);
-            assert_claim_dao(dao_id, era);
-        }
+        let init_total_issuance = Balances::total_issuance();
 
-        assert_noop!(
-            OcifStaking::staker_claim_rewards(RuntimeOrigin::signed(staker), dao_id),
-            Error::<Test>::IncorrectEra
-        );
-        assert_noop!(
-            OcifStaking::dao_claim_rewards(
-                RuntimeOrigin::signed(account(dao_id)),
-                dao_id,
-                OcifStaking::current_era()
-            ),
-            Error::<Test>::IncorrectEra
-        );
-    })
-}
+        assert_instant_unstake(staker.clone(), Some(dao_id), 40);
+        assert_eq!(OcifStaking::staker_info(dao_id, &staker).staked, 60);
+
+        // Unlocking chunks can be unlocked early too, no matter how far they are from the end of the unbonding period.
+        assert_unstake(staker.clone(), &dao_id, 30);
+        assert_instant_unstake(staker.clone(), None, 100);
+        assert!(OcifStaking::ledger(&staker).unbonding_info.is_empty());
+
+        // Remaining stake below the minimum is unlocked entirely.
+        assert_instant_unstake(staker.clone(), Some(dao_id), 25);
+        assert!(!Ledger::<Test>::contains_key(&staker));
+
+        // With no `OnUnbalanced` handler in the mock the penalties are burned.
+        assert_eq!(
+            Balances::total_issuance(),
+            init_total_issuance - INSTANT_UNSTAKE_PENALTY * 100
+        );
+    })
+}
+
+#[test]
+fn instant_unstake_withdraws_unlocked_chunks_without_penalty() {
+    ExternalityBuilder::build().execute_with(|| {
+        initialize_first_block();
+
+        let staker = account(B);
+        let dao_id = A;
+
+        assert_register(dao_id);
+        assert_stake(staker.clone(), &dao_id, 100);
+
+        assert_unstake(staker.clone(), &dao_id, 30);
+        advance_to_era(OcifStaking::current_era() + UNBONDING_PERIOD);
+        assert_unstake(staker.clone(), &dao_id, 20);
+
+        // Only the chunk still unbonding is penalized.
+        let free_balance = <Test as Config>::Currency::free_balance(&staker);
+        assert_instant_unstake(staker.clone(), None, 100);
+        assert_eq!(
+            <Test as Config>::Currency::free_balance(&staker),
+            free_balance - INSTANT_UNSTAKE_PENALTY * 20
+        );
+        assert!(OcifStaking::ledger(&staker).unbonding_info.is_empty());
+        assert_eq!(OcifStaking::ledger(&staker).locked, 50);
+
+        // Nothing is penalized when every chunk already unlocked.
+        assert_unstake(staker.clone(), &dao_id, 10);
+        advance_to_era(OcifStaking::current_era() + UNBONDING_PERIOD);
+
+        let free_balance = <Test as Config>::Currency::free_balance(&staker);
+        assert_instant_unstake(staker.clone(), None, 10);
+        assert_eq!(
+            <Test as Config>::Currency::free_balance(&staker),
+            free_balance
+        );
+        assert_eq!(OcifStaking::ledger(&staker).locked, 40);
+    })
+}
+
+#[test]
+fn instant_unstake_is_not_ok() {
+    ExternalityBuilder::build().execute_with(|| {
+        initialize_first_block();
+
+        let staker = account(B);
+        let dao_id = A;
+
+        assert_register(dao_id);
+
+        assert_noop!(
+            OcifStaking::instant_unstake(RuntimeOrigin::signed(staker.clone()), Some(dao_id), 0),
+            Error::<Test>::UnstakingNothing
+        );
+        assert_noop!(
+            OcifStaking::instant_unstake(RuntimeOrigin::signed(staker.clone()), Some(B), 10),
+            Error::<Test>::NotRegistered
+        );
+        assert_noop!(
+            OcifStaking::instant_unstake(RuntimeOrigin::signed(staker.clone()), Some(dao_id), 10),
+            Error::<Test>::NoStakeAvailable
+        );
+
+        assert_stake(staker.clone(), &dao_id, 100);
+
+        assert_noop!(
+            OcifStaking::instant_unstake(RuntimeOrigin::signed(staker.clone()), None, 10),
+            Error::<Test>::NothingToWithdraw
+        );
+
+        assert_ok!(OcifStaking::halt_unhalt_pallet(RuntimeOrigin::root(), true));
+        assert_noop!(
+            OcifStaking::instant_unstake(RuntimeOrigin::signed(staker), Some(dao_id), 10),
+            Error::<Test>::Halted
+        );
+    })
+}
+
+#[test]
+fn transfer_stake_is_ok() {
+    ExternalityBuilder::build().execute_with(|| {
+        initialize_first_block();
+
+        let staker = account(B);
+        let other_staker = account(C);
+        let new_staker = account(D);
+        let dao_id = A;
+
+        assert_register(dao_id);
+        assert_stake(staker.clone(), &dao_id, 100);
+        assert_stake(other_staker.clone(), &dao_id, 50);
+
+        advance_to_era(OcifStaking::current_era() + 2);
+
+        // Transferring to a new account makes it a staker of the dao.
+        assert_transfer_stake(staker.clone(), &dao_id, 40, new_staker.clone());
+        assert_eq!(OcifStaking::dao_stake_info(dao_id).number_of_stakers, 3);
+
+        // Remaining stake below the minimum is transferred entirely.
+        assert_transfer_stake(staker.clone(), &dao_id, 55, other_staker.clone());
+        assert_eq!(OcifStaking::dao_stake_info(dao_id).number_of_stakers, 2);
+        assert!(!Ledger::<Test>::contains_key(&staker));
+
+        // Rewards accrued before the transfers stay with the sender.
+        assert_claim_staker(staker.clone(), dao_id);
+        assert_noop!(
+            OcifStaking::staker_claim_rewards(RuntimeOrigin::signed(new_staker), dao_id),
+            Error::<Test>::NothingToClaim
+        );
+    })
+}
+
+#[test]
+fn transfer_stake_is_not_ok() {
+    ExternalityBuilder::build().execute_with(|| {
+        initialize_first_block();
+
+        let staker = account(B);
+        let dao_id = A;
+
+        assert_register(dao_id);
+
+        assert_noop!(
+            OcifStaking::transfer_stake(
+                RuntimeOrigin::signed(staker.clone()),
+                dao_id,
+                10,
+                staker.clone()
+            ),
+            Error::<Test>::TransferStakeToSameAccount
+        );
+        assert_noop!(
+            OcifStaking::transfer_stake(RuntimeOrigin::signed(staker.clone()), B, 10, account(C)),
+            Error::<Test>::NotRegistered
+        );
+        assert_noop!(
+            OcifStaking::transfer_stake(
+                RuntimeOrigin::signed(staker.clone()),
+                dao_id,
+                10,
+                account(C)
+            ),
+            Error::<Test>::NoStakeAvailable
+        );
+
+        assert_stake(staker.clone(), &dao_id, 100);
+
+        assert_noop!(
+            OcifStaking::transfer_stake(
+                RuntimeOrigin::signed(staker.clone()),
+                dao_id,
+                MINIMUM_STAKING_AMOUNT - 1,
+                account(C)
+            ),
+            Error::<Test>::InsufficientBalance
+        );
+
+        for staker_id in 2..=MAX_NUMBER_OF_STAKERS {
+            assert_stake(account(staker_id.into()), &dao_id, 20);
+        }
+        assert_noop!(
+            OcifStaking::transfer_stake(
+                RuntimeOrigin::signed(staker.clone()),
+                dao_id,
+                20,
+                account(G)
+            ),
+            Error::<Test>::MaxStakersReached
+        );
+
+        assert_ok!(OcifStaking::halt_unhalt_pallet(RuntimeOrigin::root(), true));
+        assert_noop!(
+            OcifStaking::transfer_stake(RuntimeOrigin::signed(staker), dao_id, 20, account(C)),
+            Error::<Test>::Halted
+        );
+    })
+}
+
+#[test]
+fn pool_join_and_leave_is_ok() {
+    ExternalityBuilder::build().execute_with(|| {
+        initialize_first_block();
+
+        let small_holder = account(F);
+        let dao_id = A;
+
+        assert_register(dao_id);
+        let pool_id = assert_create_pool(account(C), dao_id, 20);
+
+        // Members can join with less than the minimum staking amount.
+        assert_join_pool(small_holder.clone(), pool_id, MINIMUM_STAKING_AMOUNT / 2);
+        assert_join_pool(small_holder.clone(), pool_id, 100);
+
+        let dao_stake_info = OcifStaking::dao_stake_info(dao_id);
+        assert_eq!(dao_stake_info.number_of_stakers, 1);
+        assert_eq!(dao_stake_info.total, 28);
+        assert_eq!(
+            OcifStaking::pool_info(pool_id).unwrap().number_of_members,
+            2
+        );
+
+        assert_leave_pool(small_holder.clone(), pool_id, 4);
+        assert_leave_pool(small_holder.clone(), pool_id, 100);
+        assert!(!PoolMembers::<Test>::contains_key(pool_id, &small_holder));
+
+        advance_to_era(OcifStaking::current_era() + UNBONDING_PERIOD);
+        assert_withdraw_unbonded(small_holder);
+    })
+}
+
+#[test]
+fn pool_counts_as_single_staker() {
+    ExternalityBuilder::build().execute_with(|| {
+        initialize_first_block();
+
+        let dao_id = A;
+        assert_register(dao_id);
+
+        for staker_id in 1..MAX_NUMBER_OF_STAKERS {
+            assert_stake(account(staker_id.into()), &dao_id, 20);
+        }
+
+        let pool_id = assert_create_pool(account(E), dao_id, 20);
+        assert_join_pool(account(G), pool_id, 20);
+        assert_join_pool(account(H), pool_id, 20);
+        assert_join_pool(account(I), pool_id, 20);
+
+        assert_eq!(
+            OcifStaking::dao_stake_info(dao_id).number_of_stakers,
+            MAX_NUMBER_OF_STAKERS
+        );
+        assert_noop!(
+            OcifStaking::stake(RuntimeOrigin::signed(account(J)), dao_id, 20),
+            Error::<Test>::MaxStakersReached
+        );
+    })
+}
+
+#[test]
+fn pool_rewards_are_shared_pro_rata() {
+    ExternalityBuilder::build().execute_with(|| {
+        initialize_first_block();
+
+        let dao_id = A;
+        let first_member = account(C);
+        let second_member = account(D);
+        let staker = account(E);
+
+        assert_register(dao_id);
+        let pool_id = assert_create_pool(first_member.clone(), dao_id, 60);
+        assert_join_pool(second_member.clone(), pool_id, 30);
+        assert_stake(staker.clone(), &dao_id, 90);
+
+        advance_to_era(OcifStaking::current_era() + 3);
+
+        let staker_reward = OcifStaking::accrued_staker_info(
+            dao_id,
+            &staker,
+            &OcifStaking::accrued_dao_stake_info(dao_id),
+        )
+        .pending_rewards;
+
+        let first_member_reward = assert_claim_pool(first_member.clone(), pool_id);
+        let second_member_reward = assert_claim_pool(second_member, pool_id);
+        assert_claim_staker(staker, dao_id);
+
+        // The pool earns as much as a staker with the same stake, shared 2:1 among its members.
+        assert!(first_member_reward.abs_diff(second_member_reward * 2) <= 1);
+        assert!(first_member_reward + second_member_reward <= staker_reward);
+        assert!(staker_reward - (first_member_reward + second_member_reward) <= 2);
+
+        assert_noop!(
+            OcifStaking::claim_pool_rewards(RuntimeOrigin::signed(first_member), pool_id),
+            Error::<Test>::NothingToClaim
+        );
+    })
+}
+
+#[test]
+fn pool_members_can_leave_after_dao_unregistration() {
+    ExternalityBuilder::build().execute_with(|| {
+        initialize_first_block();
+
+        let dao_id = A;
+        let creator = account(C);
+        let member = account(B);
+
+        assert_register(dao_id);
+        let pool_id = assert_create_pool(creator.clone(), dao_id, 40);
+        assert_join_pool(member.clone(), pool_id, 10);
+
+        assert_unregister(dao_id);
+
+        let pool_account = OcifStaking::pool_account(pool_id);
+        assert!(OcifStaking::staker_info(dao_id, &pool_account)
+            .staked
+            .is_zero());
+        assert_eq!(OcifStaking::ledger(&pool_account).unbonding_info.sum(), 50);
+
+        assert_ok!(OcifStaking::leave_pool(
+            RuntimeOrigin::signed(member.clone()),
+            pool_id,
+            10
+        ));
+        assert_eq!(OcifStaking::ledger(&member).unbonding_info.sum(), 10);
+        assert_eq!(OcifStaking::ledger(&pool_account).unbonding_info.sum(), 40);
+
+        assert_noop!(
+            OcifStaking::join_pool(RuntimeOrigin::signed(member), pool_id, 10),
+            Error::<Test>::NotRegistered
+        );
+
+        assert_ok!(OcifStaking::leave_pool(
+            RuntimeOrigin::signed(creator.clone()),
+            pool_id,
+            40
+        ));
+        assert_eq!(OcifStaking::ledger(&creator).unbonding_info.sum(), 40);
+        assert!(!Ledger::<Test>::contains_key(&pool_account));
+        assert!(OcifStaking::pool_info(pool_id).is_none());
+    })
+}
+
+#[test]
+fn pool_is_removed_once_empty() {
+    ExternalityBuilder::build().execute_with(|| {
+        initialize_first_block();
+
+        let dao_id = A;
+        let creator = account(C);
+        let member = account(D);
+
+        assert_register(dao_id);
+        let init_reserved_balance = <Test as Config>::Currency::reserved_balance(&creator);
+
+        let pool_id = assert_create_pool(creator.clone(), dao_id, 60);
+        assert_join_pool(member.clone(), pool_id, 30);
+
+        advance_to_era(OcifStaking::current_era() + 2);
+
+        // The creator still has rewards to claim, so the pool stays around after its stake is gone.
+        assert_leave_pool(creator.clone(), pool_id, 60);
+        assert_leave_pool(member.clone(), pool_id, 30);
+        assert!(OcifStaking::pool_info(pool_id).is_some());
+        assert_claim_pool(member, pool_id);
+        assert!(OcifStaking::pool_info(pool_id).is_some());
+        assert_eq!(
+            init_reserved_balance + POOL_DEPOSIT,
+            <Test as Config>::Currency::reserved_balance(&creator)
+        );
+
+        // The last claim removes the pool and releases the deposit.
+        assert_claim_pool(creator.clone(), pool_id);
+        System::assert_last_event(mock::RuntimeEvent::OcifStaking(Event::PoolRemoved {
+            pool: pool_id,
+        }));
+        assert!(OcifStaking::pool_info(pool_id).is_none());
+        assert_eq!(
+            init_reserved_balance,
+            <Test as Config>::Currency::reserved_balance(&creator)
+        );
+    })
+}
+
+#[test]
+fn pool_account_does_not_take_stake_transfers() {
+    ExternalityBuilder::build().execute_with(|| {
+        initialize_first_block();
+
+        let dao_id = A;
+        let staker = account(B);
+
+        assert_register(dao_id);
+        assert_stake(staker.clone(), &dao_id, 20);
+        let pool_id = assert_create_pool(account(C), dao_id, 20);
+
+        assert_ne!(
+            OcifStaking::pool_account(pool_id),
+            OcifStaking::account_id()
+        );
+
+        assert_noop!(
+            OcifStaking::transfer_stake(
+                RuntimeOrigin::signed(staker.clone()),
+                dao_id,
+                20,
+                OcifStaking::pool_account(pool_id)
+            ),
+            Error::<Test>::TransferStakeToPool
+        );
+        // Pools that don't exist yet can't be front-run either.
+        assert_noop!(
+            OcifStaking::transfer_stake(
+                RuntimeOrigin::signed(staker),
+                dao_id,
+                20,
+                OcifStaking::pool_account(pool_id + 1)
+            ),
+            Error::<Test>::TransferStakeToPool
+        );
+    })
+}
+
+#[test]
+fn pool_is_not_ok() {
+    ExternalityBuilder::build().execute_with(|| {
+        initialize_first_block();
+
+        let dao_id = A;
+        let member = account(B);
+
+        assert_register(dao_id);
+
+        assert_noop!(
+            OcifStaking::create_pool(
+                RuntimeOrigin::signed(member.clone()),
+                dao_id,
+                MINIMUM_STAKING_AMOUNT - 1
+            ),
+            Error::<Test>::InsufficientBalance
+        );
+        assert_noop!(
+            OcifStaking::create_pool(RuntimeOrigin::signed(member.clone()), B, 20),
+            Error::<Test>::NotRegistered
+        );
+        assert_noop!(
+            OcifStaking::join_pool(RuntimeOrigin::signed(member.clone()), 0, 10),
+            Error::<Test>::PoolNotFound
+        );
+        assert_noop!(
+            OcifStaking::leave_pool(RuntimeOrigin::signed(member.clone()), 0, 10),
+            Error::<Test>::PoolNotFound
+        );
+
+        let pool_id = assert_create_pool(account(C), dao_id, 20);
+
+        assert_noop!(
+            OcifStaking::join_pool(RuntimeOrigin::signed(member.clone()), pool_id, 0),
+            Error::<Test>::StakingNothing
+        );
+        assert_noop!(
+            OcifStaking::leave_pool(RuntimeOrigin::signed(member.clone()), pool_id, 10),
+            Error::<Test>::NoStakeAvailable
+        );
+
+        assert_join_pool(member.clone(), pool_id, 10);
+
+        assert_noop!(
+            OcifStaking::leave_pool(RuntimeOrigin::signed(member.clone()), pool_id, 0),
+            Error::<Test>::UnstakingNothing
+        );
+        assert_noop!(
+            OcifStaking::claim_pool_rewards(RuntimeOrigin::signed(member.clone()), pool_id),
+            Error::<Test>::NothingToClaim
+        );
+
+        assert_ok!(OcifStaking::halt_unhalt_pallet(RuntimeOrigin::root(), true));
+        assert_noop!(
+            OcifStaking::create_pool(RuntimeOrigin::signed(member.clone()), dao_id, 20),
+            Error::<Test>::Halted
+        );
+        assert_noop!(
+            OcifStaking::join_pool(RuntimeOrigin::signed(member.clone()), pool_id, 10),
+            Error::<Test>::Halted
+        );
+        assert_noop!(
+            OcifStaking::leave_pool(RuntimeOrigin::signed(member.clone()), pool_id, 10),
+            Error::<Test>::Halted
+        );
+        assert_noop!(
+            OcifStaking::claim_pool_rewards(RuntimeOrigin::signed(member), pool_id),
+            Error::<Test>::Halted
+        );
+    })
+}
+
+#[test]
+fn liquid_stake_and_redeem_is_ok() {
+    ExternalityBuilder::build().execute_with(|| {
+        initialize_first_block();
+
+        let dao_id = A;
+        let first_staker = account(C);
+        let second_staker = account(D);
+
+        assert_register(dao_id);
+        assert_enable_liquid_staking(dao_id, RECEIPT_TOKEN);
+
+        assert_eq!(assert_liquid_stake(first_staker.clone(), dao_id, 60), 60);
+        assert_eq!(assert_liquid_stake(second_staker.clone(), dao_id, 30), 30);
+
+        // The liquid staking account stakes on behalf of every receipt holder as a single staker.
+        let dao_stake_info = OcifStaking::dao_stake_info(dao_id);
+        assert_eq!(dao_stake_info.number_of_stakers, 1);
+        assert_eq!(dao_stake_info.total, 90);
+
+        assert_eq!(assert_redeem(first_staker.clone(), dao_id, 20), 20);
+        assert_eq!(assert_redeem(second_staker, dao_id, 30), 30);
+
+        advance_to_era(OcifStaking::current_era() + UNBONDING_PERIOD);
+        assert_withdraw_unbonded(first_staker);
+    })
+}
+
+#[test]
+fn liquid_stake_rewards_accrue_to_receipt_holders() {
+    ExternalityBuilder::build().execute_with(|| {
+        initialize_first_block();
+
+        let dao_id = A;
+        let first_holder = account(C);
+        let second_holder = account(D);
+        let staker = account(E);
+
+        assert_register(dao_id);
+        assert_enable_liquid_staking(dao_id, RECEIPT_TOKEN);
+        assert_liquid_stake(first_holder.clone(), dao_id, 60);
+        assert_stake(staker.clone(), &dao_id, 60);
+
+        advance_to_era(OcifStaking::current_era() + 3);
+
+        let staker_reward = OcifStaking::accrued_staker_info(
+            dao_id,
+            &staker,
+            &OcifStaking::accrued_dao_stake_info(dao_id),
+        )
+        .pending_rewards;
+        assert!(!staker_reward.is_zero());
+
+        // Receipts are transferable, half of the rewards go to whoever holds half of the receipts.
+        assert_ok!(<CoreAssets as fungibles::Mutate<AccountId>>::transfer(
+            RECEIPT_TOKEN,
+            &first_holder,
+            &second_holder,
+            30,
+            Preservation::Expendable
+        ));
+
+        let redeemed = assert_redeem(second_holder, dao_id, 30);
+        assert!((redeemed - 30).abs_diff(staker_reward / 2) <= 1);
+
+        // The rewards were staked back towards the dao, so receipts are worth more than their amount.
+        assert!(OcifStaking::ledger(OcifStaking::liquid_account(dao_id)).locked > 30);
+        assert_noop!(
+            OcifStaking::liquid_stake(RuntimeOrigin::signed(staker.clone()), dao_id, 1),
+            Error::<Test>::ReceiptAmountTooLow
+        );
+        assert!(assert_liquid_stake(staker, dao_id, 30) < 30);
+    })
+}
+
+#[test]
+fn liquid_stake_can_be_redeemed_after_dao_unregistration() {
+    ExternalityBuilder::build().execute_with(|| {
+        initialize_first_block();
+
+        let dao_id = A;
+        let first_staker = account(C);
+        let second_staker = account(B);
+
+        assert_register(dao_id);
+        assert_enable_liquid_staking(dao_id, RECEIPT_TOKEN);
+        assert_liquid_stake(first_staker.clone(), dao_id, 40);
+        assert_liquid_stake(second_staker.clone(), dao_id, 10);
+
+        assert_unregister(dao_id);
+
+        let liquid_account = OcifStaking::liquid_account(dao_id);
+        assert!(OcifStaking::staker_info(dao_id, &liquid_account)
+            .staked
+            .is_zero());
+        assert_eq!(
+            OcifStaking::ledger(&liquid_account).unbonding_info.sum(),
+            50
+        );
+
+        assert_eq!(assert_redeem(second_staker.clone(), dao_id, 10), 10);
+        assert_eq!(
+            OcifStaking::ledger(&liquid_account).unbonding_info.sum(),
+            40
+        );
+
+        assert_eq!(assert_redeem(first_staker, dao_id, 40), 40);
+        assert!(!Ledger::<Test>::contains_key(&liquid_account));
+
+        assert_noop!(
+            OcifStaking::liquid_stake(RuntimeOrigin::signed(second_staker), dao_id, 10),
+            Error::<Test>::NotRegistered
+        );
+    })
+}
+
+#[test]
+fn liquid_staking_is_not_ok() {
+    ExternalityBuilder::build().execute_with(|| {
+        initialize_first_block();
+
+        let dao_id = A;
+        let staker = account(B);
+
+        assert_noop!(
+            OcifStaking::enable_liquid_staking(RuntimeOrigin::root(), dao_id, RECEIPT_TOKEN),
+            Error::<Test>::NotRegistered
+        );
+
+        assert_register(dao_id);
+
+        assert_noop!(
+            OcifStaking::enable_liquid_staking(
+                RuntimeOrigin::signed(staker.clone()),
+                dao_id,
+                RECEIPT_TOKEN
+            ),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_noop!(
+            OcifStaking::liquid_stake(RuntimeOrigin::signed(staker.clone()), dao_id, 10),
+            Error::<Test>::LiquidStakingNotEnabled
+        );
+        assert_noop!(
+            OcifStaking::redeem(RuntimeOrigin::signed(staker.clone()), dao_id, 10),
+            Error::<Test>::LiquidStakingNotEnabled
+        );
+
+        assert_ok!(<CoreAssets as fungibles::Mutate<AccountId>>::mint_into(
+            RECEIPT_TOKEN - 1,
+            &staker,
+            10
+        ));
+        assert_noop!(
+            OcifStaking::enable_liquid_staking(RuntimeOrigin::root(), dao_id, RECEIPT_TOKEN - 1),
+            Error::<Test>::ReceiptTokenInUse
+        );
+
+        assert_enable_liquid_staking(dao_id, RECEIPT_TOKEN);
+
+        assert_noop!(
+            OcifStaking::enable_liquid_staking(RuntimeOrigin::root(), dao_id, RECEIPT_TOKEN),
+            Error::<Test>::LiquidStakingAlreadyEnabled
+        );
+        assert_noop!(
+            OcifStaking::liquid_stake(RuntimeOrigin::signed(staker.clone()), dao_id, 0),
+            Error::<Test>::StakingNothing
+        );
+        assert_noop!(
+            OcifStaking::redeem(RuntimeOrigin::signed(staker.clone()), dao_id, 0),
+            Error::<Test>::UnstakingNothing
+        );
+
+        // The first stake can't be small enough to inflate the exchange rate against the next ones.
+        assert_noop!(
+            OcifStaking::liquid_stake(
+                RuntimeOrigin::signed(account(C)),
+                dao_id,
+                MINIMUM_STAKING_AMOUNT - 1
+            ),
+            Error::<Test>::InsufficientBalance
+        );
+
+        assert_liquid_stake(account(C), dao_id, 20);
+        assert_liquid_stake(account(D), dao_id, 1);
+
+        assert_noop!(
+            OcifStaking::redeem(RuntimeOrigin::signed(staker), dao_id, 10),
+            sp_runtime::TokenError::FundsUnavailable
+        );
+    })
+}
+
+#[test]
+fn liquid_account_does_not_take_stake_transfers() {
+    ExternalityBuilder::build().execute_with(|| {
+        initialize_first_block();
+
+        let dao_id = A;
+        let staker = account(B);
+
+        assert_register(dao_id);
+        assert_stake(staker.clone(), &dao_id, 20);
+
+        // Liquid staking doesn't have to be enabled yet for the account to be rejected.
+        assert_noop!(
+            OcifStaking::transfer_stake(
+                RuntimeOrigin::signed(staker.clone()),
+                dao_id,
+                20,
+                OcifStaking::liquid_account(dao_id)
+            ),
+            Error::<Test>::TransferStakeToLiquidAccount
+        );
+
+        assert_enable_liquid_staking(dao_id, RECEIPT_TOKEN);
+        assert_liquid_stake(account(C), dao_id, 20);
+
+        assert_noop!(
+            OcifStaking::transfer_stake(
+                RuntimeOrigin::signed(staker),
+                dao_id,
+                20,
+                OcifStaking::liquid_account(dao_id)
+            ),
+            Error::<Test>::TransferStakeToLiquidAccount
+        );
+    })
+}
+
+#[test]
+fn claim_not_staked_dao() {
+    ExternalityBuilder::build().execute_with(|| {
+        initialize_first_block();
+
+        let staker = account(B);
+        let dao_id = A;
+
+        assert_register(dao_id);
+
+        assert_noop!(
+            OcifStaking::staker_claim_rewards(RuntimeOrigin::signed(staker), dao_id),
+            Error::<Test>::NothingToClaim
+        );
+
+        advance_to_era(OcifStaking::current_era() + 1);
+        assert_noop!(
+            OcifStaking::dao_claim_rewards(RuntimeOrigin::signed(account(dao_id)), dao_id),
+            Error::<Test>::NothingToClaim
+        );
+    })
+}
+
+#[test]
+fn claim_not_registered_dao() {
+    ExternalityBuilder::build().execute_with(|| {
+        initialize_first_block();
+
+        let staker = account(B);
+        let dao_id = A;
+
+        assert_register(dao_id);
+        assert_stake(staker.clone(), &dao_id, 100);
+
+        advance_to_era(OcifStaking::current_era() + 1);
+        assert_unregister(dao_id);
+
+        assert_claim_staker(staker.clone(), dao_id);
+        assert_noop!(
+            OcifStaking::staker_claim_rewards(RuntimeOrigin::signed(staker.clone()), dao_id),
+            Error::<Test>::NothingToClaim
+        );
+
+        assert_claim_dao(dao_id);
+        assert_noop!(
+            OcifStaking::dao_claim_rewards(RuntimeOrigin::signed(account(dao_id)), dao_id),
+            Error::<Test>::NothingToClaim
+        );
+    })
+}
+
+#[test]
+fn claim_with_nothing_pending_is_not_ok() {
+    ExternalityBuilder::build().execute_with(|| {
+        initialize_first_block();
+
+        let staker = account(B);
+        let dao_id = A;
+
+        let start_era = OcifStaking::current_era();
+        assert_register(dao_id);
+        assert_stake(staker.clone(), &dao_id, 100);
+
+        assert_noop!(
+            OcifStaking::staker_claim_rewards(RuntimeOrigin::signed(staker.clone()), dao_id),
+            Error::<Test>::NothingToClaim
+        );
+
+        advance_to_era(start_era + 5);
+
+        assert_claim_staker(staker.clone(), dao_id);
+        assert_claim_dao(dao_id);
+
+        assert_noop!(
+            OcifStaking::staker_claim_rewards(RuntimeOrigin::signed(staker), dao_id),
+            Error::<Test>::NothingToClaim
+        );
+        assert_noop!(
+            OcifStaking::dao_claim_rewards(RuntimeOrigin::signed(account(dao_id)), dao_id),
+            Error::<Test>::NothingToClaim
+        );
+    })
+}
 
 #[test]
 fn claim_dao_same_era_twice() {
@@ -949,14 +2136,10 @@ fn claim_dao_same_era_twice() {
         assert_stake(staker, &dao_id, 100);
         advance_to_era(start_era + 1);
 
-        assert_claim_dao(dao_id, start_era);
+        assert_claim_dao(dao_id);
         assert_noop!(
-            OcifStaking::dao_claim_rewards(
-                RuntimeOrigin::signed(account(dao_id)),
-                dao_id,
-                start_era
-            ),
-            Error::<Test>::RewardAlreadyClaimed
+            OcifStaking::dao_claim_rewards(RuntimeOrigin::signed(account(dao_id)), dao_id),
+            Error::<Test>::NothingToClaim
         );
     })
 }
@@ -990,27 +2173,23 @@ fn claim_is_ok() {
             advance_to_era(OcifStaking::current_era() + 1);
         }
 
-        let current_era = OcifStaking::current_era();
-        for era in start_era..current_era {
-            assert_claim_staker(first_staker.clone(), first_dao_id);
-            assert_claim_dao(first_dao_id, era);
-            assert_claim_staker(second_staker.clone(), first_dao_id);
-        }
+        assert_claim_staker(first_staker.clone(), first_dao_id);
+        assert_claim_dao(first_dao_id);
+        assert_claim_staker(second_staker.clone(), first_dao_id);
 
         assert_noop!(
             OcifStaking::staker_claim_rewards(
                 RuntimeOrigin::signed(first_staker),
                 first_dao_id.clone()
             ),
-            Error::<Test>::IncorrectEra
+            Error::<Test>::NothingToClaim
         );
         assert_noop!(
             OcifStaking::dao_claim_rewards(
                 RuntimeOrigin::signed(account(first_dao_id)),
                 first_dao_id,
-                current_era
             ),
-            Error::<Test>::IncorrectEra
+            Error::<Test>::NothingToClaim
         );
     })
 }
@@ -1043,150 +2222,55 @@ fn claim_check_amount() {
         // Make sure current block is 2.
         assert_eq!(System::block_number(), 2);
 
-        // User stakes in the middle of era 1, their stake should not account for era 1.
+        // User stakes in the middle of era 1, their stake accounts for the era 1 rewards.
         assert_stake(first_staker.clone(), &first_dao_id, 100);
         assert_stake(second_staker.clone(), &second_dao_id, 30);
 
-        advance_to_era_no_rewards(2);
-
-        // Make sure current era is 2.
-        assert_eq!(OcifStaking::current_era(), 2);
-
-        // 130 for stakers, 130 for DAO.
-        issue_rewards(260);
-
-        // Nothing else happens in era 2.
-        advance_to_era_no_rewards(3);
-
-        assert_eq!(
-            OcifStaking::dao_stake_info(first_dao_id, 1),
-            Some(DaoStakeInfo {
-                total: 100,
-                number_of_stakers: 1,
-                reward_claimed: false,
-                active: false
-            })
-        );
-
-        assert_eq!(
-            OcifStaking::dao_stake_info(second_dao_id, 1),
-            Some(DaoStakeInfo {
-                total: 30,
-                number_of_stakers: 1,
-                reward_claimed: false,
-                active: false
-            })
-        );
-
-        assert_eq!(
-            OcifStaking::general_era_info(1),
-            Some(EraInfo {
-                rewards: RewardInfo {
-                    stakers: 130,
-                    dao: 130
-                },
-                staked: 130,
-                active_stake: 100,
-                locked: 130
-            })
-        );
-
-        assert_eq!(
-            OcifStaking::dao_stake_info(first_dao_id, 2),
-            Some(DaoStakeInfo {
-                total: 100,
-                number_of_stakers: 1,
-                reward_claimed: false,
-                active: true
-            })
-        );
-
-        assert_eq!(
-            OcifStaking::dao_stake_info(second_dao_id, 2),
-            Some(DaoStakeInfo {
-                total: 30,
-                number_of_stakers: 1,
-                reward_claimed: false,
-                active: false
-            })
-        );
-
-        assert_eq!(
-            OcifStaking::general_era_info(2),
-            Some(EraInfo {
-                rewards: RewardInfo {
-                    stakers: 130,
-                    dao: 130
-                },
-                staked: 130,
-                active_stake: 100,
-                locked: 130
-            })
-        );
-
-        // Let's try claiming rewards for era 1 for the first dao...
-        assert_ok!(OcifStaking::dao_claim_rewards(
-            RuntimeOrigin::signed(account(first_dao_id)),
-            first_dao_id,
-            1
-        ));
-
-        // ...there should be nothing.
-        System::assert_last_event(mock::RuntimeEvent::OcifStaking(Event::DaoClaimed {
-            dao: first_dao_id,
-            destination_account: account(first_dao_id),
-            era: 1,
-            amount: 0,
-        }));
+        advance_to_era_no_rewards(2);
 
-        // Let's try claiming rewards for era 1 for the second dao...
-        assert_ok!(OcifStaking::dao_claim_rewards(
-            RuntimeOrigin::signed(account(second_dao_id)),
-            second_dao_id,
-            1
-        ));
+        // Make sure current era is 2.
+        assert_eq!(OcifStaking::current_era(), 2);
 
-        // ...there should be nothing.
-        System::assert_last_event(mock::RuntimeEvent::OcifStaking(Event::DaoClaimed {
-            dao: second_dao_id,
-            destination_account: account(second_dao_id),
-            era: 1,
-            amount: 0,
-        }));
+        // 130 for stakers, 130 for DAO.
+        issue_rewards(260);
 
-        // Now let's try claiming rewards for era 2 for the first dao...
-        assert_ok!(OcifStaking::dao_claim_rewards(
-            RuntimeOrigin::signed(account(first_dao_id)),
-            first_dao_id,
-            2
-        ));
+        // Nothing else happens in era 2.
+        advance_to_era_no_rewards(3);
 
-        // ...there should be 130 since it's 50% of the issue 260 and the second core shouldn't be active yet.
-        System::assert_last_event(mock::RuntimeEvent::OcifStaking(Event::DaoClaimed {
-            dao: first_dao_id,
-            destination_account: account(first_dao_id),
-            era: 2,
-            amount: 130,
-        }));
+        let first_dao_info = OcifStaking::dao_stake_info(first_dao_id);
+        assert_eq!(first_dao_info.total, 100);
+        assert_eq!(first_dao_info.number_of_stakers, 1);
+        assert!(first_dao_info.active);
 
-        // Now let's try claiming rewards for era 2 for the second dao...
-        assert_ok!(OcifStaking::dao_claim_rewards(
-            RuntimeOrigin::signed(account(second_dao_id)),
-            second_dao_id,
-            2
-        ));
+        // The second dao is below the active threshold.
+        let second_dao_info = OcifStaking::dao_stake_info(second_dao_id);
+        assert_eq!(second_dao_info.total, 30);
+        assert_eq!(second_dao_info.number_of_stakers, 1);
+        assert!(!second_dao_info.active);
 
-        // ...there should be 0 since the current stake is 30, which is below the active threshold.
-        System::assert_last_event(mock::RuntimeEvent::OcifStaking(Event::DaoClaimed {
-            dao: second_dao_id,
-            destination_account: account(second_dao_id),
-            era: 2,
-            amount: 0,
-        }));
+        for era in 1..=2 {
+            assert_eq!(
+                OcifStaking::general_era_info(era),
+                Some(EraInfo {
+                    rewards: RewardInfo {
+                        stakers: 130,
+                        dao: 130
+                    },
+                    staked: 130,
+                    active_stake: 100,
+                    locked: 130
+                })
+            );
+        }
 
-        // User stakes in the middle of era 3, their stake should not account for era 3.
+        // User stakes in the middle of era 3, making the second dao active from then on.
         assert_stake(first_staker.clone(), &second_dao_id, 20);
 
+        let second_dao_info = OcifStaking::dao_stake_info(second_dao_id);
+        assert_eq!(second_dao_info.total, 50);
+        assert_eq!(second_dao_info.number_of_stakers, 2);
+        assert!(second_dao_info.active);
+
         advance_to_era_no_rewards(4);
 
         // Make sure current era is 4.
@@ -1198,26 +2282,6 @@ fn claim_check_amount() {
         // Nothing else happens in era 4.
         advance_to_era_no_rewards(5);
 
-        assert_eq!(
-            OcifStaking::dao_stake_info(first_dao_id, 4),
-            Some(DaoStakeInfo {
-                total: 100,
-                number_of_stakers: 1,
-                reward_claimed: false,
-                active: true
-            })
-        );
-
-        assert_eq!(
-            OcifStaking::dao_stake_info(second_dao_id, 4),
-            Some(DaoStakeInfo {
-                total: 50,
-                number_of_stakers: 2,
-                reward_claimed: false,
-                active: true
-            })
-        );
-
         assert_eq!(
             OcifStaking::general_era_info(4),
             Some(EraInfo {
@@ -1231,185 +2295,64 @@ fn claim_check_amount() {
             })
         );
 
-        // Let's try claiming rewards for era 4 for the first dao...
+        // Let's claim the rewards of the first dao...
         assert_ok!(OcifStaking::dao_claim_rewards(
             RuntimeOrigin::signed(account(first_dao_id)),
             first_dao_id,
-            4
         ));
 
-        // ...there should be 100 out of the 150, because the second core should be active now.
+        // ...there should be 130 for each of eras 1 and 2 since it was the only active dao,
+        // and 100 out of the 150 of era 4 since the second dao was active too.
         System::assert_last_event(mock::RuntimeEvent::OcifStaking(Event::DaoClaimed {
             dao: first_dao_id,
             destination_account: account(first_dao_id),
-            era: 4,
-            amount: 100,
+            amount: 360,
         }));
 
-        // Let's try claiming rewards for era 4 for the second dao...
+        // Let's claim the rewards of the second dao...
         assert_ok!(OcifStaking::dao_claim_rewards(
             RuntimeOrigin::signed(account(second_dao_id)),
             second_dao_id,
-            4
         ));
 
-        // ...there should be 50 out of the 150, because the second core should be active now.
+        // ...there should only be 50 out of the 150 of era 4, since it wasn't active before era 3.
         System::assert_last_event(mock::RuntimeEvent::OcifStaking(Event::DaoClaimed {
             dao: second_dao_id,
             destination_account: account(second_dao_id),
-            era: 4,
             amount: 50,
         }));
 
         // Now let's check the same stuff for the stakers instead of the dao.
 
         assert_eq!(
-            OcifStaking::staker_info(first_dao_id, first_staker.clone()),
-            StakerInfo {
-                stakes: vec![EraStake {
-                    staked: 100,
-                    era: 1
-                }]
-            }
-        );
-
-        assert_eq!(
-            OcifStaking::staker_info(second_dao_id, first_staker.clone()),
-            StakerInfo {
-                stakes: vec![EraStake { staked: 20, era: 3 }]
-            }
+            OcifStaking::staker_info(first_dao_id, first_staker.clone()).staked,
+            100
         );
-
         assert_eq!(
-            OcifStaking::staker_info(second_dao_id, second_staker.clone()),
-            StakerInfo {
-                stakes: vec![EraStake { staked: 30, era: 1 }]
-            }
+            OcifStaking::staker_info(second_dao_id, first_staker.clone()).staked,
+            20
         );
-
         assert_eq!(
-            OcifStaking::staker_info(first_dao_id, second_staker.clone()),
-            StakerInfo { stakes: vec![] }
+            OcifStaking::staker_info(second_dao_id, second_staker.clone()).staked,
+            30
         );
-
-        // Era 1:
-
-        // Let's try claiming rewards for the first staker in the first dao...
-        assert_ok!(OcifStaking::staker_claim_rewards(
-            RuntimeOrigin::signed(first_staker.clone()),
-            first_dao_id,
-        ));
-
-        // ...there should be 100 out of the 130, because the second staker had 30 staked in era 1.
-        System::assert_last_event(mock::RuntimeEvent::OcifStaking(Event::StakerClaimed {
-            staker: first_staker.clone(),
-            dao: first_dao_id,
-            era: 1,
-            amount: 100,
-        }));
-
-        // Let's try claiming rewards for the second staker in the second dao...
-        assert_ok!(OcifStaking::staker_claim_rewards(
-            RuntimeOrigin::signed(second_staker.clone()),
-            second_dao_id,
-        ));
-
-        // ...there should be 30 out of the 130, because the first staker had 100 staked in era 1.
-        System::assert_last_event(mock::RuntimeEvent::OcifStaking(Event::StakerClaimed {
-            staker: second_staker.clone(),
-            dao: second_dao_id,
-            era: 1,
-            amount: 30,
-        }));
-
-        // Era 2:
-
-        // Let's try claiming rewards for the first staker in the first dao...
-        assert_ok!(OcifStaking::staker_claim_rewards(
-            RuntimeOrigin::signed(first_staker.clone()),
-            first_dao_id,
-        ));
-
-        // ...there should be 100 out of the 130, because the second staker had 30 staked in era 2.
-        System::assert_last_event(mock::RuntimeEvent::OcifStaking(Event::StakerClaimed {
-            staker: first_staker.clone(),
-            dao: first_dao_id,
-            era: 2,
-            amount: 100,
-        }));
-
-        // Let's try claiming rewards for the second staker in the second dao...
-        assert_ok!(OcifStaking::staker_claim_rewards(
-            RuntimeOrigin::signed(second_staker.clone()),
-            second_dao_id,
-        ));
-
-        // ...there should be 30 out of the 130, because the first staker had 100 staked in era 2.
-        System::assert_last_event(mock::RuntimeEvent::OcifStaking(Event::StakerClaimed {
-            staker: second_staker.clone(),
-            dao: second_dao_id,
-            era: 2,
-            amount: 30,
-        }));
-
-        // Era 3:
-
-        // Let's try claiming rewards for the first staker in the first dao...
-        assert_ok!(OcifStaking::staker_claim_rewards(
-            RuntimeOrigin::signed(first_staker.clone()),
+        assert!(!GeneralStakerInfo::<Test>::contains_key(
             first_dao_id,
+            second_staker.clone()
         ));
 
-        // ...there should be nothing, because no rewards were issue in era 3.
-        System::assert_last_event(mock::RuntimeEvent::OcifStaking(Event::StakerClaimed {
-            staker: first_staker.clone(),
-            dao: first_dao_id,
-            era: 3,
-            amount: 0,
-        }));
-
-        // Let's try claiming rewards for the first staker in the second dao...
-        assert_ok!(OcifStaking::staker_claim_rewards(
-            RuntimeOrigin::signed(first_staker.clone()),
-            second_dao_id,
-        ));
-
-        // ...there should be nothing, because no rewards were issue in era 3.
-        System::assert_last_event(mock::RuntimeEvent::OcifStaking(Event::StakerClaimed {
-            staker: first_staker.clone(),
-            dao: second_dao_id,
-            era: 3,
-            amount: 0,
-        }));
-
-        // Let's try claiming rewards for the second staker in the second dao...
-        assert_ok!(OcifStaking::staker_claim_rewards(
-            RuntimeOrigin::signed(second_staker.clone()),
-            second_dao_id,
-        ));
-
-        // ...there should be nothing, because no rewards were issue in era 3.
-        System::assert_last_event(mock::RuntimeEvent::OcifStaking(Event::StakerClaimed {
-            staker: second_staker.clone(),
-            dao: second_dao_id,
-            era: 3,
-            amount: 0,
-        }));
-
-        // Era 4:
-
         // Let's try claiming rewards for the first staker in the first dao...
         assert_ok!(OcifStaking::staker_claim_rewards(
             RuntimeOrigin::signed(first_staker.clone()),
             first_dao_id,
         ));
 
-        // ...there should be 100 out of the 150, because the second staker had 30 staked in era 4 and first staker had 20 in the second dao.
+        // ...there should be 100 out of the 130 of eras 1 and 2, because the second staker had 30 staked,
+        // and 100 out of the 150 of era 4, because the first staker also had 20 staked in the second dao.
         System::assert_last_event(mock::RuntimeEvent::OcifStaking(Event::StakerClaimed {
             staker: first_staker.clone(),
             dao: first_dao_id,
-            era: 4,
-            amount: 100,
+            amount: 300,
         }));
 
         // Let's try claiming rewards for the first staker in the second dao...
@@ -1418,11 +2361,10 @@ fn claim_check_amount() {
             second_dao_id,
         ));
 
-        // ...there should be 20 out of the 150, because the second staker had 30 staked in era 4 and first staker had 100 in the first dao.
+        // ...there should only be 20 out of the 150 of era 4, since no rewards were issued in era 3.
         System::assert_last_event(mock::RuntimeEvent::OcifStaking(Event::StakerClaimed {
             staker: first_staker,
             dao: second_dao_id,
-            era: 4,
             amount: 20,
         }));
 
@@ -1432,12 +2374,11 @@ fn claim_check_amount() {
             second_dao_id,
         ));
 
-        // ...there should be 30 out of the 150, because the first staker had 120 staked in era 4.
+        // ...there should be 30 out of the 130 of eras 1 and 2, and 30 out of the 150 of era 4.
         System::assert_last_event(mock::RuntimeEvent::OcifStaking(Event::StakerClaimed {
             staker: second_staker,
             dao: second_dao_id,
-            era: 4,
-            amount: 30,
+            amount: 90,
         }));
     })
 }
@@ -1457,42 +2398,50 @@ fn claim_after_unregister_is_ok() {
 
         advance_to_era(start_era + 5);
         assert_unstake(staker.clone(), &dao_id, stake_value);
-        let full_unstake_era = OcifStaking::current_era();
-        let number_of_staking_eras = full_unstake_era - start_era;
 
         advance_to_era(OcifStaking::current_era() + 3);
         let stake_value = 75;
-        let restake_era = OcifStaking::current_era();
         assert_stake(staker.clone(), &dao_id, stake_value);
 
         advance_to_era(OcifStaking::current_era() + 3);
         assert_unregister(dao_id);
-        let unregister_era = OcifStaking::current_era();
-        let number_of_staking_eras = number_of_staking_eras + unregister_era - restake_era;
+
+        let staker_reward = OcifStaking::accrued_staker_info(
+            dao_id,
+            &staker,
+            &OcifStaking::accrued_dao_stake_info(dao_id),
+        )
+        .pending_rewards;
+        let dao_reward = OcifStaking::accrued_dao_stake_info(dao_id).pending_rewards;
+
         advance_to_era(OcifStaking::current_era() + 2);
 
-        for _ in 0..number_of_staking_eras {
-            assert_claim_staker(staker.clone(), dao_id);
-        }
+        // Nothing accrues for the dao or its stakers after unregistering.
+        assert_eq!(
+            staker_reward,
+            OcifStaking::accrued_staker_info(
+                dao_id,
+                &staker,
+                &OcifStaking::accrued_dao_stake_info(dao_id)
+            )
+            .pending_rewards
+        );
+        assert_eq!(
+            dao_reward,
+            OcifStaking::accrued_dao_stake_info(dao_id).pending_rewards
+        );
+
+        assert_claim_staker(staker.clone(), dao_id);
         assert_noop!(
             OcifStaking::staker_claim_rewards(RuntimeOrigin::signed(staker), dao_id.clone()),
-            Error::<Test>::NoStakeAvailable
+            Error::<Test>::NothingToClaim
         );
 
-        for era in start_era..unregister_era {
-            if era >= full_unstake_era && era < restake_era {
-                assert_noop!(
-                    OcifStaking::dao_claim_rewards(
-                        RuntimeOrigin::signed(account(A)),
-                        dao_id.clone(),
-                        era
-                    ),
-                    Error::<Test>::NoStakeAvailable
-                );
-            } else {
-                assert_claim_dao(dao_id, era);
-            }
-        }
+        assert_claim_dao(dao_id);
+        assert_noop!(
+            OcifStaking::dao_claim_rewards(RuntimeOrigin::signed(account(A)), dao_id.clone()),
+            Error::<Test>::NothingToClaim
+        );
     })
 }
 
@@ -1549,106 +2498,89 @@ fn claim_dao_with_zero_stake_periods_is_ok() {
         assert_stake(staker.clone(), &dao_id, stake_value);
 
         advance_to_era(start_era + 5);
-        let first_full_unstake_era = OcifStaking::current_era();
         assert_unstake(staker.clone(), &dao_id, stake_value);
+        assert_claim_dao(dao_id);
 
         advance_to_era(OcifStaking::current_era() + 7);
-        let restake_era = OcifStaking::current_era();
+        assert_noop!(
+            OcifStaking::dao_claim_rewards(RuntimeOrigin::signed(account(dao_id)), dao_id.clone()),
+            Error::<Test>::NothingToClaim
+        );
         assert_stake(staker.clone(), &dao_id, stake_value);
 
         advance_to_era(OcifStaking::current_era() + 4);
-        let second_full_unstake_era = OcifStaking::current_era();
         assert_unstake(staker.clone(), &dao_id, stake_value);
         advance_to_era(OcifStaking::current_era() + 10);
 
-        for era in start_era..first_full_unstake_era {
-            assert_claim_dao(dao_id, era);
-        }
-
-        for era in first_full_unstake_era..restake_era {
-            assert_noop!(
-                OcifStaking::dao_claim_rewards(
-                    RuntimeOrigin::signed(account(dao_id)),
-                    dao_id.clone(),
-                    era
-                ),
-                Error::<Test>::NoStakeAvailable
-            );
-        }
-
-        for era in restake_era..second_full_unstake_era {
-            assert_claim_dao(dao_id, era);
-        }
-
+        assert_claim_dao(dao_id);
         assert_noop!(
-            OcifStaking::dao_claim_rewards(
-                RuntimeOrigin::signed(account(dao_id)),
-                dao_id.clone(),
-                second_full_unstake_era
-            ),
-            Error::<Test>::NoStakeAvailable
+            OcifStaking::dao_claim_rewards(RuntimeOrigin::signed(account(dao_id)), dao_id.clone()),
+            Error::<Test>::NothingToClaim
         );
 
         let last_claim_era = OcifStaking::current_era();
         assert_stake(staker, &dao_id, stake_value);
         advance_to_era(last_claim_era + 1);
-        assert_claim_dao(dao_id, last_claim_era);
+        assert_claim_dao(dao_id);
     })
 }
 
 #[test]
-fn dao_stakers_split_util() {
+fn reward_per_stake_accrue_util() {
     let dao_rewards = 420;
     let stakers_rewards = 1337;
-    let staked_on_dao = 123456;
+    let staked_on_dao = 1000;
     let total_staked = staked_on_dao * 2;
 
-    let staking_points_active = DaoStakeInfo::<Balance> {
+    let rewards = RewardInfo::<Balance> {
+        dao: dao_rewards,
+        stakers: stakers_rewards,
+    };
+
+    let mut index = RewardPerStake::default();
+    index.accrue(&rewards, total_staked, staked_on_dao);
+
+    let mut dao_active = DaoStakeInfo::<Balance> {
         total: staked_on_dao,
         number_of_stakers: 10,
-        reward_claimed: false,
         active: true,
+        ..Default::default()
     };
 
-    let staking_points_inactive = DaoStakeInfo::<Balance> {
+    let mut dao_inactive = DaoStakeInfo::<Balance> {
         total: staked_on_dao,
         number_of_stakers: 10,
-        reward_claimed: false,
         active: false,
+        ..Default::default()
     };
 
-    let era_info = EraInfo::<Balance> {
-        rewards: RewardInfo {
-            dao: dao_rewards,
-            stakers: stakers_rewards,
-        },
-        staked: total_staked,
-        locked: total_staked,
-        active_stake: staked_on_dao,
-    };
-
-    let (dao_reward, stakers_reward) =
-        OcifStaking::dao_stakers_split(&staking_points_active, &era_info);
+    dao_active.accrue(&index, true);
+    dao_inactive.accrue(&index, true);
 
-    let dao_stake_ratio = Perbill::from_rational(staked_on_dao, total_staked);
-    let calculated_stakers_reward = dao_stake_ratio * stakers_rewards;
-    assert_eq!(dao_rewards, dao_reward);
-    assert_eq!(calculated_stakers_reward, stakers_reward);
+    let calculated_stakers_reward = stakers_rewards * staked_on_dao / total_staked;
 
-    assert_eq!(
-        calculated_stakers_reward + dao_rewards,
-        dao_reward + stakers_reward
-    );
+    assert_eq!(dao_rewards, dao_active.pending_rewards);
+    assert!(dao_inactive.pending_rewards.is_zero());
 
-    let (dao_reward, stakers_reward) =
-        OcifStaking::dao_stakers_split(&staking_points_inactive, &era_info);
+    for dao_info in [&dao_active, &dao_inactive] {
+        let mut staker_info = StakerInfo::<Balance> {
+            staked: staked_on_dao,
+            ..Default::default()
+        };
+        staker_info.accrue(dao_info);
+        assert_eq!(calculated_stakers_reward, staker_info.pending_rewards);
+    }
 
-    let dao_stake_ratio = Perbill::from_rational(staked_on_dao, total_staked);
-    let calculated_stakers_reward = dao_stake_ratio * stakers_rewards;
-    assert_eq!(Balance::zero(), dao_reward);
-    assert_eq!(calculated_stakers_reward, stakers_reward);
+    // Accruing again without the index moving adds nothing.
+    dao_active.accrue(&index, true);
+    assert_eq!(dao_rewards, dao_active.pending_rewards);
 
-    assert_eq!(calculated_stakers_reward, dao_reward + stakers_reward);
+    // An unregistered dao keeps its stakers index frozen.
+    let frozen = dao_active.reward_per_stake;
+    index.accrue(&rewards, total_staked, staked_on_dao);
+    dao_active.accrue(&index, false);
+    assert_eq!(frozen, dao_active.reward_per_stake);
+    assert_eq!(dao_rewards * 2, dao_active.pending_rewards);
 }
 
 #[test]
@@ -1724,211 +2656,126 @@ fn unbonding_info_test() {
 }
 
 #[test]
-fn staker_info_basic() {
-    let staker_info = StakerInfo::<Balance>::default();
-
-    assert!(staker_info.is_empty());
-    assert_eq!(staker_info.len(), 0);
-    assert_eq!(staker_info.latest_staked_value(), 0);
-}
-
-#[test]
-fn staker_info_stake_ops() {
-    let mut staker_info = StakerInfo::<Balance>::default();
+fn unbonding_info_rebond() {
+    let mut unbonding_info = UnbondingInfo::<Balance>::default();
+    assert!(unbonding_info.rebond(100).is_zero());
 
-    let first_era = 1;
-    let first_stake = 100;
-    assert_ok!(staker_info.stake(first_era, first_stake));
-    assert!(!staker_info.is_empty());
-    assert_eq!(staker_info.len(), 1);
-    assert_eq!(staker_info.latest_staked_value(), first_stake);
+    for (amount, unlock_era) in [(10, 1), (20, 3), (30, 5)] {
+        unbonding_info.add(UnlockingChunk { amount, unlock_era });
+    }
 
-    let second_era = first_era + 1;
-    let second_stake = 200;
-    assert_ok!(staker_info.stake(second_era, second_stake));
-    assert_eq!(staker_info.len(), 2);
-    assert_eq!(
-        staker_info.latest_staked_value(),
-        first_stake + second_stake
-    );
+    assert!(unbonding_info.rebond(0).is_zero());
+    assert_eq!(unbonding_info.len(), 3);
 
-    let third_era = second_era + 2;
-    let third_stake = 333;
-    assert_ok!(staker_info.stake(third_era, third_stake));
-    assert_eq!(
-        staker_info.latest_staked_value(),
-        first_stake + second_stake + third_stake
-    );
-    assert_eq!(staker_info.len(), 3);
+    assert_eq!(unbonding_info.rebond(30), 30);
+    assert_eq!(unbonding_info.len(), 2);
 
-    let fourth_era = third_era;
-    let fourth_stake = 444;
-    assert_ok!(staker_info.stake(fourth_era, fourth_stake));
-    assert_eq!(staker_info.len(), 3);
+    assert_eq!(unbonding_info.rebond(5), 5);
     assert_eq!(
-        staker_info.latest_staked_value(),
-        first_stake + second_stake + third_stake + fourth_stake
+        unbonding_info.unlocking_chunks,
+        vec![
+            UnlockingChunk {
+                amount: 10,
+                unlock_era: 1
+            },
+            UnlockingChunk {
+                amount: 15,
+                unlock_era: 3
+            }
+        ]
     );
-}
 
-#[test]
-fn staker_info_stake_error() {
-    let mut staker_info = StakerInfo::<Balance>::default();
-    assert_ok!(staker_info.stake(5, 100));
-    if let Err(_) = staker_info.stake(4, 100) {
-    } else {
-        panic!("Mustn't be able to stake with past era.");
-    }
+    assert_eq!(unbonding_info.rebond(100), 25);
+    assert!(unbonding_info.is_empty());
 }
 
 #[test]
-fn staker_info_unstake_ops() {
-    let mut staker_info = StakerInfo::<Balance>::default();
+fn staker_info_basic() {
+    let staker_info = StakerInfo::<Balance>::default();
 
     assert!(staker_info.is_empty());
-    assert_ok!(staker_info.unstake(1, 100));
-    assert!(staker_info.is_empty());
-
-    let (first_era, second_era) = (1, 3);
-    let (first_stake, second_stake) = (110, 222);
-    let total_staked = first_stake + second_stake;
-    assert_ok!(staker_info.stake(first_era, first_stake));
-    assert_ok!(staker_info.stake(second_era, second_stake));
-
-    let first_unstake_era = second_era;
-    let first_unstake = 55;
-    assert_ok!(staker_info.unstake(first_unstake_era, first_unstake));
-    assert_eq!(staker_info.len(), 2);
-    assert_eq!(
-        staker_info.latest_staked_value(),
-        total_staked - first_unstake
-    );
-    let total_staked = total_staked - first_unstake;
-
-    let second_unstake_era = first_unstake_era + 2;
-    let second_unstake = 37;
-    assert_ok!(staker_info.unstake(second_unstake_era, second_unstake));
-    assert_eq!(staker_info.len(), 3);
-    assert_eq!(
-        staker_info.latest_staked_value(),
-        total_staked - second_unstake
-    );
-    let total_staked = total_staked - second_unstake;
-
-    let temp_staker_info = staker_info.clone();
-
-    assert_ok!(staker_info.unstake(second_unstake_era, total_staked));
-    assert_eq!(staker_info.len(), 3);
-    assert_eq!(staker_info.latest_staked_value(), 0);
-
-    let mut staker_info = temp_staker_info;
-    assert_ok!(staker_info.unstake(second_unstake_era + 1, total_staked));
-    assert_eq!(staker_info.len(), 4);
-    assert_eq!(staker_info.latest_staked_value(), 0);
+    assert!(staker_info.staked.is_zero());
+    assert!(staker_info.pending_rewards.is_zero());
 }
 
 #[test]
-fn stake_after_full_unstake() {
-    let mut staker_info = StakerInfo::<Balance>::default();
-
-    let first_era = 1;
-    let first_stake = 100;
-    assert_ok!(staker_info.stake(first_era, first_stake));
-    assert_eq!(staker_info.latest_staked_value(), first_stake);
+fn staker_info_accrue_ops() {
+    let mut index = RewardPerStake::default();
+    let mut dao_stake_info = DaoStakeInfo::<Balance>::default();
+    let mut staker_info = StakerInfo::<Balance> {
+        staked: 100,
+        ..Default::default()
+    };
 
-    let unstake_era = first_era + 1;
-    assert_ok!(staker_info.unstake(unstake_era, first_stake));
-    assert!(staker_info.latest_staked_value().is_zero());
-    assert_eq!(staker_info.len(), 2);
+    staker_info.accrue(&dao_stake_info);
+    assert!(staker_info.pending_rewards.is_zero());
 
-    let restake_era = unstake_era + 2;
-    let restake_value = 57;
-    assert_ok!(staker_info.stake(restake_era, restake_value));
-    assert_eq!(staker_info.latest_staked_value(), restake_value);
-    assert_eq!(staker_info.len(), 3);
-}
+    let rewards = RewardInfo::<Balance> {
+        stakers: 500,
+        dao: 0,
+    };
 
-#[test]
-fn staker_info_unstake_error() {
-    let mut staker_info = StakerInfo::<Balance>::default();
-    assert_ok!(staker_info.stake(5, 100));
-    if let Err(_) = staker_info.unstake(4, 100) {
-    } else {
-        panic!("Mustn't be able to unstake with past era.");
-    }
+    index.accrue(&rewards, 1000, 1000);
+    dao_stake_info.accrue(&index, true);
+    staker_info.accrue(&dao_stake_info);
+    assert_eq!(staker_info.pending_rewards, 50);
+
+    // Accruing twice up to the same index doesn't add anything.
+    staker_info.accrue(&dao_stake_info);
+    assert_eq!(staker_info.pending_rewards, 50);
+
+    staker_info.staked = 300;
+    index.accrue(&rewards, 1000, 1000);
+    dao_stake_info.accrue(&index, true);
+    staker_info.accrue(&dao_stake_info);
+    assert_eq!(staker_info.pending_rewards, 50 + 150);
+
+    // Fully unstaked positions keep their pending rewards without accruing new ones.
+    staker_info.staked = 0;
+    index.accrue(&rewards, 1000, 1000);
+    dao_stake_info.accrue(&index, true);
+    staker_info.accrue(&dao_stake_info);
+    assert_eq!(staker_info.pending_rewards, 200);
+    assert!(!staker_info.is_empty());
 }
 
 #[test]
-fn staker_info_claim_ops_basic() {
-    let mut staker_info = StakerInfo::<Balance>::default();
+fn staker_info_take_rewards() {
+    let mut staker_info = StakerInfo::<Balance> {
+        staked: 100,
+        pending_rewards: 42,
+        ..Default::default()
+    };
 
-    assert!(staker_info.is_empty());
-    assert_eq!(staker_info.claim(), (0, 0));
-    assert!(staker_info.is_empty());
+    assert_eq!(staker_info.take_rewards(), 42);
+    assert!(staker_info.pending_rewards.is_zero());
+    assert_eq!(staker_info.take_rewards(), 0);
+    assert!(!staker_info.is_empty());
 
-    assert_ok!(staker_info.stake(1, 100));
-    assert_ok!(staker_info.unstake(1, 100));
-    assert!(staker_info.is_empty());
-    assert_eq!(staker_info.claim(), (0, 0));
+    staker_info.staked = 0;
     assert!(staker_info.is_empty());
-
-    staker_info = StakerInfo::<Balance>::default();
-    let stake_era = 1;
-    let stake_value = 123;
-    assert_ok!(staker_info.stake(stake_era, stake_value));
-    assert_eq!(staker_info.len(), 1);
-    assert_eq!(staker_info.claim(), (stake_era, stake_value));
-    assert_eq!(staker_info.len(), 1);
 }
 
 #[test]
-fn staker_info_claim_ops_advanced() {
-    let mut staker_info = StakerInfo::<Balance>::default();
-
-    let (first_stake_era, second_stake_era, third_stake_era) = (1, 2, 4);
-    let (first_stake_value, second_stake_value, third_stake_value) = (123, 456, 789);
-
-    assert_ok!(staker_info.stake(first_stake_era, first_stake_value));
-    assert_ok!(staker_info.stake(second_stake_era, second_stake_value));
-    assert_ok!(staker_info.stake(third_stake_era, third_stake_value));
-
-    assert_eq!(staker_info.len(), 3);
-    assert_eq!(staker_info.claim(), (first_stake_era, first_stake_value));
-    assert_eq!(staker_info.len(), 2);
-
-    assert_eq!(
-        staker_info.claim(),
-        (second_stake_era, first_stake_value + second_stake_value)
-    );
-    assert_eq!(staker_info.len(), 2);
-
-    assert_eq!(
-        staker_info.claim(),
-        (3, first_stake_value + second_stake_value)
-    );
-    assert_eq!(staker_info.len(), 1);
-
-    let total_staked = first_stake_value + second_stake_value + third_stake_value;
-    assert_ok!(staker_info.unstake(5, total_staked));
-    assert_eq!(staker_info.len(), 2);
-
-    let fourth_era = 7;
-    let fourth_stake_value = 147;
-    assert_ok!(staker_info.stake(fourth_era, fourth_stake_value));
-    assert_eq!(staker_info.len(), 3);
+fn pool_info_distribute() {
+    let mut pool = PoolInfo::<AccountId, DaoId, Balance> {
+        total: 300,
+        ..Default::default()
+    };
 
-    assert_eq!(staker_info.claim(), (third_stake_era, total_staked));
-    assert_eq!(staker_info.len(), 1);
+    pool.distribute(150);
+    assert_eq!(pool.reward_per_stake, FixedU128::from_rational(1, 2));
 
-    assert_eq!(staker_info.claim(), (fourth_era, fourth_stake_value));
-    assert_eq!(staker_info.len(), 1);
-    assert_eq!(staker_info.latest_staked_value(), fourth_stake_value);
+    pool.distribute(300);
+    assert_eq!(pool.reward_per_stake, FixedU128::from_rational(3, 2));
 
-    for x in 1..10 {
-        assert_eq!(staker_info.claim(), (fourth_era + x, fourth_stake_value));
-        assert_eq!(staker_info.len(), 1);
-        assert_eq!(staker_info.latest_staked_value(), fourth_stake_value);
-    }
+    let mut member_info = StakerInfo::<Balance> {
+        staked: 100,
+        ..Default::default()
+    };
+    member_info.accrue_to(pool.reward_per_stake);
+    assert_eq!(member_info.pending_rewards, 150);
+    assert_eq!(member_info.reward_per_stake_paid, pool.reward_per_stake);
 }
 
 #[test]
@@ -1987,9 +2834,7 @@ fn pallet_halt_is_ok() {
                     pallet_dao_manager::origin::MultisigInternalOrigin::new(dao_id)
                 )
                 .into(),
-                Vec::default().try_into().unwrap(),
-                Vec::default().try_into().unwrap(),
-                Vec::default().try_into().unwrap()
+                Some(Default::default())
             ),
             Error::<Test>::Halted
         );
@@ -2010,9 +2855,7 @@ fn pallet_halt_is_ok() {
                     pallet_dao_manager::origin::MultisigInternalOrigin::new(dao_id)
                 )
                 .into(),
-                Vec::default().try_into().unwrap(),
-                Vec::default().try_into().unwrap(),
-                Vec::default().try_into().unwrap()
+                Default::default()
             ),
             Error::<Test>::Halted
         );
@@ -2033,7 +2876,7 @@ fn pallet_halt_is_ok() {
         );
 
         assert_noop!(
-            OcifStaking::dao_claim_rewards(RuntimeOrigin::signed(account(dao_id)), dao_id, 5),
+            OcifStaking::dao_claim_rewards(RuntimeOrigin::signed(account(dao_id)), dao_id),
             Error::<Test>::Halted
         );
 
@@ -2091,12 +2934,12 @@ fn move_stake_is_ok() {
 
         assert_move_stake(staker.clone(), &dao_id_a, &dao_id_b, stake_value / 2);
         assert!(!GeneralStakerInfo::<Test>::get(&dao_id_a, &staker.clone())
-            .latest_staked_value()
+            .staked
             .is_zero());
 
         assert_move_stake(staker.clone(), &dao_id_a, &dao_id_b, stake_value / 2);
         assert!(GeneralStakerInfo::<Test>::get(&dao_id_a, &staker)
-            .latest_staked_value()
+            .staked
             .is_zero());
     })
 }
@@ -2172,32 +3015,7 @@ fn move_stake_to_unregistered_dao_err() {
 }
 
 #[test]
-fn move_stake_not_staking_err() {
-    ExternalityBuilder::build().execute_with(|| {
-        initialize_first_block();
-
-        let staker = account(B);
-        let dao_id_a = A;
-        let dao_id_b = C;
-
-        assert_register(dao_id_a);
-        assert_register(dao_id_b);
-        let stake_value = 100;
-
-        assert_noop!(
-            OcifStaking::move_stake(
-                RuntimeOrigin::signed(staker),
-                dao_id_a,
-                stake_value,
-                dao_id_b
-            ),
-            Error::<Test>::NoStakeAvailable
-        );
-    })
-}
-
-#[test]
-fn move_stake_with_no_amount_err() {
+fn move_stake_not_staking_err() {
     ExternalityBuilder::build().execute_with(|| {
         initialize_first_block();
 
@@ -2208,22 +3026,21 @@ fn move_stake_with_no_amount_err() {
         assert_register(dao_id_a);
         assert_register(dao_id_b);
         let stake_value = 100;
-        assert_stake(staker.clone(), &dao_id_a, stake_value);
 
         assert_noop!(
             OcifStaking::move_stake(
                 RuntimeOrigin::signed(staker),
                 dao_id_a,
-                Zero::zero(),
+                stake_value,
                 dao_id_b
             ),
-            Error::<Test>::UnstakingNothing
+            Error::<Test>::NoStakeAvailable
         );
     })
 }
 
 #[test]
-fn move_stake_with_insufficient_amount_err() {
+fn move_stake_with_no_amount_err() {
     ExternalityBuilder::build().execute_with(|| {
         initialize_first_block();
 
@@ -2240,16 +3057,16 @@ fn move_stake_with_insufficient_amount_err() {
             OcifStaking::move_stake(
                 RuntimeOrigin::signed(staker),
                 dao_id_a,
-                MINIMUM_STAKING_AMOUNT - 1,
+                Zero::zero(),
                 dao_id_b
             ),
-            Error::<Test>::InsufficientBalance
+            Error::<Test>::UnstakingNothing
         );
     })
 }
 
 #[test]
-fn move_stake_dao_has_too_many_era_stake_err() {
+fn move_stake_with_insufficient_amount_err() {
     ExternalityBuilder::build().execute_with(|| {
         initialize_first_block();
 
@@ -2259,30 +3076,17 @@ fn move_stake_dao_has_too_many_era_stake_err() {
 
         assert_register(dao_id_a);
         assert_register(dao_id_b);
-
-        for _ in 1..MAX_ERA_STAKE_VALUES {
-            assert_stake(staker.clone(), &dao_id_a, MINIMUM_STAKING_AMOUNT);
-            advance_to_era(OcifStaking::current_era() + 1);
-        }
-        assert_noop!(
-            OcifStaking::stake(RuntimeOrigin::signed(staker.clone()), dao_id_a, 15),
-            Error::<Test>::TooManyEraStakeValues
-        );
+        let stake_value = 100;
+        assert_stake(staker.clone(), &dao_id_a, stake_value);
 
         assert_noop!(
             OcifStaking::move_stake(
-                RuntimeOrigin::signed(staker.clone()),
+                RuntimeOrigin::signed(staker),
                 dao_id_a,
-                15,
+                MINIMUM_STAKING_AMOUNT - 1,
                 dao_id_b
             ),
-            Error::<Test>::TooManyEraStakeValues
-        );
-
-        assert_stake(staker.clone(), &dao_id_b, 15);
-        assert_noop!(
-            OcifStaking::move_stake(RuntimeOrigin::signed(staker), dao_id_b, 15, dao_id_a),
-            Error::<Test>::TooManyEraStakeValues
+            Error::<Test>::InsufficientBalance
         );
     })
 }
@@ -2366,17 +3170,425 @@ fn claim_stake_after_unregistering_dao_mid_era_changes_is_ok() {
             run_for_blocks(1);
         }
 
-        for _ in era..era_to_claim {
-            for staker in stakers.iter() {
-                assert_claim_staker(staker.clone(), dao_id_b);
-            }
+        for staker in stakers.iter() {
+            assert_claim_staker(staker.clone(), dao_id_b);
         }
 
         println!("finished claiming");
 
         assert_noop!(
             OcifStaking::staker_claim_rewards(RuntimeOrigin::signed(stakers[0].clone()), dao_id_b),
-            Error::<Test>::NoStakeAvailable
+            Error::<Test>::NothingToClaim
         );
     });
 }
+
+#[test]
+fn claim_all_is_ok() {
+    ExternalityBuilder::build().execute_with(|| {
+        initialize_first_block();
+
+        let staker = account(D);
+        let dao_id = A;
+
+        assert_register(dao_id);
+        assert_stake(staker.clone(), &dao_id, 100);
+
+        assert_noop!(
+            OcifStaking::claim_all(RuntimeOrigin::signed(staker.clone()), dao_id),
+            Error::<Test>::NothingToClaim
+        );
+
+        advance_to_era(OcifStaking::current_era() + 4);
+
+        let reward = OcifStaking::accrued_staker_info(
+            dao_id,
+            &staker,
+            &OcifStaking::accrued_dao_stake_info(dao_id),
+        )
+        .pending_rewards;
+        let staker_balance = <Test as Config>::Currency::free_balance(&staker);
+
+        // Every era passed is claimed at once.
+        assert_ok!(OcifStaking::claim_all(
+            RuntimeOrigin::signed(staker.clone()),
+            dao_id
+        ));
+        System::assert_last_event(mock::RuntimeEvent::OcifStaking(Event::StakerClaimedAll {
+            staker: staker.clone(),
+            dao: dao_id,
+            amount: reward,
+        }));
+        assert_eq!(
+            <Test as Config>::Currency::free_balance(&staker),
+            staker_balance + reward
+        );
+
+        assert_noop!(
+            OcifStaking::claim_all(RuntimeOrigin::signed(staker), dao_id),
+            Error::<Test>::NothingToClaim
+        );
+    })
+}
+
+#[test]
+fn claim_all_daos_is_ok() {
+    ExternalityBuilder::build().execute_with(|| {
+        initialize_first_block();
+
+        let first_staker = account(D);
+        let second_staker = account(E);
+        let first_dao_id = A;
+        let second_dao_id = B;
+
+        let start_era = OcifStaking::current_era();
+
+        assert_register(first_dao_id);
+        assert_register(second_dao_id);
+        assert_stake(first_staker.clone(), &first_dao_id, 100);
+        assert_stake(first_staker.clone(), &second_dao_id, 50);
+        assert_stake(second_staker.clone(), &first_dao_id, 100);
+        assert_stake(second_staker.clone(), &second_dao_id, 50);
+
+        advance_to_era(start_era + 4);
+
+        // Claiming every dao one by one pays the same as claiming them all at once.
+        let first_staker_balance = <Test as Config>::Currency::free_balance(&first_staker);
+        assert_claim_staker(first_staker.clone(), first_dao_id);
+        assert_claim_staker(first_staker.clone(), second_dao_id);
+        let claimed =
+            <Test as Config>::Currency::free_balance(&first_staker) - first_staker_balance;
+
+        let dao_ids: BoundedVec<DaoId, MaxDaosPerClaim> =
+            vec![first_dao_id, second_dao_id].try_into().unwrap();
+
+        let second_staker_balance = <Test as Config>::Currency::free_balance(&second_staker);
+        let second_dao_reward = OcifStaking::accrued_staker_info(
+            second_dao_id,
+            &second_staker,
+            &OcifStaking::accrued_dao_stake_info(second_dao_id),
+        )
+        .pending_rewards;
+
+        assert_ok!(OcifStaking::claim_all_daos(
+            RuntimeOrigin::signed(second_staker.clone()),
+            dao_ids.clone()
+        ));
+        System::assert_has_event(mock::RuntimeEvent::OcifStaking(Event::StakerClaimed {
+            staker: second_staker.clone(),
+            dao: second_dao_id,
+            amount: second_dao_reward,
+        }));
+        System::assert_last_event(mock::RuntimeEvent::OcifStaking(
+            Event::StakerClaimedAllDaos {
+                staker: second_staker.clone(),
+                daos: dao_ids.clone(),
+                amount: claimed,
+            },
+        ));
+        assert_eq!(
+            <Test as Config>::Currency::free_balance(&second_staker) - second_staker_balance,
+            claimed
+        );
+
+        assert_noop!(
+            OcifStaking::claim_all_daos(RuntimeOrigin::signed(second_staker), dao_ids),
+            Error::<Test>::NothingToClaim
+        );
+    })
+}
+
+#[test]
+fn claim_all_daos_skips_daos_with_nothing_to_claim() {
+    ExternalityBuilder::build().execute_with(|| {
+        initialize_first_block();
+
+        let staker = account(D);
+        let first_dao_id = A;
+        let second_dao_id = B;
+
+        assert_register(first_dao_id);
+        assert_register(second_dao_id);
+        assert_stake(staker.clone(), &first_dao_id, 100);
+
+        let dao_ids: BoundedVec<DaoId, MaxDaosPerClaim> =
+            vec![first_dao_id, second_dao_id].try_into().unwrap();
+
+        // Staked, but no era has ended yet.
+        assert_noop!(
+            OcifStaking::claim_all_daos(RuntimeOrigin::signed(staker.clone()), dao_ids.clone()),
+            Error::<Test>::NothingToClaim
+        );
+
+        advance_to_era(OcifStaking::current_era() + 1);
+
+        let reward = OcifStaking::accrued_staker_info(
+            first_dao_id,
+            &staker,
+            &OcifStaking::accrued_dao_stake_info(first_dao_id),
+        )
+        .pending_rewards;
+        let staker_balance = <Test as Config>::Currency::free_balance(&staker);
+
+        assert_ok!(OcifStaking::claim_all_daos(
+            RuntimeOrigin::signed(staker.clone()),
+            dao_ids.clone()
+        ));
+        System::assert_has_event(mock::RuntimeEvent::OcifStaking(Event::StakerClaimed {
+            staker: staker.clone(),
+            dao: first_dao_id,
+            amount: reward,
+        }));
+        System::assert_last_event(mock::RuntimeEvent::OcifStaking(
+            Event::StakerClaimedAllDaos {
+                staker: staker.clone(),
+                daos: dao_ids.clone(),
+                amount: reward,
+            },
+        ));
+        assert_eq!(
+            <Test as Config>::Currency::free_balance(&staker),
+            staker_balance + reward
+        );
+
+        assert_ok!(OcifStaking::halt_unhalt_pallet(RuntimeOrigin::root(), true));
+        assert_noop!(
+            OcifStaking::claim_all_daos(RuntimeOrigin::signed(staker), dao_ids),
+            Error::<Test>::Halted
+        );
+    })
+}
+
+#[test]
+fn set_reward_destination_is_ok() {
+    ExternalityBuilder::build().execute_with(|| {
+        initialize_first_block();
+
+        let staker = account(B);
+        let destination = account(C);
+        let dao_id = A;
+
+        assert_register(dao_id);
+        assert_stake(staker.clone(), &dao_id, 100);
+
+        let claim_era = OcifStaking::current_era();
+        advance_to_era(claim_era + 1);
+
+        assert_ok!(OcifStaking::set_reward_destination(
+            RuntimeOrigin::signed(staker.clone()),
+            RewardDestination::Account(destination.clone())
+        ));
+        System::assert_last_event(mock::RuntimeEvent::OcifStaking(
+            Event::RewardDestinationSet {
+                staker: staker.clone(),
+                destination: RewardDestination::Account(destination.clone()),
+            },
+        ));
+        assert_eq!(
+            OcifStaking::reward_destination(&staker),
+            RewardDestination::Account(destination.clone())
+        );
+
+        let reward = OcifStaking::accrued_staker_info(
+            dao_id,
+            &staker,
+            &OcifStaking::accrued_dao_stake_info(dao_id),
+        )
+        .pending_rewards;
+
+        let staker_balance = <Test as Config>::Currency::free_balance(&staker);
+        let destination_balance = <Test as Config>::Currency::free_balance(&destination);
+
+        assert_ok!(OcifStaking::staker_claim_rewards(
+            RuntimeOrigin::signed(staker.clone()),
+            dao_id
+        ));
+
+        assert_eq!(
+            <Test as Config>::Currency::free_balance(&staker),
+            staker_balance
+        );
+        assert_eq!(
+            <Test as Config>::Currency::free_balance(&destination),
+            destination_balance + reward
+        );
+
+        // Back to the default.
+        assert_ok!(OcifStaking::set_reward_destination(
+            RuntimeOrigin::signed(staker.clone()),
+            RewardDestination::FreeBalance
+        ));
+        assert!(!RewardDestinations::<Test>::contains_key(&staker));
+    })
+}
+
+#[test]
+fn restake_reward_destination_is_ok() {
+    ExternalityBuilder::build().execute_with(|| {
+        initialize_first_block();
+
+        let staker = account(B);
+        let dao_id = A;
+        let stake_value = 100;
+
+        assert_register(dao_id);
+        assert_stake(staker.clone(), &dao_id, stake_value);
+
+        let claim_era = OcifStaking::current_era();
+        advance_to_era(claim_era + 2);
+
+        assert_ok!(OcifStaking::set_reward_destination(
+            RuntimeOrigin::signed(staker.clone()),
+            RewardDestination::Restake
+        ));
+
+        let reward = OcifStaking::accrued_staker_info(
+            dao_id,
+            &staker,
+            &OcifStaking::accrued_dao_stake_info(dao_id),
+        )
+        .pending_rewards;
+
+        let current_era = OcifStaking::current_era();
+        let init_state = MemorySnapshot::all(current_era, &dao_id, staker.clone());
+
+        assert_ok!(OcifStaking::staker_claim_rewards(
+            RuntimeOrigin::signed(staker.clone()),
+            dao_id
+        ));
+        System::assert_has_event(mock::RuntimeEvent::OcifStaking(Event::RewardRestaked {
+            staker: staker.clone(),
+            dao: dao_id,
+            amount: reward,
+        }));
+
+        let final_state = MemorySnapshot::all(current_era, &dao_id, staker.clone());
+
+        assert_eq!(final_state.staker_info.staked, stake_value + reward);
+        assert_eq!(final_state.ledger.locked, init_state.ledger.locked + reward);
+        assert_eq!(
+            final_state.dao_stake_info.total,
+            init_state.dao_stake_info.total + reward
+        );
+        assert_eq!(
+            final_state.era_info.staked,
+            init_state.era_info.staked + reward
+        );
+        assert_eq!(final_state.free_balance, init_state.free_balance + reward);
+        assert!(final_state.staker_info.pending_rewards.is_zero());
+    })
+}
+
+#[test]
+fn restake_reward_destination_after_unregister_pays_free_balance() {
+    ExternalityBuilder::build().execute_with(|| {
+        initialize_first_block();
+
+        let staker = account(B);
+        let dao_id = A;
+
+        assert_register(dao_id);
+        assert_stake(staker.clone(), &dao_id, 100);
+
+        advance_to_era(OcifStaking::current_era() + 1);
+
+        assert_ok!(OcifStaking::set_reward_destination(
+            RuntimeOrigin::signed(staker.clone()),
+            RewardDestination::Restake
+        ));
+
+        assert_unregister(dao_id);
+        advance_to_era(OcifStaking::current_era() + 1);
+
+        let ledger = OcifStaking::ledger(&staker);
+
+        System::reset_events();
+
+        assert_claim_staker(staker.clone(), dao_id);
+
+        assert!(!System::events().iter().any(|record| matches!(
+            record.event,
+            mock::RuntimeEvent::OcifStaking(Event::RewardRestaked { .. })
+        )));
+        assert_eq!(OcifStaking::ledger(&staker), ledger);
+    })
+}
+
+#[test]
+fn set_dao_reward_destination_is_ok() {
+    ExternalityBuilder::build().execute_with(|| {
+        initialize_first_block();
+
+        let staker = account(B);
+        let destination = account(C);
+        let dao_id = A;
+
+        assert_noop!(
+            OcifStaking::set_dao_reward_destination(
+                pallet_dao_manager::Origin::Multisig(
+                    pallet_dao_manager::origin::MultisigInternalOrigin::new(dao_id)
+                )
+                .into(),
+                Some(destination.clone())
+            ),
+            Error::<Test>::NotRegistered
+        );
+
+        assert_register(dao_id);
+        assert_stake(staker.clone(), &dao_id, 100);
+
+        let claim_era = OcifStaking::current_era();
+        advance_to_era(claim_era + 2);
+
+        assert_noop!(
+            OcifStaking::set_dao_reward_destination(
+                RuntimeOrigin::signed(account(dao_id)),
+                Some(destination.clone())
+            ),
+            sp_runtime::DispatchError::BadOrigin
+        );
+
+        assert_ok!(OcifStaking::set_dao_reward_destination(
+            pallet_dao_manager::Origin::Multisig(
+                pallet_dao_manager::origin::MultisigInternalOrigin::new(dao_id)
+            )
+            .into(),
+            Some(destination.clone())
+        ));
+        System::assert_last_event(mock::RuntimeEvent::OcifStaking(
+            Event::DaoRewardDestinationSet {
+                dao: dao_id,
+                destination: Some(destination.clone()),
+            },
+        ));
+
+        let reward = OcifStaking::accrued_dao_stake_info(dao_id).pending_rewards;
+
+        let destination_balance = <Test as Config>::Currency::free_balance(&destination);
+
+        assert_ok!(OcifStaking::dao_claim_rewards(
+            RuntimeOrigin::signed(staker.clone()),
+            dao_id,
+        ));
+        System::assert_last_event(mock::RuntimeEvent::OcifStaking(Event::DaoClaimed {
+            dao: dao_id,
+            destination_account: destination.clone(),
+            amount: reward,
+        }));
+        assert_eq!(
+            <Test as Config>::Currency::free_balance(&destination),
+            destination_balance + reward
+        );
+
+        // Back to the dao account.
+        assert_ok!(OcifStaking::set_dao_reward_destination(
+            pallet_dao_manager::Origin::Multisig(
+                pallet_dao_manager::origin::MultisigInternalOrigin::new(dao_id)
+            )
+            .into(),
+            None
+        ));
+        advance_to_era(OcifStaking::current_era() + 1);
+        assert_claim_dao(dao_id);
+    })
+}
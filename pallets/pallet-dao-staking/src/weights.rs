@@ -43,6 +43,20 @@ pub trait WeightInfo {
 	fn dao_claim_rewards() -> Weight;
 	fn halt_unhalt_pallet() -> Weight;
 	fn move_stake() -> Weight;
+	fn claim_all() -> Weight;
+	fn claim_all_daos(d: u32, ) -> Weight;
+	fn set_reward_destination() -> Weight;
+	fn set_dao_reward_destination() -> Weight;
+	fn rebond() -> Weight;
+	fn instant_unstake() -> Weight;
+	fn transfer_stake() -> Weight;
+	fn create_pool() -> Weight;
+	fn join_pool() -> Weight;
+	fn leave_pool() -> Weight;
+	fn claim_pool_rewards() -> Weight;
+	fn enable_liquid_staking() -> Weight;
+	fn liquid_stake() -> Weight;
+	fn redeem() -> Weight;
 }
 
 /// Weights for `pallet_dao_staking` using the Substrate node and recommended hardware.
@@ -99,9 +113,11 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Storage: `OcifStaking::CurrentEra` (r:1 w:0)
 	/// Proof: `OcifStaking::CurrentEra` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	/// Storage: `OcifStaking::GeneralStakerInfo` (r:1 w:0)
-	/// Proof: `OcifStaking::GeneralStakerInfo` (`max_values`: None, `max_size`: Some(269), added: 2744, mode: `MaxEncodedLen`)
-	/// Storage: `OcifStaking::CoreEraStake` (r:1 w:1)
-	/// Proof: `OcifStaking::CoreEraStake` (`max_values`: None, `max_size`: Some(54), added: 2529, mode: `MaxEncodedLen`)
+	/// Proof: `OcifStaking::GeneralStakerInfo` (`max_values`: None, `max_size`: Some(118), added: 2593, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::CoreStake` (r:1 w:1)
+	/// Proof: `OcifStaking::CoreStake` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::RewardPerStakeIndex` (r:1 w:0)
+	/// Proof: `OcifStaking::RewardPerStakeIndex` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
 	/// Storage: `OcifStaking::GeneralEraInfo` (r:1 w:1)
 	/// Proof: `OcifStaking::GeneralEraInfo` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:1 w:1)
@@ -113,7 +129,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Storage: `MessageQueue::ServiceHead` (r:1 w:1)
 	/// Proof: `MessageQueue::ServiceHead` (`max_values`: Some(1), `max_size`: Some(6), added: 501, mode: `MaxEncodedLen`)
 	/// Storage: `OcifStaking::UnregisteredCoreStakeInfo` (r:0 w:1)
-	/// Proof: `OcifStaking::UnregisteredCoreStakeInfo` (`max_values`: None, `max_size`: Some(42), added: 2517, mode: `MaxEncodedLen`)
+	/// Proof: `OcifStaking::UnregisteredCoreStakeInfo` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
 	/// Storage: `OcifStaking::UnregisteredCoreStakers` (r:0 w:1)
 	/// Proof: `OcifStaking::UnregisteredCoreStakers` (`max_values`: None, `max_size`: Some(320022), added: 322497, mode: `MaxEncodedLen`)
 	/// Storage: `MessageQueue::Pages` (r:0 w:1)
@@ -124,7 +140,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		//  Estimated: `3942`
 		// Minimum execution time: 61_000_000 picoseconds.
 		Weight::from_parts(65_000_000, 3942)
-			.saturating_add(T::DbWeight::get().reads(11_u64))
+			.saturating_add(T::DbWeight::get().reads(12_u64))
 			.saturating_add(T::DbWeight::get().writes(10_u64))
 	}
 	/// Storage: `OcifStaking::Halted` (r:1 w:0)
@@ -135,10 +151,12 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `OcifStaking::Ledger` (`max_values`: None, `max_size`: Some(265), added: 2740, mode: `MaxEncodedLen`)
 	/// Storage: `OcifStaking::CurrentEra` (r:1 w:0)
 	/// Proof: `OcifStaking::CurrentEra` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
-	/// Storage: `OcifStaking::CoreEraStake` (r:1 w:1)
-	/// Proof: `OcifStaking::CoreEraStake` (`max_values`: None, `max_size`: Some(54), added: 2529, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::CoreStake` (r:1 w:1)
+	/// Proof: `OcifStaking::CoreStake` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::RewardPerStakeIndex` (r:1 w:0)
+	/// Proof: `OcifStaking::RewardPerStakeIndex` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
 	/// Storage: `OcifStaking::GeneralStakerInfo` (r:1 w:1)
-	/// Proof: `OcifStaking::GeneralStakerInfo` (`max_values`: None, `max_size`: Some(269), added: 2744, mode: `MaxEncodedLen`)
+	/// Proof: `OcifStaking::GeneralStakerInfo` (`max_values`: None, `max_size`: Some(118), added: 2593, mode: `MaxEncodedLen`)
 	/// Storage: `OcifStaking::GeneralEraInfo` (r:1 w:1)
 	/// Proof: `OcifStaking::GeneralEraInfo` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Freezes` (r:1 w:1)
@@ -151,7 +169,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		//  Estimated: `4764`
 		// Minimum execution time: 31_000_000 picoseconds.
 		Weight::from_parts(32_000_000, 4764)
-			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().reads(10_u64))
 			.saturating_add(T::DbWeight::get().writes(5_u64))
 	}
 	/// Storage: `OcifStaking::Halted` (r:1 w:0)
@@ -161,9 +179,11 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Storage: `OcifStaking::CurrentEra` (r:1 w:0)
 	/// Proof: `OcifStaking::CurrentEra` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	/// Storage: `OcifStaking::GeneralStakerInfo` (r:1 w:1)
-	/// Proof: `OcifStaking::GeneralStakerInfo` (`max_values`: None, `max_size`: Some(269), added: 2744, mode: `MaxEncodedLen`)
-	/// Storage: `OcifStaking::CoreEraStake` (r:1 w:1)
-	/// Proof: `OcifStaking::CoreEraStake` (`max_values`: None, `max_size`: Some(54), added: 2529, mode: `MaxEncodedLen`)
+	/// Proof: `OcifStaking::GeneralStakerInfo` (`max_values`: None, `max_size`: Some(118), added: 2593, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::CoreStake` (r:1 w:1)
+	/// Proof: `OcifStaking::CoreStake` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::RewardPerStakeIndex` (r:1 w:0)
+	/// Proof: `OcifStaking::RewardPerStakeIndex` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
 	/// Storage: `OcifStaking::Ledger` (r:1 w:1)
 	/// Proof: `OcifStaking::Ledger` (`max_values`: None, `max_size`: Some(265), added: 2740, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Freezes` (r:1 w:1)
@@ -178,7 +198,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		//  Estimated: `4764`
 		// Minimum execution time: 33_000_000 picoseconds.
 		Weight::from_parts(34_000_000, 4764)
-			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().reads(10_u64))
 			.saturating_add(T::DbWeight::get().writes(5_u64))
 	}
 	/// Storage: `OcifStaking::Halted` (r:1 w:0)
@@ -205,43 +225,57 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Storage: `OcifStaking::Halted` (r:1 w:0)
 	/// Proof: `OcifStaking::Halted` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
 	/// Storage: `OcifStaking::GeneralStakerInfo` (r:1 w:1)
-	/// Proof: `OcifStaking::GeneralStakerInfo` (`max_values`: None, `max_size`: Some(269), added: 2744, mode: `MaxEncodedLen`)
+	/// Proof: `OcifStaking::GeneralStakerInfo` (`max_values`: None, `max_size`: Some(118), added: 2593, mode: `MaxEncodedLen`)
 	/// Storage: `OcifStaking::CurrentEra` (r:1 w:0)
 	/// Proof: `OcifStaking::CurrentEra` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
-	/// Storage: `OcifStaking::CoreEraStake` (r:1 w:0)
-	/// Proof: `OcifStaking::CoreEraStake` (`max_values`: None, `max_size`: Some(54), added: 2529, mode: `MaxEncodedLen`)
-	/// Storage: `OcifStaking::GeneralEraInfo` (r:1 w:0)
+	/// Storage: `OcifStaking::CoreStake` (r:1 w:1)
+	/// Proof: `OcifStaking::CoreStake` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::RewardPerStakeIndex` (r:1 w:0)
+	/// Proof: `OcifStaking::RewardPerStakeIndex` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::GeneralEraInfo` (r:1 w:1)
 	/// Proof: `OcifStaking::GeneralEraInfo` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:1 w:1)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::RewardDestinations` (r:1 w:0)
+	/// Proof: `OcifStaking::RewardDestinations` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::RegisteredCore` (r:1 w:0)
+	/// Proof: `OcifStaking::RegisteredCore` (`max_values`: None, `max_size`: Some(477), added: 2952, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::Ledger` (r:1 w:1)
+	/// Proof: `OcifStaking::Ledger` (`max_values`: None, `max_size`: Some(265), added: 2740, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:1 w:1)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:1 w:0)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
 	fn staker_claim_rewards() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `213`
-		//  Estimated: `3734`
-		// Minimum execution time: 32_000_000 picoseconds.
-		Weight::from_parts(32_000_000, 3734)
-			.saturating_add(T::DbWeight::get().reads(6_u64))
-			.saturating_add(T::DbWeight::get().writes(2_u64))
+		//  Measured:  `1079`
+		//  Estimated: `4764`
+		// Minimum execution time: 61_000_000 picoseconds.
+		Weight::from_parts(63_000_000, 4764)
+			.saturating_add(T::DbWeight::get().reads(12_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
 	}
 	/// Storage: `OcifStaking::Halted` (r:1 w:0)
 	/// Proof: `OcifStaking::Halted` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainInfo::ParachainId` (r:1 w:0)
 	/// Proof: `ParachainInfo::ParachainId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
-	/// Storage: `OcifStaking::CurrentEra` (r:1 w:0)
-	/// Proof: `OcifStaking::CurrentEra` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
-	/// Storage: `OcifStaking::CoreEraStake` (r:1 w:1)
-	/// Proof: `OcifStaking::CoreEraStake` (`max_values`: None, `max_size`: Some(54), added: 2529, mode: `MaxEncodedLen`)
-	/// Storage: `OcifStaking::GeneralEraInfo` (r:1 w:0)
-	/// Proof: `OcifStaking::GeneralEraInfo` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::CoreStake` (r:1 w:1)
+	/// Proof: `OcifStaking::CoreStake` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::RewardPerStakeIndex` (r:1 w:0)
+	/// Proof: `OcifStaking::RewardPerStakeIndex` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::RegisteredCore` (r:1 w:0)
+	/// Proof: `OcifStaking::RegisteredCore` (`max_values`: None, `max_size`: Some(477), added: 2952, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:2 w:1)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::DaoRewardDestinations` (r:1 w:0)
+	/// Proof: `OcifStaking::DaoRewardDestinations` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
 	fn dao_claim_rewards() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `249`
 		//  Estimated: `6196`
 		// Minimum execution time: 31_000_000 picoseconds.
-		Weight::from_parts(32_000_000, 6196)
-			.saturating_add(T::DbWeight::get().reads(7_u64))
+		Weight::from_parts(34_000_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(8_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
 	/// Storage: `OcifStaking::Halted` (r:1 w:1)
@@ -262,17 +296,436 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Storage: `OcifStaking::CurrentEra` (r:1 w:0)
 	/// Proof: `OcifStaking::CurrentEra` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	/// Storage: `OcifStaking::GeneralStakerInfo` (r:2 w:2)
-	/// Proof: `OcifStaking::GeneralStakerInfo` (`max_values`: None, `max_size`: Some(269), added: 2744, mode: `MaxEncodedLen`)
-	/// Storage: `OcifStaking::CoreEraStake` (r:2 w:2)
-	/// Proof: `OcifStaking::CoreEraStake` (`max_values`: None, `max_size`: Some(54), added: 2529, mode: `MaxEncodedLen`)
+	/// Proof: `OcifStaking::GeneralStakerInfo` (`max_values`: None, `max_size`: Some(118), added: 2593, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::CoreStake` (r:2 w:2)
+	/// Proof: `OcifStaking::CoreStake` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::RewardPerStakeIndex` (r:1 w:0)
+	/// Proof: `OcifStaking::RewardPerStakeIndex` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::GeneralEraInfo` (r:1 w:1)
+	/// Proof: `OcifStaking::GeneralEraInfo` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
 	fn move_stake() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `217`
 		//  Estimated: `6478`
 		// Minimum execution time: 23_000_000 picoseconds.
 		Weight::from_parts(24_000_000, 6478)
-			.saturating_add(T::DbWeight::get().reads(7_u64))
-			.saturating_add(T::DbWeight::get().writes(4_u64))
+			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: `OcifStaking::Halted` (r:1 w:0)
+	/// Proof: `OcifStaking::Halted` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::GeneralStakerInfo` (r:1 w:1)
+	/// Proof: `OcifStaking::GeneralStakerInfo` (`max_values`: None, `max_size`: Some(118), added: 2593, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::CurrentEra` (r:1 w:0)
+	/// Proof: `OcifStaking::CurrentEra` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::CoreStake` (r:1 w:1)
+	/// Proof: `OcifStaking::CoreStake` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::RewardPerStakeIndex` (r:1 w:0)
+	/// Proof: `OcifStaking::RewardPerStakeIndex` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::GeneralEraInfo` (r:1 w:1)
+	/// Proof: `OcifStaking::GeneralEraInfo` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::RewardDestinations` (r:1 w:0)
+	/// Proof: `OcifStaking::RewardDestinations` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::RegisteredCore` (r:1 w:0)
+	/// Proof: `OcifStaking::RegisteredCore` (`max_values`: None, `max_size`: Some(477), added: 2952, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::Ledger` (r:1 w:1)
+	/// Proof: `OcifStaking::Ledger` (`max_values`: None, `max_size`: Some(265), added: 2740, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:1 w:1)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:1 w:0)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	fn claim_all() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1079`
+		//  Estimated: `4764`
+		// Minimum execution time: 62_000_000 picoseconds.
+		Weight::from_parts(64_000_000, 4764)
+			.saturating_add(T::DbWeight::get().reads(12_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
+	}
+	/// Storage: `OcifStaking::Halted` (r:1 w:0)
+	/// Proof: `OcifStaking::Halted` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::CoreStake` (r:16 w:16)
+	/// Proof: `OcifStaking::CoreStake` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::RewardPerStakeIndex` (r:1 w:0)
+	/// Proof: `OcifStaking::RewardPerStakeIndex` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::RegisteredCore` (r:16 w:0)
+	/// Proof: `OcifStaking::RegisteredCore` (`max_values`: None, `max_size`: Some(477), added: 2952, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::GeneralStakerInfo` (r:16 w:16)
+	/// Proof: `OcifStaking::GeneralStakerInfo` (`max_values`: None, `max_size`: Some(118), added: 2593, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::RewardDestinations` (r:1 w:0)
+	/// Proof: `OcifStaking::RewardDestinations` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::CurrentEra` (r:1 w:0)
+	/// Proof: `OcifStaking::CurrentEra` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::Ledger` (r:1 w:1)
+	/// Proof: `OcifStaking::Ledger` (`max_values`: None, `max_size`: Some(265), added: 2740, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:1 w:1)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:1 w:0)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::GeneralEraInfo` (r:1 w:1)
+	/// Proof: `OcifStaking::GeneralEraInfo` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// The range of component `d` is `[1, 16]`.
+	fn claim_all_daos(d: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1079 + d * (204 ±0)`
+		//  Estimated: `4764 + d * (2952 ±0)`
+		// Minimum execution time: 64_000_000 picoseconds.
+		Weight::from_parts(31_204_117, 4764)
+			// Standard Error: 21_463
+			.saturating_add(Weight::from_parts(33_870_402, 0).saturating_mul(d.into()))
+			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(d.into())))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(d.into())))
+			.saturating_add(Weight::from_parts(0, 2952).saturating_mul(d.into()))
+	}
+	/// Storage: `OcifStaking::RewardDestinations` (r:0 w:1)
+	/// Proof: `OcifStaking::RewardDestinations` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
+	fn set_reward_destination() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 9_000_000 picoseconds.
+		Weight::from_parts(10_000_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `ParachainInfo::ParachainId` (r:1 w:0)
+	/// Proof: `ParachainInfo::ParachainId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::RegisteredCore` (r:1 w:0)
+	/// Proof: `OcifStaking::RegisteredCore` (`max_values`: None, `max_size`: Some(477), added: 2952, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::DaoRewardDestinations` (r:0 w:1)
+	/// Proof: `OcifStaking::DaoRewardDestinations` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	fn set_dao_reward_destination() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `247`
+		//  Estimated: `3942`
+		// Minimum execution time: 14_000_000 picoseconds.
+		Weight::from_parts(15_000_000, 3942)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `OcifStaking::Halted` (r:1 w:0)
+	/// Proof: `OcifStaking::Halted` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::RegisteredCore` (r:1 w:0)
+	/// Proof: `OcifStaking::RegisteredCore` (`max_values`: None, `max_size`: Some(477), added: 2952, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::Ledger` (r:1 w:1)
+	/// Proof: `OcifStaking::Ledger` (`max_values`: None, `max_size`: Some(265), added: 2740, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::CurrentEra` (r:1 w:0)
+	/// Proof: `OcifStaking::CurrentEra` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::CoreStake` (r:1 w:1)
+	/// Proof: `OcifStaking::CoreStake` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::RewardPerStakeIndex` (r:1 w:0)
+	/// Proof: `OcifStaking::RewardPerStakeIndex` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::GeneralStakerInfo` (r:1 w:1)
+	/// Proof: `OcifStaking::GeneralStakerInfo` (`max_values`: None, `max_size`: Some(118), added: 2593, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::GeneralEraInfo` (r:1 w:1)
+	/// Proof: `OcifStaking::GeneralEraInfo` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:1 w:1)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(1249), added: 3724, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:1 w:0)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	fn rebond() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `218`
+		//  Estimated: `4764`
+		// Minimum execution time: 33_000_000 picoseconds.
+		Weight::from_parts(34_000_000, 4764)
+			.saturating_add(T::DbWeight::get().reads(10_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: `OcifStaking::Halted` (r:1 w:0)
+	/// Proof: `OcifStaking::Halted` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::CurrentEra` (r:1 w:0)
+	/// Proof: `OcifStaking::CurrentEra` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::Ledger` (r:1 w:1)
+	/// Proof: `OcifStaking::Ledger` (`max_values`: None, `max_size`: Some(265), added: 2740, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::RegisteredCore` (r:1 w:0)
+	/// Proof: `OcifStaking::RegisteredCore` (`max_values`: None, `max_size`: Some(477), added: 2952, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::CoreStake` (r:1 w:1)
+	/// Proof: `OcifStaking::CoreStake` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::RewardPerStakeIndex` (r:1 w:0)
+	/// Proof: `OcifStaking::RewardPerStakeIndex` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::GeneralStakerInfo` (r:1 w:1)
+	/// Proof: `OcifStaking::GeneralStakerInfo` (`max_values`: None, `max_size`: Some(118), added: 2593, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::GeneralEraInfo` (r:1 w:1)
+	/// Proof: `OcifStaking::GeneralEraInfo` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:1 w:1)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(1249), added: 3724, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:1 w:0)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn instant_unstake() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `340`
+		//  Estimated: `4764`
+		// Minimum execution time: 45_000_000 picoseconds.
+		Weight::from_parts(47_000_000, 4764)
+			.saturating_add(T::DbWeight::get().reads(11_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	/// Storage: `OcifStaking::Halted` (r:1 w:0)
+	/// Proof: `OcifStaking::Halted` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::RegisteredCore` (r:1 w:0)
+	/// Proof: `OcifStaking::RegisteredCore` (`max_values`: None, `max_size`: Some(477), added: 2952, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::CoreStake` (r:1 w:1)
+	/// Proof: `OcifStaking::CoreStake` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::RewardPerStakeIndex` (r:1 w:0)
+	/// Proof: `OcifStaking::RewardPerStakeIndex` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::GeneralStakerInfo` (r:2 w:2)
+	/// Proof: `OcifStaking::GeneralStakerInfo` (`max_values`: None, `max_size`: Some(118), added: 2593, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::CurrentEra` (r:1 w:0)
+	/// Proof: `OcifStaking::CurrentEra` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::GeneralEraInfo` (r:1 w:1)
+	/// Proof: `OcifStaking::GeneralEraInfo` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::Ledger` (r:2 w:2)
+	/// Proof: `OcifStaking::Ledger` (`max_values`: None, `max_size`: Some(265), added: 2740, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:2 w:2)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(1249), added: 3724, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:2 w:0)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn transfer_stake() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `412`
+		//  Estimated: `8538`
+		// Minimum execution time: 71_000_000 picoseconds.
+		Weight::from_parts(73_000_000, 8538)
+			.saturating_add(T::DbWeight::get().reads(16_u64))
+			.saturating_add(T::DbWeight::get().writes(10_u64))
+	}
+	/// Storage: `OcifStaking::Halted` (r:1 w:0)
+	/// Proof: `OcifStaking::Halted` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::NextPoolId` (r:1 w:1)
+	/// Proof: `OcifStaking::NextPoolId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::Pools` (r:1 w:1)
+	/// Proof: `OcifStaking::Pools` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::CoreStake` (r:1 w:1)
+	/// Proof: `OcifStaking::CoreStake` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::RegisteredCore` (r:1 w:0)
+	/// Proof: `OcifStaking::RegisteredCore` (`max_values`: None, `max_size`: Some(477), added: 2952, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::RewardPerStakeIndex` (r:1 w:0)
+	/// Proof: `OcifStaking::RewardPerStakeIndex` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::GeneralStakerInfo` (r:1 w:1)
+	/// Proof: `OcifStaking::GeneralStakerInfo` (`max_values`: None, `max_size`: Some(118), added: 2593, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::PoolMembers` (r:1 w:1)
+	/// Proof: `OcifStaking::PoolMembers` (`max_values`: None, `max_size`: Some(102), added: 2577, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::Ledger` (r:2 w:2)
+	/// Proof: `OcifStaking::Ledger` (`max_values`: None, `max_size`: Some(265), added: 2740, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:2 w:2)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(1249), added: 3724, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:2 w:0)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::CurrentEra` (r:1 w:0)
+	/// Proof: `OcifStaking::CurrentEra` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::GeneralEraInfo` (r:1 w:1)
+	/// Proof: `OcifStaking::GeneralEraInfo` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
+	fn create_pool() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `276`
+		//  Estimated: `8538`
+		// Minimum execution time: 78_000_000 picoseconds.
+		Weight::from_parts(80_000_000, 8538)
+			.saturating_add(T::DbWeight::get().reads(19_u64))
+			.saturating_add(T::DbWeight::get().writes(13_u64))
+	}
+	/// Storage: `OcifStaking::Halted` (r:1 w:0)
+	/// Proof: `OcifStaking::Halted` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::Pools` (r:1 w:1)
+	/// Proof: `OcifStaking::Pools` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::CoreStake` (r:1 w:1)
+	/// Proof: `OcifStaking::CoreStake` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::RegisteredCore` (r:1 w:0)
+	/// Proof: `OcifStaking::RegisteredCore` (`max_values`: None, `max_size`: Some(477), added: 2952, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::RewardPerStakeIndex` (r:1 w:0)
+	/// Proof: `OcifStaking::RewardPerStakeIndex` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::GeneralStakerInfo` (r:1 w:1)
+	/// Proof: `OcifStaking::GeneralStakerInfo` (`max_values`: None, `max_size`: Some(118), added: 2593, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::PoolMembers` (r:1 w:1)
+	/// Proof: `OcifStaking::PoolMembers` (`max_values`: None, `max_size`: Some(102), added: 2577, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::Ledger` (r:2 w:2)
+	/// Proof: `OcifStaking::Ledger` (`max_values`: None, `max_size`: Some(265), added: 2740, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:2 w:2)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(1249), added: 3724, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:2 w:0)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::CurrentEra` (r:1 w:0)
+	/// Proof: `OcifStaking::CurrentEra` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::GeneralEraInfo` (r:1 w:1)
+	/// Proof: `OcifStaking::GeneralEraInfo` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	fn join_pool() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `398`
+		//  Estimated: `8538`
+		// Minimum execution time: 74_000_000 picoseconds.
+		Weight::from_parts(76_000_000, 8538)
+			.saturating_add(T::DbWeight::get().reads(17_u64))
+			.saturating_add(T::DbWeight::get().writes(11_u64))
+	}
+	/// Storage: `OcifStaking::Halted` (r:1 w:0)
+	/// Proof: `OcifStaking::Halted` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::Pools` (r:1 w:1)
+	/// Proof: `OcifStaking::Pools` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::CoreStake` (r:1 w:1)
+	/// Proof: `OcifStaking::CoreStake` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::RegisteredCore` (r:1 w:0)
+	/// Proof: `OcifStaking::RegisteredCore` (`max_values`: None, `max_size`: Some(477), added: 2952, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::RewardPerStakeIndex` (r:1 w:0)
+	/// Proof: `OcifStaking::RewardPerStakeIndex` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::GeneralStakerInfo` (r:1 w:1)
+	/// Proof: `OcifStaking::GeneralStakerInfo` (`max_values`: None, `max_size`: Some(118), added: 2593, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::PoolMembers` (r:2 w:1)
+	/// Proof: `OcifStaking::PoolMembers` (`max_values`: None, `max_size`: Some(102), added: 2577, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::Ledger` (r:2 w:2)
+	/// Proof: `OcifStaking::Ledger` (`max_values`: None, `max_size`: Some(265), added: 2740, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:2 w:2)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(1249), added: 3724, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:2 w:0)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::CurrentEra` (r:1 w:0)
+	/// Proof: `OcifStaking::CurrentEra` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::GeneralEraInfo` (r:1 w:1)
+	/// Proof: `OcifStaking::GeneralEraInfo` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
+	fn leave_pool() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `521`
+		//  Estimated: `8538`
+		// Minimum execution time: 76_000_000 picoseconds.
+		Weight::from_parts(78_000_000, 8538)
+			.saturating_add(T::DbWeight::get().reads(19_u64))
+			.saturating_add(T::DbWeight::get().writes(12_u64))
+	}
+	/// Storage: `OcifStaking::Halted` (r:1 w:0)
+	/// Proof: `OcifStaking::Halted` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::Pools` (r:1 w:1)
+	/// Proof: `OcifStaking::Pools` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::CoreStake` (r:1 w:1)
+	/// Proof: `OcifStaking::CoreStake` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::RegisteredCore` (r:1 w:0)
+	/// Proof: `OcifStaking::RegisteredCore` (`max_values`: None, `max_size`: Some(477), added: 2952, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::RewardPerStakeIndex` (r:1 w:0)
+	/// Proof: `OcifStaking::RewardPerStakeIndex` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::GeneralStakerInfo` (r:1 w:1)
+	/// Proof: `OcifStaking::GeneralStakerInfo` (`max_values`: None, `max_size`: Some(118), added: 2593, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::PoolMembers` (r:2 w:1)
+	/// Proof: `OcifStaking::PoolMembers` (`max_values`: None, `max_size`: Some(102), added: 2577, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
+	fn claim_pool_rewards() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `482`
+		//  Estimated: `6196`
+		// Minimum execution time: 52_000_000 picoseconds.
+		Weight::from_parts(54_000_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(11_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
+	}
+	/// Storage: `OcifStaking::RegisteredCore` (r:1 w:0)
+	/// Proof: `OcifStaking::RegisteredCore` (`max_values`: None, `max_size`: Some(477), added: 2952, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::ReceiptTokens` (r:1 w:1)
+	/// Proof: `OcifStaking::ReceiptTokens` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `CoreAssets::TotalIssuance` (r:1 w:0)
+	/// Proof: `CoreAssets::TotalIssuance` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	fn enable_liquid_staking() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `183`
+		//  Estimated: `3942`
+		// Minimum execution time: 12_000_000 picoseconds.
+		Weight::from_parts(13_000_000, 3942)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `OcifStaking::Halted` (r:1 w:0)
+	/// Proof: `OcifStaking::Halted` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::RegisteredCore` (r:1 w:0)
+	/// Proof: `OcifStaking::RegisteredCore` (`max_values`: None, `max_size`: Some(477), added: 2952, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::ReceiptTokens` (r:1 w:0)
+	/// Proof: `OcifStaking::ReceiptTokens` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::CoreStake` (r:1 w:1)
+	/// Proof: `OcifStaking::CoreStake` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::RewardPerStakeIndex` (r:1 w:0)
+	/// Proof: `OcifStaking::RewardPerStakeIndex` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::GeneralStakerInfo` (r:1 w:1)
+	/// Proof: `OcifStaking::GeneralStakerInfo` (`max_values`: None, `max_size`: Some(118), added: 2593, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::Ledger` (r:2 w:2)
+	/// Proof: `OcifStaking::Ledger` (`max_values`: None, `max_size`: Some(265), added: 2740, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:2 w:2)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(1249), added: 3724, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:2 w:0)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:3 w:3)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::CurrentEra` (r:1 w:0)
+	/// Proof: `OcifStaking::CurrentEra` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::GeneralEraInfo` (r:1 w:1)
+	/// Proof: `OcifStaking::GeneralEraInfo` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// Storage: `CoreAssets::Accounts` (r:1 w:1)
+	/// Proof: `CoreAssets::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	/// Storage: `CoreAssets::TotalIssuance` (r:1 w:1)
+	/// Proof: `CoreAssets::TotalIssuance` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	fn liquid_stake() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `455`
+		//  Estimated: `8538`
+		// Minimum execution time: 86_000_000 picoseconds.
+		Weight::from_parts(88_000_000, 8538)
+			.saturating_add(T::DbWeight::get().reads(19_u64))
+			.saturating_add(T::DbWeight::get().writes(12_u64))
+	}
+	/// Storage: `OcifStaking::Halted` (r:1 w:0)
+	/// Proof: `OcifStaking::Halted` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::ReceiptTokens` (r:1 w:0)
+	/// Proof: `OcifStaking::ReceiptTokens` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::RegisteredCore` (r:1 w:0)
+	/// Proof: `OcifStaking::RegisteredCore` (`max_values`: None, `max_size`: Some(477), added: 2952, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::CoreStake` (r:1 w:1)
+	/// Proof: `OcifStaking::CoreStake` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::RewardPerStakeIndex` (r:1 w:0)
+	/// Proof: `OcifStaking::RewardPerStakeIndex` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::GeneralStakerInfo` (r:1 w:1)
+	/// Proof: `OcifStaking::GeneralStakerInfo` (`max_values`: None, `max_size`: Some(118), added: 2593, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::Ledger` (r:2 w:2)
+	/// Proof: `OcifStaking::Ledger` (`max_values`: None, `max_size`: Some(265), added: 2740, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:2 w:2)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(1249), added: 3724, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:2 w:0)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:3 w:3)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::CurrentEra` (r:1 w:0)
+	/// Proof: `OcifStaking::CurrentEra` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::GeneralEraInfo` (r:1 w:1)
+	/// Proof: `OcifStaking::GeneralEraInfo` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// Storage: `CoreAssets::Accounts` (r:1 w:1)
+	/// Proof: `CoreAssets::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	/// Storage: `CoreAssets::TotalIssuance` (r:1 w:1)
+	/// Proof: `CoreAssets::TotalIssuance` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	fn redeem() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `612`
+		//  Estimated: `8538`
+		// Minimum execution time: 90_000_000 picoseconds.
+		Weight::from_parts(92_000_000, 8538)
+			.saturating_add(T::DbWeight::get().reads(19_u64))
+			.saturating_add(T::DbWeight::get().writes(12_u64))
 	}
 }
 
@@ -329,9 +782,11 @@ impl WeightInfo for () {
 	/// Storage: `OcifStaking::CurrentEra` (r:1 w:0)
 	/// Proof: `OcifStaking::CurrentEra` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	/// Storage: `OcifStaking::GeneralStakerInfo` (r:1 w:0)
-	/// Proof: `OcifStaking::GeneralStakerInfo` (`max_values`: None, `max_size`: Some(269), added: 2744, mode: `MaxEncodedLen`)
-	/// Storage: `OcifStaking::CoreEraStake` (r:1 w:1)
-	/// Proof: `OcifStaking::CoreEraStake` (`max_values`: None, `max_size`: Some(54), added: 2529, mode: `MaxEncodedLen`)
+	/// Proof: `OcifStaking::GeneralStakerInfo` (`max_values`: None, `max_size`: Some(118), added: 2593, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::CoreStake` (r:1 w:1)
+	/// Proof: `OcifStaking::CoreStake` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::RewardPerStakeIndex` (r:1 w:0)
+	/// Proof: `OcifStaking::RewardPerStakeIndex` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
 	/// Storage: `OcifStaking::GeneralEraInfo` (r:1 w:1)
 	/// Proof: `OcifStaking::GeneralEraInfo` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:1 w:1)
@@ -343,7 +798,7 @@ impl WeightInfo for () {
 	/// Storage: `MessageQueue::ServiceHead` (r:1 w:1)
 	/// Proof: `MessageQueue::ServiceHead` (`max_values`: Some(1), `max_size`: Some(6), added: 501, mode: `MaxEncodedLen`)
 	/// Storage: `OcifStaking::UnregisteredCoreStakeInfo` (r:0 w:1)
-	/// Proof: `OcifStaking::UnregisteredCoreStakeInfo` (`max_values`: None, `max_size`: Some(42), added: 2517, mode: `MaxEncodedLen`)
+	/// Proof: `OcifStaking::UnregisteredCoreStakeInfo` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
 	/// Storage: `OcifStaking::UnregisteredCoreStakers` (r:0 w:1)
 	/// Proof: `OcifStaking::UnregisteredCoreStakers` (`max_values`: None, `max_size`: Some(320022), added: 322497, mode: `MaxEncodedLen`)
 	/// Storage: `MessageQueue::Pages` (r:0 w:1)
@@ -354,7 +809,7 @@ impl WeightInfo for () {
 		//  Estimated: `3942`
 		// Minimum execution time: 61_000_000 picoseconds.
 		Weight::from_parts(65_000_000, 3942)
-			.saturating_add(RocksDbWeight::get().reads(11_u64))
+			.saturating_add(RocksDbWeight::get().reads(12_u64))
 			.saturating_add(RocksDbWeight::get().writes(10_u64))
 	}
 	/// Storage: `OcifStaking::Halted` (r:1 w:0)
@@ -365,10 +820,12 @@ impl WeightInfo for () {
 	/// Proof: `OcifStaking::Ledger` (`max_values`: None, `max_size`: Some(265), added: 2740, mode: `MaxEncodedLen`)
 	/// Storage: `OcifStaking::CurrentEra` (r:1 w:0)
 	/// Proof: `OcifStaking::CurrentEra` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
-	/// Storage: `OcifStaking::CoreEraStake` (r:1 w:1)
-	/// Proof: `OcifStaking::CoreEraStake` (`max_values`: None, `max_size`: Some(54), added: 2529, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::CoreStake` (r:1 w:1)
+	/// Proof: `OcifStaking::CoreStake` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::RewardPerStakeIndex` (r:1 w:0)
+	/// Proof: `OcifStaking::RewardPerStakeIndex` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
 	/// Storage: `OcifStaking::GeneralStakerInfo` (r:1 w:1)
-	/// Proof: `OcifStaking::GeneralStakerInfo` (`max_values`: None, `max_size`: Some(269), added: 2744, mode: `MaxEncodedLen`)
+	/// Proof: `OcifStaking::GeneralStakerInfo` (`max_values`: None, `max_size`: Some(118), added: 2593, mode: `MaxEncodedLen`)
 	/// Storage: `OcifStaking::GeneralEraInfo` (r:1 w:1)
 	/// Proof: `OcifStaking::GeneralEraInfo` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Freezes` (r:1 w:1)
@@ -381,7 +838,7 @@ impl WeightInfo for () {
 		//  Estimated: `4764`
 		// Minimum execution time: 31_000_000 picoseconds.
 		Weight::from_parts(32_000_000, 4764)
-			.saturating_add(RocksDbWeight::get().reads(9_u64))
+			.saturating_add(RocksDbWeight::get().reads(10_u64))
 			.saturating_add(RocksDbWeight::get().writes(5_u64))
 	}
 	/// Storage: `OcifStaking::Halted` (r:1 w:0)
@@ -391,9 +848,11 @@ impl WeightInfo for () {
 	/// Storage: `OcifStaking::CurrentEra` (r:1 w:0)
 	/// Proof: `OcifStaking::CurrentEra` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	/// Storage: `OcifStaking::GeneralStakerInfo` (r:1 w:1)
-	/// Proof: `OcifStaking::GeneralStakerInfo` (`max_values`: None, `max_size`: Some(269), added: 2744, mode: `MaxEncodedLen`)
-	/// Storage: `OcifStaking::CoreEraStake` (r:1 w:1)
-	/// Proof: `OcifStaking::CoreEraStake` (`max_values`: None, `max_size`: Some(54), added: 2529, mode: `MaxEncodedLen`)
+	/// Proof: `OcifStaking::GeneralStakerInfo` (`max_values`: None, `max_size`: Some(118), added: 2593, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::CoreStake` (r:1 w:1)
+	/// Proof: `OcifStaking::CoreStake` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::RewardPerStakeIndex` (r:1 w:0)
+	/// Proof: `OcifStaking::RewardPerStakeIndex` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
 	/// Storage: `OcifStaking::Ledger` (r:1 w:1)
 	/// Proof: `OcifStaking::Ledger` (`max_values`: None, `max_size`: Some(265), added: 2740, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Freezes` (r:1 w:1)
@@ -408,7 +867,7 @@ impl WeightInfo for () {
 		//  Estimated: `4764`
 		// Minimum execution time: 33_000_000 picoseconds.
 		Weight::from_parts(34_000_000, 4764)
-			.saturating_add(RocksDbWeight::get().reads(9_u64))
+			.saturating_add(RocksDbWeight::get().reads(10_u64))
 			.saturating_add(RocksDbWeight::get().writes(5_u64))
 	}
 	/// Storage: `OcifStaking::Halted` (r:1 w:0)
@@ -435,43 +894,57 @@ impl WeightInfo for () {
 	/// Storage: `OcifStaking::Halted` (r:1 w:0)
 	/// Proof: `OcifStaking::Halted` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
 	/// Storage: `OcifStaking::GeneralStakerInfo` (r:1 w:1)
-	/// Proof: `OcifStaking::GeneralStakerInfo` (`max_values`: None, `max_size`: Some(269), added: 2744, mode: `MaxEncodedLen`)
+	/// Proof: `OcifStaking::GeneralStakerInfo` (`max_values`: None, `max_size`: Some(118), added: 2593, mode: `MaxEncodedLen`)
 	/// Storage: `OcifStaking::CurrentEra` (r:1 w:0)
 	/// Proof: `OcifStaking::CurrentEra` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
-	/// Storage: `OcifStaking::CoreEraStake` (r:1 w:0)
-	/// Proof: `OcifStaking::CoreEraStake` (`max_values`: None, `max_size`: Some(54), added: 2529, mode: `MaxEncodedLen`)
-	/// Storage: `OcifStaking::GeneralEraInfo` (r:1 w:0)
+	/// Storage: `OcifStaking::CoreStake` (r:1 w:1)
+	/// Proof: `OcifStaking::CoreStake` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::RewardPerStakeIndex` (r:1 w:0)
+	/// Proof: `OcifStaking::RewardPerStakeIndex` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::GeneralEraInfo` (r:1 w:1)
 	/// Proof: `OcifStaking::GeneralEraInfo` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:1 w:1)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::RewardDestinations` (r:1 w:0)
+	/// Proof: `OcifStaking::RewardDestinations` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::RegisteredCore` (r:1 w:0)
+	/// Proof: `OcifStaking::RegisteredCore` (`max_values`: None, `max_size`: Some(477), added: 2952, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::Ledger` (r:1 w:1)
+	/// Proof: `OcifStaking::Ledger` (`max_values`: None, `max_size`: Some(265), added: 2740, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:1 w:1)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:1 w:0)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
 	fn staker_claim_rewards() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `213`
-		//  Estimated: `3734`
-		// Minimum execution time: 32_000_000 picoseconds.
-		Weight::from_parts(32_000_000, 3734)
-			.saturating_add(RocksDbWeight::get().reads(6_u64))
-			.saturating_add(RocksDbWeight::get().writes(2_u64))
+		//  Measured:  `1079`
+		//  Estimated: `4764`
+		// Minimum execution time: 61_000_000 picoseconds.
+		Weight::from_parts(63_000_000, 4764)
+			.saturating_add(RocksDbWeight::get().reads(12_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
 	}
 	/// Storage: `OcifStaking::Halted` (r:1 w:0)
 	/// Proof: `OcifStaking::Halted` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainInfo::ParachainId` (r:1 w:0)
 	/// Proof: `ParachainInfo::ParachainId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
-	/// Storage: `OcifStaking::CurrentEra` (r:1 w:0)
-	/// Proof: `OcifStaking::CurrentEra` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
-	/// Storage: `OcifStaking::CoreEraStake` (r:1 w:1)
-	/// Proof: `OcifStaking::CoreEraStake` (`max_values`: None, `max_size`: Some(54), added: 2529, mode: `MaxEncodedLen`)
-	/// Storage: `OcifStaking::GeneralEraInfo` (r:1 w:0)
-	/// Proof: `OcifStaking::GeneralEraInfo` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::CoreStake` (r:1 w:1)
+	/// Proof: `OcifStaking::CoreStake` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::RewardPerStakeIndex` (r:1 w:0)
+	/// Proof: `OcifStaking::RewardPerStakeIndex` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::RegisteredCore` (r:1 w:0)
+	/// Proof: `OcifStaking::RegisteredCore` (`max_values`: None, `max_size`: Some(477), added: 2952, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:2 w:1)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::DaoRewardDestinations` (r:1 w:0)
+	/// Proof: `OcifStaking::DaoRewardDestinations` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
 	fn dao_claim_rewards() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `249`
 		//  Estimated: `6196`
 		// Minimum execution time: 31_000_000 picoseconds.
-		Weight::from_parts(32_000_000, 6196)
-			.saturating_add(RocksDbWeight::get().reads(7_u64))
+		Weight::from_parts(34_000_000, 6196)
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
 	/// Storage: `OcifStaking::Halted` (r:1 w:1)
@@ -492,16 +965,435 @@ impl WeightInfo for () {
 	/// Storage: `OcifStaking::CurrentEra` (r:1 w:0)
 	/// Proof: `OcifStaking::CurrentEra` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	/// Storage: `OcifStaking::GeneralStakerInfo` (r:2 w:2)
-	/// Proof: `OcifStaking::GeneralStakerInfo` (`max_values`: None, `max_size`: Some(269), added: 2744, mode: `MaxEncodedLen`)
-	/// Storage: `OcifStaking::CoreEraStake` (r:2 w:2)
-	/// Proof: `OcifStaking::CoreEraStake` (`max_values`: None, `max_size`: Some(54), added: 2529, mode: `MaxEncodedLen`)
+	/// Proof: `OcifStaking::GeneralStakerInfo` (`max_values`: None, `max_size`: Some(118), added: 2593, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::CoreStake` (r:2 w:2)
+	/// Proof: `OcifStaking::CoreStake` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::RewardPerStakeIndex` (r:1 w:0)
+	/// Proof: `OcifStaking::RewardPerStakeIndex` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::GeneralEraInfo` (r:1 w:1)
+	/// Proof: `OcifStaking::GeneralEraInfo` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
 	fn move_stake() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `217`
 		//  Estimated: `6478`
 		// Minimum execution time: 23_000_000 picoseconds.
 		Weight::from_parts(24_000_000, 6478)
-			.saturating_add(RocksDbWeight::get().reads(7_u64))
-			.saturating_add(RocksDbWeight::get().writes(4_u64))
+			.saturating_add(RocksDbWeight::get().reads(9_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: `OcifStaking::Halted` (r:1 w:0)
+	/// Proof: `OcifStaking::Halted` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::GeneralStakerInfo` (r:1 w:1)
+	/// Proof: `OcifStaking::GeneralStakerInfo` (`max_values`: None, `max_size`: Some(118), added: 2593, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::CurrentEra` (r:1 w:0)
+	/// Proof: `OcifStaking::CurrentEra` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::CoreStake` (r:1 w:1)
+	/// Proof: `OcifStaking::CoreStake` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::RewardPerStakeIndex` (r:1 w:0)
+	/// Proof: `OcifStaking::RewardPerStakeIndex` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::GeneralEraInfo` (r:1 w:1)
+	/// Proof: `OcifStaking::GeneralEraInfo` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::RewardDestinations` (r:1 w:0)
+	/// Proof: `OcifStaking::RewardDestinations` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::RegisteredCore` (r:1 w:0)
+	/// Proof: `OcifStaking::RegisteredCore` (`max_values`: None, `max_size`: Some(477), added: 2952, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::Ledger` (r:1 w:1)
+	/// Proof: `OcifStaking::Ledger` (`max_values`: None, `max_size`: Some(265), added: 2740, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:1 w:1)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:1 w:0)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	fn claim_all() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1079`
+		//  Estimated: `4764`
+		// Minimum execution time: 62_000_000 picoseconds.
+		Weight::from_parts(64_000_000, 4764)
+			.saturating_add(RocksDbWeight::get().reads(12_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
+	}
+	/// Storage: `OcifStaking::Halted` (r:1 w:0)
+	/// Proof: `OcifStaking::Halted` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::CoreStake` (r:16 w:16)
+	/// Proof: `OcifStaking::CoreStake` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::RewardPerStakeIndex` (r:1 w:0)
+	/// Proof: `OcifStaking::RewardPerStakeIndex` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::RegisteredCore` (r:16 w:0)
+	/// Proof: `OcifStaking::RegisteredCore` (`max_values`: None, `max_size`: Some(477), added: 2952, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::GeneralStakerInfo` (r:16 w:16)
+	/// Proof: `OcifStaking::GeneralStakerInfo` (`max_values`: None, `max_size`: Some(118), added: 2593, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::RewardDestinations` (r:1 w:0)
+	/// Proof: `OcifStaking::RewardDestinations` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::CurrentEra` (r:1 w:0)
+	/// Proof: `OcifStaking::CurrentEra` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::Ledger` (r:1 w:1)
+	/// Proof: `OcifStaking::Ledger` (`max_values`: None, `max_size`: Some(265), added: 2740, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:1 w:1)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:1 w:0)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::GeneralEraInfo` (r:1 w:1)
+	/// Proof: `OcifStaking::GeneralEraInfo` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// The range of component `d` is `[1, 16]`.
+	fn claim_all_daos(d: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1079 + d * (204 ±0)`
+		//  Estimated: `4764 + d * (2952 ±0)`
+		// Minimum execution time: 64_000_000 picoseconds.
+		Weight::from_parts(31_204_117, 4764)
+			// Standard Error: 21_463
+			.saturating_add(Weight::from_parts(33_870_402, 0).saturating_mul(d.into()))
+			.saturating_add(RocksDbWeight::get().reads(9_u64))
+			.saturating_add(RocksDbWeight::get().reads((3_u64).saturating_mul(d.into())))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(d.into())))
+			.saturating_add(Weight::from_parts(0, 2952).saturating_mul(d.into()))
+	}
+	/// Storage: `OcifStaking::RewardDestinations` (r:0 w:1)
+	/// Proof: `OcifStaking::RewardDestinations` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
+	fn set_reward_destination() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 9_000_000 picoseconds.
+		Weight::from_parts(10_000_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `ParachainInfo::ParachainId` (r:1 w:0)
+	/// Proof: `ParachainInfo::ParachainId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::RegisteredCore` (r:1 w:0)
+	/// Proof: `OcifStaking::RegisteredCore` (`max_values`: None, `max_size`: Some(477), added: 2952, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::DaoRewardDestinations` (r:0 w:1)
+	/// Proof: `OcifStaking::DaoRewardDestinations` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	fn set_dao_reward_destination() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `247`
+		//  Estimated: `3942`
+		// Minimum execution time: 14_000_000 picoseconds.
+		Weight::from_parts(15_000_000, 3942)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `OcifStaking::Halted` (r:1 w:0)
+	/// Proof: `OcifStaking::Halted` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::RegisteredCore` (r:1 w:0)
+	/// Proof: `OcifStaking::RegisteredCore` (`max_values`: None, `max_size`: Some(477), added: 2952, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::Ledger` (r:1 w:1)
+	/// Proof: `OcifStaking::Ledger` (`max_values`: None, `max_size`: Some(265), added: 2740, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::CurrentEra` (r:1 w:0)
+	/// Proof: `OcifStaking::CurrentEra` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::CoreStake` (r:1 w:1)
+	/// Proof: `OcifStaking::CoreStake` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::RewardPerStakeIndex` (r:1 w:0)
+	/// Proof: `OcifStaking::RewardPerStakeIndex` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::GeneralStakerInfo` (r:1 w:1)
+	/// Proof: `OcifStaking::GeneralStakerInfo` (`max_values`: None, `max_size`: Some(118), added: 2593, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::GeneralEraInfo` (r:1 w:1)
+	/// Proof: `OcifStaking::GeneralEraInfo` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:1 w:1)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(1249), added: 3724, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:1 w:0)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	fn rebond() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `218`
+		//  Estimated: `4764`
+		// Minimum execution time: 33_000_000 picoseconds.
+		Weight::from_parts(34_000_000, 4764)
+			.saturating_add(RocksDbWeight::get().reads(10_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: `OcifStaking::Halted` (r:1 w:0)
+	/// Proof: `OcifStaking::Halted` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::CurrentEra` (r:1 w:0)
+	/// Proof: `OcifStaking::CurrentEra` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::Ledger` (r:1 w:1)
+	/// Proof: `OcifStaking::Ledger` (`max_values`: None, `max_size`: Some(265), added: 2740, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::RegisteredCore` (r:1 w:0)
+	/// Proof: `OcifStaking::RegisteredCore` (`max_values`: None, `max_size`: Some(477), added: 2952, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::CoreStake` (r:1 w:1)
+	/// Proof: `OcifStaking::CoreStake` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::RewardPerStakeIndex` (r:1 w:0)
+	/// Proof: `OcifStaking::RewardPerStakeIndex` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::GeneralStakerInfo` (r:1 w:1)
+	/// Proof: `OcifStaking::GeneralStakerInfo` (`max_values`: None, `max_size`: Some(118), added: 2593, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::GeneralEraInfo` (r:1 w:1)
+	/// Proof: `OcifStaking::GeneralEraInfo` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:1 w:1)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(1249), added: 3724, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:1 w:0)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn instant_unstake() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `340`
+		//  Estimated: `4764`
+		// Minimum execution time: 45_000_000 picoseconds.
+		Weight::from_parts(47_000_000, 4764)
+			.saturating_add(RocksDbWeight::get().reads(11_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	/// Storage: `OcifStaking::Halted` (r:1 w:0)
+	/// Proof: `OcifStaking::Halted` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::RegisteredCore` (r:1 w:0)
+	/// Proof: `OcifStaking::RegisteredCore` (`max_values`: None, `max_size`: Some(477), added: 2952, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::CoreStake` (r:1 w:1)
+	/// Proof: `OcifStaking::CoreStake` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::RewardPerStakeIndex` (r:1 w:0)
+	/// Proof: `OcifStaking::RewardPerStakeIndex` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::GeneralStakerInfo` (r:2 w:2)
+	/// Proof: `OcifStaking::GeneralStakerInfo` (`max_values`: None, `max_size`: Some(118), added: 2593, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::CurrentEra` (r:1 w:0)
+	/// Proof: `OcifStaking::CurrentEra` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::GeneralEraInfo` (r:1 w:1)
+	/// Proof: `OcifStaking::GeneralEraInfo` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::Ledger` (r:2 w:2)
+	/// Proof: `OcifStaking::Ledger` (`max_values`: None, `max_size`: Some(265), added: 2740, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:2 w:2)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(1249), added: 3724, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:2 w:0)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn transfer_stake() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `412`
+		//  Estimated: `8538`
+		// Minimum execution time: 71_000_000 picoseconds.
+		Weight::from_parts(73_000_000, 8538)
+			.saturating_add(RocksDbWeight::get().reads(16_u64))
+			.saturating_add(RocksDbWeight::get().writes(10_u64))
+	}
+	/// Storage: `OcifStaking::Halted` (r:1 w:0)
+	/// Proof: `OcifStaking::Halted` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::NextPoolId` (r:1 w:1)
+	/// Proof: `OcifStaking::NextPoolId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::Pools` (r:1 w:1)
+	/// Proof: `OcifStaking::Pools` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::CoreStake` (r:1 w:1)
+	/// Proof: `OcifStaking::CoreStake` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::RegisteredCore` (r:1 w:0)
+	/// Proof: `OcifStaking::RegisteredCore` (`max_values`: None, `max_size`: Some(477), added: 2952, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::RewardPerStakeIndex` (r:1 w:0)
+	/// Proof: `OcifStaking::RewardPerStakeIndex` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::GeneralStakerInfo` (r:1 w:1)
+	/// Proof: `OcifStaking::GeneralStakerInfo` (`max_values`: None, `max_size`: Some(118), added: 2593, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::PoolMembers` (r:1 w:1)
+	/// Proof: `OcifStaking::PoolMembers` (`max_values`: None, `max_size`: Some(102), added: 2577, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::Ledger` (r:2 w:2)
+	/// Proof: `OcifStaking::Ledger` (`max_values`: None, `max_size`: Some(265), added: 2740, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:2 w:2)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(1249), added: 3724, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:2 w:0)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::CurrentEra` (r:1 w:0)
+	/// Proof: `OcifStaking::CurrentEra` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::GeneralEraInfo` (r:1 w:1)
+	/// Proof: `OcifStaking::GeneralEraInfo` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
+	fn create_pool() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `276`
+		//  Estimated: `8538`
+		// Minimum execution time: 78_000_000 picoseconds.
+		Weight::from_parts(80_000_000, 8538)
+			.saturating_add(RocksDbWeight::get().reads(19_u64))
+			.saturating_add(RocksDbWeight::get().writes(13_u64))
+	}
+	/// Storage: `OcifStaking::Halted` (r:1 w:0)
+	/// Proof: `OcifStaking::Halted` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::Pools` (r:1 w:1)
+	/// Proof: `OcifStaking::Pools` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::CoreStake` (r:1 w:1)
+	/// Proof: `OcifStaking::CoreStake` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::RegisteredCore` (r:1 w:0)
+	/// Proof: `OcifStaking::RegisteredCore` (`max_values`: None, `max_size`: Some(477), added: 2952, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::RewardPerStakeIndex` (r:1 w:0)
+	/// Proof: `OcifStaking::RewardPerStakeIndex` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::GeneralStakerInfo` (r:1 w:1)
+	/// Proof: `OcifStaking::GeneralStakerInfo` (`max_values`: None, `max_size`: Some(118), added: 2593, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::PoolMembers` (r:1 w:1)
+	/// Proof: `OcifStaking::PoolMembers` (`max_values`: None, `max_size`: Some(102), added: 2577, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::Ledger` (r:2 w:2)
+	/// Proof: `OcifStaking::Ledger` (`max_values`: None, `max_size`: Some(265), added: 2740, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:2 w:2)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(1249), added: 3724, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:2 w:0)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::CurrentEra` (r:1 w:0)
+	/// Proof: `OcifStaking::CurrentEra` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::GeneralEraInfo` (r:1 w:1)
+	/// Proof: `OcifStaking::GeneralEraInfo` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	fn join_pool() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `398`
+		//  Estimated: `8538`
+		// Minimum execution time: 74_000_000 picoseconds.
+		Weight::from_parts(76_000_000, 8538)
+			.saturating_add(RocksDbWeight::get().reads(17_u64))
+			.saturating_add(RocksDbWeight::get().writes(11_u64))
+	}
+	/// Storage: `OcifStaking::Halted` (r:1 w:0)
+	/// Proof: `OcifStaking::Halted` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::Pools` (r:1 w:1)
+	/// Proof: `OcifStaking::Pools` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::CoreStake` (r:1 w:1)
+	/// Proof: `OcifStaking::CoreStake` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::RegisteredCore` (r:1 w:0)
+	/// Proof: `OcifStaking::RegisteredCore` (`max_values`: None, `max_size`: Some(477), added: 2952, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::RewardPerStakeIndex` (r:1 w:0)
+	/// Proof: `OcifStaking::RewardPerStakeIndex` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::GeneralStakerInfo` (r:1 w:1)
+	/// Proof: `OcifStaking::GeneralStakerInfo` (`max_values`: None, `max_size`: Some(118), added: 2593, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::PoolMembers` (r:2 w:1)
+	/// Proof: `OcifStaking::PoolMembers` (`max_values`: None, `max_size`: Some(102), added: 2577, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::Ledger` (r:2 w:2)
+	/// Proof: `OcifStaking::Ledger` (`max_values`: None, `max_size`: Some(265), added: 2740, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:2 w:2)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(1249), added: 3724, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:2 w:0)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::CurrentEra` (r:1 w:0)
+	/// Proof: `OcifStaking::CurrentEra` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::GeneralEraInfo` (r:1 w:1)
+	/// Proof: `OcifStaking::GeneralEraInfo` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
+	fn leave_pool() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `521`
+		//  Estimated: `8538`
+		// Minimum execution time: 76_000_000 picoseconds.
+		Weight::from_parts(78_000_000, 8538)
+			.saturating_add(RocksDbWeight::get().reads(19_u64))
+			.saturating_add(RocksDbWeight::get().writes(12_u64))
+	}
+	/// Storage: `OcifStaking::Halted` (r:1 w:0)
+	/// Proof: `OcifStaking::Halted` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::Pools` (r:1 w:1)
+	/// Proof: `OcifStaking::Pools` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::CoreStake` (r:1 w:1)
+	/// Proof: `OcifStaking::CoreStake` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::RegisteredCore` (r:1 w:0)
+	/// Proof: `OcifStaking::RegisteredCore` (`max_values`: None, `max_size`: Some(477), added: 2952, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::RewardPerStakeIndex` (r:1 w:0)
+	/// Proof: `OcifStaking::RewardPerStakeIndex` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::GeneralStakerInfo` (r:1 w:1)
+	/// Proof: `OcifStaking::GeneralStakerInfo` (`max_values`: None, `max_size`: Some(118), added: 2593, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::PoolMembers` (r:2 w:1)
+	/// Proof: `OcifStaking::PoolMembers` (`max_values`: None, `max_size`: Some(102), added: 2577, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
+	fn claim_pool_rewards() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `482`
+		//  Estimated: `6196`
+		// Minimum execution time: 52_000_000 picoseconds.
+		Weight::from_parts(54_000_000, 6196)
+			.saturating_add(RocksDbWeight::get().reads(11_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
+	}
+	/// Storage: `OcifStaking::RegisteredCore` (r:1 w:0)
+	/// Proof: `OcifStaking::RegisteredCore` (`max_values`: None, `max_size`: Some(477), added: 2952, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::ReceiptTokens` (r:1 w:1)
+	/// Proof: `OcifStaking::ReceiptTokens` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `CoreAssets::TotalIssuance` (r:1 w:0)
+	/// Proof: `CoreAssets::TotalIssuance` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	fn enable_liquid_staking() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `183`
+		//  Estimated: `3942`
+		// Minimum execution time: 12_000_000 picoseconds.
+		Weight::from_parts(13_000_000, 3942)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `OcifStaking::Halted` (r:1 w:0)
+	/// Proof: `OcifStaking::Halted` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::RegisteredCore` (r:1 w:0)
+	/// Proof: `OcifStaking::RegisteredCore` (`max_values`: None, `max_size`: Some(477), added: 2952, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::ReceiptTokens` (r:1 w:0)
+	/// Proof: `OcifStaking::ReceiptTokens` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::CoreStake` (r:1 w:1)
+	/// Proof: `OcifStaking::CoreStake` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::RewardPerStakeIndex` (r:1 w:0)
+	/// Proof: `OcifStaking::RewardPerStakeIndex` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::GeneralStakerInfo` (r:1 w:1)
+	/// Proof: `OcifStaking::GeneralStakerInfo` (`max_values`: None, `max_size`: Some(118), added: 2593, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::Ledger` (r:2 w:2)
+	/// Proof: `OcifStaking::Ledger` (`max_values`: None, `max_size`: Some(265), added: 2740, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:2 w:2)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(1249), added: 3724, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:2 w:0)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:3 w:3)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::CurrentEra` (r:1 w:0)
+	/// Proof: `OcifStaking::CurrentEra` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::GeneralEraInfo` (r:1 w:1)
+	/// Proof: `OcifStaking::GeneralEraInfo` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// Storage: `CoreAssets::Accounts` (r:1 w:1)
+	/// Proof: `CoreAssets::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	/// Storage: `CoreAssets::TotalIssuance` (r:1 w:1)
+	/// Proof: `CoreAssets::TotalIssuance` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	fn liquid_stake() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `455`
+		//  Estimated: `8538`
+		// Minimum execution time: 86_000_000 picoseconds.
+		Weight::from_parts(88_000_000, 8538)
+			.saturating_add(RocksDbWeight::get().reads(19_u64))
+			.saturating_add(RocksDbWeight::get().writes(12_u64))
+	}
+	/// Storage: `OcifStaking::Halted` (r:1 w:0)
+	/// Proof: `OcifStaking::Halted` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::ReceiptTokens` (r:1 w:0)
+	/// Proof: `OcifStaking::ReceiptTokens` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::RegisteredCore` (r:1 w:0)
+	/// Proof: `OcifStaking::RegisteredCore` (`max_values`: None, `max_size`: Some(477), added: 2952, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::CoreStake` (r:1 w:1)
+	/// Proof: `OcifStaking::CoreStake` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::RewardPerStakeIndex` (r:1 w:0)
+	/// Proof: `OcifStaking::RewardPerStakeIndex` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::GeneralStakerInfo` (r:1 w:1)
+	/// Proof: `OcifStaking::GeneralStakerInfo` (`max_values`: None, `max_size`: Some(118), added: 2593, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::Ledger` (r:2 w:2)
+	/// Proof: `OcifStaking::Ledger` (`max_values`: None, `max_size`: Some(265), added: 2740, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:2 w:2)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(1249), added: 3724, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:2 w:0)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:3 w:3)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::CurrentEra` (r:1 w:0)
+	/// Proof: `OcifStaking::CurrentEra` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `OcifStaking::GeneralEraInfo` (r:1 w:1)
+	/// Proof: `OcifStaking::GeneralEraInfo` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// Storage: `CoreAssets::Accounts` (r:1 w:1)
+	/// Proof: `CoreAssets::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	/// Storage: `CoreAssets::TotalIssuance` (r:1 w:1)
+	/// Proof: `CoreAssets::TotalIssuance` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	fn redeem() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `612`
+		//  Estimated: `8538`
+		// Minimum execution time: 90_000_000 picoseconds.
+		Weight::from_parts(92_000_000, 8538)
+			.saturating_add(RocksDbWeight::get().reads(19_u64))
+			.saturating_add(RocksDbWeight::get().writes(12_u64))
 	}
 }
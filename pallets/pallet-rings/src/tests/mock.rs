@@ -18,7 +18,7 @@ use pallet_balances::AccountData;
 use pallet_dao_manager::fee_handling::*;
 use scale_info::TypeInfo;
 use sp_core::H256;
-use sp_runtime::{traits::IdentityLookup, AccountId32, BuildStorage};
+use sp_runtime::{traits::IdentityLookup, AccountId32, BuildStorage, Perbill};
 pub use sp_std::{cell::RefCell, fmt::Debug};
 use sp_std::{convert::TryInto, vec};
 use xcm::latest::prelude::*;
@@ -250,16 +250,16 @@ impl
 {
     fn on_transfer(
         currency_id: <Test as pallet_dao_manager::Config>::DaoId,
-        _from: &AccountId,
+        from: &AccountId,
         _to: &AccountId,
-        _amount: Balance,
+        amount: Balance,
     ) -> sp_runtime::DispatchResult {
         if let Some(true) = dao_manager::is_asset_frozen(currency_id) {
             Err(sp_runtime::DispatchError::Token(
                 sp_runtime::TokenError::Frozen,
             ))
         } else {
-            Ok(())
+            dao_manager::ensure_unlocked(currency_id, from, amount)
         }
     }
 }
@@ -321,6 +321,7 @@ parameter_types! {
     pub const DaoCreationFee: Balance = UNIT;
     pub const StringLimit: u32 = 2125;
     pub const RelayDaoCreationFee: Balance = UNIT;
+    pub const EmergencySupport: Perbill = Perbill::from_percent(80);
 }
 
 pub type AssetId = u32;
@@ -422,6 +423,7 @@ impl MultisigFeeHandler<Test> for FeeCharger {
             match fee_asset {
                 FeeAsset::Native => None,
                 FeeAsset::Relay => Some(1u32),
+                FeeAsset::Asset(asset_id) => Some(*asset_id),
             },
         ))
     }
@@ -437,6 +439,10 @@ impl MultisigFeeHandler<Test> for FeeCharger {
         Ok(())
     }
 
+    fn native_to_asset_fee(_asset_id: u32, _fee: Balance) -> Option<Balance> {
+        None
+    }
+
     fn handle_creation_fee(
         _imbalance: FeeAssetNegativeImbalance<
             <Balances as Currency<AccountId>>::NegativeImbalance,
@@ -448,6 +454,9 @@ impl MultisigFeeHandler<Test> for FeeCharger {
 
 impl pallet_dao_manager::Config for Test {
     type MaxMetadata = MaxMetadata;
+    type MaxNameLength = ConstU32<20>;
+    type MaxDescriptionLength = ConstU32<300>;
+    type MaxUrlLength = ConstU32<100>;
     type DaoId = u32;
     type RuntimeEvent = RuntimeEvent;
     type Currency = Balances;
@@ -467,6 +476,20 @@ impl pallet_dao_manager::Config for Test {
 
     type ParaId = ConstU32<2125>;
     type LengthToFee = ConstantMultiplier<Balance, TransactionByteFee>;
+    type EmergencySupport = EmergencySupport;
+    type EmergencyCalls = Everything;
+    type NftCollectionId = u32;
+    type NftItemId = u32;
+    type Nfts = pallet_dao_manager::voting::NoNfts<u32>;
+    type MaxNftVotingItems = ConstU32<100>;
+    type MaxJointDaos = ConstU32<10>;
+    type MaxHandleLength = ConstU32<32>;
+    type HandleDeposit = frame_support::traits::ConstU128<1000>;
+    type MaxSymbolLength = ConstU32<10>;
+    type MaxVestingSchedules = ConstU32<10>;
+    type MaxCouncilMembers = ConstU32<10>;
+    type PublicProposalDeposit = frame_support::traits::ConstU128<1000>;
+    type PublicProposalDuration = frame_support::traits::ConstU64<100>;
 }
 
 parameter_types! {
@@ -37,9 +37,90 @@ pub enum BoolOrWasm<Wasm> {
     Wasm(Wasm),
 }
 
+/// Strategy used by a DAO to compute the voting power of its members.
+#[derive(Encode, Decode, Clone, Copy, Eq, PartialEq, MaxEncodedLen, Debug, TypeInfo, Default)]
+pub enum VotingStrategy<CollectionId> {
+    /// Voting power equals the voting token balance.
+    #[default]
+    TokenBalance,
+    /// Every voting token holder has a single vote.
+    OneMemberOneVote,
+    /// Voting power equals the square root of the voting token balance.
+    Quadratic,
+    /// Every item held from the NFT collection is one vote.
+    NftItems(CollectionId),
+    /// Every item held from the NFT collection weighs as much as its voting weight attribute.
+    NftAttribute(CollectionId),
+}
+
+/// Structured metadata of a DAO, shared by the DAO manager and the DAO staking pallets.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, MaxEncodedLen, Debug, TypeInfo, Default)]
+pub struct DaoMetadata<Name, Description, Url, Cid> {
+    /// Display name of the DAO.
+    pub name: Name,
+    /// Short description of the DAO.
+    pub description: Description,
+    /// IPFS CID of the DAO logo.
+    pub logo: Option<Cid>,
+    /// Website of the DAO, must be an http(s) URL.
+    pub website: Option<Url>,
+    /// IPFS CID of any additional content, such as a manifesto or a more detailed description.
+    pub ipfs_cid: Option<Cid>,
+}
+
+impl<Name, Description, Url, Cid> DaoMetadata<Name, Description, Url, Cid>
+where
+    Name: AsRef<[u8]>,
+    Description: AsRef<[u8]>,
+    Url: AsRef<[u8]>,
+    Cid: AsRef<[u8]>,
+{
+    /// Checks that the name and description are UTF-8, the website is an http(s) URL
+    /// and the CIDs are made of base32/base58 characters only.
+    pub fn is_valid(&self) -> bool {
+        let is_text = |t: &[u8]| sp_std::str::from_utf8(t).is_ok();
+
+        let is_url = |u: &[u8]| {
+            let rest = u
+                .strip_prefix(b"https://")
+                .or_else(|| u.strip_prefix(b"http://"));
+
+            matches!(rest, Some(rest) if !rest.is_empty() && rest.iter().all(|c| c.is_ascii_graphic()))
+        };
+
+        let is_cid = |c: &[u8]| !c.is_empty() && c.iter().all(|c| c.is_ascii_alphanumeric());
+
+        is_text(self.name.as_ref())
+            && is_text(self.description.as_ref())
+            && self.website.as_ref().map_or(true, |u| is_url(u.as_ref()))
+            && self.logo.as_ref().map_or(true, |c| is_cid(c.as_ref()))
+            && self.ipfs_cid.as_ref().map_or(true, |c| is_cid(c.as_ref()))
+    }
+}
+
+/// Members of the second chamber of a bicameral DAO.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, MaxEncodedLen, Debug, TypeInfo)]
+pub enum ChamberMembers<Members, AssetId> {
+    /// Explicit set of council members, each one with a single vote.
+    Council(Members),
+    /// Holders of a second voting token, voting with their balance.
+    Token(AssetId),
+}
+
+/// Second chamber of a bicameral DAO, which has to approve proposals along with the voting token holders.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, MaxEncodedLen, Debug, TypeInfo)]
+pub struct Chamber<Members, AssetId> {
+    /// Who votes in the chamber.
+    pub members: ChamberMembers<Members, AssetId>,
+    /// Minimum amount of positive votes out of the chamber's total voting power required to approve a proposal.
+    pub minimum_support: Perbill,
+    /// Minimum amount of positive votes out of the chamber's positive + negative votes required to approve a proposal.
+    pub required_approval: Perbill,
+}
+
 /// DAO IP Set struct
 #[derive(Encode, Decode, Clone, Eq, PartialEq, MaxEncodedLen, Debug, TypeInfo)]
-pub struct DaoInfo<AccountId, DaoMetadataOf> {
+pub struct DaoInfo<AccountId, DaoMetadataOf, CollectionId, ChamberOf> {
     /// IPS parentage
     pub account: AccountId,
     /// IPS metadata
@@ -52,6 +133,12 @@ pub struct DaoInfo<AccountId, DaoMetadataOf> {
     pub required_approval: Perbill,
 
     pub frozen_tokens: bool,
+
+    /// How the voting power of the members is computed.
+    pub voting_strategy: VotingStrategy<CollectionId>,
+
+    /// Second chamber whose approval proposals also require, if the dao is bicameral.
+    pub second_chamber: Option<ChamberOf>,
 }
 
 /// IPF Info
@@ -2,9 +2,10 @@ use crate::{
     assets::{RelayAssetId, KSM_ASSET_ID},
     common_types::{AssetId, CommonId},
     constants::currency::UNIT,
-    fee_handling::DealWithKSMFees,
-    AccountId, Balance, Balances, CoreAssets, DealWithFees, ParachainInfo, Runtime, RuntimeCall,
-    RuntimeEvent, RuntimeOrigin, Tokens, TransactionByteFee,
+    fee_handling::{DealWithAssetFees, DealWithKSMFees, NativeToAsset, RegisteredAssetFees},
+    AccountId, Balance, Balances, BlockNumber, CoreAssets, DealWithFees, ParachainInfo, Runtime,
+    RuntimeCall, RuntimeEvent, RuntimeOrigin, Tokens, TransactionByteFee, TransactionPayment,
+    Uniques, DAYS,
 };
 use codec::{Decode, Encode};
 use frame_support::{
@@ -12,14 +13,20 @@ use frame_support::{
     traits::{fungibles::Credit, Contains, Currency, OnUnbalanced},
     weights::ConstantMultiplier,
 };
-use pallet_asset_tx_payment::ChargeAssetTxPayment;
+use pallet_asset_tx_payment::{ChargeAssetTxPayment, InitialPayment};
 use pallet_dao_manager::fee_handling::{FeeAsset, FeeAssetNegativeImbalance, MultisigFeeHandler};
 use scale_info::TypeInfo;
 use sp_core::ConstU32;
-use sp_runtime::traits::{One, SignedExtension, Zero};
+use sp_runtime::{
+    traits::{One, SignedExtension, Zero},
+    Perbill,
+};
 
 parameter_types! {
     pub const MaxMetadata: u32 = 10000;
+    pub const MaxNameLength: u32 = 20;
+    pub const MaxDescriptionLength: u32 = 300;
+    pub const MaxUrlLength: u32 = 100;
     pub const MaxCallers: u32 = 10000;
     pub const DaoSeedBalance: Balance = 1000000u128;
     pub const DaoCreationFee: Balance = UNIT * 100;
@@ -28,10 +35,34 @@ parameter_types! {
     pub const MaxCallSize: u32 = 50 * 1024;
 
     pub ParaId: u32 = ParachainInfo::parachain_id().into();
+
+    pub const EmergencySupport: Perbill = Perbill::from_percent(80);
+    /// Pallets whose calls can all be executed through emergency proposals, to move the DAO's funds.
+    pub const EmergencyFundsPallets: &'static [&'static str] = &["Balances", "Tokens"];
+
+    pub const MaxJointDaos: u32 = 10;
+    pub const MaxNftVotingItems: u32 = 1000;
+
+    pub const MaxHandleLength: u32 = 32;
+    pub const HandleDeposit: Balance = UNIT * 10;
+
+    pub const MaxSymbolLength: u32 = 10;
+    pub const MaxVestingSchedules: u32 = 10;
+    pub const MaxCouncilMembers: u32 = 20;
+
+    pub const PublicProposalDeposit: Balance = UNIT * 100;
+    pub const PublicProposalDuration: BlockNumber = DAYS * 14;
 }
 
+/// Length a signed extrinsic adds to its call when estimating fees: 4 bytes for the length prefix, 1 for the version,
+/// 33 for the signer, 66 for the longest signature and 9 for the mortal era, nonce, tip and metadata hash mode and 5 for the fee asset id.
+pub const SIGNED_EXTRINSIC_OVERHEAD: u32 = 118;
+
 impl pallet_dao_manager::Config for Runtime {
     type MaxMetadata = MaxMetadata;
+    type MaxNameLength = MaxNameLength;
+    type MaxDescriptionLength = MaxDescriptionLength;
+    type MaxUrlLength = MaxUrlLength;
     type DaoId = CommonId;
     type RuntimeEvent = RuntimeEvent;
     type Currency = Balances;
@@ -52,6 +83,26 @@ impl pallet_dao_manager::Config for Runtime {
 
     type ParaId = ParaId;
     type LengthToFee = ConstantMultiplier<Balance, TransactionByteFee>;
+
+    type EmergencySupport = EmergencySupport;
+    type EmergencyCalls =
+        pallet_dao_manager::multisig::EmergencyCalls<Runtime, EmergencyFundsPallets>;
+
+    type NftCollectionId = CommonId;
+    type NftItemId = CommonId;
+    type Nfts = Uniques;
+
+    type MaxNftVotingItems = MaxNftVotingItems;
+    type MaxJointDaos = MaxJointDaos;
+
+    type MaxHandleLength = MaxHandleLength;
+    type HandleDeposit = HandleDeposit;
+
+    type MaxSymbolLength = MaxSymbolLength;
+    type MaxVestingSchedules = MaxVestingSchedules;
+    type MaxCouncilMembers = MaxCouncilMembers;
+    type PublicProposalDeposit = PublicProposalDeposit;
+    type PublicProposalDuration = PublicProposalDuration;
 }
 
 #[derive(Encode, Decode, Clone, Eq, PartialEq, TypeInfo, Debug)]
@@ -84,6 +135,21 @@ impl MultisigFeeHandler<Runtime> for FeeCharger {
                 ChargeAssetTxPayment::<Runtime>::from(Zero::zero(), Some(KSM_ASSET_ID))
                     .pre_dispatch(who, call, info, len)
             }
+
+            // Registered assets other than KSM can't pay transaction fees,
+            // so proposal fees are withdrawn directly at the asset's rate.
+            FeeAsset::Asset(asset_id) => {
+                let fee = TransactionPayment::compute_fee(len as u32, info, Zero::zero());
+
+                let paid = RegisteredAssetFees::withdraw_fee(who, *asset_id, fee)?;
+
+                Ok((
+                    Zero::zero(),
+                    who.clone(),
+                    InitialPayment::Asset(paid),
+                    Some(*asset_id),
+                ))
+            }
         }
     }
 
@@ -103,9 +169,29 @@ impl MultisigFeeHandler<Runtime> for FeeCharger {
             FeeAsset::Relay => {
                 ChargeAssetTxPayment::<Runtime>::post_dispatch(pre, info, post_info, len, result)
             }
+
+            FeeAsset::Asset(_) => {
+                if let Some((_, who, InitialPayment::Asset(paid), _)) = pre {
+                    let fee = TransactionPayment::compute_fee(len as u32, info, Zero::zero());
+                    let corrected_fee = TransactionPayment::compute_actual_fee(
+                        len as u32,
+                        info,
+                        post_info,
+                        Zero::zero(),
+                    );
+
+                    RegisteredAssetFees::correct_and_deposit_fee(&who, fee, corrected_fee, paid);
+                }
+
+                Ok(())
+            }
         }
     }
 
+    fn native_to_asset_fee(asset_id: u32, fee: Balance) -> Option<Balance> {
+        NativeToAsset::to_asset_fee(asset_id, fee)
+    }
+
     fn handle_creation_fee(
         imbalance: FeeAssetNegativeImbalance<
             <Balances as Currency<AccountId>>::NegativeImbalance,
@@ -116,6 +202,8 @@ impl MultisigFeeHandler<Runtime> for FeeCharger {
             FeeAssetNegativeImbalance::Native(imb) => DealWithFees::on_unbalanced(imb),
 
             FeeAssetNegativeImbalance::Relay(imb) => DealWithKSMFees::on_unbalanced(imb),
+
+            FeeAssetNegativeImbalance::Asset(imb) => DealWithAssetFees::on_unbalanced(imb),
         }
     }
 }
@@ -143,20 +231,39 @@ impl
 {
     fn on_transfer(
         currency_id: <Runtime as pallet_dao_manager::Config>::DaoId,
-        _from: &AccountId,
+        from: &AccountId,
         _to: &AccountId,
-        _amount: Balance,
+        amount: Balance,
     ) -> sp_runtime::DispatchResult {
         if let Some(true) = crate::INV4::is_asset_frozen(currency_id) {
             Err(sp_runtime::DispatchError::Token(
                 sp_runtime::TokenError::Frozen,
             ))
         } else {
-            Ok(())
+            crate::INV4::ensure_unlocked(currency_id, from, amount)
         }
     }
 }
 
+pub struct UpdateQuadraticVotingPower;
+impl
+    orml_traits2::currency::OnTransfer<
+        AccountId,
+        <Runtime as pallet_dao_manager::Config>::DaoId,
+        Balance,
+    > for UpdateQuadraticVotingPower
+{
+    fn on_transfer(
+        currency_id: <Runtime as pallet_dao_manager::Config>::DaoId,
+        from: &AccountId,
+        to: &AccountId,
+        amount: Balance,
+    ) -> sp_runtime::DispatchResult {
+        crate::INV4::on_voting_token_transfer(currency_id, from, to, amount);
+        Ok(())
+    }
+}
+
 pub struct HandleNewMembers;
 impl orml_traits2::Happened<(AccountId, <Runtime as pallet_dao_manager::Config>::DaoId)>
     for HandleNewMembers
@@ -188,7 +295,7 @@ impl
     type OnSlash = ();
     type PreDeposit = ();
     type PostDeposit = ();
-    type PostTransfer = ();
+    type PostTransfer = UpdateQuadraticVotingPower;
     type OnNewTokenAccount = HandleNewMembers;
     type OnKilledTokenAccount = HandleRemovedMembers;
 }
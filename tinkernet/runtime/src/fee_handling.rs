@@ -1,22 +1,26 @@
 use crate::{
-    assets::RelayAssetId,
+    assets::KSM_ASSET_ID,
     common_types::AssetId,
     constants::{StakingPotAccount, TreasuryAccount},
-    AccountId, Balance, Runtime, RuntimeCall, RuntimeEvent, Tokens,
+    AccountId, AssetRegistry, Balance, Runtime, RuntimeCall, RuntimeEvent, Tokens, WeightToFee,
 };
 use codec::{Decode, Encode};
-use frame_support::traits::{
-    fungible::Inspect as FungibleInspect,
-    fungibles::{Balanced, Credit},
-    tokens::{Fortitude, Precision, Preservation, WithdrawConsequence},
-    Contains, OnUnbalanced,
+use frame_support::{
+    traits::{
+        fungibles::{Balanced, Credit, Inspect},
+        tokens::{Fortitude, Precision, Preservation, WithdrawConsequence},
+        Contains, OnUnbalanced,
+    },
+    weights::{constants::WEIGHT_REF_TIME_PER_SECOND, Weight, WeightToFee as WeightToFeeT},
 };
-use orml_tokens::CurrencyAdapter;
+use orml_traits::asset_registry::Inspect as AssetRegistryInspect;
 use pallet_asset_tx_payment::OnChargeAssetTransaction;
 use scale_info::TypeInfo;
 use sp_runtime::{
+    helpers_128bit::multiply_by_rational_with_rounding,
     traits::{DispatchInfoOf, One, PostDispatchInfoOf, Zero},
     transaction_validity::{InvalidTransaction, TransactionValidityError},
+    Rounding,
 };
 
 pub struct KSMEnabledPallets;
@@ -47,6 +51,104 @@ impl TnkrToKsm {
     pub fn to_asset_balance(balance: Balance) -> Balance {
         balance.saturating_div(20u128)
     }
+
+    /// Converts a fee, making sure non zero fees are at least one unit of the asset.
+    pub fn to_asset_fee(fee: Balance) -> Balance {
+        let min_converted_fee = if fee.is_zero() {
+            Zero::zero()
+        } else {
+            One::one()
+        };
+
+        Self::to_asset_balance(fee).max(min_converted_fee)
+    }
+}
+
+pub struct NativeToAsset;
+impl NativeToAsset {
+    /// Converts a fee in TNKR into a registered asset.
+    ///
+    /// KSM keeps its fixed rate, other assets are converted using the `fee_per_second`
+    /// set in their asset registry metadata, the same rate used for buying XCM execution.
+    /// Returns `None` if the asset has no rate set.
+    pub fn to_asset_fee(asset_id: AssetId, fee: Balance) -> Option<Balance> {
+        if asset_id == KSM_ASSET_ID {
+            return Some(TnkrToKsm::to_asset_fee(fee));
+        }
+
+        let fee_per_second = <AssetRegistry as AssetRegistryInspect>::metadata(&asset_id)?
+            .additional
+            .fee_per_second?;
+
+        let native_fee_per_second =
+            WeightToFee::weight_to_fee(&Weight::from_parts(WEIGHT_REF_TIME_PER_SECOND, 0));
+
+        if native_fee_per_second.is_zero() {
+            return None;
+        }
+
+        // Rounding up makes sure non zero fees are at least one unit of the asset.
+        multiply_by_rational_with_rounding(fee, fee_per_second, native_fee_per_second, Rounding::Up)
+    }
+}
+
+/// Charges the fees of dao proposals paid in a registered asset other than KSM.
+///
+/// The fee is converted with [`NativeToAsset`] when it's withdrawn, and the refund after dispatch
+/// keeps the rate of the amount paid.
+pub struct RegisteredAssetFees;
+impl RegisteredAssetFees {
+    /// Withdraws `fee`, given in TNKR, from `who` in the asset `asset_id`.
+    pub fn withdraw_fee(
+        who: &AccountId,
+        asset_id: AssetId,
+        fee: Balance,
+    ) -> Result<Credit<AccountId, Tokens>, TransactionValidityError> {
+        let fee = NativeToAsset::to_asset_fee(asset_id, fee)
+            .ok_or(TransactionValidityError::from(InvalidTransaction::Payment))?;
+
+        let can_withdraw = <Tokens as Inspect<AccountId>>::can_withdraw(asset_id, who, fee);
+
+        if !matches!(can_withdraw, WithdrawConsequence::Success) {
+            return Err(InvalidTransaction::Payment.into());
+        }
+
+        <Tokens as Balanced<AccountId>>::withdraw(
+            asset_id,
+            who,
+            fee,
+            Precision::Exact,
+            Preservation::Expendable,
+            Fortitude::Force,
+        )
+        .map_err(|_| TransactionValidityError::from(InvalidTransaction::Payment))
+    }
+
+    /// Refunds the part of `paid` that exceeds `corrected_fee`, both fees being in TNKR.
+    pub fn correct_and_deposit_fee(
+        who: &AccountId,
+        fee: Balance,
+        corrected_fee: Balance,
+        paid: Credit<AccountId, Tokens>,
+    ) {
+        let corrected_fee = if fee.is_zero() {
+            Zero::zero()
+        } else {
+            multiply_by_rational_with_rounding(
+                paid.peek(),
+                corrected_fee.min(fee),
+                fee,
+                Rounding::Up,
+            )
+            .unwrap_or(paid.peek())
+        };
+
+        let (final_fee, refund) = paid.split(corrected_fee);
+
+        let _ = <Tokens as Balanced<AccountId>>::resolve(who, refund);
+
+        DealWithAssetFees::on_unbalanced(final_fee);
+    }
 }
 
 pub struct FilteredTransactionCharger;
@@ -63,16 +165,11 @@ impl OnChargeAssetTransaction<Runtime> for FilteredTransactionCharger {
         fee: Balance,
         _tip: Balance,
     ) -> Result<Credit<AccountId, Tokens>, frame_support::unsigned::TransactionValidityError> {
-        if KSMEnabledPallets::contains(call) && asset_id == 1u32 {
-            let min_converted_fee = if fee.is_zero() {
-                Zero::zero()
-            } else {
-                One::one()
-            };
-
-            let fee = TnkrToKsm::to_asset_balance(fee).max(min_converted_fee);
+        // Transaction fees can only be paid in KSM, registered assets only pay for dao proposals.
+        if KSMEnabledPallets::contains(call) && asset_id == KSM_ASSET_ID {
+            let fee = TnkrToKsm::to_asset_fee(fee);
 
-            let can_withdraw = CurrencyAdapter::<Runtime, RelayAssetId>::can_withdraw(who, fee);
+            let can_withdraw = <Tokens as Inspect<AccountId>>::can_withdraw(asset_id, who, fee);
 
             if !matches!(can_withdraw, WithdrawConsequence::Success) {
                 return Err(InvalidTransaction::Payment.into());
@@ -100,13 +197,7 @@ impl OnChargeAssetTransaction<Runtime> for FilteredTransactionCharger {
         _tip: Balance,
         paid: Credit<AccountId, Tokens>,
     ) -> Result<(u128, u128), TransactionValidityError> {
-        let min_converted_fee = if corrected_fee.is_zero() {
-            Zero::zero()
-        } else {
-            One::one()
-        };
-
-        let corrected_fee = TnkrToKsm::to_asset_balance(corrected_fee).max(min_converted_fee);
+        let corrected_fee = TnkrToKsm::to_asset_fee(corrected_fee);
 
         let (final_fee, refund) = paid.split(corrected_fee);
 
@@ -142,6 +233,16 @@ impl OnUnbalanced<Credit<AccountId, Tokens>> for DealWithKSMFees {
     }
 }
 
+/// Sends fees paid in registered assets other than KSM to the treasury.
+///
+/// The treasury is whitelisted from dust removal, so fees below the asset's existential deposit aren't lost.
+pub struct DealWithAssetFees;
+impl OnUnbalanced<Credit<AccountId, Tokens>> for DealWithAssetFees {
+    fn on_unbalanced(amount: Credit<AccountId, Tokens>) {
+        let _ = <Tokens as Balanced<AccountId>>::resolve(&TreasuryAccount::get(), amount);
+    }
+}
+
 #[derive(Encode, Decode, Clone, Eq, PartialEq, TypeInfo)]
 pub struct ChargerExtra {
     #[codec(compact)]
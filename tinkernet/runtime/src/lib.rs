@@ -101,7 +101,7 @@ mod common_types;
 use common_types::*;
 mod assets;
 mod fee_handling;
-use fee_handling::TnkrToKsm;
+use fee_handling::{NativeToAsset, TnkrToKsm};
 mod dao_manager;
 mod inflation;
 // mod migrations;
@@ -178,6 +178,14 @@ pub type Executive = frame_executive::Executive<
         pallet_collator_selection::migration::v1::MigrateToV1<Runtime>,
         cumulus_pallet_xcmp_queue::migration::v4::MigrationToV4<Runtime>,
         pallet_xcm::migration::v1::MigrateToV1<Runtime>,
+        pallet_dao_manager::migrations::v3::MigrateToV3<Runtime>,
+        pallet_dao_manager::migrations::v4::MigrateToV4<Runtime>,
+        pallet_dao_manager::migrations::v5::MigrateToV5<Runtime>,
+        pallet_dao_manager::migrations::v6::MigrateToV6<Runtime>,
+        pallet_dao_manager::migrations::v7::MigrateToV7<Runtime>,
+        pallet_dao_staking::migrations::v2::MigrateToV2<Runtime>,
+        pallet_dao_staking::migrations::v3::MigrateToV3<Runtime>,
+        pallet_dao_staking::migrations::v4::MigrateToV4<Runtime>,
     ),
 >;
 
@@ -1060,6 +1068,55 @@ impl_runtime_apis! {
             }
     }
 
+    impl pallet_dao_manager::dry_run::DaoManagerApi<Block, common_types::CommonId, AccountId, RuntimeCall, RuntimeEvent> for Runtime {
+        fn dry_run_proposal(
+            dao_id: common_types::CommonId,
+            call: RuntimeCall,
+            fee_asset: pallet_dao_manager::fee_handling::FeeAsset,
+            fee_payer: Option<AccountId>,
+        ) -> pallet_dao_manager::dry_run::ProposalDryRunEffects<RuntimeEvent> {
+            INV4::dry_run_proposal(
+                dao_id,
+                call,
+                fee_asset,
+                fee_payer,
+                <xcm_config::XcmRouter as xcm_builder::InspectMessageQueues>::get_messages,
+            )
+        }
+    }
+
+    impl pallet_dao_manager::fee_handling::DaoManagerFeeApi<Block, common_types::CommonId, Balance, RuntimeCall> for Runtime {
+        fn estimate_operate_multisig_fees(
+            dao_id: common_types::CommonId,
+            metadata_len: u32,
+            call: RuntimeCall,
+            fee_asset: pallet_dao_manager::fee_handling::FeeAsset,
+        ) -> Option<pallet_dao_manager::fee_handling::OperateMultisigFees<Balance>> {
+            let mut fees = INV4::estimate_operate_multisig_fees(
+                dao_id,
+                metadata_len,
+                call,
+                fee_asset.clone(),
+                dao_manager::SIGNED_EXTRINSIC_OVERHEAD,
+                |len, info| TransactionPayment::compute_fee(len, info, 0),
+            );
+
+            fees.call_fee = match fee_asset {
+                pallet_dao_manager::fee_handling::FeeAsset::Native => fees.call_fee,
+                pallet_dao_manager::fee_handling::FeeAsset::Relay => TnkrToKsm::to_asset_fee(fees.call_fee),
+                pallet_dao_manager::fee_handling::FeeAsset::Asset(asset_id) => NativeToAsset::to_asset_fee(asset_id, fees.call_fee)?,
+            };
+
+            Some(fees)
+        }
+    }
+
+    impl pallet_dao_manager::token::DaoTokenApi<Block, common_types::CommonId, Balance> for Runtime {
+        fn token_info(dao_id: common_types::CommonId) -> Option<pallet_dao_manager::token::DaoTokenInfo<Balance>> {
+            INV4::token_info(dao_id)
+        }
+    }
+
     impl cumulus_primitives_core::CollectCollationInfo<Block> for Runtime {
         fn collect_collation_info(header: &<Block as BlockT>::Header) -> cumulus_primitives_core::CollationInfo {
             ParachainSystem::collect_collation_info(header)
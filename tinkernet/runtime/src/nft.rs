@@ -3,7 +3,10 @@ use crate::{
     constants::currency::{MILLIUNIT, UNIT},
     AccountId, Balance, Balances, Runtime, RuntimeEvent,
 };
-use frame_support::{parameter_types, traits::AsEnsureOriginWithArg};
+use frame_support::{
+    parameter_types,
+    traits::{AsEnsureOriginWithArg, Locker},
+};
 
 use frame_system::{EnsureRoot, EnsureSigned};
 
@@ -25,7 +28,7 @@ impl pallet_uniques::Config for Runtime {
     type Currency = Balances;
     type ForceOrigin = EnsureRoot<AccountId>;
     type CreateOrigin = AsEnsureOriginWithArg<EnsureSigned<AccountId>>;
-    type Locker = ();
+    type Locker = DisallowIfFrozen;
     type CollectionDeposit = CollectionDeposit;
     type ItemDeposit = ItemDeposit;
     type MetadataDepositBase = UniquesMetadataDepositBase;
@@ -36,3 +39,11 @@ impl pallet_uniques::Config for Runtime {
     type ValueLimit = ValueLimit;
     type WeightInfo = pallet_uniques::weights::SubstrateWeight<Runtime>;
 }
+
+/// Items of collections used for voting by a DAO can't be transferred while the DAO's tokens are frozen.
+pub struct DisallowIfFrozen;
+impl Locker<CommonId, CommonId> for DisallowIfFrozen {
+    fn is_locked(collection: CommonId, _item: CommonId) -> bool {
+        crate::INV4::is_nft_collection_frozen(collection)
+    }
+}
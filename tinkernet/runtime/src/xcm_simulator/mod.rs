@@ -233,4 +233,194 @@ mod tests {
             assert_eq!(parachain::Balances::free_balance(BOB), 10_000000000000u128);
         });
     }
+
+    fn mirrored_token_mint_xcm(amount: u128) -> Xcm<()> {
+        let call = parachain::RuntimeCall::INV4(pallet_dao_manager::Call::token_mint {
+            amount,
+            target: BOB,
+        });
+
+        Xcm(vec![Transact {
+            origin_kind: OriginKind::Native,
+            require_weight_at_most: Weight::from_parts(1_000_000_000, 1024 * 1024),
+            call: call.encode().into(),
+        }])
+    }
+
+    #[test]
+    fn mirrored_dao_origin_works() {
+        MockNet::reset();
+
+        ParaB::execute_with(|| {
+            assert_ok!(INV4::create_dao(
+                parachain::RuntimeOrigin::signed(ALICE),
+                Default::default(),
+                sp_runtime::Perbill::from_percent(100),
+                sp_runtime::Perbill::from_percent(100),
+                pallet_dao_manager::fee_handling::FeeAsset::Native,
+            ));
+
+            // DAO 0 in ParaB lets DAO 0 in Tinkernet operate it.
+            assert_ok!(INV4::set_mirror(
+                pallet_dao_manager::Origin::<parachain::Runtime>::Multisig(
+                    pallet_dao_manager::origin::MultisigInternalOrigin::<parachain::Runtime>::new(
+                        0
+                    )
+                )
+                .into(),
+                Some((2125, 0))
+            ));
+        });
+
+        Tinkernet::execute_with(|| {
+            // DAO 1 isn't mirrored, so its transact can't be converted.
+            assert_ok!(parachain::PolkadotXcm::send_xcm(
+                Plurality {
+                    id: BodyId::Index(1),
+                    part: BodyPart::Voice,
+                },
+                MultiLocation::new(1, X1(Parachain(1000))),
+                mirrored_token_mint_xcm(500),
+            ));
+
+            assert_ok!(parachain::PolkadotXcm::send_xcm(
+                Plurality {
+                    id: BodyId::Index(0),
+                    part: BodyPart::Voice,
+                },
+                MultiLocation::new(1, X1(Parachain(1000))),
+                mirrored_token_mint_xcm(1000),
+            ));
+        });
+
+        ParaB::execute_with(|| {
+            assert_eq!(parachain::CoreAssets::accounts(BOB, 0).free, 1000);
+        });
+    }
+
+    #[test]
+    fn mirrored_dao_origin_requires_trusted_sibling() {
+        MockNet::reset();
+
+        Tinkernet::execute_with(|| {
+            assert_ok!(INV4::create_dao(
+                parachain::RuntimeOrigin::signed(ALICE),
+                Default::default(),
+                sp_runtime::Perbill::from_percent(100),
+                sp_runtime::Perbill::from_percent(100),
+                pallet_dao_manager::fee_handling::FeeAsset::Native,
+            ));
+
+            // DAO 0 in Tinkernet mirrors DAO 0 in ParaB, which isn't a trusted sibling.
+            assert_ok!(INV4::set_mirror(
+                pallet_dao_manager::Origin::<parachain::Runtime>::Multisig(
+                    pallet_dao_manager::origin::MultisigInternalOrigin::<parachain::Runtime>::new(
+                        0
+                    )
+                )
+                .into(),
+                Some((1000, 0))
+            ));
+        });
+
+        ParaB::execute_with(|| {
+            assert_ok!(parachain::PolkadotXcm::send_xcm(
+                Plurality {
+                    id: BodyId::Index(0),
+                    part: BodyPart::Voice,
+                },
+                MultiLocation::new(1, X1(Parachain(2125))),
+                mirrored_token_mint_xcm(1000),
+            ));
+        });
+
+        Tinkernet::execute_with(|| {
+            assert_eq!(parachain::CoreAssets::accounts(BOB, 0).free, 0);
+        });
+    }
+
+    #[test]
+    fn proposal_fee_can_be_paid_in_registered_asset() {
+        use crate::{
+            assets::CustomAssetMetadata, constants::TreasuryAccount, fee_handling::NativeToAsset,
+            AssetRegistry, RuntimeCall, RuntimeOrigin, Tokens, TransactionPayment,
+        };
+        use frame_support::{dispatch::GetDispatchInfo, BoundedVec};
+        use orml_traits::MultiCurrency;
+        use pallet_dao_manager::{fee_handling::FeeAsset, DaoAccountDerivation};
+
+        // Asset ids are sequential, so KSM is registered first.
+        const USDT_ASSET_ID: u32 = 2;
+
+        _tinkernet_ext(2125).execute_with(|| {
+            for (symbol, fee_per_second) in [
+                (b"KSM".to_vec(), None),
+                (b"USDT".to_vec(), Some(10_000_000)),
+            ] {
+                assert_ok!(AssetRegistry::register_asset(
+                    RuntimeOrigin::root(),
+                    orml_asset_registry::AssetMetadata {
+                        decimals: 6,
+                        name: BoundedVec::truncate_from(symbol.clone()),
+                        symbol: BoundedVec::truncate_from(symbol),
+                        existential_deposit: 1,
+                        location: None,
+                        additional: CustomAssetMetadata { fee_per_second },
+                    },
+                    None,
+                ));
+            }
+
+            assert_ok!(crate::INV4::create_dao(
+                RuntimeOrigin::signed(ALICE),
+                Default::default(),
+                sp_runtime::Perbill::from_percent(100),
+                sp_runtime::Perbill::from_percent(100),
+                FeeAsset::Native,
+            ));
+
+            let dao_account =
+                <crate::INV4 as DaoAccountDerivation<crate::Runtime>>::derive_dao_account(0);
+            assert_ok!(Tokens::deposit(USDT_ASSET_ID, &dao_account, 1_000_000_000));
+
+            let call = RuntimeCall::System(frame_system::Call::remark_with_event {
+                remark: b"paid in USDT".to_vec(),
+            });
+            let fee = NativeToAsset::to_asset_fee(
+                USDT_ASSET_ID,
+                TransactionPayment::compute_fee(
+                    call.encode().len() as u32,
+                    &call.get_dispatch_info(),
+                    0,
+                ),
+            )
+            .unwrap();
+            assert!(fee > 0);
+
+            // ALICE holds all the voting power, so the proposal is executed right away.
+            assert_ok!(crate::INV4::operate_multisig(
+                RuntimeOrigin::signed(ALICE),
+                0,
+                None,
+                FeeAsset::Asset(USDT_ASSET_ID),
+                Box::new(call),
+            ));
+
+            assert!(crate::System::events().iter().any(|record| matches!(
+                record.event,
+                crate::RuntimeEvent::INV4(pallet_dao_manager::Event::MultisigExecuted {
+                    result: Ok(()),
+                    ..
+                })
+            )));
+            assert_eq!(
+                Tokens::free_balance(USDT_ASSET_ID, &dao_account),
+                1_000_000_000 - fee
+            );
+            assert_eq!(
+                Tokens::free_balance(USDT_ASSET_ID, &TreasuryAccount::get()),
+                fee
+            );
+        });
+    }
 }
@@ -1,7 +1,7 @@
 use codec::{Compact, Decode, Encode};
 use frame_support::{
     construct_runtime, parameter_types,
-    traits::{fungibles::Credit, Currency, Everything, EverythingBut, Nothing},
+    traits::{fungibles::Credit, Contains, Currency, Everything, EverythingBut, Nothing},
     weights::{constants::WEIGHT_REF_TIME_PER_SECOND, ConstantMultiplier, Weight},
 };
 use frame_system::EnsureRoot;
@@ -176,8 +176,21 @@ pub type LocationToAccountId = (
     HashedDescription,
 );
 
+/// Only DAOs from Tinkernet can operate their mirrors in the mocked chains, which are all siblings.
+pub struct TrustedDaoSiblings;
+impl Contains<xcm::v4::Location> for TrustedDaoSiblings {
+    fn contains(location: &xcm::v4::Location) -> bool {
+        matches!(location.unpack(), (1, [xcm::v4::Junction::Parachain(2125)]))
+    }
+}
+
 pub type XcmOriginToCallOrigin = (
     SovereignSignedViaLocation<LocationToAccountId, RuntimeOrigin>,
+    pallet_dao_manager::xcm_origin::DaoPluralityAsMirroredOrigin<
+        TrustedDaoSiblings,
+        Runtime,
+        RuntimeOrigin,
+    >,
     ParentAsSuperuser<RuntimeOrigin>,
     SignedAccountId32AsNative<RelayNetwork, RuntimeOrigin>,
     XcmPassthrough<RuntimeOrigin>,
@@ -445,6 +458,10 @@ impl pallet_dao_manager::fee_handling::MultisigFeeHandler<Runtime> for FeeCharge
         Ok(())
     }
 
+    fn native_to_asset_fee(_asset_id: u32, _fee: Balance) -> Option<Balance> {
+        None
+    }
+
     fn handle_creation_fee(
         _imbalance: pallet_dao_manager::fee_handling::FeeAssetNegativeImbalance<
             <Balances as Currency<AccountId>>::NegativeImbalance,
@@ -461,6 +478,9 @@ parameter_types! {
 
 impl pallet_dao_manager::Config for Runtime {
     type MaxMetadata = crate::dao_manager::MaxMetadata;
+    type MaxNameLength = crate::dao_manager::MaxNameLength;
+    type MaxDescriptionLength = crate::dao_manager::MaxDescriptionLength;
+    type MaxUrlLength = crate::dao_manager::MaxUrlLength;
     type DaoId = crate::common_types::CommonId;
     type RuntimeEvent = RuntimeEvent;
     type Currency = Balances;
@@ -481,6 +501,25 @@ impl pallet_dao_manager::Config for Runtime {
 
     type ParaId = PID;
     type LengthToFee = ConstantMultiplier<Balance, TransactionByteFee>;
+
+    type EmergencySupport = crate::dao_manager::EmergencySupport;
+    type EmergencyCalls = pallet_dao_manager::multisig::EmergencyCalls<
+        Runtime,
+        crate::dao_manager::EmergencyFundsPallets,
+    >;
+
+    type NftCollectionId = crate::common_types::CommonId;
+    type NftItemId = u32;
+    type Nfts = pallet_dao_manager::voting::NoNfts<crate::common_types::CommonId>;
+    type MaxNftVotingItems = crate::dao_manager::MaxNftVotingItems;
+    type MaxJointDaos = crate::dao_manager::MaxJointDaos;
+    type MaxHandleLength = crate::dao_manager::MaxHandleLength;
+    type HandleDeposit = crate::dao_manager::HandleDeposit;
+    type MaxSymbolLength = crate::dao_manager::MaxSymbolLength;
+    type MaxVestingSchedules = crate::dao_manager::MaxVestingSchedules;
+    type MaxCouncilMembers = crate::dao_manager::MaxCouncilMembers;
+    type PublicProposalDeposit = crate::dao_manager::PublicProposalDeposit;
+    type PublicProposalDuration = crate::dao_manager::PublicProposalDuration;
 }
 
 impl orml_tokens::Config for Runtime {